@@ -0,0 +1,83 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Points at a directory holding the topojson files to bundle, such as the `dist/topojson/`
+// directory of the plotly.js package. Required by the `topojson` feature.
+const TOPOJSON_DIR_ENV: &str = "PLOTLY_TOPOJSON_DIR";
+
+// The scopes and resolutions of `LayoutGeo`, which name the topojson files as
+// "<scope>_<resolution>m".
+const SCOPES: [&str; 7] = [
+    "world",
+    "usa",
+    "europe",
+    "asia",
+    "africa",
+    "north-america",
+    "south-america",
+];
+const RESOLUTIONS: [u8; 2] = [110, 50];
+
+fn names() -> impl Iterator<Item = String> {
+    SCOPES.iter().flat_map(|scope| {
+        RESOLUTIONS
+            .iter()
+            .map(move |resolution| format!("{}_{}m", scope, resolution))
+    })
+}
+
+fn topojson_dir() -> Result<PathBuf, String> {
+    let dir = env::var_os(TOPOJSON_DIR_ENV).ok_or_else(|| {
+        format!(
+            "the `topojson` feature bundles the topojson files of plotly.js, but {} is not set. \
+            Set it to a directory holding them, such as the `dist/topojson/` directory of the \
+            plotly.js npm package.",
+            TOPOJSON_DIR_ENV
+        )
+    })?;
+    let dir = PathBuf::from(dir);
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let missing: Vec<String> = names()
+        .map(|name| format!("{}.json", name))
+        .filter(|file| !dir.join(file).is_file())
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!(
+            "{} is set to {}, which lacks the topojson files {}",
+            TOPOJSON_DIR_ENV,
+            dir.display(),
+            missing.join(", ")
+        ));
+    }
+    Ok(dir)
+}
+
+fn bundle(dir: &Path, out: &Path) -> std::io::Result<()> {
+    let mut bundled = String::from("&[\n");
+    for name in names() {
+        let file = fs::canonicalize(dir.join(format!("{}.json", name)))?;
+        bundled.push_str(&format!("    ({:?}, include_str!({:?})),\n", name, file));
+    }
+    bundled.push(']');
+    fs::write(out, bundled)
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={}", TOPOJSON_DIR_ENV);
+
+    if env::var_os("CARGO_FEATURE_TOPOJSON").is_none() {
+        return;
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("topojson.rs");
+    let result = topojson_dir().and_then(|dir| {
+        bundle(&dir, &out).map_err(|e| format!("could not bundle the topojson files: {}", e))
+    });
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,107 @@
+use plotly::{
+    common::{Anchor, Marker, Mode, Title},
+    layout::{
+        Axis, AxisType, Button, ButtonMethod, Transition, TransitionEasing, UpdateMenu,
+        UpdateMenuType,
+    },
+    Frame, Layout, Plot, Scatter,
+};
+use serde_json::json;
+
+// A small, made-up data set in the style of gapminder: for each year, the GDP per capita, life
+// expectancy and population of a few countries.
+fn country_data(year: usize) -> (Vec<f64>, Vec<f64>, Vec<usize>) {
+    let t = (year - 1952) as f64 / 5.0;
+    let gdp = vec![
+        1000.0 * 1.05_f64.powf(t * 5.0),
+        4000.0 * 1.03_f64.powf(t * 5.0),
+        12000.0 * 1.02_f64.powf(t * 5.0),
+        600.0 * 1.07_f64.powf(t * 5.0),
+    ];
+    let life_expectancy = vec![
+        45.0 + 2.0 * t,
+        55.0 + 1.5 * t,
+        68.0 + 0.8 * t,
+        38.0 + 2.5 * t,
+    ];
+    let population = vec![
+        (20.0 + 4.0 * t) as usize,
+        (30.0 + 2.0 * t) as usize,
+        (15.0 + 0.5 * t) as usize,
+        (10.0 + 5.0 * t) as usize,
+    ];
+    (gdp, life_expectancy, population)
+}
+
+fn bubbles(year: usize) -> Box<Scatter<f64, f64>> {
+    let (gdp, life_expectancy, population) = country_data(year);
+    Scatter::new(gdp, life_expectancy)
+        .mode(Mode::MarkersText)
+        .text_array(vec!["A", "B", "C", "D"])
+        .marker(Marker::new().size_array(population))
+}
+
+// Animations
+fn gapminder_style_animation(show: bool) {
+    let years: Vec<usize> = (1952..=2007).step_by(5).collect();
+
+    let mut plot = Plot::new();
+    plot.add_trace(bubbles(years[0]));
+    for year in years.iter() {
+        plot.add_frame(
+            Frame::new()
+                .name(&year.to_string())
+                .data(vec![bubbles(*year)])
+                .layout(Layout::new().title(Title::new(&format!("Year {}", year)))),
+        );
+    }
+
+    let layout = Layout::new()
+        .title(Title::new("Year 1952"))
+        .x_axis(
+            Axis::new()
+                .title(Title::new("GDP per capita"))
+                .type_(AxisType::Log)
+                .range(vec![2.5, 5.0]),
+        )
+        .y_axis(
+            Axis::new()
+                .title(Title::new("Life expectancy"))
+                .range(vec![30.0, 90.0]),
+        )
+        .update_menus(vec![UpdateMenu::new()
+            .type_(UpdateMenuType::Buttons)
+            .show_active(false)
+            .x(0.0)
+            .x_anchor(Anchor::Left)
+            .y(1.15)
+            .y_anchor(Anchor::Top)
+            .buttons(vec![
+                // Passing `null` as the frames to animate plays all of them.
+                Button::new()
+                    .label("Play")
+                    .method(ButtonMethod::Animate)
+                    .args(json!([null, {"frame": {"duration": 500}, "fromcurrent": true}])),
+                Button::new()
+                    .label("Pause")
+                    .method(ButtonMethod::Animate)
+                    .args(json!([[null], {"frame": {"duration": 0}, "mode": "immediate"}])),
+            ])])
+        .transition(
+            Transition::new()
+                .duration(500)
+                .easing(TransitionEasing::CubicInOut),
+        );
+    plot.set_layout(layout);
+
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("gapminder_style_animation")));
+}
+
+fn main() -> std::io::Result<()> {
+    // Animations
+    gapminder_style_animation(true);
+    Ok(())
+}
@@ -2,12 +2,17 @@ use itertools_num::linspace;
 use plotly::{
     color::{NamedColor, Rgb, Rgba},
     common::{
-        ColorScale, ColorScalePalette, DashType, Fill, Font, Line, LineShape, Marker, Mode,
+        ColorScale, ColorScalePalette, DashType, Domain, Fill, Font, Line, LineShape, Marker, Mode,
         Orientation, Title,
     },
-    layout::{Axis, BarMode, Layout, Legend, TicksDirection, TraceOrder},
+    hierarchy::{Hierarchy, Leaf, Packing, TextInfo as HierarchyTextInfo, Tiling, TreeNode},
+    layout::{
+        AngularAxis, Axis, BarMode, Layout, LayoutGrid, LayoutPolar, Legend, PolarDirection,
+        RadialAxis, TicksDirection, TraceOrder,
+    },
+    pie::TextInfo,
     sankey::{Line as SankeyLine, Link, Node},
-    Bar, Plot, Sankey, Scatter, ScatterPolar,
+    Bar, BarPolar, Icicle, Pie, Plot, Sankey, Scatter, ScatterPolar, Sunburst, Treemap,
 };
 use rand_distr::{Distribution, Normal, Uniform};
 
@@ -608,6 +613,228 @@ fn stacked_bar_chart(show: bool) {
     println!("{}", plot.to_inline_html(Some("stacked_bar_chart")));
 }
 
+// Polar Charts
+fn wind_rose_chart(show: bool) {
+    let directions = vec!["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    let speeds = vec![
+        (
+            "< 5 m/s",
+            vec![1.5, 1.0, 0.8, 1.2, 2.0, 2.6, 2.2, 1.7],
+            "#fde725",
+        ),
+        (
+            "5-10 m/s",
+            vec![2.5, 1.6, 1.2, 1.8, 3.1, 4.2, 3.4, 2.4],
+            "#21918c",
+        ),
+        (
+            "> 10 m/s",
+            vec![1.2, 0.4, 0.3, 0.6, 1.4, 2.8, 2.0, 0.9],
+            "#440154",
+        ),
+    ];
+
+    let mut plot = Plot::new();
+    for (name, r, color) in speeds {
+        let trace = BarPolar::new(directions.clone(), r)
+            .name(name)
+            .marker(Marker::new().color(color));
+        plot.add_trace(trace);
+    }
+
+    let layout = Layout::new()
+        .title(Title::new("Wind speed distribution"))
+        .polar(
+            LayoutPolar::new()
+                .bar_mode(BarMode::Stack)
+                .bar_gap(0.05)
+                .radial_axis(RadialAxis::new().tick_suffix("%").angle(45.0))
+                .angular_axis(
+                    AngularAxis::new()
+                        .direction(PolarDirection::Clockwise)
+                        .rotation(90.0),
+                ),
+        );
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("wind_rose_chart")));
+}
+
+fn polar_subplots(show: bool) {
+    let theta: Vec<f64> = linspace(0., 360., 361).collect();
+    let rose: Vec<f64> = theta
+        .iter()
+        .map(|t| (4. * t.to_radians()).cos().abs())
+        .collect();
+    let spiral: Vec<f64> = theta.iter().map(|t| t / 360.).collect();
+
+    let trace1 = ScatterPolar::new(theta.clone(), rose)
+        .mode(Mode::Lines)
+        .name("rose");
+    let trace2 = ScatterPolar::new(theta, spiral)
+        .mode(Mode::Lines)
+        .name("spiral")
+        .subplot("polar2");
+
+    let layout = Layout::new()
+        .polar(
+            LayoutPolar::new()
+                .domain(Domain::new().x(&[0.0, 0.45]))
+                .sector([0.0, 180.0]),
+        )
+        .polar2(
+            LayoutPolar::new()
+                .domain(Domain::new().x(&[0.55, 1.0]))
+                .hole(0.2)
+                .radial_axis(RadialAxis::new().range(vec![0.0, 1.0])),
+        );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("polar_subplots")));
+}
+
+// Pie Charts
+fn basic_pie_chart(show: bool) {
+    let trace =
+        Pie::new(vec![19, 26, 55]).labels(vec!["Residential", "Non-Residential", "Utility"]);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_pie_chart")));
+}
+
+fn donut_chart(show: bool) {
+    let trace = Pie::new(vec![16, 15, 12, 6, 5, 4, 42])
+        .labels(vec![
+            "US",
+            "China",
+            "European Union",
+            "Russian Federation",
+            "Brazil",
+            "India",
+            "Rest of World",
+        ])
+        .hole(0.4)
+        .text_info(TextInfo::LabelAndPercent)
+        .marker(Marker::new().colors(vec![
+            "#1F77B4", "#FF7F0E", "#2CA02C", "#D62728", "#9467BD", "#8C564B", "#7F7F7F",
+        ]));
+
+    let layout = Layout::new().title(Title::new("Global Emissions 1990-2011"));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("donut_chart")));
+}
+
+fn pie_chart_subplots(show: bool) {
+    let labels = vec!["1st", "2nd", "3rd", "4th", "5th"];
+
+    let trace1 = Pie::new(vec![38, 27, 18, 10, 7])
+        .labels(labels.clone())
+        .name("Starry Night")
+        .domain(Domain::new().row(0).column(0));
+    let trace2 = Pie::new(vec![28, 26, 21, 15, 10])
+        .labels(labels)
+        .name("Sunflowers")
+        .domain(Domain::new().row(0).column(1));
+
+    let layout = Layout::new().grid(LayoutGrid::new().rows(1).columns(2));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("pie_chart_subplots")));
+}
+
+// Hierarchical Charts
+fn basic_sunburst_chart(show: bool) {
+    let labels = vec![
+        "Eve", "Cain", "Seth", "Enos", "Noam", "Abel", "Awan", "Enoch", "Azura",
+    ];
+    let parents = vec![
+        "", "Eve", "Eve", "Seth", "Seth", "Eve", "Eve", "Awan", "Eve",
+    ];
+    let values = vec![10, 14, 12, 10, 2, 6, 6, 4, 4];
+
+    let trace = Sunburst::new(labels, parents, values).leaf(Leaf::new().opacity(0.4));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_sunburst_chart")));
+}
+
+fn treemap_from_paths(show: bool) {
+    let disk_usage = vec![
+        (vec!["/", "home", "alice"], 120.0),
+        (vec!["/", "home", "bob"], 45.5),
+        (vec!["/", "usr", "lib"], 80.2),
+        (vec!["/", "usr", "bin"], 12.8),
+        (vec!["/", "usr", "share"], 30.0),
+        (vec!["/", "var", "log"], 8.4),
+    ];
+
+    let trace = Treemap::from_hierarchy(Hierarchy::from_paths(disk_usage))
+        .text_info(HierarchyTextInfo::LabelAndValue)
+        .tiling(Tiling::new().packing(Packing::Squarify));
+
+    let layout = Layout::new().title(Title::new("Disk Usage (GB)"));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("treemap_from_paths")));
+}
+
+fn icicle_from_tree(show: bool) {
+    let costs = TreeNode::new("Costs", 0).children(vec![
+        TreeNode::new("Staff", 0).children(vec![
+            TreeNode::new("Engineering", 50),
+            TreeNode::new("Sales", 20),
+        ]),
+        TreeNode::new("Infrastructure", 0).children(vec![
+            TreeNode::new("Compute", 15),
+            TreeNode::new("Storage", 5),
+        ]),
+        TreeNode::new("Office", 10),
+    ]);
+
+    let trace = Icicle::from_hierarchy(Hierarchy::from_tree(vec![costs]))
+        .tiling(Tiling::new().orientation(Orientation::Vertical));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("icicle_from_tree")));
+}
+
 // Sankey Diagrams
 fn basic_sankey_diagram(show: bool) {
     // https://plotly.com/javascript/sankey-diagram/#basic-sankey-diagram
@@ -674,6 +901,20 @@ fn main() -> std::io::Result<()> {
     grouped_bar_chart(true);
     stacked_bar_chart(true);
 
+    // Polar Charts
+    wind_rose_chart(true);
+    polar_subplots(true);
+
+    // Pie Charts
+    basic_pie_chart(true);
+    donut_chart(true);
+    pie_chart_subplots(true);
+
+    // Hierarchical Charts
+    basic_sunburst_chart(true);
+    treemap_from_paths(true);
+    icicle_from_tree(true);
+
     // Sankey Diagrams
     basic_sankey_diagram(true);
     Ok(())
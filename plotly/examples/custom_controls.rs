@@ -0,0 +1,141 @@
+use plotly::{
+    common::{Anchor, Mode, Title, Visible},
+    layout::{
+        Button, ButtonMethod, Layout, Slider, SliderCurrentValue, SliderStep, UpdateMenu,
+        UpdateMenuDirection, UpdateMenuType,
+    },
+    Bar, Plot, Scatter,
+};
+use serde_json::json;
+
+// Dropdown Menus
+fn switch_dataset_dropdown(show: bool) {
+    let months = vec!["Jan", "Feb", "Mar", "Apr", "May", "Jun"];
+    let mut plot = Plot::new();
+    plot.add_trace(Bar::new(months.clone(), vec![20, 14, 23, 25, 22, 30]).name("Sales"));
+    plot.add_trace(
+        Bar::new(months.clone(), vec![12, 18, 29, 20, 24, 26])
+            .name("Returns")
+            .visible(Visible::False),
+    );
+    plot.add_trace(
+        Bar::new(months, vec![5, 7, 6, 9, 8, 11])
+            .name("Complaints")
+            .visible(Visible::False),
+    );
+
+    // Each button shows one of the traces and hides the others.
+    let buttons = ["Sales", "Returns", "Complaints"]
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let visible: Vec<bool> = (0..3).map(|j| i == j).collect();
+            Button::new()
+                .label(name)
+                .method(ButtonMethod::Update)
+                .args(json!([{"visible": visible}, {"title": {"text": name}}]))
+        })
+        .collect();
+
+    let layout = Layout::new()
+        .title(Title::new("Sales"))
+        .update_menus(vec![UpdateMenu::new()
+            .buttons(buttons)
+            .direction(UpdateMenuDirection::Down)
+            .x(0.0)
+            .x_anchor(Anchor::Left)
+            .y(1.15)
+            .y_anchor(Anchor::Top)]);
+    plot.set_layout(layout);
+
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("switch_dataset_dropdown")));
+}
+
+// Buttons
+fn toggle_log_scale_button(show: bool) {
+    let x: Vec<f64> = (1..=50).map(|x| x as f64).collect();
+    let y: Vec<f64> = x.iter().map(|x| x.powi(3)).collect();
+    let mut plot = Plot::new();
+    plot.add_trace(Scatter::new(x, y).mode(Mode::Lines));
+
+    // Clicking the button a second time applies `args2`, which reverts it.
+    let toggle = Button::new()
+        .label("Log scale")
+        .method(ButtonMethod::Relayout)
+        .args(json!([{"yaxis.type": "log"}]))
+        .args2(json!([{"yaxis.type": "linear"}]));
+
+    let layout = Layout::new().update_menus(vec![UpdateMenu::new()
+        .type_(UpdateMenuType::Buttons)
+        .buttons(vec![toggle])
+        .show_active(true)
+        .x(0.0)
+        .x_anchor(Anchor::Left)
+        .y(1.15)
+        .y_anchor(Anchor::Top)]);
+    plot.set_layout(layout);
+
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("toggle_log_scale_button")));
+}
+
+// Sliders
+fn time_step_slider(show: bool) {
+    let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+    let time_steps = 10;
+    let mut plot = Plot::new();
+    for t in 0..time_steps {
+        let y: Vec<f64> = x.iter().map(|x| (x - t as f64 * 0.5).sin()).collect();
+        let visible = if t == 0 {
+            Visible::True
+        } else {
+            Visible::False
+        };
+        plot.add_trace(
+            Scatter::new(x.clone(), y)
+                .mode(Mode::Lines)
+                .name(&format!("t = {}", t))
+                .visible(visible),
+        );
+    }
+
+    // Each step shows the trace of one time step and hides the others, so that all of them fit in
+    // a single HTML file.
+    let steps = (0..time_steps)
+        .map(|t| {
+            let visible: Vec<bool> = (0..time_steps).map(|i| i == t).collect();
+            SliderStep::new()
+                .label(&t.to_string())
+                .method(ButtonMethod::Restyle)
+                .args(json!([{ "visible": visible }]))
+        })
+        .collect();
+
+    let layout = Layout::new().sliders(vec![Slider::new()
+        .active(0)
+        .steps(steps)
+        .current_value(SliderCurrentValue::new().prefix("Time step: "))]);
+    plot.set_layout(layout);
+
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("time_step_slider")));
+}
+
+fn main() -> std::io::Result<()> {
+    // Dropdown Menus
+    switch_dataset_dropdown(true);
+
+    // Buttons
+    toggle_log_scale_button(true);
+
+    // Sliders
+    time_step_slider(true);
+    Ok(())
+}
@@ -1,6 +1,9 @@
-use plotly::common::{TickFormatStop, Title};
-use plotly::layout::{Axis, RangeSelector, RangeSlider, SelectorButton, SelectorStep, StepMode};
-use plotly::{Candlestick, Layout, Ohlc, Plot, Scatter};
+use plotly::common::{Line, Marker, TickFormatStop, Title};
+use plotly::layout::{
+    Axis, RangeBreak, RangeSelector, RangeSlider, SelectorButton, SelectorStep, StepMode,
+};
+use plotly::waterfall::{BarStyle, Connector, Measure, TextInfo};
+use plotly::{Candlestick, Layout, Ohlc, Plot, Scatter, Waterfall};
 use serde::Deserialize;
 use std::env;
 use std::path::PathBuf;
@@ -246,6 +249,36 @@ fn simple_candlestick_chart(show: bool) {
     println!("{}", plot.to_inline_html(Some("simple_candlestick_chart")));
 }
 
+fn candlestick_chart_with_range_breaks(show: bool) {
+    let data = load_apple_data();
+    let data = &data[data.len() - 60..];
+    let date: Vec<String> = data.iter().map(|d| d.date.clone()).collect();
+    let open: Vec<f64> = data.iter().map(|d| d.open).collect();
+    let high: Vec<f64> = data.iter().map(|d| d.high).collect();
+    let low: Vec<f64> = data.iter().map(|d| d.low).collect();
+    let close: Vec<f64> = data.iter().map(|d| d.close).collect();
+
+    // Leave out the weekends and holidays, on which there is no trading.
+    let range_breaks = RangeBreak::from_gaps(&date);
+    let trace = Candlestick::new(date, open, high, low, close);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+
+    let layout = Layout::new()
+        .x_axis(Axis::new().range_breaks(range_breaks))
+        .title(Title::new("Candlestick Chart without Gaps"));
+    plot.set_layout(layout);
+
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("candlestick_chart_with_range_breaks"))
+    );
+}
+
 // OHLC Charts
 fn simple_ohlc_chart(show: bool) {
     let x = vec![
@@ -315,6 +348,47 @@ fn simple_ohlc_chart(show: bool) {
     println!("{}", plot.to_inline_html(Some("simple_ohlc_chart")));
 }
 
+// Waterfall Charts
+fn simple_waterfall_chart(show: bool) {
+    let x = vec![
+        "Sales",
+        "Consulting",
+        "Net revenue",
+        "Purchases",
+        "Other expenses",
+        "Profit before tax",
+    ];
+    let y = vec![60, 80, 0, -40, -20, 0];
+    let measure = vec![
+        Measure::Relative,
+        Measure::Relative,
+        Measure::Total,
+        Measure::Relative,
+        Measure::Relative,
+        Measure::Total,
+    ];
+
+    let trace = Waterfall::new(x, y)
+        .measure(measure)
+        .text_info(TextInfo::Delta)
+        .increasing(BarStyle::new().marker(Marker::new().color("#3D9970")))
+        .decreasing(BarStyle::new().marker(Marker::new().color("#FF4136")))
+        .totals(BarStyle::new().marker(Marker::new().color("#0074D9")))
+        .connector(Connector::new().line(Line::new().color("rgb(63, 63, 63)")));
+
+    let layout = Layout::new()
+        .title(Title::new("Profit and loss statement 2018"))
+        .show_legend(false);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("simple_waterfall_chart")));
+}
+
 fn main() -> std::io::Result<()> {
     // Time Series and Date Axes
     time_series_plot_with_custom_date_range(true);
@@ -324,9 +398,13 @@ fn main() -> std::io::Result<()> {
 
     // Candlestick Charts
     simple_candlestick_chart(true);
+    candlestick_chart_with_range_breaks(true);
 
     // OHLC Charts
     simple_ohlc_chart(true);
 
+    // Waterfall Charts
+    simple_waterfall_chart(true);
+
     Ok(())
 }
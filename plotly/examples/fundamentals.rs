@@ -1,7 +1,7 @@
 use itertools_num::linspace;
 use plotly::{
     color::NamedColor,
-    common::{DashType, Fill, Font, Mode},
+    common::{DashType, Fill, Font, Mode, XAxisRef, YAxisRef},
     layout::{
         Axis, GridPattern, Layout, LayoutGrid, Margin, Shape, ShapeLayer, ShapeLine, ShapeType,
     },
@@ -615,23 +615,23 @@ fn adding_shapes_to_subplots(show: bool) {
     let mut plot = Plot::new();
     plot.add_trace(
         Scatter::new(vec![2, 6], vec![1, 1])
-            .x_axis("x1")
-            .y_axis("y1"),
+            .x_axis(XAxisRef::new(1))
+            .y_axis(YAxisRef::new(1)),
     );
     plot.add_trace(
         Bar::new(vec![1, 2, 3], vec![4, 5, 6])
-            .x_axis("x2")
-            .y_axis("y2"),
+            .x_axis(XAxisRef::new(2))
+            .y_axis(YAxisRef::new(2)),
     );
     plot.add_trace(
         Scatter::new(vec![10, 20], vec![40, 50])
-            .x_axis("x3")
-            .y_axis("y3"),
+            .x_axis(XAxisRef::new(3))
+            .y_axis(YAxisRef::new(3)),
     );
     plot.add_trace(
         Bar::new(vec![11, 13, 15], vec![8, 11, 20])
-            .x_axis("x4")
-            .y_axis("y4"),
+            .x_axis(XAxisRef::new(4))
+            .y_axis(YAxisRef::new(4)),
     );
 
     let mut layout = Layout::new()
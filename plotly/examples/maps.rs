@@ -0,0 +1,201 @@
+use plotly::{
+    common::{ColorScale, ColorScalePalette, Line, LocationMode, Marker, Mode, Title},
+    layout::{
+        GeoAxis, GeoCenter, GeoProjection, GeoResolution, GeoScope, Layout, LayoutGeo,
+        LayoutMapbox, MapboxLayer, MapboxLayerSourceType, MapboxStyle, ProjectionRotation,
+        ProjectionType,
+    },
+    Choropleth, DensityMapbox, Plot, ScatterGeo, ScatterMapbox,
+};
+
+// Scatter Maps
+fn scatter_geo_plot(show: bool) {
+    let trace = ScatterGeo::new(
+        vec![51.51, 48.86, 52.52, 41.90, 40.42],
+        vec![-0.13, 2.35, 13.40, 12.50, -3.70],
+    )
+    .mode(Mode::MarkersText)
+    .text_array(vec!["London", "Paris", "Berlin", "Rome", "Madrid"])
+    .marker(Marker::new().size(10));
+
+    let layout = Layout::new().title(Title::new("European capitals")).geo(
+        LayoutGeo::new()
+            .scope(GeoScope::Europe)
+            .resolution(GeoResolution::High)
+            .show_countries(true)
+            .lat_axis(GeoAxis::new().range([35.0, 60.0]))
+            .lon_axis(GeoAxis::new().range([-10.0, 25.0])),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("scatter_geo_plot")));
+}
+
+fn orthographic_projection(show: bool) {
+    let trace = ScatterGeo::new(vec![40.71, 51.51, 35.68], vec![-74.01, -0.13, 139.69])
+        .mode(Mode::Lines)
+        .name("route");
+
+    let layout = Layout::new().geo(
+        LayoutGeo::new()
+            .projection(
+                GeoProjection::new()
+                    .projection_type(ProjectionType::Orthographic)
+                    .rotation(ProjectionRotation::new().lon(-30.0).lat(30.0)),
+            )
+            .show_land(true)
+            .land_color("#e5ecf6")
+            .show_ocean(true)
+            .ocean_color("#c8d7f0"),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("orthographic_projection")));
+}
+
+// Choropleth Maps
+fn choropleth_plot(show: bool) {
+    let trace = Choropleth::new(
+        vec!["USA", "CAN", "MEX", "BRA", "ARG"],
+        vec![331.0, 38.0, 126.0, 213.0, 45.0],
+    )
+    .location_mode(LocationMode::Iso3)
+    .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+    .name("population (millions)");
+
+    let layout = Layout::new().geo(
+        LayoutGeo::new()
+            .projection(GeoProjection::new().projection_type(ProjectionType::NaturalEarth))
+            .show_countries(true),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("choropleth_plot")));
+}
+
+fn us_states_choropleth(show: bool) {
+    let trace = Choropleth::new(vec!["CA", "TX", "FL", "NY"], vec![39.2, 29.5, 21.8, 19.8])
+        .location_mode(LocationMode::UsaStates)
+        .color_scale(ColorScale::Palette(ColorScalePalette::Blues));
+
+    let layout = Layout::new().geo(LayoutGeo::new().scope(GeoScope::Usa));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("us_states_choropleth")));
+}
+
+// Tile Maps
+fn gps_track(show: bool) {
+    let lat = vec![45.5017, 45.5048, 45.5088, 45.5119, 45.5152, 45.5187];
+    let lon = vec![-73.5673, -73.5714, -73.5689, -73.5731, -73.5770, -73.5752];
+    let trace = ScatterMapbox::new(lat, lon)
+        .mode(Mode::LinesMarkers)
+        .line(Line::new().width(3.0).color("#d62728"))
+        .marker(Marker::new().size(8))
+        .name("delivery route");
+
+    let layout = Layout::new().mapbox(
+        LayoutMapbox::new()
+            .style(MapboxStyle::OpenStreetMap)
+            .center(GeoCenter::new().lat(45.51).lon(-73.572))
+            .zoom(13.0),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("gps_track")));
+}
+
+fn custom_tile_layer(show: bool) {
+    let trace = ScatterMapbox::new(vec![45.5017, 45.5187], vec![-73.5673, -73.5752])
+        .mode(Mode::Markers)
+        .marker(Marker::new().size(12));
+
+    // Any XYZ tile server can be used as the background, including one running locally.
+    let tiles = MapboxLayer::new()
+        .source_type(MapboxLayerSourceType::Raster)
+        .source(vec!["https://tile.openstreetmap.org/{z}/{x}/{y}.png"])
+        .source_attribution("© OpenStreetMap contributors")
+        .below("traces");
+
+    let layout = Layout::new().mapbox(
+        LayoutMapbox::new()
+            .style(MapboxStyle::WhiteBg)
+            .layers(vec![tiles])
+            .center(GeoCenter::new().lat(45.51).lon(-73.57))
+            .zoom(12.0),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("custom_tile_layer")));
+}
+
+fn density_mapbox_plot(show: bool) {
+    let trace = DensityMapbox::new(
+        vec![45.50, 45.51, 45.52, 45.51, 45.50],
+        vec![-73.57, -73.58, -73.56, -73.55, -73.56],
+        vec![1.0, 3.0, 2.0, 5.0, 4.0],
+    )
+    .radius(30)
+    .color_scale(ColorScale::Palette(ColorScalePalette::Hot));
+
+    let layout = Layout::new().mapbox(
+        LayoutMapbox::new()
+            .style(MapboxStyle::CartoPositron)
+            .center(GeoCenter::new().lat(45.51).lon(-73.565))
+            .zoom(12.0),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("density_mapbox_plot")));
+}
+
+fn main() -> std::io::Result<()> {
+    // Scatter Maps
+    scatter_geo_plot(true);
+    orthographic_projection(true);
+
+    // Choropleth Maps
+    choropleth_plot(true);
+    us_states_choropleth(true);
+
+    // Tile Maps
+    gps_track(true);
+    custom_tile_layer(true);
+    density_mapbox_plot(true);
+    Ok(())
+}
@@ -1,8 +1,14 @@
 use itertools_num::linspace;
 use plotly::{
-    common::{ColorScale, ColorScalePalette, Marker, MarkerSymbol, Mode, Title},
-    layout::{Axis, Layout},
-    Plot, Scatter3D, Surface,
+    common::{ColorScale, ColorScalePalette, Domain, Marker, MarkerSymbol, Mode, Title},
+    layout::{
+        AspectMode, AspectRatio, Axis, Camera, CameraProjection, CameraProjectionType, Eye, Layout,
+        LayoutScene,
+    },
+    streamtube::Starts,
+    surface::Lighting,
+    volume::{Cap, Caps, VolumeSurface},
+    Cone, Isosurface, Mesh3D, Plot, Scatter3D, Streamtube, Surface, Volume,
 };
 
 // 3D Scatter Plots
@@ -56,11 +62,12 @@ fn customized_scatter3d_plot(show: bool) {
     let mut plot = Plot::new();
     plot.add_trace(trace);
     plot.add_trace(trace2);
-    let layout = Layout::new()
-        .title("Helix".into())
-        .x_axis(Axis::new().title("x (A meaningful axis name goes here)".into()))
-        .y_axis(Axis::new().title(Title::new("This is the label of the Y axis")))
-        .z_axis(Axis::new().title("z Axis".into()));
+    let layout = Layout::new().title("Helix".into()).scene(
+        LayoutScene::new()
+            .x_axis(Axis::new().title("x (A meaningful axis name goes here)".into()))
+            .y_axis(Axis::new().title(Title::new("This is the label of the Y axis")))
+            .z_axis(Axis::new().title("z Axis".into())),
+    );
     plot.set_layout(layout);
 
     if show {
@@ -107,11 +114,232 @@ fn surface_plot(show: bool) {
     }
 }
 
+fn customized_scene_plot(show: bool) {
+    let n: usize = 50;
+    let x: Vec<f64> = linspace(-3., 3., n).collect();
+    let y: Vec<f64> = linspace(-3., 3., n).collect();
+    let z: Vec<Vec<f64>> = y
+        .iter()
+        .map(|j| x.iter().map(|i| (-(i * i + j * j) / 2.0).exp()).collect())
+        .collect();
+
+    let trace = Surface::new(z).x(x).y(y).show_scale(false);
+    let layout = Layout::new().scene(
+        LayoutScene::new()
+            .aspect_mode(AspectMode::Manual)
+            .aspect_ratio(AspectRatio::new().x(1.0).y(1.0).z(0.4))
+            .camera(
+                Camera::new()
+                    .eye(Eye::new().x(1.6).y(-1.6).z(0.8))
+                    .projection(
+                        CameraProjection::new().projection_type(CameraProjectionType::Orthographic),
+                    ),
+            )
+            .x_axis(
+                Axis::new()
+                    .show_background(true)
+                    .background_color("#e5ecf6"),
+            )
+            .y_axis(
+                Axis::new()
+                    .show_background(true)
+                    .background_color("#e5ecf6"),
+            )
+            .z_axis(Axis::new().range(vec![0.0, 1.5])),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+
+    if show {
+        plot.show();
+    }
+}
+
+// 3D Subplots
+fn multiple_scenes(show: bool) {
+    let t: Vec<f64> = linspace(0., 20., 200).collect();
+    let helix = Scatter3D::new(
+        t.iter().map(|t| t.cos()).collect(),
+        t.iter().map(|t| t.sin()).collect(),
+        t.clone(),
+    )
+    .mode(Mode::Lines)
+    .name("helix");
+    let spiral = Scatter3D::new(
+        t.iter().map(|t| t * t.cos()).collect(),
+        t.iter().map(|t| t * t.sin()).collect(),
+        t.clone(),
+    )
+    .mode(Mode::Lines)
+    .name("spiral")
+    .scene("scene2");
+
+    let layout = Layout::new()
+        .scene(LayoutScene::new().domain(Domain::new().x(&[0.0, 0.5])))
+        .scene2(
+            LayoutScene::new()
+                .domain(Domain::new().x(&[0.5, 1.0]))
+                .aspect_mode(AspectMode::Cube),
+        );
+
+    let mut plot = Plot::new();
+    plot.add_trace(helix);
+    plot.add_trace(spiral);
+    plot.set_layout(layout);
+
+    if show {
+        plot.show();
+    }
+}
+
+// 3D Mesh Plots
+fn mesh3d_plot(show: bool) {
+    // A tetrahedron, with each of its four faces given by indexing into the vertices.
+    let trace = Mesh3D::new(
+        vec![0.0, 1.0, 2.0, 0.0],
+        vec![0.0, 0.0, 1.0, 2.0],
+        vec![0.0, 2.0, 0.0, 1.0],
+        vec![0, 0, 0, 1],
+        vec![1, 2, 3, 2],
+        vec![2, 3, 1, 3],
+    )
+    .intensity(vec![0.0, 0.33, 0.66, 1.0])
+    .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+    .flat_shading(true)
+    .lighting(Lighting::new().ambient(0.6).diffuse(0.8));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+
+    if show {
+        plot.show();
+    }
+}
+
+// 3D Volume Plots
+fn grid_values(n: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+    let points: Vec<f64> = linspace(-1., 1., n).collect();
+    let (mut x, mut y, mut z, mut value) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    for i in points.iter() {
+        for j in points.iter() {
+            for k in points.iter() {
+                x.push(*i);
+                y.push(*j);
+                z.push(*k);
+                value.push(i * i + j * j + k * k);
+            }
+        }
+    }
+    (x, y, z, value)
+}
+
+fn isosurface_plot(show: bool) {
+    let (x, y, z, value) = grid_values(20);
+
+    let trace = Isosurface::new(x, y, z, value)
+        .iso_min(0.5)
+        .iso_max(1.5)
+        .surface(VolumeSurface::new().count(3))
+        .caps(
+            Caps::new()
+                .x(Cap::new().show(false))
+                .y(Cap::new().show(false)),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+
+    if show {
+        plot.show();
+    }
+}
+
+fn volume_plot(show: bool) {
+    let (x, y, z, value) = grid_values(20);
+
+    let trace = Volume::new(x, y, z, value)
+        .iso_min(0.1)
+        .iso_max(0.8)
+        .opacity(0.1)
+        .surface(VolumeSurface::new().count(17));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+
+    if show {
+        plot.show();
+    }
+}
+
+// 3D Vector Fields
+fn cone_plot(show: bool) {
+    let trace = Cone::new(
+        vec![1, 2, 3],
+        vec![1, 2, 3],
+        vec![1, 2, 3],
+        vec![1.0, 0.0, 0.0],
+        vec![0.0, 3.0, 0.0],
+        vec![0.0, 0.0, 2.0],
+    )
+    .size_ref(1.5)
+    .color_scale(ColorScale::Palette(ColorScalePalette::Blues));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+
+    if show {
+        plot.show();
+    }
+}
+
+fn streamtube_plot(show: bool) {
+    let n = 8;
+    let (mut x, mut y, mut z) = (Vec::new(), Vec::new(), Vec::new());
+    let (mut u, mut v, mut w) = (Vec::new(), Vec::new(), Vec::new());
+    for i in 0..n {
+        for j in 0..n {
+            for k in 0..n {
+                let (xi, yj, zk) = (i as f64, j as f64, k as f64);
+                x.push(xi);
+                y.push(yj);
+                z.push(zk);
+                u.push(1.0);
+                v.push((xi / 2.0).sin());
+                w.push(0.1 * zk);
+            }
+        }
+    }
+
+    let trace = Streamtube::new(x, y, z, u, v, w)
+        .starts(Starts::new(
+            vec![0.0; 4],
+            vec![1.0, 2.0, 3.0, 4.0],
+            vec![2.0, 3.0, 4.0, 5.0],
+        ))
+        .size_ref(0.5)
+        .max_displayed(500);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+
+    if show {
+        plot.show();
+    }
+}
+
 fn main() -> std::io::Result<()> {
     // Scatter3D Plots
     simple_scatter3d_plot(true);
     simple_line3d_plot(true);
     customized_scatter3d_plot(true);
     surface_plot(true);
+    customized_scene_plot(true);
+    mesh3d_plot(true);
+    isosurface_plot(true);
+    volume_plot(true);
+
+    // 3D Subplots
+    multiple_scenes(true);
+
+    // 3D Vector Fields
+    cone_plot(true);
+    streamtube_plot(true);
     Ok(())
 }
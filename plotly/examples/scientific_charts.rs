@@ -1,8 +1,9 @@
 use std::f64::consts::PI;
 
-use plotly::common::{ColorScale, ColorScalePalette, Title};
+use plotly::common::{ColorScale, ColorScalePalette, Fill, Marker, Mode, Title};
 use plotly::contour::Contours;
-use plotly::{Contour, HeatMap, Layout, Plot};
+use plotly::layout::{LayoutTernary, TernaryAxis};
+use plotly::{Contour, HeatMap, Layout, Plot, ScatterTernary};
 
 // Contour Plots
 fn simple_contour_plot(show: bool) {
@@ -128,6 +129,77 @@ fn basic_heat_map(show: bool) {
     println!("{}", plot.to_inline_html(Some("basic_heat_map")));
 }
 
+// Ternary Plots
+fn basic_ternary_plot(show: bool) {
+    let trace = ScatterTernary::new(
+        vec![
+            75.0, 70.0, 75.0, 5.0, 10.0, 10.0, 20.0, 10.0, 15.0, 10.0, 20.0,
+        ],
+        vec![
+            25.0, 10.0, 20.0, 60.0, 80.0, 90.0, 70.0, 20.0, 5.0, 10.0, 10.0,
+        ],
+        vec![
+            0.0, 20.0, 5.0, 35.0, 10.0, 0.0, 10.0, 70.0, 80.0, 80.0, 70.0,
+        ],
+    )
+    .mode(Mode::Markers)
+    .marker(Marker::new().size(12).color("#DB7365"));
+
+    let axis = |title: &str| TernaryAxis::new().title(Title::new(title)).min(0.01);
+    let layout = Layout::new().ternary(
+        LayoutTernary::new()
+            .sum(100.0)
+            .a_axis(axis("Joker"))
+            .b_axis(axis("Psycho"))
+            .c_axis(axis("Lone Wolf")),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_ternary_plot")));
+}
+
+fn filled_ternary_regions(show: bool) {
+    let liquid = ScatterTernary::new(
+        vec![0.0, 0.0, 0.6],
+        vec![1.0, 0.4, 0.0],
+        vec![0.0, 0.6, 0.4],
+    )
+    .mode(Mode::Lines)
+    .fill(Fill::ToSelf)
+    .fill_color("#8dd3c7")
+    .name("liquid");
+    let solid = ScatterTernary::new(
+        vec![1.0, 0.6, 0.0],
+        vec![0.0, 0.0, 0.4],
+        vec![0.0, 0.4, 0.6],
+    )
+    .mode(Mode::Lines)
+    .fill(Fill::ToSelf)
+    .fill_color("#bebada")
+    .name("solid");
+
+    let layout = Layout::new().ternary(
+        LayoutTernary::new()
+            .a_axis(TernaryAxis::new().title(Title::new("A")))
+            .b_axis(TernaryAxis::new().title(Title::new("B")))
+            .c_axis(TernaryAxis::new().title(Title::new("C"))),
+    );
+
+    let mut plot = Plot::new();
+    plot.add_trace(liquid);
+    plot.add_trace(solid);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("filled_ternary_regions")));
+}
+
 fn main() -> std::io::Result<()> {
     // Contour Plots
     simple_contour_plot(true);
@@ -137,5 +209,9 @@ fn main() -> std::io::Result<()> {
 
     // Heatmaps
     basic_heat_map(true);
+
+    // Ternary Plots
+    basic_ternary_plot(true);
+    filled_ternary_regions(true);
     Ok(())
 }
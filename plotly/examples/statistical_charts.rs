@@ -2,10 +2,14 @@ use itertools_num::linspace;
 use plotly::{
     box_plot::{BoxMean, BoxPoints},
     color::{NamedColor, Rgb, Rgba},
-    common::{ErrorData, ErrorType, Line, Marker, Mode, Orientation, Title},
+    common::{
+        ColorScale, ColorScalePalette, ErrorData, ErrorType, Line, Marker, Mode, Orientation, Title,
+    },
+    contour::{Coloring, Contours},
     histogram::{Bins, Cumulative, HistFunc, HistNorm},
-    layout::{Axis, BarMode, BoxMode, Layout, Margin},
-    Bar, BoxPlot, Histogram, Plot, Scatter,
+    layout::{Axis, BarMode, BoxMode, Layout, Margin, ViolinMode},
+    violin::{MeanLine, Side, ViolinBox},
+    Bar, BoxPlot, Histogram, Histogram2d, Histogram2dContour, Plot, Scatter, Violin,
 };
 use rand_distr::{Distribution, Normal, Uniform};
 
@@ -649,6 +653,117 @@ fn specify_binning_function(show: bool) {
     println!("{}", plot.to_inline_html(Some("specify_binning_function")));
 }
 
+// 2D Histograms
+fn basic_2d_histogram(show: bool) {
+    let n = 500;
+    let x = sample_normal_distribution(n, 0.0, 1.0);
+    let y = sample_normal_distribution(n, 1.0, 0.5);
+
+    let trace = Histogram2d::new(x, y)
+        .x_bins(Bins::new(-3.0, 3.0, 0.25))
+        .y_bins(Bins::new(-0.5, 2.5, 0.125))
+        .color_scale(ColorScale::Palette(ColorScalePalette::YlGnBu));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_2d_histogram")));
+}
+
+fn histogram2d_contour_with_points(show: bool) {
+    let n = 500;
+    let x = sample_normal_distribution(n, 0.0, 1.0);
+    let y = sample_normal_distribution(n, 0.0, 1.0);
+
+    let points = Scatter::new(x.clone(), y.clone())
+        .mode(Mode::Markers)
+        .name("points")
+        .marker(Marker::new().color(Rgba::new(102, 0, 0, 0.3)).size(3));
+    let density = Histogram2dContour::new(x, y)
+        .name("density")
+        .n_contours(20)
+        .contours(Contours::new().coloring(Coloring::HeatMap))
+        .color_scale(ColorScale::Palette(ColorScalePalette::Hot))
+        .reverse_scale(true)
+        .show_scale(false);
+
+    let mut plot = Plot::new();
+    plot.add_trace(points);
+    plot.add_trace(density);
+    if show {
+        plot.show();
+    }
+    println!(
+        "{}",
+        plot.to_inline_html(Some("histogram2d_contour_with_points"))
+    );
+}
+
+// Violin Plots
+fn basic_violin_plot(show: bool) {
+    let trace = Violin::new(sample_normal_distribution(500, 0.0, 1.0))
+        .name("")
+        .box_(ViolinBox::new().visible(true))
+        .mean_line(MeanLine::new().visible(true))
+        .points(BoxPoints::False);
+    let layout = Layout::new().title(Title::new("Basic Violin Plot"));
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("basic_violin_plot")));
+}
+
+fn split_violin_plot(show: bool) {
+    let days = ["Thur", "Fri", "Sat", "Sun"];
+    let mut x_days = Vec::new();
+    for day in days.iter() {
+        for _ in 0..100 {
+            x_days.push(*day);
+        }
+    }
+
+    let mut smokers = Vec::new();
+    let mut non_smokers = Vec::new();
+    for (i, _) in days.iter().enumerate() {
+        smokers.extend(sample_normal_distribution(100, 15.0 + 2.0 * i as f64, 4.0));
+        non_smokers.extend(sample_normal_distribution(100, 18.0 + i as f64, 5.0));
+    }
+
+    let trace1 = Violin::new_xy(x_days.clone(), smokers)
+        .name("Yes")
+        .legend_group("Yes")
+        .scale_group("Yes")
+        .side(Side::Negative)
+        .line(Line::new().color(NamedColor::Blue));
+    let trace2 = Violin::new_xy(x_days, non_smokers)
+        .name("No")
+        .legend_group("No")
+        .scale_group("No")
+        .side(Side::Positive)
+        .line(Line::new().color(NamedColor::Green));
+
+    let layout = Layout::new()
+        .title(Title::new("Split Violin Plot"))
+        .violin_gap(0.0)
+        .violin_group_gap(0.0)
+        .violin_mode(ViolinMode::Overlay);
+
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("split_violin_plot")));
+}
+
 fn main() -> std::io::Result<()> {
     // Error Bars
     basic_symmetric_error_bars(true);
@@ -679,5 +794,13 @@ fn main() -> std::io::Result<()> {
     normalized_histogram(true);
     specify_binning_function(true);
 
+    // 2D Histograms
+    basic_2d_histogram(true);
+    histogram2d_contour_with_points(true);
+
+    // Violin Plots
+    basic_violin_plot(true);
+    split_violin_plot(true);
+
     Ok(())
 }
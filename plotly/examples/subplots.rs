@@ -1,14 +1,15 @@
-use plotly::common::{AxisSide, Font, Title};
+use plotly::common::{AxisSide, Font, Title, XAxisRef, YAxisRef};
+use plotly::layout::subplots::{SubplotSpec, Subplots};
 use plotly::layout::{Axis, GridPattern, Layout, LayoutGrid, Legend, RowOrder, TraceOrder};
-use plotly::{color::Rgb, Plot, Scatter};
+use plotly::{color::Rgb, Bar, Plot, Scatter};
 
 // Subplots
 fn simple_subplot(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![20, 30, 40], vec![50, 60, 70])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisRef::new(2))
+        .y_axis(YAxisRef::new(2));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -31,8 +32,8 @@ fn custom_sized_subplot(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![20, 30, 40], vec![50, 60, 70])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisRef::new(2))
+        .y_axis(YAxisRef::new(2));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -53,14 +54,14 @@ fn multiple_subplots(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![20, 30, 40], vec![50, 60, 70])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisRef::new(2))
+        .y_axis(YAxisRef::new(2));
     let trace3 = Scatter::new(vec![300, 400, 500], vec![600, 700, 800])
-        .x_axis("x3")
-        .y_axis("y3");
+        .x_axis(XAxisRef::new(3))
+        .y_axis(YAxisRef::new(3));
     let trace4 = Scatter::new(vec![4000, 5000, 6000], vec![7000, 8000, 9000])
-        .x_axis("x4")
-        .y_axis("y4");
+        .x_axis(XAxisRef::new(4))
+        .y_axis(YAxisRef::new(4));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -85,11 +86,11 @@ fn stacked_subplots(show: bool) {
     let trace1 = Scatter::new(vec![0, 1, 2], vec![10, 11, 12]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![100, 110, 120])
         .name("trace2")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisRef::new(2))
+        .y_axis(YAxisRef::new(2));
     let trace3 = Scatter::new(vec![3, 4, 5], vec![1000, 1100, 1200])
-        .x_axis("x3")
-        .y_axis("y3");
+        .x_axis(XAxisRef::new(3))
+        .y_axis(YAxisRef::new(3));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -114,8 +115,8 @@ fn stacked_subplots_with_shared_x_axis(show: bool) {
     let trace1 = Scatter::new(vec![0, 1, 2], vec![10, 11, 12]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![100, 110, 120])
         .name("trace2")
-        .y_axis("y2");
-    let trace3 = Scatter::new(vec![3, 4, 5], vec![1000, 1100, 1200]).y_axis("y3");
+        .y_axis(YAxisRef::new(2));
+    let trace3 = Scatter::new(vec![3, 4, 5], vec![1000, 1100, 1200]).y_axis(YAxisRef::new(3));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -141,16 +142,16 @@ fn multiple_custom_sized_subplots(show: bool) {
     let trace1 = Scatter::new(vec![1, 2], vec![1, 2]).name("(1,1)");
     let trace2 = Scatter::new(vec![1, 2], vec![1, 2])
         .name("(1,2,1)")
-        .x_axis("x2")
-        .y_axis("y2");
+        .x_axis(XAxisRef::new(2))
+        .y_axis(YAxisRef::new(2));
     let trace3 = Scatter::new(vec![1, 2], vec![1, 2])
         .name("(1,2,2)")
-        .x_axis("x3")
-        .y_axis("y3");
+        .x_axis(XAxisRef::new(3))
+        .y_axis(YAxisRef::new(3));
     let trace4 = Scatter::new(vec![1, 2], vec![1, 2])
         .name("{(2,1), (2,2)}")
-        .x_axis("x4")
-        .y_axis("y4");
+        .x_axis(XAxisRef::new(4))
+        .y_axis(YAxisRef::new(4));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -178,12 +179,54 @@ fn multiple_custom_sized_subplots(show: bool) {
     );
 }
 
+fn subplots_builder(show: bool) {
+    let x = vec![1, 2, 3, 4, 5];
+    let mut subplots = Subplots::new(2, 2)
+        .specs(vec![
+            vec![Some(SubplotSpec::new().col_span(2).secondary_y(true)), None],
+            vec![Some(SubplotSpec::new()), Some(SubplotSpec::new())],
+        ])
+        .row_heights(vec![0.6, 0.4])
+        .subplot_titles(vec!["Price and volume", "Returns", "Spread"])
+        .secondary_y_axis(1, 1, Axis::new().title(Title::new("volume")))
+        .layout(Layout::new().title(Title::new("Subplots Builder")));
+
+    subplots.add_trace(
+        Scatter::new(x.clone(), vec![10.0, 10.4, 10.1, 10.8, 11.2]).name("price"),
+        1,
+        1,
+    );
+    subplots.add_secondary_y_trace(
+        Bar::new(x.clone(), vec![300, 120, 210, 180, 260])
+            .name("volume")
+            .opacity(0.4),
+        1,
+        1,
+    );
+    subplots.add_trace(
+        Bar::new(x.clone(), vec![0.0, 0.04, -0.03, 0.07, 0.04]).name("returns"),
+        2,
+        1,
+    );
+    subplots.add_trace(
+        Scatter::new(x, vec![0.02, 0.03, 0.02, 0.05, 0.04]).name("spread"),
+        2,
+        2,
+    );
+
+    let plot = subplots.into_plot();
+    if show {
+        plot.show();
+    }
+    println!("{}", plot.to_inline_html(Some("subplots_builder")));
+}
+
 // Multiple Axes
 fn two_y_axes(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![40, 50, 60]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![4, 5, 6])
         .name("trace2")
-        .y_axis("y2");
+        .y_axis(YAxisRef::new(2));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -210,9 +253,10 @@ fn multiple_axes(show: bool) {
     let trace1 = Scatter::new(vec![1, 2, 3], vec![4, 5, 6]).name("trace1");
     let trace2 = Scatter::new(vec![2, 3, 4], vec![40, 50, 60])
         .name("trace2")
-        .y_axis("y2");
-    let trace3 = Scatter::new(vec![4, 5, 6], vec![40_000, 50_000, 60_000]).y_axis("y3");
-    let trace4 = Scatter::new(vec![5, 6, 7], vec![400_000, 500_000, 600_000]).y_axis("y4");
+        .y_axis(YAxisRef::new(2));
+    let trace3 = Scatter::new(vec![4, 5, 6], vec![40_000, 50_000, 60_000]).y_axis(YAxisRef::new(3));
+    let trace4 =
+        Scatter::new(vec![5, 6, 7], vec![400_000, 500_000, 600_000]).y_axis(YAxisRef::new(4));
 
     let mut plot = Plot::new();
     plot.add_trace(trace1);
@@ -270,6 +314,7 @@ fn main() -> std::io::Result<()> {
     stacked_subplots(true);
     stacked_subplots_with_shared_x_axis(true);
     multiple_custom_sized_subplots(true);
+    subplots_builder(true);
 
     // Multiple Axes
     two_y_axes(true);
@@ -16,7 +16,9 @@
 
 use dyn_clone::DynClone;
 use erased_serde::Serialize as ErasedSerialize;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::private::NumOrString;
 
 /// A marker trait allowing several ways to describe a color.
 pub trait Color: DynClone + ErasedSerialize + Send + Sync + std::fmt::Debug + 'static {}
@@ -29,6 +31,18 @@ impl Color for &'static str {}
 impl Color for String {}
 impl Color for Rgb {}
 impl Color for Rgba {}
+impl Color for NumOrString {}
+
+/// Colors are read back as they were written: either as a string, or as a number to be mapped onto
+/// a colorscale.
+impl<'de> Deserialize<'de> for Box<dyn Color> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Box::new(NumOrString::deserialize(deserializer)?))
+    }
+}
 
 /// ColorArray is only used internally to provide a helper method for converting Vec<impl Color> to
 /// Vec<Box<dyn Color>>, as we would otherwise fall foul of the orphan rules.
@@ -99,7 +113,7 @@ impl Serialize for Rgba {
 /// Cross-browser compatible [`predefined colors`].
 ///
 /// [`predefined colors`]: https://www.w3schools.com/cssref/css_colors.asp
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum NamedColor {
     AliceBlue,
@@ -255,7 +269,7 @@ pub enum NamedColor {
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{json, to_value};
+    use serde_json::{from_value, json, to_value};
 
     use super::*;
 
@@ -433,4 +447,13 @@ mod tests {
         assert_eq!(to_value(NamedColor::YellowGreen).unwrap(), json!("yellowgreen"));
         assert_eq!(to_value(NamedColor::Transparent).unwrap(), json!("transparent"));
     }
+
+    #[test]
+    fn test_deserialize_color() {
+        let color: Box<dyn Color> = from_value(json!("#ff0000")).unwrap();
+        assert_eq!(to_value(color).unwrap(), json!("#ff0000"));
+
+        let colors: Vec<Box<dyn Color>> = from_value(json!([1, 2.5, "red"])).unwrap();
+        assert_eq!(to_value(colors).unwrap(), json!([1, 2.5, "red"]));
+    }
 }
@@ -1,13 +1,16 @@
 pub mod color;
 
-use serde::{Serialize, Serializer};
+use std::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Number, Value};
 
 use crate::{
     color::{Color, ColorArray},
-    private,
+    private::{self, BoolOrString, Extra},
 };
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(untagged)]
 pub enum Direction {
     Increasing { line: Line },
@@ -34,7 +37,23 @@ impl Serialize for Visible {
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+impl<'de> Deserialize<'de> for Visible {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(true) => Ok(Self::True),
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::String(s) => match s.as_str() {
+                "legendonly" => Ok(Self::LegendOnly),
+                _ => Err(de::Error::unknown_variant(&s, &["legendonly"])),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum HoverInfo {
     X,
@@ -56,16 +75,18 @@ pub enum HoverInfo {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct LegendGroupTitle {
-    text: String,
+    text: Option<String>,
     font: Option<Font>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl LegendGroupTitle {
     pub fn new(text: &str) -> Self {
         Self {
-            text: text.to_string(),
+            text: Some(text.to_string()),
             ..Default::default()
         }
     }
@@ -77,12 +98,14 @@ impl LegendGroupTitle {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Domain {
     column: Option<usize>,
     row: Option<usize>,
     x: Option<[f64; 2]>,
     y: Option<[f64; 2]>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Domain {
@@ -111,7 +134,7 @@ impl Domain {
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum TextPosition {
     Inside,
@@ -120,7 +143,7 @@ pub enum TextPosition {
     None,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum ConstrainText {
     Inside,
@@ -129,7 +152,7 @@ pub enum ConstrainText {
     None,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum Orientation {
     #[serde(rename = "v")]
     Vertical,
@@ -137,7 +160,7 @@ pub enum Orientation {
     Horizontal,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum Fill {
     ToZeroY,
@@ -149,7 +172,19 @@ pub enum Fill {
     None,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum LocationMode {
+    #[serde(rename = "ISO-3")]
+    Iso3,
+    #[serde(rename = "USA-states")]
+    UsaStates,
+    #[serde(rename = "country names")]
+    CountryNames,
+    #[serde(rename = "geojson-id")]
+    GeoJsonId,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum Calendar {
     Gregorian,
@@ -170,7 +205,7 @@ pub enum Calendar {
     Ummalqura,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(untagged)]
 pub enum Dim<T>
 where
@@ -180,27 +215,46 @@ where
     Vector(Vec<T>),
 }
 
-#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum PlotType {
     Scatter,
     ScatterGL,
     Scatter3D,
+    ScatterGeo,
+    ScatterMapbox,
     ScatterPolar,
     ScatterPolarGL,
+    ScatterTernary,
     Bar,
+    BarPolar,
     Box,
     Candlestick,
+    Choropleth,
+    ChoroplethMapbox,
+    Cone,
     Contour,
+    DensityMapbox,
     HeatMap,
     Histogram,
+    Histogram2d,
     Histogram2dContour,
+    Icicle,
+    Isosurface,
+    Mesh3D,
     Ohlc,
+    Pie,
     Sankey,
+    Streamtube,
+    Sunburst,
     Surface,
+    Treemap,
+    Violin,
+    Volume,
+    Waterfall,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum Mode {
     Lines,
@@ -217,7 +271,7 @@ pub enum Mode {
     None,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum Ticks {
     Outside,
@@ -226,7 +280,7 @@ pub enum Ticks {
     None,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum Position {
     #[serde(rename = "top left")]
     TopLeft,
@@ -248,7 +302,7 @@ pub enum Position {
     BottomRight,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "kebab-case")]
 pub enum MarkerSymbol {
     Circle,
@@ -421,7 +475,7 @@ pub enum MarkerSymbol {
     LineNWOpen,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum TickMode {
     Auto,
@@ -429,7 +483,7 @@ pub enum TickMode {
     Array,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum DashType {
     Solid,
@@ -440,10 +494,10 @@ pub enum DashType {
     LongDashDot,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ColorScaleElement(pub f64, pub String);
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum ColorScalePalette {
     Greys,
     YlGnBu,
@@ -465,14 +519,14 @@ pub enum ColorScalePalette {
     Cividis,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(untagged)]
 pub enum ColorScale {
     Palette(ColorScalePalette),
     Vector(Vec<ColorScaleElement>),
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum LineShape {
     Linear,
@@ -484,7 +538,7 @@ pub enum LineShape {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Line {
     width: Option<f64>,
     shape: Option<LineShape>,
@@ -505,7 +559,9 @@ pub struct Line {
     #[serde(rename = "outliercolor")]
     outlier_color: Option<Box<dyn Color>>,
     #[serde(rename = "outlierwidth")]
-    outlier_width: Option<usize>,
+    outlier_width: Option<Number>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Line {
@@ -584,12 +640,12 @@ impl Line {
     }
 
     pub fn outlier_width(mut self, outlier_width: usize) -> Self {
-        self.outlier_width = Some(outlier_width);
+        self.outlier_width = Some(outlier_width.into());
         self
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum GradientType {
     Radial,
@@ -598,21 +654,21 @@ pub enum GradientType {
     None,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum SizeMode {
     Diameter,
     Area,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum ThicknessMode {
     Fraction,
     Pixels,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum Anchor {
     Auto,
@@ -624,7 +680,7 @@ pub enum Anchor {
     Bottom,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum TextAnchor {
     Start,
@@ -632,7 +688,7 @@ pub enum TextAnchor {
     End,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum ExponentFormat {
     None,
@@ -647,10 +703,12 @@ pub enum ExponentFormat {
     B,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Gradient {
     r#type: GradientType,
     color: Dim<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Gradient {
@@ -658,6 +716,7 @@ impl Gradient {
         Gradient {
             r#type: gradient_type,
             color: Dim::Scalar(Box::new(color)),
+            extra: Extra::new(),
         }
     }
 
@@ -665,32 +724,35 @@ impl Gradient {
         Gradient {
             r#type: gradient_type,
             color: Dim::Vector(ColorArray(colors).into()),
+            extra: Extra::new(),
         }
     }
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct TickFormatStop {
-    enabled: bool,
+    enabled: Option<bool>,
     #[serde(rename = "dtickrange")]
     dtick_range: Option<private::NumOrStringCollection>,
     value: Option<String>,
     name: Option<String>,
     #[serde(rename = "templateitemname")]
     template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl TickFormatStop {
     pub fn new() -> Self {
         TickFormatStop {
-            enabled: true,
+            enabled: Some(true),
             ..Default::default()
         }
     }
 
     pub fn enabled(mut self, enabled: bool) -> Self {
-        self.enabled = enabled;
+        self.enabled = Some(enabled);
         self
     }
 
@@ -715,7 +777,7 @@ impl TickFormatStop {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum Show {
     All,
@@ -725,18 +787,18 @@ pub enum Show {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ColorBar {
     #[serde(rename = "bgcolor")]
     background_color: Option<Box<dyn Color>>,
     #[serde(rename = "bordercolor")]
     border_color: Option<Box<dyn Color>>,
     #[serde(rename = "borderwidth")]
-    border_width: Option<usize>,
+    border_width: Option<Number>,
     dtick: Option<f64>,
     #[serde(rename = "exponentformat")]
     exponent_format: Option<ExponentFormat>,
-    len: Option<usize>,
+    len: Option<Number>,
     #[serde(rename = "lenmode")]
     len_mode: Option<ThicknessMode>,
     #[serde(rename = "nticks")]
@@ -744,7 +806,7 @@ pub struct ColorBar {
     #[serde(rename = "outlinecolor")]
     outline_color: Option<Box<dyn Color>>,
     #[serde(rename = "outlinewidth")]
-    outline_width: Option<usize>,
+    outline_width: Option<Number>,
     #[serde(rename = "separatethousands")]
     separate_thousands: Option<bool>,
     #[serde(rename = "showexponent")]
@@ -756,7 +818,7 @@ pub struct ColorBar {
     #[serde(rename = "showticksuffix")]
     show_tick_suffix: Option<Show>,
 
-    thickness: Option<usize>,
+    thickness: Option<Number>,
     #[serde(rename = "thicknessmode")]
     thickness_mode: Option<ThicknessMode>,
     #[serde(rename = "tickangle")]
@@ -770,7 +832,7 @@ pub struct ColorBar {
     #[serde(rename = "tickformatstops")]
     tick_format_stops: Option<Vec<TickFormatStop>>,
     #[serde(rename = "ticklen")]
-    tick_len: Option<usize>,
+    tick_len: Option<Number>,
     #[serde(rename = "tickmode")]
     tick_mode: Option<TickMode>,
     #[serde(rename = "tickprefix")]
@@ -782,7 +844,7 @@ pub struct ColorBar {
     #[serde(rename = "tickvals")]
     tick_vals: Option<Vec<f64>>,
     #[serde(rename = "tickwidth")]
-    tick_width: Option<usize>,
+    tick_width: Option<Number>,
     tick0: Option<f64>,
     ticks: Option<Ticks>,
     title: Option<Title>,
@@ -796,6 +858,8 @@ pub struct ColorBar {
     y_anchor: Option<Anchor>,
     #[serde(rename = "ypad")]
     y_pad: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ColorBar {
@@ -814,7 +878,7 @@ impl ColorBar {
     }
 
     pub fn border_width(mut self, border_width: usize) -> Self {
-        self.border_width = Some(border_width);
+        self.border_width = Some(border_width.into());
         self
     }
 
@@ -829,7 +893,7 @@ impl ColorBar {
     }
 
     pub fn len(mut self, len: usize) -> Self {
-        self.len = Some(len);
+        self.len = Some(len.into());
         self
     }
 
@@ -849,7 +913,7 @@ impl ColorBar {
     }
 
     pub fn outline_width(mut self, outline_width: usize) -> Self {
-        self.outline_width = Some(outline_width);
+        self.outline_width = Some(outline_width.into());
         self
     }
 
@@ -879,7 +943,7 @@ impl ColorBar {
     }
 
     pub fn thickness(mut self, thickness: usize) -> Self {
-        self.thickness = Some(thickness);
+        self.thickness = Some(thickness.into());
         self
     }
 
@@ -914,7 +978,7 @@ impl ColorBar {
     }
 
     pub fn tick_len(mut self, tick_len: usize) -> Self {
-        self.tick_len = Some(tick_len);
+        self.tick_len = Some(tick_len.into());
         self
     }
 
@@ -945,7 +1009,7 @@ impl ColorBar {
     }
 
     pub fn tick_width(mut self, tick_width: usize) -> Self {
-        self.tick_width = Some(tick_width);
+        self.tick_width = Some(tick_width.into());
         self
     }
 
@@ -995,7 +1059,99 @@ impl ColorBar {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+/// A reference from a trace to one of the x axes of the layout: `XAxisRef::new(1)` refers to the
+/// axis set with `Layout::x_axis`, `XAxisRef::new(n)` to the one set with `Layout::x_axis_n(n, ..)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XAxisRef(usize);
+
+impl XAxisRef {
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, as axes are numbered from 1.
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        Self(n)
+    }
+
+    pub fn number(&self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for XAxisRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&private::axis_id("x", self.0))
+    }
+}
+
+impl Serialize for XAxisRef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for XAxisRef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        private::parse_axis_id("x", &id).map(Self).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Str(&id), &"an x axis such as \"x2\"")
+        })
+    }
+}
+
+/// A reference from a trace to one of the y axes of the layout: `YAxisRef::new(1)` refers to the
+/// axis set with `Layout::y_axis`, `YAxisRef::new(n)` to the one set with `Layout::y_axis_n(n, ..)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YAxisRef(usize);
+
+impl YAxisRef {
+    /// # Panics
+    ///
+    /// Panics if `n` is 0, as axes are numbered from 1.
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        Self(n)
+    }
+
+    pub fn number(&self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for YAxisRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&private::axis_id("y", self.0))
+    }
+}
+
+impl Serialize for YAxisRef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for YAxisRef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let id = String::deserialize(deserializer)?;
+        private::parse_axis_id("y", &id).map(Self).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Str(&id), &"a y axis such as \"y2\"")
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum AxisSide {
     Top,
@@ -1005,7 +1161,7 @@ pub enum AxisSide {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Marker {
     symbol: Option<MarkerSymbol>,
     opacity: Option<f64>,
@@ -1013,14 +1169,15 @@ pub struct Marker {
     #[serde(rename = "maxdisplayed")]
     max_displayed: Option<usize>,
     #[serde(rename = "sizeref")]
-    size_ref: Option<usize>,
+    size_ref: Option<Number>,
     #[serde(rename = "sizemin")]
-    size_min: Option<usize>,
+    size_min: Option<Number>,
     #[serde(rename = "sizemode")]
     size_mode: Option<SizeMode>,
     line: Option<Line>,
     gradient: Option<Gradient>,
     color: Option<Dim<Box<dyn Color>>>,
+    colors: Option<Vec<Box<dyn Color>>>,
     cauto: Option<bool>,
     cmin: Option<f64>,
     cmax: Option<f64>,
@@ -1037,6 +1194,8 @@ pub struct Marker {
     color_bar: Option<ColorBar>,
     #[serde(rename = "outliercolor")]
     outlier_color: Option<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Marker {
@@ -1070,12 +1229,12 @@ impl Marker {
     }
 
     pub fn size_ref(mut self, size: usize) -> Self {
-        self.size_ref = Some(size);
+        self.size_ref = Some(size.into());
         self
     }
 
     pub fn size_min(mut self, size: usize) -> Self {
-        self.size_min = Some(size);
+        self.size_min = Some(size.into());
         self
     }
 
@@ -1104,6 +1263,13 @@ impl Marker {
         self
     }
 
+    /// Sets the color of each sector in traces which color by sector rather than by point, such
+    /// as `Pie`.
+    pub fn colors<C: Color>(mut self, colors: Vec<C>) -> Self {
+        self.colors = Some(ColorArray(colors).into());
+        self
+    }
+
     pub fn cauto(mut self, cauto: bool) -> Self {
         self.cauto = Some(cauto);
         self
@@ -1156,11 +1322,13 @@ impl Marker {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Font {
     family: Option<String>,
-    size: Option<usize>,
+    size: Option<Number>,
     color: Option<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Font {
@@ -1174,7 +1342,7 @@ impl Font {
     }
 
     pub fn size(mut self, size: usize) -> Self {
-        self.size = Some(size);
+        self.size = Some(size.into());
         self
     }
 
@@ -1184,7 +1352,7 @@ impl Font {
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum Side {
     Right,
@@ -1195,30 +1363,39 @@ pub enum Side {
     TopLeft,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum Reference {
     Container,
     Paper,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Pad {
-    t: usize,
-    b: usize,
-    l: usize,
+    t: Option<usize>,
+    b: Option<usize>,
+    l: Option<usize>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Pad {
     pub fn new(t: usize, b: usize, l: usize) -> Self {
-        Pad { t, b, l }
+        Pad {
+            t: Some(t),
+            b: Some(b),
+            l: Some(l),
+            extra: Extra::new(),
+        }
     }
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(remote = "Self")]
 pub struct Title {
-    text: String,
+    text: Option<String>,
     font: Option<Font>,
     side: Option<Side>,
     #[serde(rename = "xref")]
@@ -1232,6 +1409,30 @@ pub struct Title {
     #[serde(rename = "yanchor")]
     y_anchor: Option<Anchor>,
     pad: Option<Pad>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Serialize for Title {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Title::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Title {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Plotly accepts a plain string as the text of a title, and plotly.py writes it that way.
+        match Value::deserialize(deserializer)? {
+            Value::String(text) => Ok(Title::new(&text)),
+            title => Title::deserialize(title).map_err(de::Error::custom),
+        }
+    }
 }
 
 impl From<&str> for Title {
@@ -1243,7 +1444,7 @@ impl From<&str> for Title {
 impl Title {
     pub fn new(text: &str) -> Self {
         Title {
-            text: text.to_owned(),
+            text: Some(text.to_owned()),
             ..Default::default()
         }
     }
@@ -1295,7 +1496,7 @@ impl Title {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Label {
     #[serde(rename = "bgcolor")]
     background_color: Option<Box<dyn Color>>,
@@ -1305,6 +1506,8 @@ pub struct Label {
     align: Option<String>,
     #[serde(rename = "namelength")]
     name_length: Option<Dim<i32>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Label {
@@ -1343,9 +1546,10 @@ impl Label {
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum ErrorType {
+    #[default]
     Percent,
     Constant,
     #[serde(rename = "sqrt")]
@@ -1353,14 +1557,8 @@ pub enum ErrorType {
     Data,
 }
 
-impl Default for ErrorType {
-    fn default() -> Self {
-        ErrorType::Percent
-    }
-}
-
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ErrorData {
     r#type: ErrorType,
     array: Option<Vec<f64>>,
@@ -1378,7 +1576,9 @@ pub struct ErrorData {
     copy_ystyle: Option<bool>,
     color: Option<Box<dyn Color>>,
     thickness: Option<f64>,
-    width: Option<usize>,
+    width: Option<Number>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ErrorData {
@@ -1445,12 +1645,12 @@ impl ErrorData {
     }
 
     pub fn width(mut self, width: usize) -> Self {
-        self.width = Some(width);
+        self.width = Some(width.into());
         self
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum HoverOn {
     Points,
@@ -1461,7 +1661,7 @@ pub enum HoverOn {
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{json, to_value};
+    use serde_json::{from_value, json, to_value};
 
     use super::*;
     use crate::color::NamedColor;
@@ -1541,6 +1741,23 @@ mod tests {
         assert_eq!(to_value(Fill::None).unwrap(), json!("none"));
     }
 
+    #[test]
+    fn test_serialize_location_mode() {
+        assert_eq!(to_value(LocationMode::Iso3).unwrap(), json!("ISO-3"));
+        assert_eq!(
+            to_value(LocationMode::UsaStates).unwrap(),
+            json!("USA-states")
+        );
+        assert_eq!(
+            to_value(LocationMode::CountryNames).unwrap(),
+            json!("country names")
+        );
+        assert_eq!(
+            to_value(LocationMode::GeoJsonId).unwrap(),
+            json!("geojson-id")
+        );
+    }
+
     #[test]
     fn test_serialize_calendar() {
         assert_eq!(to_value(Calendar::Gregorian).unwrap(), json!("gregorian"));
@@ -1573,18 +1790,37 @@ mod tests {
         assert_eq!(to_value(PlotType::Scatter).unwrap(), json!("scatter"));
         assert_eq!(to_value(PlotType::ScatterGL).unwrap(), json!("scattergl"));
         assert_eq!(to_value(PlotType::Scatter3D).unwrap(), json!("scatter3d"));
+        assert_eq!(to_value(PlotType::ScatterGeo).unwrap(), json!("scattergeo"));
+        assert_eq!(to_value(PlotType::ScatterMapbox).unwrap(), json!("scattermapbox"));
         assert_eq!(to_value(PlotType::ScatterPolar).unwrap(), json!("scatterpolar"));
         assert_eq!(to_value(PlotType::ScatterPolarGL).unwrap(), json!("scatterpolargl"));
+        assert_eq!(to_value(PlotType::ScatterTernary).unwrap(), json!("scatterternary"));
         assert_eq!(to_value(PlotType::Bar).unwrap(), json!("bar"));
+        assert_eq!(to_value(PlotType::BarPolar).unwrap(), json!("barpolar"));
         assert_eq!(to_value(PlotType::Box).unwrap(), json!("box"));
         assert_eq!(to_value(PlotType::Candlestick).unwrap(), json!("candlestick"));
+        assert_eq!(to_value(PlotType::Choropleth).unwrap(), json!("choropleth"));
+        assert_eq!(to_value(PlotType::ChoroplethMapbox).unwrap(), json!("choroplethmapbox"));
+        assert_eq!(to_value(PlotType::Cone).unwrap(), json!("cone"));
         assert_eq!(to_value(PlotType::Contour).unwrap(), json!("contour"));
+        assert_eq!(to_value(PlotType::DensityMapbox).unwrap(), json!("densitymapbox"));
         assert_eq!(to_value(PlotType::HeatMap).unwrap(), json!("heatmap"));
         assert_eq!(to_value(PlotType::Histogram).unwrap(), json!("histogram"));
+        assert_eq!(to_value(PlotType::Histogram2d).unwrap(), json!("histogram2d"));
         assert_eq!(to_value(PlotType::Histogram2dContour).unwrap(), json!("histogram2dcontour"));
+        assert_eq!(to_value(PlotType::Icicle).unwrap(), json!("icicle"));
+        assert_eq!(to_value(PlotType::Isosurface).unwrap(), json!("isosurface"));
+        assert_eq!(to_value(PlotType::Mesh3D).unwrap(), json!("mesh3d"));
         assert_eq!(to_value(PlotType::Ohlc).unwrap(), json!("ohlc"));
+        assert_eq!(to_value(PlotType::Pie).unwrap(), json!("pie"));
         assert_eq!(to_value(PlotType::Sankey).unwrap(), json!("sankey"));
+        assert_eq!(to_value(PlotType::Streamtube).unwrap(), json!("streamtube"));
+        assert_eq!(to_value(PlotType::Sunburst).unwrap(), json!("sunburst"));
         assert_eq!(to_value(PlotType::Surface).unwrap(), json!("surface"));
+        assert_eq!(to_value(PlotType::Treemap).unwrap(), json!("treemap"));
+        assert_eq!(to_value(PlotType::Violin).unwrap(), json!("violin"));
+        assert_eq!(to_value(PlotType::Volume).unwrap(), json!("volume"));
+        assert_eq!(to_value(PlotType::Waterfall).unwrap(), json!("waterfall"));
     }
 
     #[test]
@@ -1609,6 +1845,32 @@ mod tests {
         assert_eq!(to_value(AxisSide::Bottom).unwrap(), json!("bottom"));
     }
 
+    #[test]
+    fn test_serialize_axis_ref() {
+        assert_eq!(to_value(XAxisRef::new(1)).unwrap(), json!("x"));
+        assert_eq!(to_value(XAxisRef::new(12)).unwrap(), json!("x12"));
+        assert_eq!(to_value(YAxisRef::new(1)).unwrap(), json!("y"));
+        assert_eq!(to_value(YAxisRef::new(2)).unwrap(), json!("y2"));
+    }
+
+    #[test]
+    fn test_deserialize_axis_ref() {
+        assert_eq!(
+            from_value::<XAxisRef>(json!("x")).unwrap(),
+            XAxisRef::new(1)
+        );
+        assert_eq!(
+            from_value::<XAxisRef>(json!("x12")).unwrap(),
+            XAxisRef::new(12)
+        );
+        assert_eq!(
+            from_value::<YAxisRef>(json!("y3")).unwrap(),
+            YAxisRef::new(3)
+        );
+        assert!(from_value::<XAxisRef>(json!("y2")).is_err());
+        assert!(from_value::<YAxisRef>(json!("y1")).is_err());
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_serialize_position() {
@@ -2093,6 +2355,7 @@ mod tests {
             .gradient(Gradient::new(GradientType::Radial, "#FFFFFF"))
             .color(NamedColor::Blue)
             .color_array(vec![NamedColor::Black, NamedColor::Blue])
+            .colors(vec![NamedColor::Red, NamedColor::Green])
             .cauto(true)
             .cmin(0.0)
             .cmax(1.0)
@@ -2115,6 +2378,7 @@ mod tests {
             "line": {},
             "gradient": {"type": "radial", "color": "#FFFFFF"},
             "color": ["black", "blue"],
+            "colors": ["red", "green"],
             "colorbar": {},
             "cauto": true,
             "cmin": 0.0,
@@ -2169,6 +2433,14 @@ mod tests {
         assert_eq!(to_value(pad).unwrap(), expected);
     }
 
+    #[test]
+    fn test_deserialize_pad() {
+        let json = json!({"t": 10, "r": 5});
+        let pad: Pad = from_value(json.clone()).unwrap();
+
+        assert_eq!(to_value(pad).unwrap(), json);
+    }
+
     #[test]
     fn test_serialize_title() {
         let title = Title::new("title")
@@ -2210,6 +2482,18 @@ mod tests {
         assert_eq!(to_value(title).unwrap(), expected);
     }
 
+    #[test]
+    fn test_deserialize_title() {
+        let title: Title = from_value(json!("shorthand")).unwrap();
+        assert_eq!(to_value(title).unwrap(), json!({"text": "shorthand"}));
+
+        let json = json!({"text": "title", "font": {"size": 12.5}, "x": 0.05});
+        let title: Title = from_value(json.clone()).unwrap();
+        assert_eq!(to_value(title).unwrap(), json);
+
+        assert!(from_value::<Title>(json!(5)).is_err());
+    }
+
     #[test]
     fn test_serialize_label() {
         let label = Label::new()
@@ -2284,6 +2568,17 @@ mod tests {
         assert_eq!(to_value(Visible::LegendOnly).unwrap(), json!("legendonly"));
     }
 
+    #[test]
+    fn test_deserialize_visible() {
+        assert!(matches!(from_value(json!(true)).unwrap(), Visible::True));
+        assert!(matches!(from_value(json!(false)).unwrap(), Visible::False));
+        assert!(matches!(
+            from_value(json!("legendonly")).unwrap(),
+            Visible::LegendOnly
+        ));
+        assert!(from_value::<Visible>(json!("hidden")).is_err());
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_serialize_hover_on() {
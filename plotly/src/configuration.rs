@@ -1,7 +1,9 @@
-use serde::{ser::Serializer, Serialize};
-use serde_repr::Serialize_repr;
+use serde::{de, ser::Serializer, Deserialize, Deserializer, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
-#[derive(Serialize, Debug, Clone)]
+use crate::private::{BoolOrString, Extra};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum ImageButtonFormats {
     Png,
@@ -12,13 +14,15 @@ pub enum ImageButtonFormats {
 
 // TODO: should this be behind the plotly-kaleido feature?
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct ToImageButtonOptions {
     format: Option<ImageButtonFormats>,
     filename: Option<String>,
     height: Option<usize>,
     width: Option<usize>,
     scale: Option<usize>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ToImageButtonOptions {
@@ -79,7 +83,23 @@ impl Serialize for DisplayModeBar {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+impl<'de> Deserialize<'de> for DisplayModeBar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(true) => Ok(Self::True),
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::String(s) => match s.as_str() {
+                "hover" => Ok(Self::Hover),
+                _ => Err(de::Error::unknown_variant(&s, &["hover"])),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum ModeBarButtonName {
     Zoom2d,
@@ -137,7 +157,31 @@ impl Serialize for DoubleClick {
     }
 }
 
-#[derive(Serialize_repr, Debug, Clone)]
+impl<'de> Deserialize<'de> for DoubleClick {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "reset" => Ok(Self::Reset),
+                "autosize" => Ok(Self::AutoSize),
+                "reset+autosize" => Ok(Self::ResetAutoSize),
+                _ => Err(de::Error::unknown_variant(
+                    &s,
+                    &["reset", "autosize", "reset+autosize"],
+                )),
+            },
+        }
+    }
+}
+
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone)]
 #[repr(u8)]
 pub enum PlotGLPixelRatio {
     One = 1,
@@ -147,7 +191,7 @@ pub enum PlotGLPixelRatio {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Configuration {
     // reference is here: https://github.com/plotly/plotly.js/blob/master/src/plot_api/plot_config.js
@@ -186,6 +230,8 @@ pub struct Configuration {
     plot_gl_pixel_ratio: Option<PlotGLPixelRatio>,
     show_send_to_cloud: Option<bool>,
     queue_length: Option<usize>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Configuration {
@@ -383,6 +429,7 @@ impl Configuration {
     /// Set the URL to topojson used in geo charts. By default, the topojson files are fetched from
     /// cdn.plot.ly. For example, set this option to: "<path-to-plotly.js>/dist/topojson/" to render
     /// geographical feature using the topojson files that ship with the plotly.js module.
+    /// Alternatively, use `Plot::add_topojson` to embed the topojson in the plot itself.
     pub fn topojson_url(mut self, topojson_url: &str) -> Self {
         self.topojson_url = Some(topojson_url.to_string());
         self
@@ -412,7 +459,7 @@ impl Configuration {
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{json, to_value};
+    use serde_json::{from_value, json, to_value};
 
     use super::*;
 
@@ -449,6 +496,23 @@ mod tests {
         assert_eq!(to_value(DisplayModeBar::False).unwrap(), json!(false));
     }
 
+    #[test]
+    fn test_deserialize_display_mode_bar() {
+        assert!(matches!(
+            from_value(json!("hover")).unwrap(),
+            DisplayModeBar::Hover
+        ));
+        assert!(matches!(
+            from_value(json!(true)).unwrap(),
+            DisplayModeBar::True
+        ));
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            DisplayModeBar::False
+        ));
+        assert!(from_value::<DisplayModeBar>(json!("always")).is_err());
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_serialize_mode_bar_button_name() {
@@ -494,6 +558,27 @@ mod tests {
         assert_eq!(to_value(DoubleClick::ResetAutoSize).unwrap(), json!("reset+autosize"));
     }
 
+    #[test]
+    fn test_deserialize_double_click() {
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            DoubleClick::False
+        ));
+        assert!(matches!(
+            from_value(json!("reset")).unwrap(),
+            DoubleClick::Reset
+        ));
+        assert!(matches!(
+            from_value(json!("autosize")).unwrap(),
+            DoubleClick::AutoSize
+        ));
+        assert!(matches!(
+            from_value(json!("reset+autosize")).unwrap(),
+            DoubleClick::ResetAutoSize
+        ));
+        assert!(from_value::<DoubleClick>(json!(true)).is_err());
+    }
+
     #[test]
     fn test_serialize_plot_gl_pixel_ratio() {
         assert_eq!(to_value(PlotGLPixelRatio::One).unwrap(), json!(1));
@@ -0,0 +1,106 @@
+use std::fmt;
+use std::io;
+
+/// The ways in which rendering, showing, saving or reading a `Plot` can fail.
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be written, or a program (e.g. the browser) could not be started.
+    Io(io::Error),
+    /// The HTML template could not be rendered.
+    Render(askama::Error),
+    /// Kaleido failed to export the plot to a static image, with the code and message it returned.
+    /// A missing Kaleido executable is reported as an `Error::Io`.
+    Kaleido { code: i32, message: String },
+    /// The plot could not be serialized to, or read back from, JSON.
+    Serialization(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Render(e) => write!(f, "failed to render plot: {}", e),
+            Error::Kaleido { code, message } => {
+                write!(f, "Kaleido failed with code {}: {}", code, message)
+            }
+            Error::Serialization(e) => write!(f, "invalid plot JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Render(e) => Some(e),
+            Error::Kaleido { .. } => None,
+            Error::Serialization(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<askama::Error> for Error {
+    fn from(e: askama::Error) -> Self {
+        Error::Render(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Serialization(e)
+    }
+}
+
+#[cfg(feature = "kaleido")]
+impl From<plotly_kaleido::Error> for Error {
+    fn from(e: plotly_kaleido::Error) -> Self {
+        match e {
+            plotly_kaleido::Error::Io(e) => Error::Io(e),
+            plotly_kaleido::Error::Kaleido { code, message } => Error::Kaleido { code, message },
+            plotly_kaleido::Error::Serialization(e) => Error::Serialization(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_display() {
+        let err = Error::Kaleido {
+            code: 525,
+            message: "Mapbox error.".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Kaleido failed with code 525: Mapbox error."
+        );
+
+        let err: Error = io::Error::new(io::ErrorKind::NotFound, "no such file").into();
+        assert!(matches!(err, Error::Io(_)));
+        assert_eq!(err.to_string(), "no such file");
+    }
+
+    #[test]
+    fn test_error_source() {
+        use std::error::Error as _;
+
+        let err: Error = serde_json::from_str::<serde_json::Value>("{")
+            .unwrap_err()
+            .into();
+        assert!(err.source().is_some());
+        assert!(Error::Kaleido {
+            code: 1,
+            message: String::new()
+        }
+        .source()
+        .is_none());
+    }
+}
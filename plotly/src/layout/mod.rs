@@ -1,19 +1,24 @@
+pub mod subplots;
 pub mod themes;
 
 use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 
-use serde::{Serialize, Serializer};
+use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Number, Value};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::{
     color::{Color, ColorArray},
     common::{
-        Anchor, AxisSide, Calendar, ColorBar, ColorScale, DashType, ExponentFormat, Font, Label,
-        Orientation, TickFormatStop, TickMode, Title,
+        Anchor, AxisSide, Calendar, ColorBar, ColorScale, DashType, Domain, ExponentFormat, Font,
+        Label, Orientation, Pad, Position, ThicknessMode, TickFormatStop, TickMode, Title,
     },
-    private::{NumOrString, NumOrStringCollection},
+    private::{self, BoolOrString, Extra, NumOrString, NumOrStringCollection},
+    Error, Trace,
 };
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum AxisType {
     #[serde(rename = "-")]
@@ -25,14 +30,14 @@ pub enum AxisType {
     MultiCategory,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum AxisConstrain {
     Range,
     Domain,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum ConstrainDirection {
     Left,
@@ -43,7 +48,7 @@ pub enum ConstrainDirection {
     Bottom,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum RangeMode {
     Normal,
@@ -51,21 +56,23 @@ pub enum RangeMode {
     NonNegative,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum TicksDirection {
     Outside,
     Inside,
+    #[serde(rename = "")]
+    None,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum TicksPosition {
     Labels,
     Boundaries,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum ArrayShow {
     All,
@@ -74,7 +81,7 @@ pub enum ArrayShow {
     None,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum BarMode {
     Stack,
@@ -83,7 +90,7 @@ pub enum BarMode {
     Relative,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum BarNorm {
     #[serde(rename = "")]
@@ -92,28 +99,28 @@ pub enum BarNorm {
     Percent,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum BoxMode {
     Group,
     Overlay,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum ViolinMode {
     Group,
     Overlay,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum WaterfallMode {
     Group,
     Overlay,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum TraceOrder {
     Reversed,
@@ -123,7 +130,7 @@ pub enum TraceOrder {
     Normal,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum ItemSizing {
     Trace,
@@ -150,7 +157,27 @@ impl Serialize for ItemClick {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+impl<'de> Deserialize<'de> for ItemClick {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "toggle" => Ok(Self::Toggle),
+                "toggleothers" => Ok(Self::ToggleOthers),
+                _ => Err(de::Error::unknown_variant(&s, &["toggle", "toggleothers"])),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum GroupClick {
     ToggleItem,
@@ -158,20 +185,20 @@ pub enum GroupClick {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct Legend {
     #[serde(rename = "bgcolor")]
     background_color: Option<Box<dyn Color>>,
     #[serde(rename = "bordercolor")]
     border_color: Option<Box<dyn Color>>,
     #[serde(rename = "borderwidth")]
-    border_width: Option<usize>,
+    border_width: Option<Number>,
     font: Option<Font>,
     orientation: Option<Orientation>,
     #[serde(rename = "traceorder")]
     trace_order: Option<TraceOrder>,
     #[serde(rename = "tracegroupgap")]
-    trace_group_gap: Option<usize>,
+    trace_group_gap: Option<Number>,
     #[serde(rename = "itemsizing")]
     item_sizing: Option<ItemSizing>,
     #[serde(rename = "itemclick")]
@@ -189,7 +216,9 @@ pub struct Legend {
     #[serde(rename = "groupclick")]
     group_click: Option<GroupClick>,
     #[serde(rename = "itemwidth")]
-    item_width: Option<usize>,
+    item_width: Option<Number>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Legend {
@@ -208,7 +237,7 @@ impl Legend {
     }
 
     pub fn border_width(mut self, border_width: usize) -> Self {
-        self.border_width = Some(border_width);
+        self.border_width = Some(border_width.into());
         self
     }
 
@@ -228,7 +257,7 @@ impl Legend {
     }
 
     pub fn trace_group_gap(mut self, trace_group_gap: usize) -> Self {
-        self.trace_group_gap = Some(trace_group_gap);
+        self.trace_group_gap = Some(trace_group_gap.into());
         self
     }
 
@@ -283,12 +312,12 @@ impl Legend {
     }
 
     pub fn item_width(mut self, item_width: usize) -> Self {
-        self.item_width = Some(item_width);
+        self.item_width = Some(item_width.into());
         self
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum VAlign {
     Top,
@@ -296,7 +325,7 @@ pub enum VAlign {
     Bottom,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum HAlign {
     Left,
@@ -305,7 +334,7 @@ pub enum HAlign {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct Margin {
     l: Option<usize>,
     r: Option<usize>,
@@ -314,6 +343,8 @@ pub struct Margin {
     pad: Option<usize>,
     #[serde(rename = "autoexpand")]
     auto_expand: Option<bool>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Margin {
@@ -353,12 +384,14 @@ impl Margin {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct LayoutColorScale {
     sequential: Option<ColorScale>,
     #[serde(rename = "sequentialminus")]
     sequential_minus: Option<ColorScale>,
     diverging: Option<ColorScale>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl LayoutColorScale {
@@ -382,7 +415,7 @@ impl LayoutColorScale {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum SliderRangeMode {
     Auto,
@@ -391,11 +424,13 @@ pub enum SliderRangeMode {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct RangeSliderYAxis {
     #[serde(rename = "rangemode")]
     range_mode: Option<SliderRangeMode>,
     range: Option<NumOrStringCollection>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl RangeSliderYAxis {
@@ -415,7 +450,7 @@ impl RangeSliderYAxis {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct RangeSlider {
     #[serde(rename = "bgcolor")]
     background_color: Option<Box<dyn Color>>,
@@ -430,6 +465,8 @@ pub struct RangeSlider {
     visible: Option<bool>,
     #[serde(rename = "yaxis")]
     y_axis: Option<RangeSliderYAxis>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl RangeSlider {
@@ -478,7 +515,7 @@ impl RangeSlider {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum SelectorStep {
     Month,
@@ -490,7 +527,7 @@ pub enum SelectorStep {
     All,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum StepMode {
     Backward,
@@ -498,7 +535,7 @@ pub enum StepMode {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct SelectorButton {
     visible: Option<bool>,
     step: Option<SelectorStep>,
@@ -509,6 +546,8 @@ pub struct SelectorButton {
     name: Option<String>,
     #[serde(rename = "templateitemname")]
     template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl SelectorButton {
@@ -553,7 +592,7 @@ impl SelectorButton {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct RangeSelector {
     visible: Option<bool>,
     buttons: Option<Vec<SelectorButton>>,
@@ -571,7 +610,9 @@ pub struct RangeSelector {
     #[serde(rename = "bordercolor")]
     border_color: Option<Box<dyn Color>>,
     #[serde(rename = "borderwidth")]
-    border_width: Option<usize>,
+    border_width: Option<Number>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl RangeSelector {
@@ -630,13 +671,341 @@ impl RangeSelector {
     }
 
     pub fn border_width(mut self, border_width: usize) -> Self {
-        self.border_width = Some(border_width);
+        self.border_width = Some(border_width.into());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum RangeBreakPattern {
+    #[serde(rename = "day of week")]
+    DayOfWeek,
+    Hour,
+    #[serde(rename = "")]
+    None,
+}
+
+/// A range on a date axis that is left out of the plot, e.g. weekends or the hours a market is
+/// closed.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::layout::{RangeBreak, RangeBreakPattern};
+///
+/// // Hide weekends and everything outside of 9:30 to 16:00.
+/// let breaks = vec![
+///     RangeBreak::new().bounds(vec!["sat", "mon"]),
+///     RangeBreak::new()
+///         .pattern(RangeBreakPattern::Hour)
+///         .bounds(vec![16.0, 9.5]),
+/// ];
+///
+/// let expected = serde_json::json!([
+///     {"bounds": ["sat", "mon"]},
+///     {"pattern": "hour", "bounds": [16.0, 9.5]}
+/// ]);
+///
+/// assert_eq!(serde_json::to_value(breaks).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct RangeBreak {
+    enabled: Option<bool>,
+    bounds: Option<NumOrStringCollection>,
+    pattern: Option<RangeBreakPattern>,
+    values: Option<NumOrStringCollection>,
+    dvalue: Option<f64>,
+    name: Option<String>,
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl RangeBreak {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Derives range breaks from the gaps in the given date coordinates, so that a trace plotted
+    /// against them is drawn without empty stretches, e.g. over weekends and holidays for daily
+    /// data or overnight for intraday data.
+    ///
+    /// The sampling step is taken to be the most common distance between two consecutive dates,
+    /// and every distance of at least two steps is a gap, left out from one step after the earlier
+    /// date up to the later one. If there is no data on any weekend, weekends are left out by a
+    /// single `RangeBreakPattern::DayOfWeek` break. The remaining gaps are grouped by their length
+    /// into one break each, listing where they start in `values` and their length in `dvalue`.
+    ///
+    /// Dates must be of the form "YYYY-MM-DD", optionally followed by a time "HH:MM[:SS[.sss]]";
+    /// any other value is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use plotly::layout::RangeBreak;
+    ///
+    /// // Two weeks of trading days, without Monday the 16th.
+    /// let breaks = RangeBreak::from_gaps(&[
+    ///     "2017-01-09", "2017-01-10", "2017-01-11", "2017-01-12", "2017-01-13",
+    ///     "2017-01-17", "2017-01-18", "2017-01-19", "2017-01-20",
+    /// ]);
+    ///
+    /// let expected = serde_json::json!([
+    ///     {"bounds": ["sat", "mon"], "pattern": "day of week"},
+    ///     {"values": ["2017-01-16"], "dvalue": 86400000.0}
+    /// ]);
+    ///
+    /// assert_eq!(serde_json::to_value(breaks).unwrap(), expected);
+    /// ```
+    pub fn from_gaps<S: AsRef<str>>(x: &[S]) -> Vec<RangeBreak> {
+        let mut dates: Vec<i64> = x
+            .iter()
+            .filter_map(|date| parse_date_millis(date.as_ref()))
+            .collect();
+        dates.sort_unstable();
+        dates.dedup();
+
+        let mut distances: BTreeMap<i64, usize> = BTreeMap::new();
+        for pair in dates.windows(2) {
+            *distances.entry(pair[1] - pair[0]).or_insert(0) += 1;
+        }
+        // The most common distance, or the shortest of them on a tie.
+        let step = match distances
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+        {
+            Some((&step, _)) => step,
+            None => return Vec::new(),
+        };
+
+        let first_day = dates[0].div_euclid(MILLIS_PER_DAY);
+        let last_day = dates[dates.len() - 1].div_euclid(MILLIS_PER_DAY);
+        let skip_weekends = (first_day..=last_day).take(7).any(is_weekend)
+            && dates
+                .iter()
+                .all(|date| !is_weekend(date.div_euclid(MILLIS_PER_DAY)));
+
+        // The start of each gap, by its length.
+        let mut gaps: BTreeMap<i64, Vec<i64>> = BTreeMap::new();
+        for pair in dates
+            .windows(2)
+            .filter(|pair| pair[1] - pair[0] >= 2 * step)
+        {
+            let (mut start, end) = (pair[0] + step, pair[1]);
+            if !skip_weekends {
+                gaps.entry(end - start).or_default().push(start);
+                continue;
+            }
+            while start < end {
+                let day = start.div_euclid(MILLIS_PER_DAY);
+                let weekday = day_of_week(day);
+                if is_weekend(day) {
+                    let monday = if weekday == 6 { day + 2 } else { day + 1 };
+                    start = monday * MILLIS_PER_DAY;
+                    continue;
+                }
+                let saturday = (day + 6 - weekday) * MILLIS_PER_DAY;
+                let piece_end = end.min(saturday);
+                gaps.entry(piece_end - start).or_default().push(start);
+                start = piece_end;
+            }
+        }
+
+        let mut breaks = Vec::new();
+        if skip_weekends {
+            breaks.push(
+                RangeBreak::new()
+                    .pattern(RangeBreakPattern::DayOfWeek)
+                    .bounds(vec!["sat", "mon"]),
+            );
+        }
+        for (length, starts) in gaps {
+            let values: Vec<String> = starts.into_iter().map(format_date_millis).collect();
+            breaks.push(RangeBreak::new().values(values).dvalue(length as f64));
+        }
+        breaks
+    }
+
+    /// Enables or disables this range break.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the lower and upper bounds of this range break. Without a `pattern` these are dates,
+    /// e.g. `vec!["2020-12-24", "2020-12-27"]`; with `RangeBreakPattern::DayOfWeek` they are days
+    /// (0 or "sun" to 6 or "sat") and with `RangeBreakPattern::Hour` hours (0 to 24).
+    pub fn bounds<V: Into<NumOrString> + Clone>(mut self, bounds: Vec<V>) -> Self {
+        self.bounds = Some(bounds.into());
+        self
+    }
+
+    /// Determines the pattern by which `bounds` are interpreted. Plotly.js defaults to
+    /// `RangeBreakPattern::DayOfWeek` when the bounds are day names.
+    pub fn pattern(mut self, pattern: RangeBreakPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Sets the coordinate values which are left out, each spanning `dvalue`.
+    pub fn values<V: Into<NumOrString> + Clone>(mut self, values: Vec<V>) -> Self {
+        self.values = Some(values.into());
+        self
+    }
+
+    /// Sets the size of each of the `values`, in milliseconds. Plotly.js defaults to one day.
+    pub fn dvalue(mut self, dvalue: f64) -> Self {
+        self.dvalue = Some(dvalue);
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
+        self.template_item_name = Some(template_item_name.to_owned());
         self
     }
 }
 
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Parses a date of the form "YYYY-MM-DD[ HH:MM[:SS[.sss]]]" (a "T" may separate the date and
+/// time) into milliseconds since the Unix epoch.
+fn parse_date_millis(date: &str) -> Option<i64> {
+    fn number(s: &str, digits: usize) -> Option<i64> {
+        if s.len() == digits && s.bytes().all(|b| b.is_ascii_digit()) {
+            s.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    let (day, time) = match date.find(&[' ', 'T'][..]) {
+        Some(i) => (&date[..i], Some(&date[i + 1..])),
+        None => (date, None),
+    };
+
+    let mut parts = day.split('-');
+    let year = number(parts.next()?, 4)?;
+    let month = number(parts.next()?, 2)?;
+    let day = number(parts.next()?, 2)?;
+    if parts.next().is_some()
+        || !(1..=12).contains(&month)
+        || day < 1
+        || day > days_in_month(year, month)
+    {
+        return None;
+    }
+    let mut millis = days_from_civil(year, month, day) * MILLIS_PER_DAY;
+
+    if let Some(time) = time {
+        let (time, fraction) = match time.find('.') {
+            Some(i) => (&time[..i], Some(&time[i + 1..])),
+            None => (time, None),
+        };
+        let mut parts = time.split(':');
+        let hour = number(parts.next()?, 2)?;
+        let minute = number(parts.next()?, 2)?;
+        let second = match parts.next() {
+            Some(second) => number(second, 2)?,
+            None if fraction.is_none() => 0,
+            None => return None,
+        };
+        if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+        millis += ((hour * 60 + minute) * 60 + second) * 1000;
+
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let fraction = format!("{:0<3}", &fraction[..fraction.len().min(3)]);
+            millis += fraction.parse::<i64>().ok()?;
+        }
+    }
+
+    Some(millis)
+}
+
+/// Formats milliseconds since the Unix epoch as a date in the form understood by plotly.js,
+/// leaving out the time at midnight.
+fn format_date_millis(millis: i64) -> String {
+    let days = millis.div_euclid(MILLIS_PER_DAY);
+    let millis = millis.rem_euclid(MILLIS_PER_DAY);
+    let (year, month, day) = civil_from_days(days);
+    let date = format!("{:04}-{:02}-{:02}", year, month, day);
+    if millis == 0 {
+        return date;
+    }
+
+    let seconds = millis / 1000;
+    let time = format!(
+        "{} {:02}:{:02}:{:02}",
+        date,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    match millis % 1000 {
+        0 => time,
+        fraction => format!("{}.{:03}", time, fraction),
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the day of the week of a number of days since 1970-01-01, from 0 for Sunday to 6 for
+/// Saturday.
+fn day_of_week(days: i64) -> i64 {
+    // 1970-01-01 was a Thursday.
+    (days + 4).rem_euclid(7)
+}
+
+fn is_weekend(days: i64) -> bool {
+    matches!(day_of_week(days), 0 | 6)
+}
+
+/// Returns the number of days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct ColorAxis {
     cauto: Option<bool>,
     cmin: Option<f64>,
@@ -652,6 +1021,8 @@ pub struct ColorAxis {
     show_scale: Option<bool>,
     #[serde(rename = "colorbar")]
     color_bar: Option<ColorBar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ColorAxis {
@@ -705,7 +1076,7 @@ impl ColorAxis {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum SpikeMode {
     ToAxis,
@@ -721,7 +1092,7 @@ pub enum SpikeMode {
     ToaxisAcrossMarker,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum SpikeSnap {
     Data,
@@ -731,7 +1102,7 @@ pub enum SpikeSnap {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct Axis {
     visible: Option<bool>,
     color: Option<Box<dyn Color>>,
@@ -766,9 +1137,9 @@ pub struct Axis {
     ticks_on: Option<TicksPosition>,
     mirror: Option<bool>,
     #[serde(rename = "ticklen")]
-    tick_length: Option<usize>,
+    tick_length: Option<Number>,
     #[serde(rename = "tickwidth")]
-    tick_width: Option<usize>,
+    tick_width: Option<Number>,
     #[serde(rename = "tickcolor")]
     tick_color: Option<Box<dyn Color>>,
     #[serde(rename = "showticklabels")]
@@ -780,7 +1151,7 @@ pub struct Axis {
     #[serde(rename = "spikecolor")]
     spike_color: Option<Box<dyn Color>>,
     #[serde(rename = "spikethickness")]
-    spike_thickness: Option<usize>,
+    spike_thickness: Option<Number>,
     #[serde(rename = "spikedash")]
     spike_dash: Option<DashType>,
     #[serde(rename = "spikemode")]
@@ -816,25 +1187,25 @@ pub struct Axis {
     #[serde(rename = "linecolor")]
     line_color: Option<Box<dyn Color>>,
     #[serde(rename = "linewidth")]
-    line_width: Option<usize>,
+    line_width: Option<Number>,
     #[serde(rename = "showgrid")]
     show_grid: Option<bool>,
     #[serde(rename = "gridcolor")]
     grid_color: Option<Box<dyn Color>>,
     #[serde(rename = "gridwidth")]
-    grid_width: Option<usize>,
+    grid_width: Option<Number>,
     #[serde(rename = "zeroline")]
     zero_line: Option<bool>,
     #[serde(rename = "zerolinecolor")]
     zero_line_color: Option<Box<dyn Color>>,
     #[serde(rename = "zerolinewidth")]
-    zero_line_width: Option<usize>,
+    zero_line_width: Option<Number>,
     #[serde(rename = "showdividers")]
     show_dividers: Option<bool>,
     #[serde(rename = "dividercolor")]
     divider_color: Option<Box<dyn Color>>,
     #[serde(rename = "dividerwidth")]
-    divider_width: Option<usize>,
+    divider_width: Option<Number>,
     anchor: Option<String>,
     side: Option<AxisSide>,
     overlaying: Option<String>,
@@ -844,7 +1215,15 @@ pub struct Axis {
     range_slider: Option<RangeSlider>,
     #[serde(rename = "rangeselector")]
     range_selector: Option<RangeSelector>,
+    #[serde(rename = "rangebreaks")]
+    range_breaks: Option<Vec<RangeBreak>>,
     calendar: Option<Calendar>,
+    #[serde(rename = "showbackground")]
+    show_background: Option<bool>,
+    #[serde(rename = "backgroundcolor")]
+    background_color: Option<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Axis {
@@ -955,12 +1334,12 @@ impl Axis {
     }
 
     pub fn tick_length(mut self, tick_length: usize) -> Self {
-        self.tick_length = Some(tick_length);
+        self.tick_length = Some(tick_length.into());
         self
     }
 
     pub fn tick_width(mut self, tick_width: usize) -> Self {
-        self.tick_width = Some(tick_width);
+        self.tick_width = Some(tick_width.into());
         self
     }
 
@@ -990,7 +1369,7 @@ impl Axis {
     }
 
     pub fn spike_thickness(mut self, spike_thickness: usize) -> Self {
-        self.spike_thickness = Some(spike_thickness);
+        self.spike_thickness = Some(spike_thickness.into());
         self
     }
 
@@ -1080,7 +1459,7 @@ impl Axis {
     }
 
     pub fn line_width(mut self, line_width: usize) -> Self {
-        self.line_width = Some(line_width);
+        self.line_width = Some(line_width.into());
         self
     }
 
@@ -1095,7 +1474,7 @@ impl Axis {
     }
 
     pub fn grid_width(mut self, grid_width: usize) -> Self {
-        self.grid_width = Some(grid_width);
+        self.grid_width = Some(grid_width.into());
         self
     }
 
@@ -1110,7 +1489,7 @@ impl Axis {
     }
 
     pub fn zero_line_width(mut self, zero_line_width: usize) -> Self {
-        self.zero_line_width = Some(zero_line_width);
+        self.zero_line_width = Some(zero_line_width.into());
         self
     }
 
@@ -1125,7 +1504,7 @@ impl Axis {
     }
 
     pub fn divider_width(mut self, divider_width: usize) -> Self {
-        self.divider_width = Some(divider_width);
+        self.divider_width = Some(divider_width.into());
         self
     }
 
@@ -1164,13 +1543,34 @@ impl Axis {
         self
     }
 
+    /// Sets the ranges left out of a date axis. See `RangeBreak::from_gaps` to derive them from
+    /// the data.
+    pub fn range_breaks(mut self, range_breaks: Vec<RangeBreak>) -> Self {
+        self.range_breaks = Some(range_breaks);
+        self
+    }
+
     pub fn calendar(mut self, calendar: Calendar) -> Self {
         self.calendar = Some(calendar);
         self
     }
+
+    /// Sets whether or not this axis' wall has a background color. Only has an effect on the axes
+    /// of a `LayoutScene`.
+    pub fn show_background(mut self, show_background: bool) -> Self {
+        self.show_background = Some(show_background);
+        self
+    }
+
+    /// Sets the background color of this axis' wall. Only has an effect on the axes of a
+    /// `LayoutScene`.
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
+        self
+    }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum RowOrder {
     #[serde(rename = "top to bottom")]
     TopToBottom,
@@ -1178,14 +1578,14 @@ pub enum RowOrder {
     BottomToTop,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum GridPattern {
     Independent,
     Coupled,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum GridXSide {
     Bottom,
@@ -1196,7 +1596,7 @@ pub enum GridXSide {
     Top,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum GridYSide {
     Left,
@@ -1208,10 +1608,12 @@ pub enum GridYSide {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct GridDomain {
     x: Option<Vec<f64>>,
     y: Option<Vec<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl GridDomain {
@@ -1231,7 +1633,7 @@ impl GridDomain {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct LayoutGrid {
     rows: Option<usize>,
     #[serde(rename = "roworder")]
@@ -1253,6 +1655,8 @@ pub struct LayoutGrid {
     x_side: Option<GridXSide>,
     #[serde(rename = "yside")]
     y_side: Option<GridYSide>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl LayoutGrid {
@@ -1320,2630 +1724,7590 @@ impl LayoutGrid {
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum UniformTextMode {
-    False,
-    Hide,
-    Show,
-}
-
-impl Serialize for UniformTextMode {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match *self {
-            Self::False => serializer.serialize_bool(false),
-            Self::Hide => serializer.serialize_str("hide"),
-            Self::Show => serializer.serialize_str("show"),
-        }
-    }
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum AspectMode {
+    Auto,
+    Cube,
+    Data,
+    Manual,
 }
 
+/// The relative length of the x, y and z axes of a `LayoutScene`, used when its `aspect_mode` is
+/// `AspectMode::Manual`.
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct UniformText {
-    mode: Option<UniformTextMode>,
-    #[serde(rename = "minsize")]
-    min_size: Option<usize>,
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct AspectRatio {
+    x: Option<f64>,
+    y: Option<f64>,
+    z: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
-impl UniformText {
+impl AspectRatio {
     pub fn new() -> Self {
         Default::default()
     }
 
-    pub fn mode(mut self, mode: UniformTextMode) -> Self {
-        self.mode = Some(mode);
+    pub fn x(mut self, x: f64) -> Self {
+        self.x = Some(x);
         self
     }
 
-    pub fn min_size(mut self, min_size: usize) -> Self {
-        self.min_size = Some(min_size);
+    pub fn y(mut self, y: f64) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn z(mut self, z: f64) -> Self {
+        self.z = Some(z);
         self
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum HoverMode {
-    X,
-    Y,
-    Closest,
-    False,
-    XUnified,
-    YUnified,
+/// The point the camera looks at, relative to the center of the scene's bounding box. The center
+/// of the bounding box is at (0, 0, 0).
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct CameraCenter {
+    x: Option<f64>,
+    y: Option<f64>,
+    z: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
-impl Serialize for HoverMode {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match *self {
-            Self::X => serializer.serialize_str("x"),
-            Self::Y => serializer.serialize_str("y"),
-            Self::Closest => serializer.serialize_str("closest"),
-            Self::False => serializer.serialize_bool(false),
-            Self::XUnified => serializer.serialize_str("x unified"),
-            Self::YUnified => serializer.serialize_str("y unified"),
-        }
+impl CameraCenter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn x(mut self, x: f64) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: f64) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn z(mut self, z: f64) -> Self {
+        self.z = Some(z);
+        self
     }
 }
 
+/// The position of the camera, relative to the center of the scene's bounding box. Defaults to
+/// (1.25, 1.25, 1.25).
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct ModeBar {
-    orientation: Option<Orientation>,
-    #[serde(rename = "bgcolor")]
-    background_color: Option<Box<dyn Color>>,
-    color: Option<Box<dyn Color>>,
-    #[serde(rename = "activecolor")]
-    active_color: Option<Box<dyn Color>>,
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Eye {
+    x: Option<f64>,
+    y: Option<f64>,
+    z: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
-impl ModeBar {
+impl Eye {
     pub fn new() -> Self {
         Default::default()
     }
 
-    pub fn orientation(mut self, orientation: Orientation) -> Self {
-        self.orientation = Some(orientation);
+    pub fn x(mut self, x: f64) -> Self {
+        self.x = Some(x);
         self
     }
 
-    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
-        self.background_color = Some(Box::new(background_color));
+    pub fn y(mut self, y: f64) -> Self {
+        self.y = Some(y);
         self
     }
 
-    pub fn color<C: Color>(mut self, color: C) -> Self {
-        self.color = Some(Box::new(color));
+    pub fn z(mut self, z: f64) -> Self {
+        self.z = Some(z);
         self
     }
+}
 
-    pub fn active_color<C: Color>(mut self, active_color: C) -> Self {
-        self.active_color = Some(Box::new(active_color));
+/// The direction which points up on the page when viewing the scene. Defaults to (0, 0, 1), i.e.
+/// the z axis points up.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Up {
+    x: Option<f64>,
+    y: Option<f64>,
+    z: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Up {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn x(mut self, x: f64) -> Self {
+        self.x = Some(x);
         self
     }
-}
 
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum ShapeType {
-    Circle,
-    Rect,
-    Path,
-    Line,
+    pub fn y(mut self, y: f64) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn z(mut self, z: f64) -> Self {
+        self.z = Some(z);
+        self
+    }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
-pub enum ShapeLayer {
-    Below,
-    Above,
+pub enum CameraProjectionType {
+    Perspective,
+    Orthographic,
 }
 
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum ShapeSizeMode {
-    Scaled,
-    Pixel,
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct CameraProjection {
+    #[serde(rename = "type")]
+    projection_type: Option<CameraProjectionType>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum FillRule {
-    EvenOdd,
-    NonZero,
+impl CameraProjection {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn projection_type(mut self, projection_type: CameraProjectionType) -> Self {
+        self.projection_type = Some(projection_type);
+        self
+    }
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct ShapeLine {
-    color: Option<Box<dyn Color>>,
-    width: Option<f64>,
-    dash: Option<DashType>,
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Camera {
+    center: Option<CameraCenter>,
+    eye: Option<Eye>,
+    up: Option<Up>,
+    projection: Option<CameraProjection>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
-impl ShapeLine {
+impl Camera {
     pub fn new() -> Self {
         Default::default()
     }
 
-    /// Sets the line color.
-    pub fn color<C: Color>(mut self, color: C) -> Self {
-        self.color = Some(Box::new(color));
+    pub fn center(mut self, center: CameraCenter) -> Self {
+        self.center = Some(center);
         self
     }
 
-    /// Sets the line width (in px).
-    pub fn width(mut self, width: f64) -> Self {
-        self.width = Some(width);
+    pub fn eye(mut self, eye: Eye) -> Self {
+        self.eye = Some(eye);
         self
     }
 
-    /// Sets the dash style of lines. Set to a dash type string ("solid", "dot", "dash", "longdash",
-    /// "dashdot", or "longdashdot") or a dash length list in px (eg "5px,10px,2px,2px").
-    pub fn dash(mut self, dash: DashType) -> Self {
-        self.dash = Some(dash);
+    pub fn up(mut self, up: Up) -> Self {
+        self.up = Some(up);
+        self
+    }
+
+    pub fn projection(mut self, projection: CameraProjection) -> Self {
+        self.projection = Some(projection);
         self
     }
 }
 
+/// A 3D subplot, used by `Scatter3D`, `Surface`, `Mesh3D`, `Isosurface`, `Volume`, `Cone` and
+/// `Streamtube` traces.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{
+///     common::Title,
+///     layout::{AspectMode, Axis, Camera, Eye, LayoutScene},
+///     Layout,
+/// };
+///
+/// let layout = Layout::new().scene(
+///     LayoutScene::new()
+///         .aspect_mode(AspectMode::Cube)
+///         .camera(Camera::new().eye(Eye::new().x(1.5).y(1.5).z(0.5)))
+///         .z_axis(Axis::new().title(Title::new("depth"))),
+/// );
+///
+/// let expected = serde_json::json!({
+///     "scene": {
+///         "aspectmode": "cube",
+///         "camera": {"eye": {"x": 1.5, "y": 1.5, "z": 0.5}},
+///         "zaxis": {"title": {"text": "depth"}}
+///     }
+/// });
+///
+/// assert_eq!(serde_json::to_value(layout).unwrap(), expected);
+/// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct Shape {
-    visible: Option<bool>,
-    r#type: Option<ShapeType>,
-    layer: Option<ShapeLayer>,
-    #[serde(rename = "xref")]
-    x_ref: Option<String>,
-    #[serde(rename = "xsizemode")]
-    x_size_mode: Option<ShapeSizeMode>,
-    #[serde(rename = "xanchor")]
-    x_anchor: Option<NumOrString>,
-    x0: Option<NumOrString>,
-    x1: Option<NumOrString>,
-    #[serde(rename = "yref")]
-    y_ref: Option<String>,
-    #[serde(rename = "ysizemode")]
-    y_size_mode: Option<ShapeSizeMode>,
-    #[serde(rename = "yanchor")]
-    y_anchor: Option<NumOrString>,
-    y0: Option<NumOrString>,
-    y1: Option<NumOrString>,
-    path: Option<String>,
-    opacity: Option<f64>,
-    line: Option<ShapeLine>,
-    #[serde(rename = "fillcolor")]
-    fill_color: Option<Box<dyn Color>>,
-    #[serde(rename = "fillrule")]
-    fill_rule: Option<FillRule>,
-    editable: Option<bool>,
-    name: Option<String>,
-    #[serde(rename = "templateitemname")]
-    template_item_name: Option<String>,
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayoutScene {
+    domain: Option<Domain>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    camera: Option<Camera>,
+    #[serde(rename = "aspectmode")]
+    aspect_mode: Option<AspectMode>,
+    #[serde(rename = "aspectratio")]
+    aspect_ratio: Option<AspectRatio>,
+    #[serde(rename = "xaxis")]
+    x_axis: Option<Box<Axis>>,
+    #[serde(rename = "yaxis")]
+    y_axis: Option<Box<Axis>>,
+    #[serde(rename = "zaxis")]
+    z_axis: Option<Box<Axis>>,
+    #[serde(rename = "dragmode")]
+    drag_mode: Option<DragMode>,
+    #[serde(rename = "hovermode")]
+    hover_mode: Option<HoverMode>,
+    annotations: Option<Vec<Annotation>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
-impl Shape {
+impl LayoutScene {
     pub fn new() -> Self {
         Default::default()
     }
 
-    /// Determines whether or not this shape is visible.
-    pub fn visible(mut self, visible: bool) -> Self {
-        self.visible = Some(visible);
+    pub fn domain(mut self, domain: Domain) -> Self {
+        self.domain = Some(domain);
         self
     }
 
-    /// Specifies the shape type to be drawn. If "line", a line is drawn from (`x0`,`y0`) to
-    /// (`x1`,`y1`) with respect to the axes' sizing mode. If "circle", a circle is drawn from
-    /// ((`x0`+`x1`)/2, (`y0`+`y1`)/2)) with radius (|(`x0`+`x1`)/2 - `x0`|, |(`y0`+`y1`)/2 -`y0`)|)
-    /// with respect to the axes' sizing mode. If "rect", a rectangle is drawn linking
-    /// (`x0`,`y0`), (`x1`,`y0`), (`x1`,`y1`), (`x0`,`y1`), (`x0`,`y0`) with respect to the axes'
-    /// sizing mode. If "path", draw a custom SVG path using `path`. with respect to the axes'
-    /// sizing mode.
-    pub fn shape_type(mut self, shape_type: ShapeType) -> Self {
-        self.r#type = Some(shape_type);
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
         self
     }
 
-    /// Specifies whether shapes are drawn below or above traces.
-    pub fn layer(mut self, layer: ShapeLayer) -> Self {
-        self.layer = Some(layer);
+    pub fn camera(mut self, camera: Camera) -> Self {
+        self.camera = Some(camera);
         self
     }
 
-    /// Sets the shape's x coordinate axis. If set to an x axis id (e.g. "x" or "x2"), the `x`
-    /// position refers to an x coordinate. If set to "paper", the `x` position refers to the
-    /// distance from the left side of the plotting area in normalized coordinates where "0" ("1")
-    /// corresponds to the left (right) side. If the axis `type` is "log", then you must take the
-    /// log of your desired range. If the axis `type` is "date", then you must convert the date to
-    /// unix time in milliseconds.
-    pub fn x_ref(mut self, x_ref: &str) -> Self {
-        self.x_ref = Some(x_ref.to_owned());
+    /// If `AspectMode::Cube`, the axes are drawn as a cube regardless of the axes ranges. If
+    /// `AspectMode::Data`, the axes are drawn in proportion to the axes ranges. If
+    /// `AspectMode::Manual`, the axes are drawn in proportion to `aspect_ratio`. If
+    /// `AspectMode::Auto` (the default), "data" is used unless one axis is more than four times
+    /// the size of the other two, in which case "cube" is used.
+    pub fn aspect_mode(mut self, aspect_mode: AspectMode) -> Self {
+        self.aspect_mode = Some(aspect_mode);
         self
     }
 
-    /// Sets the shapes's sizing mode along the x axis. If set to "scaled", `x0`, `x1` and x
-    /// coordinates within `path` refer to data values on the x axis or a fraction of the plot
-    /// area's width (`xref` set to "paper"). If set to "pixel", `xanchor` specifies the x position
-    /// in terms of data or plot fraction but `x0`, `x1` and x coordinates within `path` are pixels
-    /// relative to `xanchor`. This way, the shape can have a fixed width while maintaining a
-    /// position relative to data or plot fraction.
-    pub fn x_size_mode(mut self, x_size_mode: ShapeSizeMode) -> Self {
-        self.x_size_mode = Some(x_size_mode);
+    pub fn aspect_ratio(mut self, aspect_ratio: AspectRatio) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
         self
     }
 
-    /// Only relevant in conjunction with `xsizemode` set to "pixel". Specifies the anchor point on
-    /// the x axis to which `x0`, `x1` and x coordinates within `path` are relative to. E.g. useful
-    /// to attach a pixel sized shape to a certain data value. No effect when `xsizemode` not set
-    /// to "pixel".
-    pub fn x_anchor<V: Into<NumOrString>>(mut self, x_anchor: V) -> Self {
-        self.x_anchor = Some(x_anchor.into());
+    pub fn x_axis(mut self, x_axis: Axis) -> Self {
+        self.x_axis = Some(Box::new(x_axis));
         self
     }
 
-    /// Sets the shape's starting x position. See `type` and `xsizemode` for more info.
-    pub fn x0<V: Into<NumOrString>>(mut self, x0: V) -> Self {
-        self.x0 = Some(x0.into());
+    pub fn y_axis(mut self, y_axis: Axis) -> Self {
+        self.y_axis = Some(Box::new(y_axis));
         self
     }
 
-    /// Sets the shape's end x position. See `type` and `xsizemode` for more info.
-    pub fn x1<V: Into<NumOrString>>(mut self, x1: V) -> Self {
-        self.x1 = Some(x1.into());
+    pub fn z_axis(mut self, z_axis: Axis) -> Self {
+        self.z_axis = Some(Box::new(z_axis));
         self
     }
 
-    /// Sets the annotation's y coordinate axis. If set to an y axis id (e.g. "y" or "y2"),
-    /// the `y` position refers to an y coordinate If set to "paper", the `y` position refers to
-    /// the distance from the bottom of the plotting area in normalized coordinates where "0" ("1")
-    /// corresponds to the bottom (top).
-    pub fn y_ref(mut self, y_ref: &str) -> Self {
-        self.y_ref = Some(y_ref.to_owned());
+    /// Determines the mode of drag interactions for this scene. Only `DragMode::Orbit`,
+    /// `DragMode::Turntable`, `DragMode::Zoom`, `DragMode::Pan` and `DragMode::False` apply to 3D
+    /// scenes.
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = Some(drag_mode);
         self
     }
 
-    /// Sets the shapes's sizing mode along the y axis. If set to "scaled", `y0`, `y1` and y
-    /// coordinates within `path` refer to data values on the y axis or a fraction of the plot
-    /// area's height (`yref` set to "paper"). If set to "pixel", `yanchor` specifies the y position
-    /// in terms of data or plot fraction but `y0`, `y1` and y coordinates within `path` are pixels
-    /// relative to `yanchor`. This way, the shape can have a fixed height while maintaining a
-    /// position relative to data or plot fraction.
-    pub fn y_size_mode(mut self, y_size_mode: ShapeSizeMode) -> Self {
-        self.y_size_mode = Some(y_size_mode);
+    /// Determines the mode of hover interactions for this scene. Only `HoverMode::Closest` and
+    /// `HoverMode::False` apply to 3D scenes.
+    pub fn hover_mode(mut self, hover_mode: HoverMode) -> Self {
+        self.hover_mode = Some(hover_mode);
         self
     }
 
-    /// Only relevant in conjunction with `ysizemode` set to "pixel". Specifies the anchor point on
-    /// the y axis to which `y0`, `y1` and y coordinates within `path` are relative to. E.g. useful
-    /// to attach a pixel sized shape to a certain data value. No effect when `ysizemode` not set
-    /// to "pixel".
-    pub fn y_anchor<V: Into<NumOrString>>(mut self, y_anchor: V) -> Self {
-        self.y_anchor = Some(y_anchor.into());
+    /// Annotations placed at (x, y, z) data coordinates of this scene, see `Annotation::z`.
+    pub fn annotations(mut self, annotations: Vec<Annotation>) -> Self {
+        self.annotations = Some(annotations);
         self
     }
+}
 
-    /// Sets the shape's starting y position. See `type` and `ysizemode` for more info.
-    pub fn y0<V: Into<NumOrString>>(mut self, y0: V) -> Self {
-        self.y0 = Some(y0.into());
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct TernaryAxis {
+    color: Option<Box<dyn Color>>,
+    title: Option<Title>,
+    #[serde(rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(rename = "nticks")]
+    n_ticks: Option<usize>,
+    tick0: Option<f64>,
+    dtick: Option<f64>,
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    ticks: Option<TicksDirection>,
+    #[serde(rename = "ticklen")]
+    tick_length: Option<Number>,
+    #[serde(rename = "tickwidth")]
+    tick_width: Option<Number>,
+    #[serde(rename = "tickcolor")]
+    tick_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(rename = "showtickprefix")]
+    show_tick_prefix: Option<ArrayShow>,
+    #[serde(rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(rename = "showticksuffix")]
+    show_tick_suffix: Option<ArrayShow>,
+    #[serde(rename = "showexponent")]
+    show_exponent: Option<ArrayShow>,
+    #[serde(rename = "exponentformat")]
+    exponent_format: Option<ExponentFormat>,
+    #[serde(rename = "separatethousands")]
+    separate_thousands: Option<bool>,
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(rename = "tickformatstops")]
+    tick_format_stops: Option<Vec<TickFormatStop>>,
+    #[serde(rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(rename = "linecolor")]
+    line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "linewidth")]
+    line_width: Option<Number>,
+    #[serde(rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(rename = "gridcolor")]
+    grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "gridwidth")]
+    grid_width: Option<Number>,
+    min: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl TernaryAxis {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
         self
     }
 
-    /// Sets the shape's end y position. See `type` and `ysizemode` for more info.
-    pub fn y1<V: Into<NumOrString>>(mut self, y1: V) -> Self {
-        self.y1 = Some(y1.into());
+    pub fn title(mut self, title: Title) -> Self {
+        self.title = Some(title);
         self
     }
 
-    /// For `type` "path" - a valid SVG path with the pixel values replaced by data values in
-    /// `xsizemode`/`ysizemode` being "scaled" and taken unmodified as pixels relative to
-    /// `xanchor` and `yanchor` in case of "pixel" size mode. There are a few restrictions / quirks
-    /// only absolute instructions, not relative. So the allowed segments
-    /// are: M, L, H, V, Q, C, T, S, and Z arcs (A) are not allowed because radius rx and ry are
-    /// relative. In the future we could consider supporting relative commands, but we would have
-    /// to decide on how to handle date and log axes. Note that even as is, Q and C Bezier paths
-    /// that are smooth on linear axes may not be smooth on log, and vice versa. no chained
-    /// "polybezier" commands - specify the segment type for each one. On category axes, values are
-    /// numbers scaled to the serial numbers of categories because using the categories themselves
-    /// there would be no way to describe fractional positions On data axes: because space and T are
-    /// both normal components of path strings, we can't use either to separate date from time parts.
-    /// Therefore we'll use underscore for this purpose: 2015-02-21_13:45:56.789
-    pub fn path(mut self, path: &str) -> Self {
-        self.path = Some(path.to_owned());
+    pub fn tick_mode(mut self, tick_mode: TickMode) -> Self {
+        self.tick_mode = Some(tick_mode);
         self
     }
 
-    /// Sets the opacity of the shape. Number between or equal to 0 and 1.
-    pub fn opacity(mut self, opacity: f64) -> Self {
-        self.opacity = Some(opacity);
+    pub fn n_ticks(mut self, n_ticks: usize) -> Self {
+        self.n_ticks = Some(n_ticks);
         self
     }
 
-    /// Sets the shape line properties (`color`, `width`, `dash`).
-    pub fn line(mut self, line: ShapeLine) -> Self {
-        self.line = Some(line);
+    pub fn tick0(mut self, tick0: f64) -> Self {
+        self.tick0 = Some(tick0);
         self
     }
 
-    /// Sets the color filling the shape's interior. Only applies to closed shapes.
-    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Self {
-        self.fill_color = Some(Box::new(fill_color));
+    pub fn dtick(mut self, dtick: f64) -> Self {
+        self.dtick = Some(dtick);
         self
     }
 
-    /// Determines which regions of complex paths constitute the interior. For more info please
-    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
-    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
-        self.fill_rule = Some(fill_rule);
+    pub fn tick_values(mut self, tick_values: Vec<f64>) -> Self {
+        self.tick_values = Some(tick_values);
         self
     }
 
-    /// Determines whether the shape could be activated for edit or not. Has no effect when the
-    /// older editable shapes mode is enabled via `config.editable` or `config.edits.shapePosition`.
-    pub fn editable(mut self, editable: bool) -> Self {
-        self.editable = Some(editable);
+    pub fn tick_text(mut self, tick_text: Vec<String>) -> Self {
+        self.tick_text = Some(tick_text);
         self
     }
 
-    /// When used in a template, named items are created in the output figure in addition to any
-    /// items the figure already has in this array. You can modify these items in the output figure
-    /// by making your own item with `templateitemname` matching this `name` alongside your
-    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
-    /// outside of a template.
-    pub fn name(mut self, name: &str) -> Self {
-        self.name = Some(name.to_owned());
+    pub fn ticks(mut self, ticks: TicksDirection) -> Self {
+        self.ticks = Some(ticks);
         self
     }
 
-    /// Used to refer to a named item in this array in the template. Named items from the template
-    /// will be created even without a matching item in the input figure, but you can modify one
-    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
-    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
-    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
-    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
-        self.template_item_name = Some(template_item_name.to_owned());
+    pub fn tick_length(mut self, tick_length: usize) -> Self {
+        self.tick_length = Some(tick_length.into());
         self
     }
-}
 
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum DrawDirection {
-    Ortho,
-    Horizontal,
-    Vertical,
-    Diagonal,
-}
-
-#[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct NewShape {
-    line: Option<ShapeLine>,
-    #[serde(rename = "fillcolor")]
-    fill_color: Option<Box<dyn Color>>,
-    #[serde(rename = "fillrule")]
-    fill_rule: Option<FillRule>,
-    opacity: Option<f64>,
-    layer: Option<ShapeLayer>,
-    #[serde(rename = "drawdirection")]
-    draw_direction: Option<DrawDirection>,
-}
-
-impl NewShape {
-    pub fn new() -> Self {
-        Default::default()
-    }
-
-    /// Sets the shape line properties (`color`, `width`, `dash`).
-    pub fn line(mut self, line: ShapeLine) -> Self {
-        self.line = Some(line);
+    pub fn tick_width(mut self, tick_width: usize) -> Self {
+        self.tick_width = Some(tick_width.into());
         self
     }
 
-    /// Sets the color filling new shapes' interior. Please note that if using a fillcolor with
-    /// alpha greater than half, drag inside the active shape starts moving the shape underneath,
-    /// otherwise a new shape could be started over.
-    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Self {
-        self.fill_color = Some(Box::new(fill_color));
+    pub fn tick_color<C: Color>(mut self, tick_color: C) -> Self {
+        self.tick_color = Some(Box::new(tick_color));
         self
     }
 
-    /// Determines the path's interior. For more info please
-    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
-    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
-        self.fill_rule = Some(fill_rule);
+    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> Self {
+        self.show_tick_labels = Some(show_tick_labels);
         self
     }
 
-    /// Sets the opacity of new shapes. Number between or equal to 0 and 1.
-    pub fn opacity(mut self, opacity: f64) -> Self {
-        self.opacity = Some(opacity);
+    pub fn tick_font(mut self, tick_font: Font) -> Self {
+        self.tick_font = Some(tick_font);
         self
     }
 
-    /// Specifies whether new shapes are drawn below or above traces.
-    pub fn layer(mut self, layer: ShapeLayer) -> Self {
-        self.layer = Some(layer);
+    pub fn tick_angle(mut self, tick_angle: f64) -> Self {
+        self.tick_angle = Some(tick_angle);
         self
     }
 
-    /// When `dragmode` is set to "drawrect", "drawline" or "drawcircle" this limits the drag to be
-    /// horizontal, vertical or diagonal. Using "diagonal" there is no limit e.g. in drawing lines
-    /// in any direction. "ortho" limits the draw to be either horizontal or vertical. "horizontal"
-    /// allows horizontal extend. "vertical" allows vertical extend.
-    pub fn draw_direction(mut self, draw_direction: DrawDirection) -> Self {
-        self.draw_direction = Some(draw_direction);
+    pub fn tick_prefix(mut self, tick_prefix: &str) -> Self {
+        self.tick_prefix = Some(tick_prefix.to_owned());
         self
     }
-}
-
-#[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct ActiveShape {
-    #[serde(rename = "fillcolor")]
-    fill_color: Option<Box<dyn Color>>,
-    opacity: Option<f64>,
-}
 
-impl ActiveShape {
-    pub fn new() -> Self {
-        Default::default()
+    pub fn show_tick_prefix(mut self, show_tick_prefix: ArrayShow) -> Self {
+        self.show_tick_prefix = Some(show_tick_prefix);
+        self
     }
 
-    /// Sets the color filling the active shape' interior.
-    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Self {
-        self.fill_color = Some(Box::new(fill_color));
+    pub fn tick_suffix(mut self, tick_suffix: &str) -> Self {
+        self.tick_suffix = Some(tick_suffix.to_owned());
         self
     }
 
-    /// Sets the opacity of the active shape. Number between or equal to 0 and 1.
-    pub fn opacity(mut self, opacity: f64) -> Self {
-        self.opacity = Some(opacity);
+    pub fn show_tick_suffix(mut self, show_tick_suffix: ArrayShow) -> Self {
+        self.show_tick_suffix = Some(show_tick_suffix);
         self
     }
-}
-
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum ArrowSide {
-    End,
-    Start,
-    #[serde(rename = "end+start")]
-    StartEnd,
-    None,
-}
-
-#[derive(Debug, Clone)]
-pub enum ClickToShow {
-    False,
-    OnOff,
-    OnOut,
-}
 
-impl Serialize for ClickToShow {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match *self {
-            Self::False => serializer.serialize_bool(false),
-            Self::OnOff => serializer.serialize_str("onoff"),
-            Self::OnOut => serializer.serialize_str("onout"),
-        }
+    pub fn show_exponent(mut self, show_exponent: ArrayShow) -> Self {
+        self.show_exponent = Some(show_exponent);
+        self
     }
-}
-
-#[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct Annotation {
-    visible: Option<bool>,
-    text: Option<String>,
-    #[serde(rename = "textangle")]
-    text_angle: Option<f64>,
-    font: Option<Font>,
-    width: Option<f64>,
-    height: Option<f64>,
-    opacity: Option<f64>,
-    align: Option<HAlign>,
-    valign: Option<VAlign>,
-    #[serde(rename = "bgcolor")]
-    background_color: Option<Box<dyn Color>>,
-    #[serde(rename = "bordercolor")]
-    border_color: Option<Box<dyn Color>>,
-    #[serde(rename = "borderpad")]
-    border_pad: Option<f64>,
-    #[serde(rename = "borderwidth")]
-    border_width: Option<f64>,
-    #[serde(rename = "showarrow")]
-    show_arrow: Option<bool>,
-    #[serde(rename = "arrowcolor")]
-    arrow_color: Option<Box<dyn Color>>,
-    #[serde(rename = "arrowhead")]
-    arrow_head: Option<u8>,
-    #[serde(rename = "startarrowhead")]
-    start_arrow_head: Option<u8>,
-    #[serde(rename = "arrowside")]
-    arrow_side: Option<ArrowSide>,
-    #[serde(rename = "arrowsize")]
-    arrow_size: Option<f64>,
-    #[serde(rename = "startarrowsize")]
-    start_arrow_size: Option<f64>,
-    #[serde(rename = "arrowwidth")]
-    arrow_width: Option<f64>,
-    #[serde(rename = "standoff")]
-    stand_off: Option<f64>,
-    #[serde(rename = "startstandoff")]
-    start_stand_off: Option<f64>,
-    ax: Option<NumOrString>,
-    ay: Option<NumOrString>,
-    #[serde(rename = "axref")]
-    ax_ref: Option<String>,
-    #[serde(rename = "ayref")]
-    ay_ref: Option<String>,
-    #[serde(rename = "xref")]
-    x_ref: Option<String>,
-    x: Option<NumOrString>,
-    #[serde(rename = "xanchor")]
-    x_anchor: Option<Anchor>,
-    #[serde(rename = "xshift")]
-    x_shift: Option<f64>,
-    #[serde(rename = "yref")]
-    y_ref: Option<String>,
-    y: Option<NumOrString>,
-    #[serde(rename = "yanchor")]
-    y_anchor: Option<Anchor>,
-    #[serde(rename = "yshift")]
-    y_shift: Option<f64>,
-    #[serde(rename = "clicktoshow")]
-    click_to_show: Option<ClickToShow>,
-    #[serde(rename = "xclick")]
-    x_click: Option<NumOrString>,
-    #[serde(rename = "yclick")]
-    y_click: Option<NumOrString>,
-    #[serde(rename = "hovertext")]
-    hover_text: Option<String>,
-    #[serde(rename = "hoverlabel")]
-    hover_label: Option<Label>,
-    #[serde(rename = "captureevents")]
-    capture_events: Option<bool>,
-    name: Option<String>,
-    #[serde(rename = "templateitemname")]
-    template_item_name: Option<String>,
-}
 
-impl Annotation {
-    pub fn new() -> Self {
-        Default::default()
+    pub fn exponent_format(mut self, exponent_format: ExponentFormat) -> Self {
+        self.exponent_format = Some(exponent_format);
+        self
     }
 
-    /// Determines whether or not this annotation is visible.
-    pub fn visible(mut self, visible: bool) -> Self {
-        self.visible = Some(visible);
+    pub fn separate_thousands(mut self, separate_thousands: bool) -> Self {
+        self.separate_thousands = Some(separate_thousands);
         self
     }
 
-    /// Sets the text associated with this annotation. Plotly uses a subset of HTML tags to do
-    /// things like newline (<br>), bold (<b></b>), italics (<i></i>), hyperlinks
-    /// (<a href='...'></a>). Tags <em>, <sup>, <sub> <span> are also supported.
-    pub fn text(mut self, text: &str) -> Self {
-        self.text = Some(text.to_owned());
+    pub fn tick_format(mut self, tick_format: &str) -> Self {
+        self.tick_format = Some(tick_format.to_owned());
         self
     }
 
-    /// Sets the angle at which the `text` is drawn with respect to the horizontal.
-    pub fn text_angle(mut self, text_angle: f64) -> Self {
-        self.text_angle = Some(text_angle);
+    pub fn tick_format_stops(mut self, tick_format_stops: Vec<TickFormatStop>) -> Self {
+        self.tick_format_stops = Some(tick_format_stops);
         self
     }
 
-    /// Sets the annotation text font.
-    pub fn font(mut self, font: Font) -> Self {
-        self.font = Some(font);
+    pub fn hover_format(mut self, hover_format: &str) -> Self {
+        self.hover_format = Some(hover_format.to_owned());
         self
     }
 
-    /// Sets an explicit width for the text box. null (default) lets the text set the box width.
-    /// Wider text will be clipped. There is no automatic wrapping; use <br> to start a new line.
-    pub fn width(mut self, width: f64) -> Self {
-        self.width = Some(width);
+    pub fn show_line(mut self, show_line: bool) -> Self {
+        self.show_line = Some(show_line);
         self
     }
 
-    /// Sets an explicit height for the text box. null (default) lets the text set the box height.
-    /// Taller text will be clipped.
-    pub fn height(mut self, height: f64) -> Self {
-        self.height = Some(height);
+    pub fn line_color<C: Color>(mut self, line_color: C) -> Self {
+        self.line_color = Some(Box::new(line_color));
         self
     }
 
-    /// Sets the opacity of the annotation (text + arrow).
-    pub fn opacity(mut self, opacity: f64) -> Self {
-        self.opacity = Some(opacity);
+    pub fn line_width(mut self, line_width: usize) -> Self {
+        self.line_width = Some(line_width.into());
         self
     }
 
-    /// Sets the horizontal alignment of the `text` within the box. Has an effect only if `text`
-    /// spans two or more lines (i.e. `text` contains one or more <br> HTML tags) or if an explicit
-    /// width is set to override the text width.
-    pub fn align(mut self, align: HAlign) -> Self {
-        self.align = Some(align);
+    pub fn show_grid(mut self, show_grid: bool) -> Self {
+        self.show_grid = Some(show_grid);
         self
     }
 
-    /// Sets the vertical alignment of the `text` within the box. Has an effect only if an explicit
-    /// height is set to override the text height.
-    pub fn valign(mut self, valign: VAlign) -> Self {
-        self.valign = Some(valign);
+    pub fn grid_color<C: Color>(mut self, grid_color: C) -> Self {
+        self.grid_color = Some(Box::new(grid_color));
         self
     }
 
-    /// Sets the background color of the annotation.
-    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
-        self.background_color = Some(Box::new(background_color));
+    pub fn grid_width(mut self, grid_width: usize) -> Self {
+        self.grid_width = Some(grid_width.into());
         self
     }
 
-    /// Sets the color of the border enclosing the annotation `text`.
-    pub fn border_color<C: Color>(mut self, border_color: C) -> Self {
-        self.border_color = Some(Box::new(border_color));
+    /// Sets the minimum value visible on this axis. The maximum is determined by the sum minus the
+    /// minimum values of the other two axes. The full view corresponds to all the minima set to
+    /// zero.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
         self
     }
+}
 
-    /// Sets the padding (in px) between the `text` and the enclosing border.
-    pub fn border_pad(mut self, border_pad: f64) -> Self {
-        self.border_pad = Some(border_pad);
-        self
+/// The ternary subplot used by `ScatterTernary` traces, in which each point is placed by the
+/// proportions of three components summing to a constant.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayoutTernary {
+    domain: Option<Domain>,
+    sum: Option<f64>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "aaxis")]
+    a_axis: Option<TernaryAxis>,
+    #[serde(rename = "baxis")]
+    b_axis: Option<TernaryAxis>,
+    #[serde(rename = "caxis")]
+    c_axis: Option<TernaryAxis>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl LayoutTernary {
+    pub fn new() -> Self {
+        Default::default()
     }
 
-    /// Sets the width (in px) of the border enclosing the annotation `text`.
-    pub fn border_width(mut self, border_width: f64) -> Self {
-        self.border_width = Some(border_width);
+    pub fn domain(mut self, domain: Domain) -> Self {
+        self.domain = Some(domain);
         self
     }
 
-    /// Determines whether or not the annotation is drawn with an arrow. If "True", `text` is
-    /// placed near the arrow's tail. If "False", `text` lines up with the `x` and `y` provided.
-    pub fn show_arrow(mut self, show_arrow: bool) -> Self {
-        self.show_arrow = Some(show_arrow);
+    /// The number each triplet should sum to, if only two of `a`, `b` and `c` are provided. This
+    /// also sets the scale of all three axes. Defaults to 1.
+    pub fn sum(mut self, sum: f64) -> Self {
+        self.sum = Some(sum);
         self
     }
 
-    /// Sets the color of the annotation arrow.
-    pub fn arrow_color<C: Color>(mut self, arrow_color: C) -> Self {
-        self.arrow_color = Some(Box::new(arrow_color));
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
         self
     }
 
-    /// Sets the end annotation arrow head style. Integer between or equal to 0 and 8.
-    pub fn arrow_head(mut self, arrow_head: u8) -> Self {
-        self.arrow_head = Some(arrow_head);
+    pub fn a_axis(mut self, a_axis: TernaryAxis) -> Self {
+        self.a_axis = Some(a_axis);
         self
     }
 
-    /// Sets the start annotation arrow head style. Integer between or equal to 0 and 8.
-    pub fn start_arrow_head(mut self, start_arrow_head: u8) -> Self {
-        self.start_arrow_head = Some(start_arrow_head);
+    pub fn b_axis(mut self, b_axis: TernaryAxis) -> Self {
+        self.b_axis = Some(b_axis);
         self
     }
 
-    /// Sets the annotation arrow head position.
-    pub fn arrow_side(mut self, arrow_side: ArrowSide) -> Self {
-        self.arrow_side = Some(arrow_side);
+    pub fn c_axis(mut self, c_axis: TernaryAxis) -> Self {
+        self.c_axis = Some(c_axis);
         self
     }
+}
 
-    /// Sets the size of the end annotation arrow head, relative to `arrowwidth`. A value of 1
-    /// (default) gives a head about 3x as wide as the line.
-    pub fn arrow_size(mut self, arrow_size: f64) -> Self {
-        self.arrow_size = Some(arrow_size);
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PolarDirection {
+    Clockwise,
+    CounterClockwise,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ThetaUnit {
+    Radians,
+    Degrees,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum AxisLayer {
+    #[serde(rename = "above traces")]
+    AboveTraces,
+    #[serde(rename = "below traces")]
+    BelowTraces,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PolarGridShape {
+    Circular,
+    Linear,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct RadialAxis {
+    visible: Option<bool>,
+    color: Option<Box<dyn Color>>,
+    title: Option<Title>,
+    r#type: Option<AxisType>,
+    #[serde(rename = "autorange")]
+    auto_range: Option<bool>,
+    #[serde(rename = "rangemode")]
+    range_mode: Option<RangeMode>,
+    range: Option<NumOrStringCollection>,
+    angle: Option<f64>,
+    side: Option<PolarDirection>,
+    #[serde(rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(rename = "nticks")]
+    n_ticks: Option<usize>,
+    tick0: Option<f64>,
+    dtick: Option<f64>,
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    ticks: Option<TicksDirection>,
+    #[serde(rename = "ticklen")]
+    tick_length: Option<Number>,
+    #[serde(rename = "tickwidth")]
+    tick_width: Option<Number>,
+    #[serde(rename = "tickcolor")]
+    tick_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(rename = "showtickprefix")]
+    show_tick_prefix: Option<ArrayShow>,
+    #[serde(rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(rename = "showticksuffix")]
+    show_tick_suffix: Option<ArrayShow>,
+    #[serde(rename = "showexponent")]
+    show_exponent: Option<ArrayShow>,
+    #[serde(rename = "exponentformat")]
+    exponent_format: Option<ExponentFormat>,
+    #[serde(rename = "separatethousands")]
+    separate_thousands: Option<bool>,
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(rename = "tickformatstops")]
+    tick_format_stops: Option<Vec<TickFormatStop>>,
+    #[serde(rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(rename = "linecolor")]
+    line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "linewidth")]
+    line_width: Option<Number>,
+    #[serde(rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(rename = "gridcolor")]
+    grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "gridwidth")]
+    grid_width: Option<Number>,
+    layer: Option<AxisLayer>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl RadialAxis {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
         self
     }
 
-    /// Sets the size of the start annotation arrow head, relative to `arrowwidth`. A value of 1
-    /// (default) gives a head about 3x as wide as the line.
-    pub fn start_arrow_size(mut self, start_arrow_size: f64) -> Self {
-        self.start_arrow_size = Some(start_arrow_size);
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
         self
     }
 
-    /// Sets the width (in px) of annotation arrow line.
-    pub fn arrow_width(mut self, arrow_width: f64) -> Self {
-        self.arrow_width = Some(arrow_width);
+    pub fn title(mut self, title: Title) -> Self {
+        self.title = Some(title);
         self
     }
 
-    /// Sets a distance, in pixels, to move the end arrowhead away from the position it is pointing
-    /// at, for example to point at the edge of a marker independent of zoom. Note that this
-    /// shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift` which
-    /// moves everything by this amount.
-    pub fn stand_off(mut self, stand_off: f64) -> Self {
-        self.stand_off = Some(stand_off);
+    pub fn type_(mut self, t: AxisType) -> Self {
+        self.r#type = Some(t);
         self
     }
 
-    /// Sets a distance, in pixels, to move the start arrowhead away from the position it is
-    /// pointing at, for example to point at the edge of a marker independent of zoom. Note that
-    /// this shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift`
-    /// which moves everything by this amount.
-    pub fn start_stand_off(mut self, start_stand_off: f64) -> Self {
-        self.start_stand_off = Some(start_stand_off);
+    pub fn auto_range(mut self, auto_range: bool) -> Self {
+        self.auto_range = Some(auto_range);
         self
     }
 
-    /// Sets the x component of the arrow tail about the arrow head. If `axref` is `pixel`, a
-    /// positive (negative) component corresponds to an arrow pointing from right to left (left
-    /// to right). If `axref` is an axis, this is an absolute value on that axis, like `x`, NOT a
-    /// relative value.
-    pub fn ax<V: Into<NumOrString>>(mut self, ax: V) -> Self {
-        self.ax = Some(ax.into());
+    pub fn range_mode(mut self, range_mode: RangeMode) -> Self {
+        self.range_mode = Some(range_mode);
         self
     }
 
-    /// Sets the y component of the arrow tail about the arrow head. If `ayref` is `pixel`, a
-    /// positive (negative) component corresponds to an arrow pointing from bottom to top (top to
-    /// bottom). If `ayref` is an axis, this is an absolute value on that axis, like `y`, NOT a
-    /// relative value.
-    pub fn ay<V: Into<NumOrString>>(mut self, ay: V) -> Self {
-        self.ay = Some(ay.into());
+    pub fn range<V: Into<NumOrString> + Clone>(mut self, range: Vec<V>) -> Self {
+        self.range = Some(range.into());
         self
     }
 
-    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If `pixel`, `ax`
-    /// is a relative offset in pixels from `x`. If set to an x axis id (e.g. "x" or "x2"), `ax` is
-    /// specified in the same terms as that axis. This is useful for trendline annotations which
-    /// should continue to indicate the correct trend when zoomed.
-    pub fn ax_ref(mut self, ax_ref: &str) -> Self {
-        self.ax_ref = Some(ax_ref.to_owned());
+    /// Sets the angle (in degrees) from which the radial axis is drawn. Note that by default, the
+    /// radial axis line on the theta=0 line corresponds to a line pointing right.
+    pub fn angle(mut self, angle: f64) -> Self {
+        self.angle = Some(angle);
         self
     }
 
-    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If `pixel`, `ay`
-    /// is a relative offset in pixels from `y`. If set to a y axis id (e.g. "y" or "y2"), `ay` is
-    /// specified in the same terms as that axis. This is useful for trendline annotations which
-    /// should continue to indicate the correct trend when zoomed.
-    pub fn ay_ref(mut self, ay_ref: &str) -> Self {
-        self.ay_ref = Some(ay_ref.to_owned());
+    /// Determines on which side of the radial axis line the tick and tick labels appear.
+    pub fn side(mut self, side: PolarDirection) -> Self {
+        self.side = Some(side);
         self
     }
 
-    /// Sets the annotation's x coordinate axis. If set to an x axis id (e.g. "x" or "x2"), the `x`
-    /// position refers to an x coordinate If set to "paper", the `x` position refers to the
-    /// distance from the left side of the plotting area in normalized coordinates where 0 (1)
-    /// corresponds to the left (right) side.
-    pub fn x_ref(mut self, x_ref: &str) -> Self {
-        self.x_ref = Some(x_ref.to_owned());
+    pub fn tick_mode(mut self, tick_mode: TickMode) -> Self {
+        self.tick_mode = Some(tick_mode);
         self
     }
 
-    /// Sets the annotation's x position. If the axis `type` is "log", then you must take the log
-    /// of your desired range. If the axis `type` is "date", it should be date strings, like date
-    /// data, though Date objects and unix milliseconds will be accepted and converted to strings.
-    /// If the axis `type` is "category", it should be numbers, using the scale where each category
-    /// is assigned a serial number from zero in the order it appears.
-    pub fn x<V: Into<NumOrString>>(mut self, x: V) -> Self {
-        self.x = Some(x.into());
+    pub fn n_ticks(mut self, n_ticks: usize) -> Self {
+        self.n_ticks = Some(n_ticks);
         self
     }
 
-    /// Sets the text box's horizontal position anchor This anchor binds the `x` position to the
-    /// "left", "center" or "right" of the annotation. For example, if `x` is set to 1, `xref` to
-    /// "paper" and `xanchor` to "right" then the right-most portion of the annotation lines up with
-    /// the right-most edge of the plotting area. If "auto", the anchor is equivalent to "center"
-    /// for data-referenced annotations or if there is an arrow, whereas for paper-referenced with
-    /// no arrow, the anchor picked corresponds to the closest side.
-    pub fn x_anchor(mut self, x_anchor: Anchor) -> Self {
-        self.x_anchor = Some(x_anchor);
+    pub fn tick0(mut self, tick0: f64) -> Self {
+        self.tick0 = Some(tick0);
         self
     }
 
-    /// Shifts the position of the whole annotation and arrow to the right (positive) or left
-    /// (negative) by this many pixels.
-    pub fn x_shift(mut self, x_shift: f64) -> Self {
-        self.x_shift = Some(x_shift);
+    pub fn dtick(mut self, dtick: f64) -> Self {
+        self.dtick = Some(dtick);
         self
     }
 
-    /// Sets the annotation's y coordinate axis. If set to an y axis id (e.g. "y" or "y2"), the `y`
-    /// position refers to an y coordinate If set to "paper", the `y` position refers to the
-    /// distance from the bottom of the plotting area in normalized coordinates where 0 (1)
-    /// corresponds to the bottom (top).
-    pub fn y_ref(mut self, y_ref: &str) -> Self {
-        self.y_ref = Some(y_ref.to_owned());
+    pub fn tick_values(mut self, tick_values: Vec<f64>) -> Self {
+        self.tick_values = Some(tick_values);
         self
     }
 
-    /// Sets the annotation's y position. If the axis `type` is "log", then you must take the log of
-    /// your desired range. If the axis `type` is "date", it should be date strings, like date data,
-    /// though Date objects and unix milliseconds will be accepted and converted to strings. If the
-    /// axis `type` is "category", it should be numbers, using the scale where each category is
-    /// assigned a serial number from zero in the order it appears.
-    pub fn y<V: Into<NumOrString>>(mut self, y: V) -> Self {
-        self.y = Some(y.into());
+    pub fn tick_text(mut self, tick_text: Vec<String>) -> Self {
+        self.tick_text = Some(tick_text);
         self
     }
 
-    /// Sets the text box's vertical position anchor This anchor binds the `y` position to the
-    /// "top", "middle" or "bottom" of the annotation. For example, if `y` is set to 1, `yref` to
-    /// "paper" and `yanchor` to "top" then the top-most portion of the annotation lines up with the
-    /// top-most edge of the plotting area. If "auto", the anchor is equivalent to "middle" for
-    /// data-referenced annotations or if there is an arrow, whereas for paper-referenced with no
-    /// arrow, the anchor picked corresponds to the closest side.
-    pub fn y_anchor(mut self, y_anchor: Anchor) -> Self {
-        self.y_anchor = Some(y_anchor);
+    pub fn ticks(mut self, ticks: TicksDirection) -> Self {
+        self.ticks = Some(ticks);
         self
     }
 
-    /// Shifts the position of the whole annotation and arrow up (positive) or down (negative) by
-    /// this many pixels.
-    pub fn y_shift(mut self, y_shift: f64) -> Self {
-        self.y_shift = Some(y_shift);
+    pub fn tick_length(mut self, tick_length: usize) -> Self {
+        self.tick_length = Some(tick_length.into());
         self
     }
 
-    /// Makes this annotation respond to clicks on the plot. If you click a data point that exactly
-    /// matches the `x` and `y` values of this annotation, and it is hidden (visible: false), it
-    /// will appear. In "onoff" mode, you must click the same point again to make it disappear, so
-    /// if you click multiple points, you can show multiple annotations. In "onout" mode, a click
-    /// anywhere else in the plot (on another data point or not) will hide this annotation. If you
-    /// need to show/hide this annotation in response to different `x` or `y` values, you can set
-    /// `xclick` and/or `yclick`. This is useful for example to label the side of a bar. To label
-    /// markers though, `standoff` is preferred over `xclick` and `yclick`.
-    pub fn click_to_show(mut self, click_to_show: ClickToShow) -> Self {
-        self.click_to_show = Some(click_to_show);
+    pub fn tick_width(mut self, tick_width: usize) -> Self {
+        self.tick_width = Some(tick_width.into());
         self
     }
 
-    /// Toggle this annotation when clicking a data point whose `x` value is `xclick` rather than
-    /// the annotation's `x` value.
-    pub fn x_click<V: Into<NumOrString>>(mut self, x_click: V) -> Self {
-        self.x_click = Some(x_click.into());
+    pub fn tick_color<C: Color>(mut self, tick_color: C) -> Self {
+        self.tick_color = Some(Box::new(tick_color));
         self
     }
 
-    /// Toggle this annotation when clicking a data point whose `y` value is `yclick` rather than
-    /// the annotation's `y` value.
-    pub fn y_click<V: Into<NumOrString>>(mut self, y_click: V) -> Self {
-        self.y_click = Some(y_click.into());
+    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> Self {
+        self.show_tick_labels = Some(show_tick_labels);
         self
     }
 
-    /// Sets text to appear when hovering over this annotation. If omitted or blank, no hover label
-    /// will appear.
-    pub fn hover_text(mut self, hover_text: &str) -> Self {
-        self.hover_text = Some(hover_text.to_owned());
+    pub fn tick_font(mut self, tick_font: Font) -> Self {
+        self.tick_font = Some(tick_font);
         self
     }
 
-    /// Label displayed on mouse hover.
-    pub fn hover_label(mut self, hover_label: Label) -> Self {
-        self.hover_label = Some(hover_label);
+    pub fn tick_angle(mut self, tick_angle: f64) -> Self {
+        self.tick_angle = Some(tick_angle);
         self
     }
 
-    /// Determines whether the annotation text box captures mouse move and click events, or allows
-    /// those events to pass through to data points in the plot that may be behind the annotation.
-    /// By default `captureevents` is "false" unless `hovertext` is provided. If you use the event
-    /// `plotly_clickannotation` without `hovertext` you must explicitly enable `captureevents`.
-    pub fn capture_events(mut self, capture_events: bool) -> Self {
-        self.capture_events = Some(capture_events);
+    pub fn tick_prefix(mut self, tick_prefix: &str) -> Self {
+        self.tick_prefix = Some(tick_prefix.to_owned());
         self
     }
 
-    /// When used in a template, named items are created in the output figure in addition to any
-    /// items the figure already has in this array. You can modify these items in the output figure
-    /// by making your own item with `templateitemname` matching this `name` alongside your
-    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
-    /// outside of a template.
-    pub fn name(mut self, name: &str) -> Self {
-        self.name = Some(name.to_owned());
+    pub fn show_tick_prefix(mut self, show_tick_prefix: ArrayShow) -> Self {
+        self.show_tick_prefix = Some(show_tick_prefix);
         self
     }
 
-    /// Used to refer to a named item in this array in the template. Named items from the template
-    /// will be created even without a matching item in the input figure, but you can modify one by
-    /// making an item with `templateitemname` matching its `name`, alongside your modifications
-    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
-    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
-    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
-        self.template_item_name = Some(template_item_name.to_owned());
+    pub fn tick_suffix(mut self, tick_suffix: &str) -> Self {
+        self.tick_suffix = Some(tick_suffix.to_owned());
         self
     }
-}
-
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum ClickMode {
-    Event,
-    Select,
-    #[serde(rename = "event+select")]
-    EventAndSelect,
-    None,
-}
-
-#[derive(Debug, Clone)]
-pub enum DragMode {
-    Zoom,
-    Pan,
-    Select,
-    Lasso,
-    DrawClosedPath,
-    DrawOpenPath,
-    DrawLine,
-    DrawRect,
-    DrawCircle,
-    Orbit,
-    Turntable,
-    False,
-}
-
-impl Serialize for DragMode {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        match *self {
-            Self::Zoom => serializer.serialize_str("zoom"),
-            Self::Pan => serializer.serialize_str("pan"),
-            Self::Select => serializer.serialize_str("select"),
-            Self::Lasso => serializer.serialize_str("lasso"),
-            Self::DrawClosedPath => serializer.serialize_str("drawclosedpath"),
-            Self::DrawOpenPath => serializer.serialize_str("drawopenpath"),
-            Self::DrawLine => serializer.serialize_str("drawline"),
-            Self::DrawRect => serializer.serialize_str("drawrect"),
-            Self::DrawCircle => serializer.serialize_str("drawcircle"),
-            Self::Orbit => serializer.serialize_str("orbit"),
-            Self::Turntable => serializer.serialize_str("turntable"),
-            Self::False => serializer.serialize_bool(false),
-        }
-    }
-}
-
-#[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "lowercase")]
-pub enum SelectDirection {
-    #[serde(rename = "h")]
-    Horizontal,
-    #[serde(rename = "v")]
-    Vertical,
-    #[serde(rename = "d")]
-    Diagonal,
-    Any,
-}
-
-#[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct Template {
-    layout: Option<LayoutTemplate>,
-}
 
-impl Template {
-    pub fn new() -> Self {
-        Default::default()
+    pub fn show_tick_suffix(mut self, show_tick_suffix: ArrayShow) -> Self {
+        self.show_tick_suffix = Some(show_tick_suffix);
+        self
     }
 
-    pub fn layout(mut self, layout: LayoutTemplate) -> Self {
-        self.layout = Some(layout);
+    pub fn show_exponent(mut self, show_exponent: ArrayShow) -> Self {
+        self.show_exponent = Some(show_exponent);
         self
     }
-}
 
-#[allow(clippy::from_over_into)]
-impl Into<Cow<'static, Template>> for Template {
-    fn into(self) -> Cow<'static, Template> {
-        Cow::Owned(self)
+    pub fn exponent_format(mut self, exponent_format: ExponentFormat) -> Self {
+        self.exponent_format = Some(exponent_format);
+        self
     }
-}
 
-#[allow(clippy::from_over_into)]
-impl Into<Cow<'static, Template>> for &'static Template {
-    fn into(self) -> Cow<'static, Template> {
-        Cow::Borrowed(self)
+    pub fn separate_thousands(mut self, separate_thousands: bool) -> Self {
+        self.separate_thousands = Some(separate_thousands);
+        self
     }
-}
-
-// LayoutTemplate matches Layout except it lacks a field for template
-#[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct LayoutTemplate {
-    title: Option<Title>,
-    #[serde(rename = "showlegend")]
-    show_legend: Option<bool>,
-    legend: Option<Legend>,
-    margin: Option<Margin>,
-    #[serde(rename = "autosize")]
-    auto_size: Option<bool>,
-    width: Option<usize>,
-    height: Option<usize>,
-    font: Option<Font>,
-    #[serde(rename = "uniformtext")]
-    uniform_text: Option<UniformText>,
-    separators: Option<String>,
-    #[serde(rename = "paper_bgcolor")]
-    paper_background_color: Option<Box<dyn Color>>,
-    #[serde(rename = "plot_bgcolor")]
-    plot_background_color: Option<Box<dyn Color>>,
-    #[serde(rename = "colorscale")]
-    color_scale: Option<LayoutColorScale>,
-    colorway: Option<Vec<Box<dyn Color>>>,
-    #[serde(rename = "coloraxis")]
-    color_axis: Option<ColorAxis>,
-    #[serde(rename = "modebar")]
-    mode_bar: Option<ModeBar>,
-    #[serde(rename = "hovermode")]
-    hover_mode: Option<HoverMode>,
-    #[serde(rename = "clickmode")]
-    click_mode: Option<ClickMode>,
-    #[serde(rename = "dragmode")]
-    drag_mode: Option<DragMode>,
-    #[serde(rename = "selectdirection")]
-    select_direction: Option<SelectDirection>,
-    #[serde(rename = "hoverdistance")]
-    hover_distance: Option<i32>,
-    #[serde(rename = "spikedistance")]
-    spike_distance: Option<i32>,
-    #[serde(rename = "hoverlabel")]
-    hover_label: Option<Label>,
-
-    grid: Option<LayoutGrid>,
-    calendar: Option<Calendar>,
-
-    #[serde(rename = "xaxis")]
-    x_axis: Option<Box<Axis>>,
-    #[serde(rename = "yaxis")]
-    y_axis: Option<Box<Axis>>,
-    #[serde(rename = "xaxis2")]
-    x_axis2: Option<Box<Axis>>,
-    #[serde(rename = "yaxis2")]
-    y_axis2: Option<Box<Axis>>,
-    #[serde(rename = "xaxis3")]
-    x_axis3: Option<Box<Axis>>,
-    #[serde(rename = "yaxis3")]
-    y_axis3: Option<Box<Axis>>,
-    #[serde(rename = "xaxis4")]
-    x_axis4: Option<Box<Axis>>,
-    #[serde(rename = "yaxis4")]
-    y_axis4: Option<Box<Axis>>,
-    #[serde(rename = "xaxis5")]
-    x_axis5: Option<Box<Axis>>,
-    #[serde(rename = "yaxis5")]
-    y_axis5: Option<Box<Axis>>,
-    #[serde(rename = "xaxis6")]
-    x_axis6: Option<Box<Axis>>,
-    #[serde(rename = "yaxis6")]
-    y_axis6: Option<Box<Axis>>,
-    #[serde(rename = "xaxis7")]
-    x_axis7: Option<Box<Axis>>,
-    #[serde(rename = "yaxis7")]
-    y_axis7: Option<Box<Axis>>,
-    #[serde(rename = "xaxis8")]
-    x_axis8: Option<Box<Axis>>,
-    #[serde(rename = "yaxis8")]
-    y_axis8: Option<Box<Axis>>,
-
-    // ternary: Option<LayoutTernary>,
-    // scene: Option<LayoutScene>,
-    // polar: Option<LayoutPolar>,
-    annotations: Option<Vec<Annotation>>,
-    shapes: Option<Vec<Shape>>,
-    #[serde(rename = "newshape")]
-    new_shape: Option<NewShape>,
-    #[serde(rename = "activeshape")]
-    active_shape: Option<ActiveShape>,
-
-    #[serde(rename = "boxmode")]
-    box_mode: Option<BoxMode>,
-    #[serde(rename = "boxgap")]
-    box_gap: Option<f64>,
-    #[serde(rename = "boxgroupgap")]
-    box_group_gap: Option<f64>,
-
-    #[serde(rename = "barmode")]
-    bar_mode: Option<BarMode>,
-    #[serde(rename = "barnorm")]
-    bar_norm: Option<BarNorm>,
-    #[serde(rename = "bargap")]
-    bar_gap: Option<f64>,
-    #[serde(rename = "bargroupgap")]
-    bar_group_gap: Option<f64>,
-
-    #[serde(rename = "violinmode")]
-    violin_mode: Option<ViolinMode>,
-    #[serde(rename = "violingap")]
-    violin_gap: Option<f64>,
-    #[serde(rename = "violingroupgap")]
-    violin_group_gap: Option<f64>,
-
-    #[serde(rename = "waterfallmode")]
-    waterfall_mode: Option<WaterfallMode>,
-    #[serde(rename = "waterfallgap")]
-    waterfall_gap: Option<f64>,
-    #[serde(rename = "waterfallgroupgap")]
-    waterfall_group_gap: Option<f64>,
-
-    #[serde(rename = "piecolorway")]
-    pie_colorway: Option<Vec<Box<dyn Color>>>,
-    #[serde(rename = "extendpiecolors")]
-    extend_pie_colors: Option<bool>,
-
-    #[serde(rename = "sunburstcolorway")]
-    sunburst_colorway: Option<Vec<Box<dyn Color>>>,
-    #[serde(rename = "extendsunburstcolors")]
-    extend_sunburst_colors: Option<bool>,
-}
 
-impl LayoutTemplate {
-    pub fn new() -> Self {
-        Default::default()
+    pub fn tick_format(mut self, tick_format: &str) -> Self {
+        self.tick_format = Some(tick_format.to_owned());
+        self
     }
 
-    pub fn title(mut self, title: Title) -> Self {
-        self.title = Some(title);
+    pub fn tick_format_stops(mut self, tick_format_stops: Vec<TickFormatStop>) -> Self {
+        self.tick_format_stops = Some(tick_format_stops);
         self
     }
 
-    pub fn show_legend(mut self, show_legend: bool) -> Self {
-        self.show_legend = Some(show_legend);
+    pub fn hover_format(mut self, hover_format: &str) -> Self {
+        self.hover_format = Some(hover_format.to_owned());
         self
     }
 
-    pub fn legend(mut self, legend: Legend) -> Self {
-        self.legend = Some(legend);
+    pub fn show_line(mut self, show_line: bool) -> Self {
+        self.show_line = Some(show_line);
         self
     }
 
-    pub fn margin(mut self, margin: Margin) -> Self {
-        self.margin = Some(margin);
+    pub fn line_color<C: Color>(mut self, line_color: C) -> Self {
+        self.line_color = Some(Box::new(line_color));
         self
     }
 
-    pub fn auto_size(mut self, auto_size: bool) -> Self {
-        self.auto_size = Some(auto_size);
+    pub fn line_width(mut self, line_width: usize) -> Self {
+        self.line_width = Some(line_width.into());
         self
     }
 
-    pub fn width(mut self, width: usize) -> Self {
-        self.width = Some(width);
-        self
-    }
-
-    pub fn height(mut self, height: usize) -> Self {
-        self.height = Some(height);
+    pub fn show_grid(mut self, show_grid: bool) -> Self {
+        self.show_grid = Some(show_grid);
         self
     }
 
-    pub fn font(mut self, font: Font) -> Self {
-        self.font = Some(font);
+    pub fn grid_color<C: Color>(mut self, grid_color: C) -> Self {
+        self.grid_color = Some(Box::new(grid_color));
         self
     }
 
-    pub fn uniform_text(mut self, uniform_text: UniformText) -> Self {
-        self.uniform_text = Some(uniform_text);
+    pub fn grid_width(mut self, grid_width: usize) -> Self {
+        self.grid_width = Some(grid_width.into());
         self
     }
 
-    pub fn separators(mut self, separators: &str) -> Self {
-        self.separators = Some(separators.to_owned());
+    /// Sets the layer on which this axis is displayed. If `AxisLayer::AboveTraces`, this axis is
+    /// displayed above all the subplot's traces.
+    pub fn layer(mut self, layer: AxisLayer) -> Self {
+        self.layer = Some(layer);
         self
     }
+}
 
-    pub fn paper_background_color<C: Color>(mut self, paper_background_color: C) -> Self {
-        self.paper_background_color = Some(Box::new(paper_background_color));
-        self
-    }
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct AngularAxis {
+    visible: Option<bool>,
+    color: Option<Box<dyn Color>>,
+    r#type: Option<AxisType>,
+    #[serde(rename = "thetaunit")]
+    theta_unit: Option<ThetaUnit>,
+    period: Option<f64>,
+    direction: Option<PolarDirection>,
+    rotation: Option<f64>,
+    #[serde(rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(rename = "nticks")]
+    n_ticks: Option<usize>,
+    tick0: Option<f64>,
+    dtick: Option<f64>,
+    #[serde(rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    ticks: Option<TicksDirection>,
+    #[serde(rename = "ticklen")]
+    tick_length: Option<Number>,
+    #[serde(rename = "tickwidth")]
+    tick_width: Option<Number>,
+    #[serde(rename = "tickcolor")]
+    tick_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(rename = "showtickprefix")]
+    show_tick_prefix: Option<ArrayShow>,
+    #[serde(rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(rename = "showticksuffix")]
+    show_tick_suffix: Option<ArrayShow>,
+    #[serde(rename = "showexponent")]
+    show_exponent: Option<ArrayShow>,
+    #[serde(rename = "exponentformat")]
+    exponent_format: Option<ExponentFormat>,
+    #[serde(rename = "separatethousands")]
+    separate_thousands: Option<bool>,
+    #[serde(rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(rename = "tickformatstops")]
+    tick_format_stops: Option<Vec<TickFormatStop>>,
+    #[serde(rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(rename = "linecolor")]
+    line_color: Option<Box<dyn Color>>,
+    #[serde(rename = "linewidth")]
+    line_width: Option<Number>,
+    #[serde(rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(rename = "gridcolor")]
+    grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "gridwidth")]
+    grid_width: Option<Number>,
+    layer: Option<AxisLayer>,
+    #[serde(flatten)]
+    extra: Extra,
+}
 
-    pub fn plot_background_color<C: Color>(mut self, plot_background_color: C) -> Self {
-        self.plot_background_color = Some(Box::new(plot_background_color));
-        self
+impl AngularAxis {
+    pub fn new() -> Self {
+        Default::default()
     }
 
-    pub fn color_scale(mut self, color_scale: LayoutColorScale) -> Self {
-        self.color_scale = Some(color_scale);
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
         self
     }
 
-    pub fn colorway<C: Color>(mut self, colorway: Vec<C>) -> Self {
-        self.colorway = Some(ColorArray(colorway).into());
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
         self
     }
 
-    pub fn color_axis(mut self, color_axis: ColorAxis) -> Self {
-        self.color_axis = Some(color_axis);
+    pub fn type_(mut self, t: AxisType) -> Self {
+        self.r#type = Some(t);
         self
     }
 
-    pub fn mode_bar(mut self, mode_bar: ModeBar) -> Self {
-        self.mode_bar = Some(mode_bar);
+    /// Sets the format unit of the formatted "theta" values. Has an effect only when the axis type
+    /// is "linear".
+    pub fn theta_unit(mut self, theta_unit: ThetaUnit) -> Self {
+        self.theta_unit = Some(theta_unit);
         self
     }
 
-    /// Determines the mode of hover interactions. If "closest", a single hoverlabel will appear for the "closest"
-    /// point within the `hoverdistance`. If "x" (or "y"), multiple hoverlabels will appear for multiple points at
-    /// the "closest" x- (or y-) coordinate within the `hoverdistance`, with the caveat that no more than one hoverlabel
-    /// will appear per trace. If "x unified" (or "y unified"), a single hoverlabel will appear multiple points at
-    /// the closest x- (or y-) coordinate within the `hoverdistance` with the caveat that no more than one hoverlabel
-    /// will appear per trace. In this mode, spikelines are enabled by default perpendicular to the specified axis.
-    /// If false, hover interactions are disabled. If `clickmode` includes the "select" flag, `hovermode` defaults to
-    /// "closest". If `clickmode` lacks the "select" flag, it defaults to "x" or "y"
-    /// (depending on the trace's `orientation` value) for plots based on cartesian coordinates. For anything
-    /// else the default value is "closest".
-    pub fn hover_mode(mut self, hover_mode: HoverMode) -> Self {
-        self.hover_mode = Some(hover_mode);
+    /// Sets the angular period. Has an effect only when the axis type is "category".
+    pub fn period(mut self, period: f64) -> Self {
+        self.period = Some(period);
         self
     }
 
-    pub fn click_mode(mut self, click_mode: ClickMode) -> Self {
-        self.click_mode = Some(click_mode);
+    /// Sets the direction corresponding to positive angles.
+    pub fn direction(mut self, direction: PolarDirection) -> Self {
+        self.direction = Some(direction);
         self
     }
 
-    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
-        self.drag_mode = Some(drag_mode);
+    /// Sets the start position (in degrees) of the angular axis. By default, polar subplots with
+    /// counterclockwise direction get a rotation of 0, which corresponds to due East (like what
+    /// mathematicians prefer). In turn, polar with clockwise direction get a rotation of 90 which
+    /// corresponds to due North (like on a compass).
+    pub fn rotation(mut self, rotation: f64) -> Self {
+        self.rotation = Some(rotation);
         self
     }
 
-    pub fn select_direction(mut self, select_direction: SelectDirection) -> Self {
-        self.select_direction = Some(select_direction);
+    pub fn tick_mode(mut self, tick_mode: TickMode) -> Self {
+        self.tick_mode = Some(tick_mode);
         self
     }
 
-    pub fn hover_distance(mut self, hover_distance: i32) -> Self {
-        self.hover_distance = Some(hover_distance);
+    pub fn n_ticks(mut self, n_ticks: usize) -> Self {
+        self.n_ticks = Some(n_ticks);
         self
     }
 
-    pub fn spike_distance(mut self, spike_distance: i32) -> Self {
-        self.spike_distance = Some(spike_distance);
+    pub fn tick0(mut self, tick0: f64) -> Self {
+        self.tick0 = Some(tick0);
         self
     }
 
-    pub fn hover_label(mut self, hover_label: Label) -> Self {
-        self.hover_label = Some(hover_label);
+    pub fn dtick(mut self, dtick: f64) -> Self {
+        self.dtick = Some(dtick);
         self
     }
 
-    pub fn grid(mut self, grid: LayoutGrid) -> Self {
-        self.grid = Some(grid);
+    pub fn tick_values(mut self, tick_values: Vec<f64>) -> Self {
+        self.tick_values = Some(tick_values);
         self
     }
 
-    pub fn calendar(mut self, calendar: Calendar) -> Self {
-        self.calendar = Some(calendar);
+    pub fn tick_text(mut self, tick_text: Vec<String>) -> Self {
+        self.tick_text = Some(tick_text);
         self
     }
 
-    pub fn x_axis(mut self, xaxis: Axis) -> Self {
-        self.x_axis = Some(Box::new(xaxis));
+    pub fn ticks(mut self, ticks: TicksDirection) -> Self {
+        self.ticks = Some(ticks);
         self
     }
 
-    pub fn y_axis(mut self, yaxis: Axis) -> Self {
-        self.y_axis = Some(Box::new(yaxis));
+    pub fn tick_length(mut self, tick_length: usize) -> Self {
+        self.tick_length = Some(tick_length.into());
         self
     }
 
-    pub fn x_axis2(mut self, xaxis: Axis) -> Self {
-        self.x_axis2 = Some(Box::new(xaxis));
+    pub fn tick_width(mut self, tick_width: usize) -> Self {
+        self.tick_width = Some(tick_width.into());
         self
     }
 
-    pub fn y_axis2(mut self, yaxis: Axis) -> Self {
-        self.y_axis2 = Some(Box::new(yaxis));
+    pub fn tick_color<C: Color>(mut self, tick_color: C) -> Self {
+        self.tick_color = Some(Box::new(tick_color));
         self
     }
 
-    pub fn x_axis3(mut self, xaxis: Axis) -> Self {
-        self.x_axis3 = Some(Box::new(xaxis));
+    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> Self {
+        self.show_tick_labels = Some(show_tick_labels);
         self
     }
 
-    pub fn y_axis3(mut self, yaxis: Axis) -> Self {
-        self.y_axis3 = Some(Box::new(yaxis));
+    pub fn tick_font(mut self, tick_font: Font) -> Self {
+        self.tick_font = Some(tick_font);
         self
     }
 
-    pub fn x_axis4(mut self, xaxis: Axis) -> Self {
-        self.x_axis4 = Some(Box::new(xaxis));
+    pub fn tick_angle(mut self, tick_angle: f64) -> Self {
+        self.tick_angle = Some(tick_angle);
         self
     }
 
-    pub fn y_axis4(mut self, yaxis: Axis) -> Self {
-        self.y_axis4 = Some(Box::new(yaxis));
+    pub fn tick_prefix(mut self, tick_prefix: &str) -> Self {
+        self.tick_prefix = Some(tick_prefix.to_owned());
         self
     }
 
-    pub fn x_axis5(mut self, xaxis: Axis) -> Self {
-        self.x_axis5 = Some(Box::new(xaxis));
+    pub fn show_tick_prefix(mut self, show_tick_prefix: ArrayShow) -> Self {
+        self.show_tick_prefix = Some(show_tick_prefix);
         self
     }
 
-    pub fn y_axis5(mut self, yaxis: Axis) -> Self {
-        self.y_axis5 = Some(Box::new(yaxis));
+    pub fn tick_suffix(mut self, tick_suffix: &str) -> Self {
+        self.tick_suffix = Some(tick_suffix.to_owned());
         self
     }
 
-    pub fn x_axis6(mut self, xaxis: Axis) -> Self {
-        self.x_axis6 = Some(Box::new(xaxis));
+    pub fn show_tick_suffix(mut self, show_tick_suffix: ArrayShow) -> Self {
+        self.show_tick_suffix = Some(show_tick_suffix);
         self
     }
 
-    pub fn y_axis6(mut self, yaxis: Axis) -> Self {
-        self.y_axis6 = Some(Box::new(yaxis));
+    pub fn show_exponent(mut self, show_exponent: ArrayShow) -> Self {
+        self.show_exponent = Some(show_exponent);
         self
     }
 
-    pub fn x_axis7(mut self, xaxis: Axis) -> Self {
-        self.x_axis7 = Some(Box::new(xaxis));
+    pub fn exponent_format(mut self, exponent_format: ExponentFormat) -> Self {
+        self.exponent_format = Some(exponent_format);
         self
     }
 
-    pub fn y_axis7(mut self, yaxis: Axis) -> Self {
-        self.y_axis7 = Some(Box::new(yaxis));
+    pub fn separate_thousands(mut self, separate_thousands: bool) -> Self {
+        self.separate_thousands = Some(separate_thousands);
         self
     }
 
-    pub fn x_axis8(mut self, xaxis: Axis) -> Self {
-        self.x_axis8 = Some(Box::new(xaxis));
+    pub fn tick_format(mut self, tick_format: &str) -> Self {
+        self.tick_format = Some(tick_format.to_owned());
         self
     }
 
-    pub fn y_axis8(mut self, yaxis: Axis) -> Self {
-        self.y_axis8 = Some(Box::new(yaxis));
+    pub fn tick_format_stops(mut self, tick_format_stops: Vec<TickFormatStop>) -> Self {
+        self.tick_format_stops = Some(tick_format_stops);
         self
     }
 
-    pub fn annotations(mut self, annotations: Vec<Annotation>) -> Self {
-        self.annotations = Some(annotations);
+    pub fn hover_format(mut self, hover_format: &str) -> Self {
+        self.hover_format = Some(hover_format.to_owned());
         self
     }
 
-    pub fn add_annotation(&mut self, annotation: Annotation) {
-        if self.annotations.is_none() {
-            self.annotations = Some(Vec::new());
-        }
-        self.annotations.as_mut().unwrap().push(annotation);
-    }
-
-    pub fn shapes(mut self, shapes: Vec<Shape>) -> Self {
-        self.shapes = Some(shapes);
+    pub fn show_line(mut self, show_line: bool) -> Self {
+        self.show_line = Some(show_line);
         self
     }
 
-    pub fn add_shape(&mut self, shape: Shape) {
-        if self.shapes.is_none() {
-            self.shapes = Some(Vec::new());
-        }
-        self.shapes.as_mut().unwrap().push(shape);
-    }
-
-    pub fn new_shape(mut self, new_shape: NewShape) -> Self {
-        self.new_shape = Some(new_shape);
+    pub fn line_color<C: Color>(mut self, line_color: C) -> Self {
+        self.line_color = Some(Box::new(line_color));
         self
     }
 
-    pub fn active_shape(mut self, active_shape: ActiveShape) -> Self {
-        self.active_shape = Some(active_shape);
+    pub fn line_width(mut self, line_width: usize) -> Self {
+        self.line_width = Some(line_width.into());
         self
     }
 
-    pub fn box_mode(mut self, box_mode: BoxMode) -> Self {
-        self.box_mode = Some(box_mode);
+    pub fn show_grid(mut self, show_grid: bool) -> Self {
+        self.show_grid = Some(show_grid);
         self
     }
 
-    pub fn box_gap(mut self, box_gap: f64) -> Self {
-        self.box_gap = Some(box_gap);
+    pub fn grid_color<C: Color>(mut self, grid_color: C) -> Self {
+        self.grid_color = Some(Box::new(grid_color));
         self
     }
 
-    pub fn box_group_gap(mut self, box_group_gap: f64) -> Self {
-        self.box_group_gap = Some(box_group_gap);
+    pub fn grid_width(mut self, grid_width: usize) -> Self {
+        self.grid_width = Some(grid_width.into());
         self
     }
 
-    pub fn bar_mode(mut self, bar_mode: BarMode) -> Self {
-        self.bar_mode = Some(bar_mode);
+    /// Sets the layer on which this axis is displayed. If `AxisLayer::AboveTraces`, this axis is
+    /// displayed above all the subplot's traces.
+    pub fn layer(mut self, layer: AxisLayer) -> Self {
+        self.layer = Some(layer);
         self
     }
+}
 
-    pub fn bar_norm(mut self, bar_norm: BarNorm) -> Self {
-        self.bar_norm = Some(bar_norm);
-        self
-    }
+/// The polar subplot used by `ScatterPolar` and `BarPolar` traces.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::layout::{AngularAxis, Layout, LayoutPolar, PolarDirection, RadialAxis};
+///
+/// let layout = Layout::new().polar(
+///     LayoutPolar::new()
+///         .hole(0.1)
+///         .radial_axis(RadialAxis::new().range(vec![0.0, 5.0]))
+///         .angular_axis(AngularAxis::new().direction(PolarDirection::Clockwise).rotation(90.0)),
+/// );
+///
+/// let expected = serde_json::json!({
+///     "polar": {
+///         "hole": 0.1,
+///         "radialaxis": {"range": [0.0, 5.0]},
+///         "angularaxis": {"direction": "clockwise", "rotation": 90.0}
+///     }
+/// });
+///
+/// assert_eq!(serde_json::to_value(layout).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayoutPolar {
+    domain: Option<Domain>,
+    sector: Option<[f64; 2]>,
+    hole: Option<f64>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "radialaxis")]
+    radial_axis: Option<RadialAxis>,
+    #[serde(rename = "angularaxis")]
+    angular_axis: Option<AngularAxis>,
+    #[serde(rename = "gridshape")]
+    grid_shape: Option<PolarGridShape>,
+    #[serde(rename = "barmode")]
+    bar_mode: Option<BarMode>,
+    #[serde(rename = "bargap")]
+    bar_gap: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
 
-    pub fn bar_gap(mut self, bar_gap: f64) -> Self {
-        self.bar_gap = Some(bar_gap);
-        self
+impl LayoutPolar {
+    pub fn new() -> Self {
+        Default::default()
     }
 
-    pub fn bar_group_gap(mut self, bar_group_gap: f64) -> Self {
-        self.bar_group_gap = Some(bar_group_gap);
+    pub fn domain(mut self, domain: Domain) -> Self {
+        self.domain = Some(domain);
         self
     }
 
-    pub fn violin_mode(mut self, violin_mode: ViolinMode) -> Self {
-        self.violin_mode = Some(violin_mode);
+    /// Sets angular span of this polar subplot with two angles (in degrees). Sector are assumed to
+    /// be spanned in the counterclockwise direction with 0 corresponding to rightmost limit of the
+    /// polar subplot.
+    pub fn sector(mut self, sector: [f64; 2]) -> Self {
+        self.sector = Some(sector);
         self
     }
 
-    pub fn violin_gap(mut self, violin_gap: f64) -> Self {
-        self.violin_gap = Some(violin_gap);
+    /// Sets the fraction of the radius to cut out of the polar subplot.
+    pub fn hole(mut self, hole: f64) -> Self {
+        self.hole = Some(hole);
         self
     }
 
-    pub fn violin_group_gap(mut self, violin_group_gap: f64) -> Self {
-        self.violin_group_gap = Some(violin_group_gap);
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
         self
     }
 
-    pub fn waterfall_mode(mut self, waterfall_mode: WaterfallMode) -> Self {
-        self.waterfall_mode = Some(waterfall_mode);
+    pub fn radial_axis(mut self, radial_axis: RadialAxis) -> Self {
+        self.radial_axis = Some(radial_axis);
         self
     }
 
-    pub fn waterfall_gap(mut self, waterfall_gap: f64) -> Self {
-        self.waterfall_gap = Some(waterfall_gap);
+    pub fn angular_axis(mut self, angular_axis: AngularAxis) -> Self {
+        self.angular_axis = Some(angular_axis);
         self
     }
 
-    pub fn waterfall_group_gap(mut self, waterfall_group_gap: f64) -> Self {
-        self.waterfall_group_gap = Some(waterfall_group_gap);
+    /// Determines if the radial axis grid lines and angular axis line are drawn as "circular"
+    /// sectors or as "linear" (polygon) sectors. Has an effect only when the angular axis has
+    /// `type` "category".
+    pub fn grid_shape(mut self, grid_shape: PolarGridShape) -> Self {
+        self.grid_shape = Some(grid_shape);
         self
     }
 
-    pub fn pie_colorway<C: Color>(mut self, pie_colorway: Vec<C>) -> Self {
-        self.pie_colorway = Some(ColorArray(pie_colorway).into());
+    /// Determines how bars at the same location coordinate are displayed on the graph. Only
+    /// `BarMode::Stack` and `BarMode::Overlay` apply to polar subplots.
+    pub fn bar_mode(mut self, bar_mode: BarMode) -> Self {
+        self.bar_mode = Some(bar_mode);
         self
     }
 
-    pub fn extend_pie_colors(mut self, extend_pie_colors: bool) -> Self {
-        self.extend_pie_colors = Some(extend_pie_colors);
+    /// Sets the gap between bars of adjacent location coordinates. Values are unitless, they
+    /// represent fractions of the minimum difference in bar positions in the data.
+    pub fn bar_gap(mut self, bar_gap: f64) -> Self {
+        self.bar_gap = Some(bar_gap);
         self
     }
+}
 
-    pub fn sunburst_colorway<C: Color>(mut self, sunburst_colorway: Vec<C>) -> Self {
-        self.sunburst_colorway = Some(ColorArray(sunburst_colorway).into());
-        self
-    }
-
-    pub fn extend_sunburst_colors(mut self, extend_sunburst_colors: bool) -> Self {
-        self.extend_sunburst_colors = Some(extend_sunburst_colors);
-        self
-    }
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum GeoScope {
+    World,
+    Usa,
+    Europe,
+    Asia,
+    Africa,
+    #[serde(rename = "north america")]
+    NorthAmerica,
+    #[serde(rename = "south america")]
+    SouthAmerica,
 }
 
-#[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
-pub struct Layout {
-    title: Option<Title>,
-    #[serde(rename = "showlegend")]
-    show_legend: Option<bool>,
-    legend: Option<Legend>,
-    margin: Option<Margin>,
-    #[serde(rename = "autosize")]
-    auto_size: Option<bool>,
-    width: Option<usize>,
-    height: Option<usize>,
-    font: Option<Font>,
-    #[serde(rename = "uniformtext")]
-    uniform_text: Option<UniformText>,
-    separators: Option<String>,
-    #[serde(rename = "paper_bgcolor")]
-    paper_background_color: Option<Box<dyn Color>>,
-    #[serde(rename = "plot_bgcolor")]
-    plot_background_color: Option<Box<dyn Color>>,
-    #[serde(rename = "colorscale")]
-    color_scale: Option<LayoutColorScale>,
-    colorway: Option<Vec<Box<dyn Color>>>,
-    #[serde(rename = "coloraxis")]
-    color_axis: Option<ColorAxis>,
-    #[serde(rename = "modebar")]
-    mode_bar: Option<ModeBar>,
-    #[serde(rename = "hovermode")]
-    hover_mode: Option<HoverMode>,
-    #[serde(rename = "clickmode")]
-    click_mode: Option<ClickMode>,
-    #[serde(rename = "dragmode")]
-    drag_mode: Option<DragMode>,
-    #[serde(rename = "selectdirection")]
-    select_direction: Option<SelectDirection>,
-    #[serde(rename = "hoverdistance")]
-    hover_distance: Option<i32>,
-    #[serde(rename = "spikedistance")]
-    spike_distance: Option<i32>,
-    #[serde(rename = "hoverlabel")]
-    hover_label: Option<Label>,
+/// The resolution of the base map, in km per pixel at the equator.
+#[derive(Serialize_repr, Deserialize_repr, Debug, Clone)]
+#[repr(u8)]
+pub enum GeoResolution {
+    High = 50,
+    Low = 110,
+}
 
-    template: Option<Box<Cow<'static, Template>>>,
+#[derive(Debug, Clone)]
+pub enum FitBounds {
+    False,
+    Locations,
+    GeoJson,
+}
 
-    grid: Option<LayoutGrid>,
-    calendar: Option<Calendar>,
+impl Serialize for FitBounds {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::False => serializer.serialize_bool(false),
+            Self::Locations => serializer.serialize_str("locations"),
+            Self::GeoJson => serializer.serialize_str("geojson"),
+        }
+    }
+}
 
-    #[serde(rename = "xaxis")]
-    x_axis: Option<Box<Axis>>,
-    #[serde(rename = "yaxis")]
-    y_axis: Option<Box<Axis>>,
-    #[serde(rename = "zaxis")]
-    z_axis: Option<Box<Axis>>,
+impl<'de> Deserialize<'de> for FitBounds {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "locations" => Ok(Self::Locations),
+                "geojson" => Ok(Self::GeoJson),
+                _ => Err(de::Error::unknown_variant(&s, &["locations", "geojson"])),
+            },
+        }
+    }
+}
 
-    #[serde(rename = "xaxis2")]
-    x_axis2: Option<Box<Axis>>,
-    #[serde(rename = "yaxis2")]
-    y_axis2: Option<Box<Axis>>,
-    #[serde(rename = "zaxis2")]
-    z_axis2: Option<Box<Axis>>,
-    #[serde(rename = "xaxis3")]
-    x_axis3: Option<Box<Axis>>,
-    #[serde(rename = "yaxis3")]
-    y_axis3: Option<Box<Axis>>,
-    #[serde(rename = "zaxis3")]
-    z_axis3: Option<Box<Axis>>,
-    #[serde(rename = "xaxis4")]
-    x_axis4: Option<Box<Axis>>,
-    #[serde(rename = "yaxis4")]
-    y_axis4: Option<Box<Axis>>,
-    #[serde(rename = "zaxis4")]
-    z_axis4: Option<Box<Axis>>,
-    #[serde(rename = "xaxis5")]
-    x_axis5: Option<Box<Axis>>,
-    #[serde(rename = "yaxis5")]
-    y_axis5: Option<Box<Axis>>,
-    #[serde(rename = "zaxis5")]
-    z_axis5: Option<Box<Axis>>,
-    #[serde(rename = "xaxis6")]
-    x_axis6: Option<Box<Axis>>,
-    #[serde(rename = "yaxis6")]
-    y_axis6: Option<Box<Axis>>,
-    #[serde(rename = "zaxis6")]
-    z_axis6: Option<Box<Axis>>,
-    #[serde(rename = "xaxis7")]
-    x_axis7: Option<Box<Axis>>,
-    #[serde(rename = "yaxis7")]
-    y_axis7: Option<Box<Axis>>,
-    #[serde(rename = "zaxis7")]
-    z_axis7: Option<Box<Axis>>,
-    #[serde(rename = "xaxis8")]
-    x_axis8: Option<Box<Axis>>,
-    #[serde(rename = "yaxis8")]
-    y_axis8: Option<Box<Axis>>,
-    #[serde(rename = "zaxis8")]
-    z_axis8: Option<Box<Axis>>,
-
-    // ternary: Option<LayoutTernary>,
-    // scene: Option<LayoutScene>,
-    // polar: Option<LayoutPolar>,
-    annotations: Option<Vec<Annotation>>,
-    shapes: Option<Vec<Shape>>,
-    #[serde(rename = "newshape")]
-    new_shape: Option<NewShape>,
-    #[serde(rename = "activeshape")]
-    active_shape: Option<ActiveShape>,
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectionType {
+    Equirectangular,
+    Mercator,
+    Orthographic,
+    #[serde(rename = "natural earth")]
+    NaturalEarth,
+    Kavrayskiy7,
+    Miller,
+    Robinson,
+    Eckert4,
+    #[serde(rename = "azimuthal equal area")]
+    AzimuthalEqualArea,
+    #[serde(rename = "azimuthal equidistant")]
+    AzimuthalEquidistant,
+    #[serde(rename = "conic equal area")]
+    ConicEqualArea,
+    #[serde(rename = "conic conformal")]
+    ConicConformal,
+    #[serde(rename = "conic equidistant")]
+    ConicEquidistant,
+    Gnomonic,
+    Stereographic,
+    Mollweide,
+    Hammer,
+    #[serde(rename = "transverse mercator")]
+    TransverseMercator,
+    #[serde(rename = "albers usa")]
+    AlbersUsa,
+    #[serde(rename = "winkel tripel")]
+    WinkelTripel,
+    Aitoff,
+    Sinusoidal,
+}
 
-    #[serde(rename = "boxmode")]
-    box_mode: Option<BoxMode>,
-    #[serde(rename = "boxgap")]
-    box_gap: Option<f64>,
-    #[serde(rename = "boxgroupgap")]
-    box_group_gap: Option<f64>,
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ProjectionRotation {
+    lon: Option<f64>,
+    lat: Option<f64>,
+    roll: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
 
-    #[serde(rename = "barmode")]
-    bar_mode: Option<BarMode>,
-    #[serde(rename = "barnorm")]
-    bar_norm: Option<BarNorm>,
-    #[serde(rename = "bargap")]
-    bar_gap: Option<f64>,
-    #[serde(rename = "bargroupgap")]
-    bar_group_gap: Option<f64>,
+impl ProjectionRotation {
+    pub fn new() -> Self {
+        Default::default()
+    }
 
-    #[serde(rename = "violinmode")]
-    violin_mode: Option<ViolinMode>,
-    #[serde(rename = "violingap")]
-    violin_gap: Option<f64>,
-    #[serde(rename = "violingroupgap")]
-    violin_group_gap: Option<f64>,
+    /// Rotates the map along parallels (in degrees East).
+    pub fn lon(mut self, lon: f64) -> Self {
+        self.lon = Some(lon);
+        self
+    }
 
-    #[serde(rename = "waterfallmode")]
-    waterfall_mode: Option<WaterfallMode>,
-    #[serde(rename = "waterfallgap")]
-    waterfall_gap: Option<f64>,
-    #[serde(rename = "waterfallgroupgap")]
-    waterfall_group_gap: Option<f64>,
+    /// Rotates the map along meridians (in degrees North).
+    pub fn lat(mut self, lat: f64) -> Self {
+        self.lat = Some(lat);
+        self
+    }
 
-    #[serde(rename = "piecolorway")]
-    pie_colorway: Option<Vec<Box<dyn Color>>>,
-    #[serde(rename = "extendpiecolors")]
-    extend_pie_colors: Option<bool>,
+    /// Rolls the map (in degrees). For example, a roll of 180 makes the map appear upside down.
+    pub fn roll(mut self, roll: f64) -> Self {
+        self.roll = Some(roll);
+        self
+    }
+}
 
-    #[serde(rename = "sunburstcolorway")]
-    sunburst_colorway: Option<Vec<Box<dyn Color>>>,
-    #[serde(rename = "extendsunburstcolors")]
-    extend_sunburst_colors: Option<bool>,
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct GeoProjection {
+    #[serde(rename = "type")]
+    projection_type: Option<ProjectionType>,
+    rotation: Option<ProjectionRotation>,
+    parallels: Option<[f64; 2]>,
+    scale: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
-impl Layout {
+impl GeoProjection {
     pub fn new() -> Self {
         Default::default()
     }
 
-    pub fn to_json(&self) -> String {
-        serde_json::to_string(self).unwrap()
+    pub fn projection_type(mut self, projection_type: ProjectionType) -> Self {
+        self.projection_type = Some(projection_type);
+        self
     }
 
-    pub fn title(mut self, title: Title) -> Self {
-        self.title = Some(title);
+    pub fn rotation(mut self, rotation: ProjectionRotation) -> Self {
+        self.rotation = Some(rotation);
         self
     }
 
-    pub fn show_legend(mut self, show_legend: bool) -> Self {
-        self.show_legend = Some(show_legend);
+    /// Sets the standard parallels (in degrees North) of the conic projection types.
+    pub fn parallels(mut self, parallels: [f64; 2]) -> Self {
+        self.parallels = Some(parallels);
         self
     }
 
-    pub fn legend(mut self, legend: Legend) -> Self {
-        self.legend = Some(legend);
+    /// Zooms in or out on the map view. A scale of 1 corresponds to the largest zoom level that
+    /// fits the map's lon and lat ranges.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
         self
     }
+}
 
-    pub fn margin(mut self, margin: Margin) -> Self {
-        self.margin = Some(margin);
-        self
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct GeoCenter {
+    lon: Option<f64>,
+    lat: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl GeoCenter {
+    pub fn new() -> Self {
+        Default::default()
     }
 
-    pub fn auto_size(mut self, auto_size: bool) -> Self {
-        self.auto_size = Some(auto_size);
+    pub fn lon(mut self, lon: f64) -> Self {
+        self.lon = Some(lon);
         self
     }
 
-    pub fn width(mut self, width: usize) -> Self {
-        self.width = Some(width);
+    pub fn lat(mut self, lat: f64) -> Self {
+        self.lat = Some(lat);
         self
     }
+}
 
-    pub fn height(mut self, height: usize) -> Self {
-        self.height = Some(height);
-        self
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct GeoAxis {
+    range: Option<[f64; 2]>,
+    #[serde(rename = "showgrid")]
+    show_grid: Option<bool>,
+    tick0: Option<f64>,
+    dtick: Option<f64>,
+    #[serde(rename = "gridcolor")]
+    grid_color: Option<Box<dyn Color>>,
+    #[serde(rename = "gridwidth")]
+    grid_width: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl GeoAxis {
+    pub fn new() -> Self {
+        Default::default()
     }
 
-    pub fn font(mut self, font: Font) -> Self {
-        self.font = Some(font);
+    /// Sets the range of this axis (in degrees), which determines the extent of the map.
+    pub fn range(mut self, range: [f64; 2]) -> Self {
+        self.range = Some(range);
         self
     }
 
-    pub fn uniform_text(mut self, uniform_text: UniformText) -> Self {
-        self.uniform_text = Some(uniform_text);
+    pub fn show_grid(mut self, show_grid: bool) -> Self {
+        self.show_grid = Some(show_grid);
         self
     }
 
-    pub fn separators(mut self, separators: &str) -> Self {
-        self.separators = Some(separators.to_owned());
+    pub fn tick0(mut self, tick0: f64) -> Self {
+        self.tick0 = Some(tick0);
         self
     }
 
-    pub fn paper_background_color<C: Color>(mut self, paper_background_color: C) -> Self {
-        self.paper_background_color = Some(Box::new(paper_background_color));
+    pub fn dtick(mut self, dtick: f64) -> Self {
+        self.dtick = Some(dtick);
         self
     }
 
-    pub fn plot_background_color<C: Color>(mut self, plot_background_color: C) -> Self {
-        self.plot_background_color = Some(Box::new(plot_background_color));
+    pub fn grid_color<C: Color>(mut self, grid_color: C) -> Self {
+        self.grid_color = Some(Box::new(grid_color));
         self
     }
 
-    pub fn color_scale(mut self, color_scale: LayoutColorScale) -> Self {
-        self.color_scale = Some(color_scale);
+    pub fn grid_width(mut self, grid_width: f64) -> Self {
+        self.grid_width = Some(grid_width);
         self
     }
+}
 
-    pub fn colorway<C: Color>(mut self, colorway: Vec<C>) -> Self {
-        self.colorway = Some(ColorArray(colorway).into());
-        self
+/// The geographic map used by `ScatterGeo` and `Choropleth` traces.
+///
+/// The base map is drawn from a topojson file named after the map's `scope` and `resolution`, for
+/// example "world_110m". By default it is fetched from the URL set in
+/// `Configuration::topojson_url`, or from cdn.plot.ly if unset; use `Plot::add_topojson` to
+/// embed it in the plot instead, so that the map can be rendered offline.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayoutGeo {
+    domain: Option<Domain>,
+    #[serde(rename = "fitbounds")]
+    fit_bounds: Option<FitBounds>,
+    resolution: Option<GeoResolution>,
+    scope: Option<GeoScope>,
+    projection: Option<GeoProjection>,
+    center: Option<GeoCenter>,
+    visible: Option<bool>,
+    #[serde(rename = "showcoastlines")]
+    show_coastlines: Option<bool>,
+    #[serde(rename = "coastlinecolor")]
+    coastline_color: Option<Box<dyn Color>>,
+    #[serde(rename = "coastlinewidth")]
+    coastline_width: Option<f64>,
+    #[serde(rename = "showland")]
+    show_land: Option<bool>,
+    #[serde(rename = "landcolor")]
+    land_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showocean")]
+    show_ocean: Option<bool>,
+    #[serde(rename = "oceancolor")]
+    ocean_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showlakes")]
+    show_lakes: Option<bool>,
+    #[serde(rename = "lakecolor")]
+    lake_color: Option<Box<dyn Color>>,
+    #[serde(rename = "showrivers")]
+    show_rivers: Option<bool>,
+    #[serde(rename = "rivercolor")]
+    river_color: Option<Box<dyn Color>>,
+    #[serde(rename = "riverwidth")]
+    river_width: Option<f64>,
+    #[serde(rename = "showcountries")]
+    show_countries: Option<bool>,
+    #[serde(rename = "countrycolor")]
+    country_color: Option<Box<dyn Color>>,
+    #[serde(rename = "countrywidth")]
+    country_width: Option<f64>,
+    #[serde(rename = "showsubunits")]
+    show_subunits: Option<bool>,
+    #[serde(rename = "subunitcolor")]
+    subunit_color: Option<Box<dyn Color>>,
+    #[serde(rename = "subunitwidth")]
+    subunit_width: Option<f64>,
+    #[serde(rename = "showframe")]
+    show_frame: Option<bool>,
+    #[serde(rename = "framecolor")]
+    frame_color: Option<Box<dyn Color>>,
+    #[serde(rename = "framewidth")]
+    frame_width: Option<f64>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "lataxis")]
+    lat_axis: Option<GeoAxis>,
+    #[serde(rename = "lonaxis")]
+    lon_axis: Option<GeoAxis>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl LayoutGeo {
+    pub fn new() -> Self {
+        Default::default()
     }
 
-    pub fn color_axis(mut self, color_axis: ColorAxis) -> Self {
-        self.color_axis = Some(color_axis);
+    /// The name of the topojson file Plotly.js draws this map from, e.g. "north-america_50m".
+    pub(crate) fn topojson_name(&self) -> String {
+        let scope = match self.scope {
+            None | Some(GeoScope::World) => "world",
+            Some(GeoScope::Usa) => "usa",
+            Some(GeoScope::Europe) => "europe",
+            Some(GeoScope::Asia) => "asia",
+            Some(GeoScope::Africa) => "africa",
+            Some(GeoScope::NorthAmerica) => "north-america",
+            Some(GeoScope::SouthAmerica) => "south-america",
+        };
+        let resolution = match self.resolution {
+            Some(GeoResolution::High) => 50,
+            None | Some(GeoResolution::Low) => 110,
+        };
+        format!("{}_{}m", scope, resolution)
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Self {
+        self.domain = Some(domain);
         self
     }
 
-    pub fn mode_bar(mut self, mode_bar: ModeBar) -> Self {
-        self.mode_bar = Some(mode_bar);
+    /// Determines if and how this map's view is automatically fitted to the data of its traces.
+    /// When set, the `projection.scale`, `center` and the lon and lat axis ranges are computed
+    /// from the trace data, and any values given for them are ignored.
+    pub fn fit_bounds(mut self, fit_bounds: FitBounds) -> Self {
+        self.fit_bounds = Some(fit_bounds);
         self
     }
 
-    /// Determines the mode of hover interactions. If "closest", a single hoverlabel will appear for the "closest"
-    /// point within the `hoverdistance`. If "x" (or "y"), multiple hoverlabels will appear for multiple points at
-    /// the "closest" x- (or y-) coordinate within the `hoverdistance`, with the caveat that no more than one hoverlabel
-    /// will appear per trace. If "x unified" (or "y unified"), a single hoverlabel will appear multiple points at
-    /// the closest x- (or y-) coordinate within the `hoverdistance` with the caveat that no more than one hoverlabel
-    /// will appear per trace. In this mode, spikelines are enabled by default perpendicular to the specified axis.
-    /// If false, hover interactions are disabled. If `clickmode` includes the "select" flag, `hovermode` defaults to
-    /// "closest". If `clickmode` lacks the "select" flag, it defaults to "x" or "y"
-    /// (depending on the trace's `orientation` value) for plots based on cartesian coordinates. For anything
-    /// else the default value is "closest".
-    pub fn hover_mode(mut self, hover_mode: HoverMode) -> Self {
-        self.hover_mode = Some(hover_mode);
+    pub fn resolution(mut self, resolution: GeoResolution) -> Self {
+        self.resolution = Some(resolution);
         self
     }
 
-    pub fn click_mode(mut self, click_mode: ClickMode) -> Self {
-        self.click_mode = Some(click_mode);
+    pub fn scope(mut self, scope: GeoScope) -> Self {
+        self.scope = Some(scope);
         self
     }
 
-    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
-        self.drag_mode = Some(drag_mode);
+    pub fn projection(mut self, projection: GeoProjection) -> Self {
+        self.projection = Some(projection);
         self
     }
 
-    pub fn select_direction(mut self, select_direction: SelectDirection) -> Self {
-        self.select_direction = Some(select_direction);
+    pub fn center(mut self, center: GeoCenter) -> Self {
+        self.center = Some(center);
         self
     }
 
-    pub fn hover_distance(mut self, hover_distance: i32) -> Self {
-        self.hover_distance = Some(hover_distance);
+    /// Sets the default visibility of the base layers, which can be overridden by the individual
+    /// `show_*` options.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
         self
     }
 
-    pub fn spike_distance(mut self, spike_distance: i32) -> Self {
-        self.spike_distance = Some(spike_distance);
+    pub fn show_coastlines(mut self, show_coastlines: bool) -> Self {
+        self.show_coastlines = Some(show_coastlines);
         self
     }
 
-    pub fn hover_label(mut self, hover_label: Label) -> Self {
-        self.hover_label = Some(hover_label);
+    pub fn coastline_color<C: Color>(mut self, coastline_color: C) -> Self {
+        self.coastline_color = Some(Box::new(coastline_color));
         self
     }
 
-    pub fn grid(mut self, grid: LayoutGrid) -> Self {
-        self.grid = Some(grid);
+    pub fn coastline_width(mut self, coastline_width: f64) -> Self {
+        self.coastline_width = Some(coastline_width);
         self
     }
 
-    pub fn calendar(mut self, calendar: Calendar) -> Self {
-        self.calendar = Some(calendar);
+    pub fn show_land(mut self, show_land: bool) -> Self {
+        self.show_land = Some(show_land);
         self
     }
 
-    pub fn x_axis(mut self, xaxis: Axis) -> Self {
-        self.x_axis = Some(Box::new(xaxis));
+    pub fn land_color<C: Color>(mut self, land_color: C) -> Self {
+        self.land_color = Some(Box::new(land_color));
         self
     }
 
-    pub fn y_axis(mut self, yaxis: Axis) -> Self {
-        self.y_axis = Some(Box::new(yaxis));
+    pub fn show_ocean(mut self, show_ocean: bool) -> Self {
+        self.show_ocean = Some(show_ocean);
         self
     }
 
-    pub fn z_axis(mut self, zaxis: Axis) -> Layout {
-        self.z_axis = Some(Box::new(zaxis));
+    pub fn ocean_color<C: Color>(mut self, ocean_color: C) -> Self {
+        self.ocean_color = Some(Box::new(ocean_color));
         self
     }
 
-    pub fn x_axis2(mut self, xaxis: Axis) -> Layout {
-        self.x_axis2 = Some(Box::new(xaxis));
+    pub fn show_lakes(mut self, show_lakes: bool) -> Self {
+        self.show_lakes = Some(show_lakes);
         self
     }
 
-    pub fn y_axis2(mut self, yaxis: Axis) -> Self {
-        self.y_axis2 = Some(Box::new(yaxis));
+    pub fn lake_color<C: Color>(mut self, lake_color: C) -> Self {
+        self.lake_color = Some(Box::new(lake_color));
         self
     }
 
-    pub fn x_axis3(mut self, xaxis: Axis) -> Self {
-        self.x_axis3 = Some(Box::new(xaxis));
+    pub fn show_rivers(mut self, show_rivers: bool) -> Self {
+        self.show_rivers = Some(show_rivers);
         self
     }
 
-    pub fn y_axis3(mut self, yaxis: Axis) -> Self {
-        self.y_axis3 = Some(Box::new(yaxis));
+    pub fn river_color<C: Color>(mut self, river_color: C) -> Self {
+        self.river_color = Some(Box::new(river_color));
         self
     }
 
-    pub fn x_axis4(mut self, xaxis: Axis) -> Self {
-        self.x_axis4 = Some(Box::new(xaxis));
+    pub fn river_width(mut self, river_width: f64) -> Self {
+        self.river_width = Some(river_width);
         self
     }
 
-    pub fn y_axis4(mut self, yaxis: Axis) -> Self {
-        self.y_axis4 = Some(Box::new(yaxis));
+    pub fn show_countries(mut self, show_countries: bool) -> Self {
+        self.show_countries = Some(show_countries);
         self
     }
 
-    pub fn x_axis5(mut self, xaxis: Axis) -> Self {
-        self.x_axis5 = Some(Box::new(xaxis));
+    pub fn country_color<C: Color>(mut self, country_color: C) -> Self {
+        self.country_color = Some(Box::new(country_color));
         self
     }
 
-    pub fn y_axis5(mut self, yaxis: Axis) -> Self {
-        self.y_axis5 = Some(Box::new(yaxis));
+    pub fn country_width(mut self, country_width: f64) -> Self {
+        self.country_width = Some(country_width);
         self
     }
 
-    pub fn x_axis6(mut self, xaxis: Axis) -> Self {
-        self.x_axis6 = Some(Box::new(xaxis));
+    /// Determines whether or not boundaries of subunits within countries (e.g. states, provinces)
+    /// are drawn.
+    pub fn show_subunits(mut self, show_subunits: bool) -> Self {
+        self.show_subunits = Some(show_subunits);
         self
     }
 
-    pub fn y_axis6(mut self, yaxis: Axis) -> Self {
-        self.y_axis6 = Some(Box::new(yaxis));
+    pub fn subunit_color<C: Color>(mut self, subunit_color: C) -> Self {
+        self.subunit_color = Some(Box::new(subunit_color));
         self
     }
 
-    pub fn x_axis7(mut self, xaxis: Axis) -> Self {
-        self.x_axis7 = Some(Box::new(xaxis));
+    pub fn subunit_width(mut self, subunit_width: f64) -> Self {
+        self.subunit_width = Some(subunit_width);
         self
     }
 
-    pub fn y_axis7(mut self, yaxis: Axis) -> Self {
-        self.y_axis7 = Some(Box::new(yaxis));
+    pub fn show_frame(mut self, show_frame: bool) -> Self {
+        self.show_frame = Some(show_frame);
         self
     }
 
-    pub fn x_axis8(mut self, xaxis: Axis) -> Self {
-        self.x_axis8 = Some(Box::new(xaxis));
+    pub fn frame_color<C: Color>(mut self, frame_color: C) -> Self {
+        self.frame_color = Some(Box::new(frame_color));
         self
     }
 
-    pub fn y_axis8(mut self, yaxis: Axis) -> Self {
-        self.y_axis8 = Some(Box::new(yaxis));
+    pub fn frame_width(mut self, frame_width: f64) -> Self {
+        self.frame_width = Some(frame_width);
         self
     }
 
-    pub fn annotations(mut self, annotations: Vec<Annotation>) -> Self {
-        self.annotations = Some(annotations);
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
         self
     }
 
-    pub fn add_annotation(&mut self, annotation: Annotation) {
-        if self.annotations.is_none() {
-            self.annotations = Some(Vec::new());
-        }
-        self.annotations.as_mut().unwrap().push(annotation);
+    pub fn lat_axis(mut self, lat_axis: GeoAxis) -> Self {
+        self.lat_axis = Some(lat_axis);
+        self
     }
 
-    pub fn shapes(mut self, shapes: Vec<Shape>) -> Self {
-        self.shapes = Some(shapes);
+    pub fn lon_axis(mut self, lon_axis: GeoAxis) -> Self {
+        self.lon_axis = Some(lon_axis);
         self
     }
+}
 
-    pub fn add_shape(&mut self, shape: Shape) {
-        if self.shapes.is_none() {
-            self.shapes = Some(Vec::new());
-        }
-        self.shapes.as_mut().unwrap().push(shape);
+/// The base map style of a `LayoutMapbox`. The styles that use Mapbox-hosted tiles (`Basic`,
+/// `Streets`, `Outdoors`, `Light`, `Dark`, `Satellite` and `SatelliteStreets`) require a Mapbox
+/// access token, while the others do not.
+#[derive(Debug, Clone)]
+pub enum MapboxStyle {
+    /// An empty white background, on which custom `MapboxLayer`s can be drawn.
+    WhiteBg,
+    OpenStreetMap,
+    CartoPositron,
+    CartoDarkMatter,
+    StamenTerrain,
+    StamenToner,
+    StamenWatercolor,
+    Basic,
+    Streets,
+    Outdoors,
+    Light,
+    Dark,
+    Satellite,
+    SatelliteStreets,
+    /// The URL of a Mapbox style, for example "mapbox://styles/mapbox/streets-v11", or of a style
+    /// JSON served by a tile server.
+    Url(String),
+}
+
+impl Serialize for MapboxStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::WhiteBg => serializer.serialize_str("white-bg"),
+            Self::OpenStreetMap => serializer.serialize_str("open-street-map"),
+            Self::CartoPositron => serializer.serialize_str("carto-positron"),
+            Self::CartoDarkMatter => serializer.serialize_str("carto-darkmatter"),
+            Self::StamenTerrain => serializer.serialize_str("stamen-terrain"),
+            Self::StamenToner => serializer.serialize_str("stamen-toner"),
+            Self::StamenWatercolor => serializer.serialize_str("stamen-watercolor"),
+            Self::Basic => serializer.serialize_str("basic"),
+            Self::Streets => serializer.serialize_str("streets"),
+            Self::Outdoors => serializer.serialize_str("outdoors"),
+            Self::Light => serializer.serialize_str("light"),
+            Self::Dark => serializer.serialize_str("dark"),
+            Self::Satellite => serializer.serialize_str("satellite"),
+            Self::SatelliteStreets => serializer.serialize_str("satellite-streets"),
+            Self::Url(url) => serializer.serialize_str(url),
+        }
     }
+}
 
-    pub fn new_shape(mut self, new_shape: NewShape) -> Self {
-        self.new_shape = Some(new_shape);
+impl<'de> Deserialize<'de> for MapboxStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let style = String::deserialize(deserializer)?;
+        Ok(match style.as_str() {
+            "white-bg" => Self::WhiteBg,
+            "open-street-map" => Self::OpenStreetMap,
+            "carto-positron" => Self::CartoPositron,
+            "carto-darkmatter" => Self::CartoDarkMatter,
+            "stamen-terrain" => Self::StamenTerrain,
+            "stamen-toner" => Self::StamenToner,
+            "stamen-watercolor" => Self::StamenWatercolor,
+            "basic" => Self::Basic,
+            "streets" => Self::Streets,
+            "outdoors" => Self::Outdoors,
+            "light" => Self::Light,
+            "dark" => Self::Dark,
+            "satellite" => Self::Satellite,
+            "satellite-streets" => Self::SatelliteStreets,
+            _ => Self::Url(style),
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum MapboxLayerSourceType {
+    GeoJson,
+    Vector,
+    Raster,
+    Image,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum MapboxLayerType {
+    Circle,
+    Line,
+    Fill,
+    Symbol,
+    Raster,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum SymbolPlacement {
+    Point,
+    Line,
+    #[serde(rename = "line-center")]
+    LineCenter,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayerCircle {
+    radius: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl LayerCircle {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = Some(radius);
         self
     }
+}
 
-    pub fn active_shape(mut self, active_shape: ActiveShape) -> Self {
-        self.active_shape = Some(active_shape);
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayerLine {
+    width: Option<f64>,
+    dash: Option<Vec<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl LayerLine {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
         self
     }
 
-    pub fn template<T>(mut self, template: T) -> Layout
-    where
-        T: Into<Cow<'static, Template>>,
-    {
-        self.template = Some(Box::new(template.into()));
+    /// Sets the lengths of the alternating dashes and gaps of the line, in multiples of its width.
+    pub fn dash(mut self, dash: Vec<f64>) -> Self {
+        self.dash = Some(dash);
         self
     }
+}
 
-    pub fn box_mode(mut self, box_mode: BoxMode) -> Self {
-        self.box_mode = Some(box_mode);
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayerFill {
+    #[serde(rename = "outlinecolor")]
+    outline_color: Option<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl LayerFill {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn outline_color<C: Color>(mut self, outline_color: C) -> Self {
+        self.outline_color = Some(Box::new(outline_color));
         self
     }
+}
 
-    pub fn box_gap(mut self, box_gap: f64) -> Self {
-        self.box_gap = Some(box_gap);
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayerSymbol {
+    icon: Option<String>,
+    #[serde(rename = "iconsize")]
+    icon_size: Option<f64>,
+    text: Option<String>,
+    placement: Option<SymbolPlacement>,
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(rename = "textposition")]
+    text_position: Option<Position>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl LayerSymbol {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the symbol icon image, from the Maki icon set.
+    pub fn icon(mut self, icon: &str) -> Self {
+        self.icon = Some(icon.to_string());
         self
     }
 
-    pub fn box_group_gap(mut self, box_group_gap: f64) -> Self {
-        self.box_group_gap = Some(box_group_gap);
+    pub fn icon_size(mut self, icon_size: f64) -> Self {
+        self.icon_size = Some(icon_size);
         self
     }
 
-    pub fn bar_mode(mut self, bar_mode: BarMode) -> Self {
-        self.bar_mode = Some(bar_mode);
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
         self
     }
 
-    pub fn bar_norm(mut self, bar_norm: BarNorm) -> Self {
-        self.bar_norm = Some(bar_norm);
+    pub fn placement(mut self, placement: SymbolPlacement) -> Self {
+        self.placement = Some(placement);
         self
     }
 
-    pub fn bar_gap(mut self, bar_gap: f64) -> Self {
-        self.bar_gap = Some(bar_gap);
+    pub fn text_font(mut self, text_font: Font) -> Self {
+        self.text_font = Some(text_font);
         self
     }
 
-    pub fn bar_group_gap(mut self, bar_group_gap: f64) -> Self {
-        self.bar_group_gap = Some(bar_group_gap);
+    pub fn text_position(mut self, text_position: Position) -> Self {
+        self.text_position = Some(text_position);
         self
     }
+}
 
-    pub fn violin_mode(mut self, violin_mode: ViolinMode) -> Self {
-        self.violin_mode = Some(violin_mode);
+/// A custom layer drawn on a `LayoutMapbox`, either on top of its base map style or on a
+/// `MapboxStyle::WhiteBg` background.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::layout::{MapboxLayer, MapboxLayerSourceType};
+///
+/// let layer = MapboxLayer::new()
+///     .source_type(MapboxLayerSourceType::Raster)
+///     .source(vec!["http://localhost:8080/tiles/{z}/{x}/{y}.png"])
+///     .below("traces");
+///
+/// let expected = serde_json::json!({
+///     "sourcetype": "raster",
+///     "source": ["http://localhost:8080/tiles/{z}/{x}/{y}.png"],
+///     "below": "traces"
+/// });
+///
+/// assert_eq!(serde_json::to_value(layer).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct MapboxLayer {
+    visible: Option<bool>,
+    #[serde(rename = "sourcetype")]
+    source_type: Option<MapboxLayerSourceType>,
+    source: Option<serde_json::Value>,
+    #[serde(rename = "sourcelayer")]
+    source_layer: Option<String>,
+    #[serde(rename = "sourceattribution")]
+    source_attribution: Option<String>,
+    #[serde(rename = "type")]
+    layer_type: Option<MapboxLayerType>,
+    coordinates: Option<Vec<[f64; 2]>>,
+    below: Option<String>,
+    color: Option<Box<dyn Color>>,
+    opacity: Option<f64>,
+    #[serde(rename = "minzoom")]
+    min_zoom: Option<f64>,
+    #[serde(rename = "maxzoom")]
+    max_zoom: Option<f64>,
+    circle: Option<LayerCircle>,
+    line: Option<LayerLine>,
+    fill: Option<LayerFill>,
+    symbol: Option<LayerSymbol>,
+    name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl MapboxLayer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
         self
     }
 
-    pub fn violin_gap(mut self, violin_gap: f64) -> Self {
-        self.violin_gap = Some(violin_gap);
+    pub fn source_type(mut self, source_type: MapboxLayerSourceType) -> Self {
+        self.source_type = Some(source_type);
         self
     }
 
-    pub fn violin_group_gap(mut self, violin_group_gap: f64) -> Self {
-        self.violin_group_gap = Some(violin_group_gap);
+    /// Sets the source data for this layer. For `MapboxLayerSourceType::GeoJson` this is a URL to
+    /// a GeoJSON file or a GeoJSON object, for `MapboxLayerSourceType::Vector` and
+    /// `MapboxLayerSourceType::Raster` a list of tile URLs such as
+    /// "http://localhost:8080/{z}/{x}/{y}.png", and for `MapboxLayerSourceType::Image` the URL of
+    /// the image.
+    pub fn source<V: Into<serde_json::Value>>(mut self, source: V) -> Self {
+        self.source = Some(source.into());
         self
     }
 
-    pub fn waterfall_mode(mut self, waterfall_mode: WaterfallMode) -> Self {
-        self.waterfall_mode = Some(waterfall_mode);
+    /// Specifies the layer to use from a vector tile source. Required for
+    /// `MapboxLayerSourceType::Vector` sources.
+    pub fn source_layer(mut self, source_layer: &str) -> Self {
+        self.source_layer = Some(source_layer.to_string());
         self
     }
 
-    pub fn waterfall_gap(mut self, waterfall_gap: f64) -> Self {
-        self.waterfall_gap = Some(waterfall_gap);
+    /// Sets the attribution for this source.
+    pub fn source_attribution(mut self, source_attribution: &str) -> Self {
+        self.source_attribution = Some(source_attribution.to_string());
         self
     }
 
-    pub fn waterfall_group_gap(mut self, waterfall_group_gap: f64) -> Self {
-        self.waterfall_group_gap = Some(waterfall_group_gap);
+    /// Sets the layer type. Defaults to `MapboxLayerType::Raster` for raster and image sources,
+    /// and to `MapboxLayerType::Circle` otherwise.
+    pub fn layer_type(mut self, layer_type: MapboxLayerType) -> Self {
+        self.layer_type = Some(layer_type);
         self
     }
 
-    pub fn pie_colorway<C: Color>(mut self, pie_colorway: Vec<C>) -> Self {
-        self.pie_colorway = Some(ColorArray(pie_colorway).into());
+    /// Sets the coordinates of the four corners of an image source, clockwise from the top left,
+    /// as `[lon, lat]` pairs.
+    pub fn coordinates(mut self, coordinates: Vec<[f64; 2]>) -> Self {
+        self.coordinates = Some(coordinates);
         self
     }
 
-    pub fn extend_pie_colors(mut self, extend_pie_colors: bool) -> Self {
-        self.extend_pie_colors = Some(extend_pie_colors);
+    /// Determines if the layer will be inserted before the layer with the specified id. If set to
+    /// "traces", the layer will be inserted above the base map layers, but below the traces.
+    pub fn below(mut self, below: &str) -> Self {
+        self.below = Some(below.to_string());
         self
     }
 
-    pub fn sunburst_colorway<C: Color>(mut self, sunburst_colorway: Vec<C>) -> Self {
-        self.sunburst_colorway = Some(ColorArray(sunburst_colorway).into());
+    /// Sets the primary layer color, used as the circle, line, fill or icon color depending on the
+    /// layer type.
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
         self
     }
 
-    pub fn extend_sunburst_colors(mut self, extend_sunburst_colors: bool) -> Self {
-        self.extend_sunburst_colors = Some(extend_sunburst_colors);
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Sets the minimum zoom level at which the layer is visible.
+    pub fn min_zoom(mut self, min_zoom: f64) -> Self {
+        self.min_zoom = Some(min_zoom);
+        self
+    }
+
+    /// Sets the maximum zoom level at which the layer is visible.
+    pub fn max_zoom(mut self, max_zoom: f64) -> Self {
+        self.max_zoom = Some(max_zoom);
+        self
+    }
+
+    pub fn circle(mut self, circle: LayerCircle) -> Self {
+        self.circle = Some(circle);
+        self
+    }
+
+    pub fn line(mut self, line: LayerLine) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn fill(mut self, fill: LayerFill) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    pub fn symbol(mut self, symbol: LayerSymbol) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
         self
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use serde_json::{json, to_value};
+/// The tile map used by `ScatterMapbox`, `DensityMapbox` and `ChoroplethMapbox` traces.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct LayoutMapbox {
+    domain: Option<Domain>,
+    #[serde(rename = "accesstoken")]
+    access_token: Option<String>,
+    style: Option<MapboxStyle>,
+    center: Option<GeoCenter>,
+    zoom: Option<f64>,
+    bearing: Option<f64>,
+    pitch: Option<f64>,
+    layers: Option<Vec<MapboxLayer>>,
+    #[serde(flatten)]
+    extra: Extra,
+}
 
-    use super::*;
-    use crate::common::ColorScalePalette;
+impl LayoutMapbox {
+    pub fn new() -> Self {
+        Default::default()
+    }
 
-    #[test]
-    fn test_serialize_uniform_text_mode() {
-        assert_eq!(to_value(UniformTextMode::False).unwrap(), json!(false));
-        assert_eq!(to_value(UniformTextMode::Hide).unwrap(), json!("hide"));
-        assert_eq!(to_value(UniformTextMode::Show).unwrap(), json!("show"));
+    pub fn domain(mut self, domain: Domain) -> Self {
+        self.domain = Some(domain);
+        self
     }
 
-    #[test]
-    fn test_serialize_click_to_show() {
-        assert_eq!(to_value(ClickToShow::False).unwrap(), json!(false));
-        assert_eq!(to_value(ClickToShow::OnOff).unwrap(), json!("onoff"));
-        assert_eq!(to_value(ClickToShow::OnOut).unwrap(), json!("onout"));
+    /// Sets the Mapbox access token for this map. Takes precedence over
+    /// `Configuration::mapbox_access_token`.
+    pub fn access_token(mut self, access_token: &str) -> Self {
+        self.access_token = Some(access_token.to_string());
+        self
     }
 
-    #[test]
-    fn test_serialize_hover_mode() {
-        assert_eq!(to_value(HoverMode::X).unwrap(), json!("x"));
-        assert_eq!(to_value(HoverMode::Y).unwrap(), json!("y"));
-        assert_eq!(to_value(HoverMode::Closest).unwrap(), json!("closest"));
-        assert_eq!(to_value(HoverMode::False).unwrap(), json!(false));
-        assert_eq!(to_value(HoverMode::XUnified).unwrap(), json!("x unified"));
-        assert_eq!(to_value(HoverMode::YUnified).unwrap(), json!("y unified"));
+    pub fn style(mut self, style: MapboxStyle) -> Self {
+        self.style = Some(style);
+        self
     }
 
-    #[test]
-    #[rustfmt::skip]
-    fn test_serialize_axis_type() {
-        assert_eq!(to_value(AxisType::Default).unwrap(), json!("-"));
-        assert_eq!(to_value(AxisType::Linear).unwrap(), json!("linear"));
-        assert_eq!(to_value(AxisType::Log).unwrap(), json!("log"));
-        assert_eq!(to_value(AxisType::Date).unwrap(), json!("date"));
-        assert_eq!(to_value(AxisType::Category).unwrap(), json!("category"));
-        assert_eq!(to_value(AxisType::MultiCategory).unwrap(), json!("multicategory"));
+    pub fn center(mut self, center: GeoCenter) -> Self {
+        self.center = Some(center);
+        self
+    }
+
+    pub fn zoom(mut self, zoom: f64) -> Self {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    /// Sets the bearing angle of the map in degrees counter-clockwise from North.
+    pub fn bearing(mut self, bearing: f64) -> Self {
+        self.bearing = Some(bearing);
+        self
+    }
+
+    /// Sets the pitch angle of the map in degrees, where 0 means perpendicular to the surface of
+    /// the map.
+    pub fn pitch(mut self, pitch: f64) -> Self {
+        self.pitch = Some(pitch);
+        self
+    }
+
+    pub fn layers(mut self, layers: Vec<MapboxLayer>) -> Self {
+        self.layers = Some(layers);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum UniformTextMode {
+    False,
+    Hide,
+    Show,
+}
+
+impl Serialize for UniformTextMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::False => serializer.serialize_bool(false),
+            Self::Hide => serializer.serialize_str("hide"),
+            Self::Show => serializer.serialize_str("show"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UniformTextMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "hide" => Ok(Self::Hide),
+                "show" => Ok(Self::Show),
+                _ => Err(de::Error::unknown_variant(&s, &["hide", "show"])),
+            },
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct UniformText {
+    mode: Option<UniformTextMode>,
+    #[serde(rename = "minsize")]
+    min_size: Option<Number>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl UniformText {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn mode(mut self, mode: UniformTextMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn min_size(mut self, min_size: usize) -> Self {
+        self.min_size = Some(min_size.into());
+        self
     }
+}
+
+#[derive(Debug, Clone)]
+pub enum HoverMode {
+    X,
+    Y,
+    Closest,
+    False,
+    XUnified,
+    YUnified,
+}
+
+impl Serialize for HoverMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::X => serializer.serialize_str("x"),
+            Self::Y => serializer.serialize_str("y"),
+            Self::Closest => serializer.serialize_str("closest"),
+            Self::False => serializer.serialize_bool(false),
+            Self::XUnified => serializer.serialize_str("x unified"),
+            Self::YUnified => serializer.serialize_str("y unified"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HoverMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "x" => Ok(Self::X),
+                "y" => Ok(Self::Y),
+                "closest" => Ok(Self::Closest),
+                "x unified" => Ok(Self::XUnified),
+                "y unified" => Ok(Self::YUnified),
+                _ => Err(de::Error::unknown_variant(
+                    &s,
+                    &["x", "y", "closest", "x unified", "y unified"],
+                )),
+            },
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ModeBar {
+    orientation: Option<Orientation>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    color: Option<Box<dyn Color>>,
+    #[serde(rename = "activecolor")]
+    active_color: Option<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl ModeBar {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
+        self
+    }
+
+    pub fn active_color<C: Color>(mut self, active_color: C) -> Self {
+        self.active_color = Some(Box::new(active_color));
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ShapeType {
+    Circle,
+    Rect,
+    Path,
+    Line,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ShapeLayer {
+    Below,
+    Above,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ShapeSizeMode {
+    Scaled,
+    Pixel,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum FillRule {
+    EvenOdd,
+    NonZero,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ShapeLine {
+    color: Option<Box<dyn Color>>,
+    width: Option<f64>,
+    dash: Option<DashType>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl ShapeLine {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the line color.
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
+        self
+    }
+
+    /// Sets the line width (in px).
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the dash style of lines. Set to a dash type string ("solid", "dot", "dash", "longdash",
+    /// "dashdot", or "longdashdot") or a dash length list in px (eg "5px,10px,2px,2px").
+    pub fn dash(mut self, dash: DashType) -> Self {
+        self.dash = Some(dash);
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Shape {
+    visible: Option<bool>,
+    r#type: Option<ShapeType>,
+    layer: Option<ShapeLayer>,
+    #[serde(rename = "xref")]
+    x_ref: Option<String>,
+    #[serde(rename = "xsizemode")]
+    x_size_mode: Option<ShapeSizeMode>,
+    #[serde(rename = "xanchor")]
+    x_anchor: Option<NumOrString>,
+    x0: Option<NumOrString>,
+    x1: Option<NumOrString>,
+    #[serde(rename = "yref")]
+    y_ref: Option<String>,
+    #[serde(rename = "ysizemode")]
+    y_size_mode: Option<ShapeSizeMode>,
+    #[serde(rename = "yanchor")]
+    y_anchor: Option<NumOrString>,
+    y0: Option<NumOrString>,
+    y1: Option<NumOrString>,
+    path: Option<String>,
+    opacity: Option<f64>,
+    line: Option<ShapeLine>,
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    #[serde(rename = "fillrule")]
+    fill_rule: Option<FillRule>,
+    editable: Option<bool>,
+    name: Option<String>,
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Shape {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines whether or not this shape is visible.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Specifies the shape type to be drawn. If "line", a line is drawn from (`x0`,`y0`) to
+    /// (`x1`,`y1`) with respect to the axes' sizing mode. If "circle", a circle is drawn from
+    /// ((`x0`+`x1`)/2, (`y0`+`y1`)/2)) with radius (|(`x0`+`x1`)/2 - `x0`|, |(`y0`+`y1`)/2 -`y0`)|)
+    /// with respect to the axes' sizing mode. If "rect", a rectangle is drawn linking
+    /// (`x0`,`y0`), (`x1`,`y0`), (`x1`,`y1`), (`x0`,`y1`), (`x0`,`y0`) with respect to the axes'
+    /// sizing mode. If "path", draw a custom SVG path using `path`. with respect to the axes'
+    /// sizing mode.
+    pub fn shape_type(mut self, shape_type: ShapeType) -> Self {
+        self.r#type = Some(shape_type);
+        self
+    }
+
+    /// Specifies whether shapes are drawn below or above traces.
+    pub fn layer(mut self, layer: ShapeLayer) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the shape's x coordinate axis. If set to an x axis id (e.g. "x" or "x2"), the `x`
+    /// position refers to an x coordinate. If set to "paper", the `x` position refers to the
+    /// distance from the left side of the plotting area in normalized coordinates where "0" ("1")
+    /// corresponds to the left (right) side. If the axis `type` is "log", then you must take the
+    /// log of your desired range. If the axis `type` is "date", then you must convert the date to
+    /// unix time in milliseconds.
+    pub fn x_ref(mut self, x_ref: &str) -> Self {
+        self.x_ref = Some(x_ref.to_owned());
+        self
+    }
+
+    /// Sets the shapes's sizing mode along the x axis. If set to "scaled", `x0`, `x1` and x
+    /// coordinates within `path` refer to data values on the x axis or a fraction of the plot
+    /// area's width (`xref` set to "paper"). If set to "pixel", `xanchor` specifies the x position
+    /// in terms of data or plot fraction but `x0`, `x1` and x coordinates within `path` are pixels
+    /// relative to `xanchor`. This way, the shape can have a fixed width while maintaining a
+    /// position relative to data or plot fraction.
+    pub fn x_size_mode(mut self, x_size_mode: ShapeSizeMode) -> Self {
+        self.x_size_mode = Some(x_size_mode);
+        self
+    }
+
+    /// Only relevant in conjunction with `xsizemode` set to "pixel". Specifies the anchor point on
+    /// the x axis to which `x0`, `x1` and x coordinates within `path` are relative to. E.g. useful
+    /// to attach a pixel sized shape to a certain data value. No effect when `xsizemode` not set
+    /// to "pixel".
+    pub fn x_anchor<V: Into<NumOrString>>(mut self, x_anchor: V) -> Self {
+        self.x_anchor = Some(x_anchor.into());
+        self
+    }
+
+    /// Sets the shape's starting x position. See `type` and `xsizemode` for more info.
+    pub fn x0<V: Into<NumOrString>>(mut self, x0: V) -> Self {
+        self.x0 = Some(x0.into());
+        self
+    }
+
+    /// Sets the shape's end x position. See `type` and `xsizemode` for more info.
+    pub fn x1<V: Into<NumOrString>>(mut self, x1: V) -> Self {
+        self.x1 = Some(x1.into());
+        self
+    }
+
+    /// Sets the annotation's y coordinate axis. If set to an y axis id (e.g. "y" or "y2"),
+    /// the `y` position refers to an y coordinate If set to "paper", the `y` position refers to
+    /// the distance from the bottom of the plotting area in normalized coordinates where "0" ("1")
+    /// corresponds to the bottom (top).
+    pub fn y_ref(mut self, y_ref: &str) -> Self {
+        self.y_ref = Some(y_ref.to_owned());
+        self
+    }
+
+    /// Sets the shapes's sizing mode along the y axis. If set to "scaled", `y0`, `y1` and y
+    /// coordinates within `path` refer to data values on the y axis or a fraction of the plot
+    /// area's height (`yref` set to "paper"). If set to "pixel", `yanchor` specifies the y position
+    /// in terms of data or plot fraction but `y0`, `y1` and y coordinates within `path` are pixels
+    /// relative to `yanchor`. This way, the shape can have a fixed height while maintaining a
+    /// position relative to data or plot fraction.
+    pub fn y_size_mode(mut self, y_size_mode: ShapeSizeMode) -> Self {
+        self.y_size_mode = Some(y_size_mode);
+        self
+    }
+
+    /// Only relevant in conjunction with `ysizemode` set to "pixel". Specifies the anchor point on
+    /// the y axis to which `y0`, `y1` and y coordinates within `path` are relative to. E.g. useful
+    /// to attach a pixel sized shape to a certain data value. No effect when `ysizemode` not set
+    /// to "pixel".
+    pub fn y_anchor<V: Into<NumOrString>>(mut self, y_anchor: V) -> Self {
+        self.y_anchor = Some(y_anchor.into());
+        self
+    }
+
+    /// Sets the shape's starting y position. See `type` and `ysizemode` for more info.
+    pub fn y0<V: Into<NumOrString>>(mut self, y0: V) -> Self {
+        self.y0 = Some(y0.into());
+        self
+    }
+
+    /// Sets the shape's end y position. See `type` and `ysizemode` for more info.
+    pub fn y1<V: Into<NumOrString>>(mut self, y1: V) -> Self {
+        self.y1 = Some(y1.into());
+        self
+    }
+
+    /// For `type` "path" - a valid SVG path with the pixel values replaced by data values in
+    /// `xsizemode`/`ysizemode` being "scaled" and taken unmodified as pixels relative to
+    /// `xanchor` and `yanchor` in case of "pixel" size mode. There are a few restrictions / quirks
+    /// only absolute instructions, not relative. So the allowed segments
+    /// are: M, L, H, V, Q, C, T, S, and Z arcs (A) are not allowed because radius rx and ry are
+    /// relative. In the future we could consider supporting relative commands, but we would have
+    /// to decide on how to handle date and log axes. Note that even as is, Q and C Bezier paths
+    /// that are smooth on linear axes may not be smooth on log, and vice versa. no chained
+    /// "polybezier" commands - specify the segment type for each one. On category axes, values are
+    /// numbers scaled to the serial numbers of categories because using the categories themselves
+    /// there would be no way to describe fractional positions On data axes: because space and T are
+    /// both normal components of path strings, we can't use either to separate date from time parts.
+    /// Therefore we'll use underscore for this purpose: 2015-02-21_13:45:56.789
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_owned());
+        self
+    }
+
+    /// Sets the opacity of the shape. Number between or equal to 0 and 1.
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Sets the shape line properties (`color`, `width`, `dash`).
+    pub fn line(mut self, line: ShapeLine) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets the color filling the shape's interior. Only applies to closed shapes.
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Self {
+        self.fill_color = Some(Box::new(fill_color));
+        self
+    }
+
+    /// Determines which regions of complex paths constitute the interior. For more info please
+    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
+    /// Determines whether the shape could be activated for edit or not. Has no effect when the
+    /// older editable shapes mode is enabled via `config.editable` or `config.edits.shapePosition`.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = Some(editable);
+        self
+    }
+
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output figure
+    /// by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one
+    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
+    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
+        self.template_item_name = Some(template_item_name.to_owned());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum DrawDirection {
+    Ortho,
+    Horizontal,
+    Vertical,
+    Diagonal,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct NewShape {
+    line: Option<ShapeLine>,
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    #[serde(rename = "fillrule")]
+    fill_rule: Option<FillRule>,
+    opacity: Option<f64>,
+    layer: Option<ShapeLayer>,
+    #[serde(rename = "drawdirection")]
+    draw_direction: Option<DrawDirection>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl NewShape {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the shape line properties (`color`, `width`, `dash`).
+    pub fn line(mut self, line: ShapeLine) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets the color filling new shapes' interior. Please note that if using a fillcolor with
+    /// alpha greater than half, drag inside the active shape starts moving the shape underneath,
+    /// otherwise a new shape could be started over.
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Self {
+        self.fill_color = Some(Box::new(fill_color));
+        self
+    }
+
+    /// Determines the path's interior. For more info please
+    /// visit https://developer.mozilla.org/en-US/docs/Web/SVG/Attribute/fill-rule
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
+    /// Sets the opacity of new shapes. Number between or equal to 0 and 1.
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Specifies whether new shapes are drawn below or above traces.
+    pub fn layer(mut self, layer: ShapeLayer) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// When `dragmode` is set to "drawrect", "drawline" or "drawcircle" this limits the drag to be
+    /// horizontal, vertical or diagonal. Using "diagonal" there is no limit e.g. in drawing lines
+    /// in any direction. "ortho" limits the draw to be either horizontal or vertical. "horizontal"
+    /// allows horizontal extend. "vertical" allows vertical extend.
+    pub fn draw_direction(mut self, draw_direction: DrawDirection) -> Self {
+        self.draw_direction = Some(draw_direction);
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ActiveShape {
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    opacity: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl ActiveShape {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the color filling the active shape' interior.
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Self {
+        self.fill_color = Some(Box::new(fill_color));
+        self
+    }
+
+    /// Sets the opacity of the active shape. Number between or equal to 0 and 1.
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ArrowSide {
+    End,
+    Start,
+    #[serde(rename = "end+start")]
+    StartEnd,
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub enum ClickToShow {
+    False,
+    OnOff,
+    OnOut,
+}
+
+impl Serialize for ClickToShow {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::False => serializer.serialize_bool(false),
+            Self::OnOff => serializer.serialize_str("onoff"),
+            Self::OnOut => serializer.serialize_str("onout"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ClickToShow {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "onoff" => Ok(Self::OnOff),
+                "onout" => Ok(Self::OnOut),
+                _ => Err(de::Error::unknown_variant(&s, &["onoff", "onout"])),
+            },
+        }
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Annotation {
+    visible: Option<bool>,
+    text: Option<String>,
+    #[serde(rename = "textangle")]
+    text_angle: Option<f64>,
+    font: Option<Font>,
+    width: Option<f64>,
+    height: Option<f64>,
+    opacity: Option<f64>,
+    align: Option<HAlign>,
+    valign: Option<VAlign>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "bordercolor")]
+    border_color: Option<Box<dyn Color>>,
+    #[serde(rename = "borderpad")]
+    border_pad: Option<f64>,
+    #[serde(rename = "borderwidth")]
+    border_width: Option<f64>,
+    #[serde(rename = "showarrow")]
+    show_arrow: Option<bool>,
+    #[serde(rename = "arrowcolor")]
+    arrow_color: Option<Box<dyn Color>>,
+    #[serde(rename = "arrowhead")]
+    arrow_head: Option<u8>,
+    #[serde(rename = "startarrowhead")]
+    start_arrow_head: Option<u8>,
+    #[serde(rename = "arrowside")]
+    arrow_side: Option<ArrowSide>,
+    #[serde(rename = "arrowsize")]
+    arrow_size: Option<f64>,
+    #[serde(rename = "startarrowsize")]
+    start_arrow_size: Option<f64>,
+    #[serde(rename = "arrowwidth")]
+    arrow_width: Option<f64>,
+    #[serde(rename = "standoff")]
+    stand_off: Option<f64>,
+    #[serde(rename = "startstandoff")]
+    start_stand_off: Option<f64>,
+    ax: Option<NumOrString>,
+    ay: Option<NumOrString>,
+    #[serde(rename = "axref")]
+    ax_ref: Option<String>,
+    #[serde(rename = "ayref")]
+    ay_ref: Option<String>,
+    #[serde(rename = "xref")]
+    x_ref: Option<String>,
+    x: Option<NumOrString>,
+    #[serde(rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    #[serde(rename = "xshift")]
+    x_shift: Option<f64>,
+    #[serde(rename = "yref")]
+    y_ref: Option<String>,
+    y: Option<NumOrString>,
+    #[serde(rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    #[serde(rename = "yshift")]
+    y_shift: Option<f64>,
+    z: Option<NumOrString>,
+    #[serde(rename = "clicktoshow")]
+    click_to_show: Option<ClickToShow>,
+    #[serde(rename = "xclick")]
+    x_click: Option<NumOrString>,
+    #[serde(rename = "yclick")]
+    y_click: Option<NumOrString>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<String>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "captureevents")]
+    capture_events: Option<bool>,
+    name: Option<String>,
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Annotation {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines whether or not this annotation is visible.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Sets the text associated with this annotation. Plotly uses a subset of HTML tags to do
+    /// things like newline (<br>), bold (<b></b>), italics (<i></i>), hyperlinks
+    /// (<a href='...'></a>). Tags <em>, <sup>, <sub> <span> are also supported.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_owned());
+        self
+    }
+
+    /// Sets the angle at which the `text` is drawn with respect to the horizontal.
+    pub fn text_angle(mut self, text_angle: f64) -> Self {
+        self.text_angle = Some(text_angle);
+        self
+    }
+
+    /// Sets the annotation text font.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets an explicit width for the text box. null (default) lets the text set the box width.
+    /// Wider text will be clipped. There is no automatic wrapping; use <br> to start a new line.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets an explicit height for the text box. null (default) lets the text set the box height.
+    /// Taller text will be clipped.
+    pub fn height(mut self, height: f64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Sets the opacity of the annotation (text + arrow).
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Sets the horizontal alignment of the `text` within the box. Has an effect only if `text`
+    /// spans two or more lines (i.e. `text` contains one or more <br> HTML tags) or if an explicit
+    /// width is set to override the text width.
+    pub fn align(mut self, align: HAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Sets the vertical alignment of the `text` within the box. Has an effect only if an explicit
+    /// height is set to override the text height.
+    pub fn valign(mut self, valign: VAlign) -> Self {
+        self.valign = Some(valign);
+        self
+    }
+
+    /// Sets the background color of the annotation.
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
+        self
+    }
+
+    /// Sets the color of the border enclosing the annotation `text`.
+    pub fn border_color<C: Color>(mut self, border_color: C) -> Self {
+        self.border_color = Some(Box::new(border_color));
+        self
+    }
+
+    /// Sets the padding (in px) between the `text` and the enclosing border.
+    pub fn border_pad(mut self, border_pad: f64) -> Self {
+        self.border_pad = Some(border_pad);
+        self
+    }
+
+    /// Sets the width (in px) of the border enclosing the annotation `text`.
+    pub fn border_width(mut self, border_width: f64) -> Self {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Determines whether or not the annotation is drawn with an arrow. If "True", `text` is
+    /// placed near the arrow's tail. If "False", `text` lines up with the `x` and `y` provided.
+    pub fn show_arrow(mut self, show_arrow: bool) -> Self {
+        self.show_arrow = Some(show_arrow);
+        self
+    }
+
+    /// Sets the color of the annotation arrow.
+    pub fn arrow_color<C: Color>(mut self, arrow_color: C) -> Self {
+        self.arrow_color = Some(Box::new(arrow_color));
+        self
+    }
+
+    /// Sets the end annotation arrow head style. Integer between or equal to 0 and 8.
+    pub fn arrow_head(mut self, arrow_head: u8) -> Self {
+        self.arrow_head = Some(arrow_head);
+        self
+    }
+
+    /// Sets the start annotation arrow head style. Integer between or equal to 0 and 8.
+    pub fn start_arrow_head(mut self, start_arrow_head: u8) -> Self {
+        self.start_arrow_head = Some(start_arrow_head);
+        self
+    }
+
+    /// Sets the annotation arrow head position.
+    pub fn arrow_side(mut self, arrow_side: ArrowSide) -> Self {
+        self.arrow_side = Some(arrow_side);
+        self
+    }
+
+    /// Sets the size of the end annotation arrow head, relative to `arrowwidth`. A value of 1
+    /// (default) gives a head about 3x as wide as the line.
+    pub fn arrow_size(mut self, arrow_size: f64) -> Self {
+        self.arrow_size = Some(arrow_size);
+        self
+    }
+
+    /// Sets the size of the start annotation arrow head, relative to `arrowwidth`. A value of 1
+    /// (default) gives a head about 3x as wide as the line.
+    pub fn start_arrow_size(mut self, start_arrow_size: f64) -> Self {
+        self.start_arrow_size = Some(start_arrow_size);
+        self
+    }
+
+    /// Sets the width (in px) of annotation arrow line.
+    pub fn arrow_width(mut self, arrow_width: f64) -> Self {
+        self.arrow_width = Some(arrow_width);
+        self
+    }
+
+    /// Sets a distance, in pixels, to move the end arrowhead away from the position it is pointing
+    /// at, for example to point at the edge of a marker independent of zoom. Note that this
+    /// shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift` which
+    /// moves everything by this amount.
+    pub fn stand_off(mut self, stand_off: f64) -> Self {
+        self.stand_off = Some(stand_off);
+        self
+    }
+
+    /// Sets a distance, in pixels, to move the start arrowhead away from the position it is
+    /// pointing at, for example to point at the edge of a marker independent of zoom. Note that
+    /// this shortens the arrow from the `ax` / `ay` vector, in contrast to `xshift` / `yshift`
+    /// which moves everything by this amount.
+    pub fn start_stand_off(mut self, start_stand_off: f64) -> Self {
+        self.start_stand_off = Some(start_stand_off);
+        self
+    }
+
+    /// Sets the x component of the arrow tail about the arrow head. If `axref` is `pixel`, a
+    /// positive (negative) component corresponds to an arrow pointing from right to left (left
+    /// to right). If `axref` is an axis, this is an absolute value on that axis, like `x`, NOT a
+    /// relative value.
+    pub fn ax<V: Into<NumOrString>>(mut self, ax: V) -> Self {
+        self.ax = Some(ax.into());
+        self
+    }
+
+    /// Sets the y component of the arrow tail about the arrow head. If `ayref` is `pixel`, a
+    /// positive (negative) component corresponds to an arrow pointing from bottom to top (top to
+    /// bottom). If `ayref` is an axis, this is an absolute value on that axis, like `y`, NOT a
+    /// relative value.
+    pub fn ay<V: Into<NumOrString>>(mut self, ay: V) -> Self {
+        self.ay = Some(ay.into());
+        self
+    }
+
+    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If `pixel`, `ax`
+    /// is a relative offset in pixels from `x`. If set to an x axis id (e.g. "x" or "x2"), `ax` is
+    /// specified in the same terms as that axis. This is useful for trendline annotations which
+    /// should continue to indicate the correct trend when zoomed.
+    pub fn ax_ref(mut self, ax_ref: &str) -> Self {
+        self.ax_ref = Some(ax_ref.to_owned());
+        self
+    }
+
+    /// Indicates in what terms the tail of the annotation (ax,ay) is specified. If `pixel`, `ay`
+    /// is a relative offset in pixels from `y`. If set to a y axis id (e.g. "y" or "y2"), `ay` is
+    /// specified in the same terms as that axis. This is useful for trendline annotations which
+    /// should continue to indicate the correct trend when zoomed.
+    pub fn ay_ref(mut self, ay_ref: &str) -> Self {
+        self.ay_ref = Some(ay_ref.to_owned());
+        self
+    }
+
+    /// Sets the annotation's x coordinate axis. If set to an x axis id (e.g. "x" or "x2"), the `x`
+    /// position refers to an x coordinate If set to "paper", the `x` position refers to the
+    /// distance from the left side of the plotting area in normalized coordinates where 0 (1)
+    /// corresponds to the left (right) side.
+    pub fn x_ref(mut self, x_ref: &str) -> Self {
+        self.x_ref = Some(x_ref.to_owned());
+        self
+    }
+
+    /// Sets the annotation's x position. If the axis `type` is "log", then you must take the log
+    /// of your desired range. If the axis `type` is "date", it should be date strings, like date
+    /// data, though Date objects and unix milliseconds will be accepted and converted to strings.
+    /// If the axis `type` is "category", it should be numbers, using the scale where each category
+    /// is assigned a serial number from zero in the order it appears.
+    pub fn x<V: Into<NumOrString>>(mut self, x: V) -> Self {
+        self.x = Some(x.into());
+        self
+    }
+
+    /// Sets the text box's horizontal position anchor This anchor binds the `x` position to the
+    /// "left", "center" or "right" of the annotation. For example, if `x` is set to 1, `xref` to
+    /// "paper" and `xanchor` to "right" then the right-most portion of the annotation lines up with
+    /// the right-most edge of the plotting area. If "auto", the anchor is equivalent to "center"
+    /// for data-referenced annotations or if there is an arrow, whereas for paper-referenced with
+    /// no arrow, the anchor picked corresponds to the closest side.
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> Self {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    /// Shifts the position of the whole annotation and arrow to the right (positive) or left
+    /// (negative) by this many pixels.
+    pub fn x_shift(mut self, x_shift: f64) -> Self {
+        self.x_shift = Some(x_shift);
+        self
+    }
+
+    /// Sets the annotation's y coordinate axis. If set to an y axis id (e.g. "y" or "y2"), the `y`
+    /// position refers to an y coordinate If set to "paper", the `y` position refers to the
+    /// distance from the bottom of the plotting area in normalized coordinates where 0 (1)
+    /// corresponds to the bottom (top).
+    pub fn y_ref(mut self, y_ref: &str) -> Self {
+        self.y_ref = Some(y_ref.to_owned());
+        self
+    }
+
+    /// Sets the annotation's y position. If the axis `type` is "log", then you must take the log of
+    /// your desired range. If the axis `type` is "date", it should be date strings, like date data,
+    /// though Date objects and unix milliseconds will be accepted and converted to strings. If the
+    /// axis `type` is "category", it should be numbers, using the scale where each category is
+    /// assigned a serial number from zero in the order it appears.
+    pub fn y<V: Into<NumOrString>>(mut self, y: V) -> Self {
+        self.y = Some(y.into());
+        self
+    }
+
+    /// Sets the text box's vertical position anchor This anchor binds the `y` position to the
+    /// "top", "middle" or "bottom" of the annotation. For example, if `y` is set to 1, `yref` to
+    /// "paper" and `yanchor` to "top" then the top-most portion of the annotation lines up with the
+    /// top-most edge of the plotting area. If "auto", the anchor is equivalent to "middle" for
+    /// data-referenced annotations or if there is an arrow, whereas for paper-referenced with no
+    /// arrow, the anchor picked corresponds to the closest side.
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> Self {
+        self.y_anchor = Some(y_anchor);
+        self
+    }
+
+    /// Shifts the position of the whole annotation and arrow up (positive) or down (negative) by
+    /// this many pixels.
+    pub fn y_shift(mut self, y_shift: f64) -> Self {
+        self.y_shift = Some(y_shift);
+        self
+    }
+
+    /// Sets the annotation's z position. Only used by the annotations of a `LayoutScene`, which
+    /// are positioned by their (x, y, z) data coordinates and ignore `x_ref` and `y_ref`.
+    pub fn z<V: Into<NumOrString>>(mut self, z: V) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
+    /// Makes this annotation respond to clicks on the plot. If you click a data point that exactly
+    /// matches the `x` and `y` values of this annotation, and it is hidden (visible: false), it
+    /// will appear. In "onoff" mode, you must click the same point again to make it disappear, so
+    /// if you click multiple points, you can show multiple annotations. In "onout" mode, a click
+    /// anywhere else in the plot (on another data point or not) will hide this annotation. If you
+    /// need to show/hide this annotation in response to different `x` or `y` values, you can set
+    /// `xclick` and/or `yclick`. This is useful for example to label the side of a bar. To label
+    /// markers though, `standoff` is preferred over `xclick` and `yclick`.
+    pub fn click_to_show(mut self, click_to_show: ClickToShow) -> Self {
+        self.click_to_show = Some(click_to_show);
+        self
+    }
+
+    /// Toggle this annotation when clicking a data point whose `x` value is `xclick` rather than
+    /// the annotation's `x` value.
+    pub fn x_click<V: Into<NumOrString>>(mut self, x_click: V) -> Self {
+        self.x_click = Some(x_click.into());
+        self
+    }
+
+    /// Toggle this annotation when clicking a data point whose `y` value is `yclick` rather than
+    /// the annotation's `y` value.
+    pub fn y_click<V: Into<NumOrString>>(mut self, y_click: V) -> Self {
+        self.y_click = Some(y_click.into());
+        self
+    }
+
+    /// Sets text to appear when hovering over this annotation. If omitted or blank, no hover label
+    /// will appear.
+    pub fn hover_text(mut self, hover_text: &str) -> Self {
+        self.hover_text = Some(hover_text.to_owned());
+        self
+    }
+
+    /// Label displayed on mouse hover.
+    pub fn hover_label(mut self, hover_label: Label) -> Self {
+        self.hover_label = Some(hover_label);
+        self
+    }
+
+    /// Determines whether the annotation text box captures mouse move and click events, or allows
+    /// those events to pass through to data points in the plot that may be behind the annotation.
+    /// By default `captureevents` is "false" unless `hovertext` is provided. If you use the event
+    /// `plotly_clickannotation` without `hovertext` you must explicitly enable `captureevents`.
+    pub fn capture_events(mut self, capture_events: bool) -> Self {
+        self.capture_events = Some(capture_events);
+        self
+    }
+
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output figure
+    /// by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one by
+    /// making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with `visible: true`.
+    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
+        self.template_item_name = Some(template_item_name.to_owned());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ButtonMethod {
+    Restyle,
+    Relayout,
+    Animate,
+    Update,
+    Skip,
+}
+
+/// A button of an `UpdateMenu`. Clicking it calls the Plotly.js function given by its `method`
+/// with its `args`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::layout::{Button, ButtonMethod};
+/// use serde_json::json;
+///
+/// // Show the first trace and hide the second one.
+/// let button = Button::new()
+///     .label("First")
+///     .method(ButtonMethod::Restyle)
+///     .args(json!([{"visible": [true, false]}]));
+///
+/// let expected = json!({
+///     "label": "First",
+///     "method": "restyle",
+///     "args": [{"visible": [true, false]}]
+/// });
+///
+/// assert_eq!(serde_json::to_value(button).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Button {
+    visible: Option<bool>,
+    label: Option<String>,
+    method: Option<ButtonMethod>,
+    args: Option<serde_json::Value>,
+    args2: Option<serde_json::Value>,
+    execute: Option<bool>,
+    name: Option<String>,
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Button {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines whether or not this button is visible.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Sets the text label to appear on the button.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the Plotly method to be called on click. If the `ButtonMethod::Skip` method is used,
+    /// the API updatemenu will function as normal but will perform no API calls and will not bind
+    /// automatically to state updates. This may be used to create a component interface and
+    /// attach to updatemenu events manually via JavaScript.
+    pub fn method(mut self, method: ButtonMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Sets the arguments values to be passed to the Plotly method set in `method` on click. For
+    /// example, `json!([{"visible": [true, false]}])` for `ButtonMethod::Restyle`, or
+    /// `json!([null, {"frame": {"duration": 500}}])` for `ButtonMethod::Animate`.
+    pub fn args<V: Into<serde_json::Value>>(mut self, args: V) -> Self {
+        self.args = Some(args.into());
+        self
+    }
+
+    /// Sets a 2nd set of `args`, these arguments values are passed to the Plotly method set in
+    /// `method` when clicking this button while in the active state. Use this to create toggle
+    /// buttons.
+    pub fn args2<V: Into<serde_json::Value>>(mut self, args2: V) -> Self {
+        self.args2 = Some(args2.into());
+        self
+    }
+
+    /// When true, the API method is executed. When false, all other behaviors are the same and
+    /// command execution is skipped. This may be useful when hooking into, for example, the
+    /// `plotly_buttonclicked` method and executing the API command manually without losing the
+    /// benefit of the updatemenu automatically binding to the state of the plot through the
+    /// specification of `method` and `args`.
+    pub fn execute(mut self, execute: bool) -> Self {
+        self.execute = Some(execute);
+        self
+    }
+
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output
+    /// figure by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one
+    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with
+    /// `visible: true`.
+    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
+        self.template_item_name = Some(template_item_name.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateMenuType {
+    Dropdown,
+    Buttons,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateMenuDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// A dropdown menu or a group of buttons, which update the plot when clicked. See `Button`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct UpdateMenu {
+    visible: Option<bool>,
+    r#type: Option<UpdateMenuType>,
+    direction: Option<UpdateMenuDirection>,
+    active: Option<i32>,
+    #[serde(rename = "showactive")]
+    show_active: Option<bool>,
+    buttons: Option<Vec<Button>>,
+    x: Option<f64>,
+    #[serde(rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    y: Option<f64>,
+    #[serde(rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    pad: Option<Pad>,
+    font: Option<Font>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "bordercolor")]
+    border_color: Option<Box<dyn Color>>,
+    #[serde(rename = "borderwidth")]
+    border_width: Option<Number>,
+    name: Option<String>,
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl UpdateMenu {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines whether or not the update menu is visible.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Determines whether the buttons are accessible via a dropdown menu or whether the buttons
+    /// are stacked horizontally or vertically.
+    pub fn type_(mut self, t: UpdateMenuType) -> Self {
+        self.r#type = Some(t);
+        self
+    }
+
+    /// Determines the direction in which the buttons are laid out, whether in a dropdown menu or
+    /// a row/column of buttons. For `UpdateMenuDirection::Left` and `UpdateMenuDirection::Up`,
+    /// the buttons will still appear in left-to-right or top-to-bottom order respectively.
+    pub fn direction(mut self, direction: UpdateMenuDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Determines which button (by index starting from 0) is considered active.
+    pub fn active(mut self, active: i32) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Highlights active dropdown item or active button if true.
+    pub fn show_active(mut self, show_active: bool) -> Self {
+        self.show_active = Some(show_active);
+        self
+    }
+
+    pub fn buttons(mut self, buttons: Vec<Button>) -> Self {
+        self.buttons = Some(buttons);
+        self
+    }
+
+    /// Sets the x position (in normalized coordinates) of the update menu.
+    pub fn x(mut self, x: f64) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Sets the update menu's horizontal position anchor. This anchor binds the `x` position to
+    /// the "left", "center" or "right" of the update menu.
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> Self {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    /// Sets the y position (in normalized coordinates) of the update menu.
+    pub fn y(mut self, y: f64) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Sets the update menu's vertical position anchor. This anchor binds the `y` position to the
+    /// "top", "middle" or "bottom" of the update menu.
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> Self {
+        self.y_anchor = Some(y_anchor);
+        self
+    }
+
+    /// Sets the padding around the buttons or dropdown menu.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = Some(pad);
+        self
+    }
+
+    /// Sets the font of the update menu button text.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the background color of the update menu buttons.
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
+        self
+    }
+
+    /// Sets the color of the border enclosing the update menu.
+    pub fn border_color<C: Color>(mut self, border_color: C) -> Self {
+        self.border_color = Some(Box::new(border_color));
+        self
+    }
+
+    /// Sets the width (in px) of the border enclosing the update menu.
+    pub fn border_width(mut self, border_width: usize) -> Self {
+        self.border_width = Some(border_width.into());
+        self
+    }
+
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output
+    /// figure by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one
+    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with
+    /// `visible: true`.
+    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
+        self.template_item_name = Some(template_item_name.to_string());
+        self
+    }
+}
+
+/// A step of a `Slider`. Moving the slider to this step calls the Plotly.js function given by its
+/// `method` with its `args`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::layout::{ButtonMethod, SliderStep};
+/// use serde_json::json;
+///
+/// // Show the second trace and hide the others.
+/// let step = SliderStep::new()
+///     .label("t = 1")
+///     .method(ButtonMethod::Restyle)
+///     .args(json!([{"visible": [false, true, false]}]));
+///
+/// let expected = json!({
+///     "label": "t = 1",
+///     "method": "restyle",
+///     "args": [{"visible": [false, true, false]}]
+/// });
+///
+/// assert_eq!(serde_json::to_value(step).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SliderStep {
+    visible: Option<bool>,
+    method: Option<ButtonMethod>,
+    args: Option<serde_json::Value>,
+    label: Option<String>,
+    value: Option<String>,
+    execute: Option<bool>,
+    name: Option<String>,
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl SliderStep {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines whether or not this step is included in the slider.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Sets the Plotly method to be called when the slider value is changed. If the
+    /// `ButtonMethod::Skip` method is used, the API slider will function as normal but will
+    /// perform no API calls and will not bind automatically to state updates. This may be used to
+    /// create a component interface and attach to slider events manually via JavaScript.
+    pub fn method(mut self, method: ButtonMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Sets the arguments values to be passed to the Plotly method set in `method` on slide. For
+    /// example, `json!([{"visible": [false, true]}])` for `ButtonMethod::Restyle`, or
+    /// `json!([["frame-1"], {"mode": "immediate"}])` for `ButtonMethod::Animate`.
+    pub fn args<V: Into<serde_json::Value>>(mut self, args: V) -> Self {
+        self.args = Some(args.into());
+        self
+    }
+
+    /// Sets the text label to appear on the slider.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the value of the slider step, used to refer to the step programatically. Defaults to
+    /// the slider label if not provided.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// When true, the API method is executed. When false, all other behaviors are the same and
+    /// command execution is skipped. This may be useful when hooking into, for example, the
+    /// `plotly_sliderchange` method and executing the API command manually without losing the
+    /// benefit of the slider automatically binding to the state of the plot through the
+    /// specification of `method` and `args`.
+    pub fn execute(mut self, execute: bool) -> Self {
+        self.execute = Some(execute);
+        self
+    }
+
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output
+    /// figure by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one
+    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with
+    /// `visible: true`.
+    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
+        self.template_item_name = Some(template_item_name.to_string());
+        self
+    }
+}
+
+/// The label showing the value of the currently selected `SliderStep`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SliderCurrentValue {
+    visible: Option<bool>,
+    #[serde(rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    offset: Option<Number>,
+    prefix: Option<String>,
+    suffix: Option<String>,
+    font: Option<Font>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl SliderCurrentValue {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Shows the currently-selected value above the slider.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// The alignment of the value readout relative to the length of the slider.
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> Self {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    /// The amount of space, in pixels, between the current value label and the slider.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset.into());
+        self
+    }
+
+    /// When currentvalue.visible is true, this sets the prefix of the label.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// When currentvalue.visible is true, this sets the suffix of the label.
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        self.suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Sets the font of the current value label text.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+}
+
+/// The transition used when the slider handle moves between steps.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SliderTransition {
+    duration: Option<usize>,
+    easing: Option<TransitionEasing>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl SliderTransition {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the duration of the slider transition, in milliseconds.
+    pub fn duration(mut self, duration: usize) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the easing function of the slider transition.
+    pub fn easing(mut self, easing: TransitionEasing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+}
+
+/// A slider, whose steps update the plot when selected. See `SliderStep`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::layout::{ButtonMethod, Layout, Slider, SliderStep};
+/// use serde_json::json;
+///
+/// // One step per trace, each showing only that trace.
+/// let steps = (0..3)
+///     .map(|i| {
+///         let visible: Vec<bool> = (0..3).map(|j| i == j).collect();
+///         SliderStep::new()
+///             .label(&format!("t = {}", i))
+///             .method(ButtonMethod::Restyle)
+///             .args(json!([{"visible": visible}]))
+///     })
+///     .collect();
+///
+/// let layout = Layout::new().sliders(vec![Slider::new().active(0).steps(steps)]);
+///
+/// let expected = json!({
+///     "sliders": [{
+///         "active": 0,
+///         "steps": [
+///             {"label": "t = 0", "method": "restyle", "args": [{"visible": [true, false, false]}]},
+///             {"label": "t = 1", "method": "restyle", "args": [{"visible": [false, true, false]}]},
+///             {"label": "t = 2", "method": "restyle", "args": [{"visible": [false, false, true]}]}
+///         ]
+///     }]
+/// });
+///
+/// assert_eq!(serde_json::to_value(layout).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Slider {
+    visible: Option<bool>,
+    active: Option<usize>,
+    steps: Option<Vec<SliderStep>>,
+    #[serde(rename = "lenmode")]
+    len_mode: Option<ThicknessMode>,
+    len: Option<f64>,
+    x: Option<f64>,
+    #[serde(rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    y: Option<f64>,
+    #[serde(rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    pad: Option<Pad>,
+    transition: Option<SliderTransition>,
+    #[serde(rename = "currentvalue")]
+    current_value: Option<SliderCurrentValue>,
+    font: Option<Font>,
+    #[serde(rename = "activebgcolor")]
+    active_background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "bgcolor")]
+    background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "bordercolor")]
+    border_color: Option<Box<dyn Color>>,
+    #[serde(rename = "borderwidth")]
+    border_width: Option<Number>,
+    #[serde(rename = "ticklen")]
+    tick_length: Option<Number>,
+    #[serde(rename = "tickcolor")]
+    tick_color: Option<Box<dyn Color>>,
+    #[serde(rename = "tickwidth")]
+    tick_width: Option<Number>,
+    #[serde(rename = "minorticklen")]
+    minor_tick_length: Option<Number>,
+    name: Option<String>,
+    #[serde(rename = "templateitemname")]
+    template_item_name: Option<String>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Slider {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines whether or not the slider is visible.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Determines which step (by index starting from 0) is considered active.
+    pub fn active(mut self, active: usize) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn steps(mut self, steps: Vec<SliderStep>) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Determines whether this slider length is set in units of plot "fraction" or in "pixels".
+    /// Use `len` to set the value.
+    pub fn len_mode(mut self, len_mode: ThicknessMode) -> Self {
+        self.len_mode = Some(len_mode);
+        self
+    }
+
+    /// Sets the length of the slider. This measure excludes the padding of both ends. That is, the
+    /// slider's length is this length minus the padding on both ends.
+    pub fn len(mut self, len: f64) -> Self {
+        self.len = Some(len);
+        self
+    }
+
+    /// Sets the x position (in normalized coordinates) of the slider.
+    pub fn x(mut self, x: f64) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Sets the slider's horizontal position anchor. This anchor binds the `x` position to the
+    /// "left", "center" or "right" of the slider.
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> Self {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    /// Sets the y position (in normalized coordinates) of the slider.
+    pub fn y(mut self, y: f64) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Sets the slider's vertical position anchor. This anchor binds the `y` position to the
+    /// "top", "middle" or "bottom" of the slider.
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> Self {
+        self.y_anchor = Some(y_anchor);
+        self
+    }
+
+    /// Set the padding of the slider component along each side.
+    pub fn pad(mut self, pad: Pad) -> Self {
+        self.pad = Some(pad);
+        self
+    }
+
+    pub fn transition(mut self, transition: SliderTransition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    pub fn current_value(mut self, current_value: SliderCurrentValue) -> Self {
+        self.current_value = Some(current_value);
+        self
+    }
+
+    /// Sets the font of the slider step labels.
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the background color of the slider grip while dragging.
+    pub fn active_background_color<C: Color>(mut self, active_background_color: C) -> Self {
+        self.active_background_color = Some(Box::new(active_background_color));
+        self
+    }
+
+    /// Sets the background color of the slider.
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Self {
+        self.background_color = Some(Box::new(background_color));
+        self
+    }
+
+    /// Sets the color of the border enclosing the slider.
+    pub fn border_color<C: Color>(mut self, border_color: C) -> Self {
+        self.border_color = Some(Box::new(border_color));
+        self
+    }
+
+    /// Sets the width (in px) of the border enclosing the slider.
+    pub fn border_width(mut self, border_width: usize) -> Self {
+        self.border_width = Some(border_width.into());
+        self
+    }
+
+    /// Sets the length in pixels of step tick marks.
+    pub fn tick_length(mut self, tick_length: usize) -> Self {
+        self.tick_length = Some(tick_length.into());
+        self
+    }
+
+    /// Sets the color of the slider tick marks.
+    pub fn tick_color<C: Color>(mut self, tick_color: C) -> Self {
+        self.tick_color = Some(Box::new(tick_color));
+        self
+    }
+
+    /// Sets the tick width (in px).
+    pub fn tick_width(mut self, tick_width: usize) -> Self {
+        self.tick_width = Some(tick_width.into());
+        self
+    }
+
+    /// Sets the length in pixels of minor step tick marks.
+    pub fn minor_tick_length(mut self, minor_tick_length: usize) -> Self {
+        self.minor_tick_length = Some(minor_tick_length.into());
+        self
+    }
+
+    /// When used in a template, named items are created in the output figure in addition to any
+    /// items the figure already has in this array. You can modify these items in the output
+    /// figure by making your own item with `templateitemname` matching this `name` alongside your
+    /// modifications (including `visible: false` or `enabled: false` to hide it). Has no effect
+    /// outside of a template.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Used to refer to a named item in this array in the template. Named items from the template
+    /// will be created even without a matching item in the input figure, but you can modify one
+    /// by making an item with `templateitemname` matching its `name`, alongside your modifications
+    /// (including `visible: false` or `enabled: false` to hide it). If there is no template or no
+    /// matching item, this item will be hidden unless you explicitly show it with
+    /// `visible: true`.
+    pub fn template_item_name(mut self, template_item_name: &str) -> Self {
+        self.template_item_name = Some(template_item_name.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ClickMode {
+    Event,
+    Select,
+    #[serde(rename = "event+select")]
+    EventAndSelect,
+    None,
+}
+
+#[derive(Debug, Clone)]
+pub enum DragMode {
+    Zoom,
+    Pan,
+    Select,
+    Lasso,
+    DrawClosedPath,
+    DrawOpenPath,
+    DrawLine,
+    DrawRect,
+    DrawCircle,
+    Orbit,
+    Turntable,
+    False,
+}
+
+impl Serialize for DragMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            Self::Zoom => serializer.serialize_str("zoom"),
+            Self::Pan => serializer.serialize_str("pan"),
+            Self::Select => serializer.serialize_str("select"),
+            Self::Lasso => serializer.serialize_str("lasso"),
+            Self::DrawClosedPath => serializer.serialize_str("drawclosedpath"),
+            Self::DrawOpenPath => serializer.serialize_str("drawopenpath"),
+            Self::DrawLine => serializer.serialize_str("drawline"),
+            Self::DrawRect => serializer.serialize_str("drawrect"),
+            Self::DrawCircle => serializer.serialize_str("drawcircle"),
+            Self::Orbit => serializer.serialize_str("orbit"),
+            Self::Turntable => serializer.serialize_str("turntable"),
+            Self::False => serializer.serialize_bool(false),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DragMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "zoom" => Ok(Self::Zoom),
+                "pan" => Ok(Self::Pan),
+                "select" => Ok(Self::Select),
+                "lasso" => Ok(Self::Lasso),
+                "drawclosedpath" => Ok(Self::DrawClosedPath),
+                "drawopenpath" => Ok(Self::DrawOpenPath),
+                "drawline" => Ok(Self::DrawLine),
+                "drawrect" => Ok(Self::DrawRect),
+                "drawcircle" => Ok(Self::DrawCircle),
+                "orbit" => Ok(Self::Orbit),
+                "turntable" => Ok(Self::Turntable),
+                _ => Err(de::Error::unknown_variant(
+                    &s,
+                    &[
+                        "zoom",
+                        "pan",
+                        "select",
+                        "lasso",
+                        "drawclosedpath",
+                        "drawopenpath",
+                        "drawline",
+                        "drawrect",
+                        "drawcircle",
+                        "orbit",
+                        "turntable",
+                    ],
+                )),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum SelectDirection {
+    #[serde(rename = "h")]
+    Horizontal,
+    #[serde(rename = "v")]
+    Vertical,
+    #[serde(rename = "d")]
+    Diagonal,
+    Any,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum TransitionEasing {
+    Linear,
+    Quad,
+    Cubic,
+    Sin,
+    Exp,
+    Circle,
+    Elastic,
+    Back,
+    Bounce,
+    #[serde(rename = "linear-in")]
+    LinearIn,
+    #[serde(rename = "quad-in")]
+    QuadIn,
+    #[serde(rename = "cubic-in")]
+    CubicIn,
+    #[serde(rename = "sin-in")]
+    SinIn,
+    #[serde(rename = "exp-in")]
+    ExpIn,
+    #[serde(rename = "circle-in")]
+    CircleIn,
+    #[serde(rename = "elastic-in")]
+    ElasticIn,
+    #[serde(rename = "back-in")]
+    BackIn,
+    #[serde(rename = "bounce-in")]
+    BounceIn,
+    #[serde(rename = "linear-out")]
+    LinearOut,
+    #[serde(rename = "quad-out")]
+    QuadOut,
+    #[serde(rename = "cubic-out")]
+    CubicOut,
+    #[serde(rename = "sin-out")]
+    SinOut,
+    #[serde(rename = "exp-out")]
+    ExpOut,
+    #[serde(rename = "circle-out")]
+    CircleOut,
+    #[serde(rename = "elastic-out")]
+    ElasticOut,
+    #[serde(rename = "back-out")]
+    BackOut,
+    #[serde(rename = "bounce-out")]
+    BounceOut,
+    #[serde(rename = "linear-in-out")]
+    LinearInOut,
+    #[serde(rename = "quad-in-out")]
+    QuadInOut,
+    #[serde(rename = "cubic-in-out")]
+    CubicInOut,
+    #[serde(rename = "sin-in-out")]
+    SinInOut,
+    #[serde(rename = "exp-in-out")]
+    ExpInOut,
+    #[serde(rename = "circle-in-out")]
+    CircleInOut,
+    #[serde(rename = "elastic-in-out")]
+    ElasticInOut,
+    #[serde(rename = "back-in-out")]
+    BackInOut,
+    #[serde(rename = "bounce-in-out")]
+    BounceInOut,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum TransitionOrdering {
+    #[serde(rename = "layout first")]
+    LayoutFirst,
+    #[serde(rename = "traces first")]
+    TracesFirst,
+}
+
+/// Sets the transition between two states of a plot, used when animating from one `Frame` to the
+/// next or when the plot is updated with `Plotly.react`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Transition {
+    duration: Option<usize>,
+    easing: Option<TransitionEasing>,
+    ordering: Option<TransitionOrdering>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Transition {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The duration of the transition, in milliseconds. If equal to zero, updates are
+    /// synchronous.
+    pub fn duration(mut self, duration: usize) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// The easing function used for the transition.
+    pub fn easing(mut self, easing: TransitionEasing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Determines whether the figure's layout or traces smoothly transitions during updates that
+    /// make both traces and layout change.
+    pub fn ordering(mut self, ordering: TransitionOrdering) -> Self {
+        self.ordering = Some(ordering);
+        self
+    }
+}
+
+/// A template holding default attributes for the layout, as well as for the traces of each type.
+/// Attributes which are set on the layout or on a trace itself take precedence.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{
+///     common::Marker,
+///     layout::{LayoutTemplate, Template, TemplateData},
+///     Bar, Layout,
+/// };
+///
+/// let template = Template::new()
+///     .layout(LayoutTemplate::new().bar_gap(0.1))
+///     .data(TemplateData::new().trace(Bar::<f64, f64>::default().marker(Marker::new().opacity(0.8))));
+/// let layout = Layout::new().template(template);
+///
+/// let expected = serde_json::json!({
+///     "template": {
+///         "layout": {"bargap": 0.1},
+///         "data": {"bar": [{"type": "bar", "marker": {"opacity": 0.8}}]}
+///     }
+/// });
+///
+/// assert_eq!(serde_json::to_value(layout).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Template {
+    layout: Option<LayoutTemplate>,
+    data: Option<TemplateData>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Template {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Read a template from JSON, such as the templates of plotly.py (as written by
+    /// `plotly.io.templates["..."].to_plotly_json()`) or `plotly/templates/template.json`.
+    /// Attributes which this crate does not know about are kept as they are.
+    pub fn from_json(json: &str) -> Result<Template, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn layout(mut self, layout: LayoutTemplate) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Sets the default attributes of the traces of each type.
+    pub fn data(mut self, data: TemplateData) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Overlays `other` on this template, as plotly.py does for themes joined with a `+` (e.g.
+    /// `"simple_white+presentation"`). Attributes set by `other` take precedence, all others are
+    /// kept. The default attributes of each trace type are overlaid trace by trace, cycling the
+    /// shorter list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use plotly::layout::{themes::SIMPLE_WHITE, LayoutTemplate, Template};
+    /// use plotly::Layout;
+    ///
+    /// let company = Template::new().layout(LayoutTemplate::new().colorway(vec!["#003366"]));
+    /// let layout = Layout::new().template(SIMPLE_WHITE.clone().merge(&company));
+    /// ```
+    pub fn merge(mut self, other: &Template) -> Template {
+        let data = match (self.data.take(), &other.data) {
+            (Some(mut data), Some(other)) => {
+                data.merge(other);
+                Some(data)
+            }
+            (data, other) => data.or_else(|| other.clone()),
+        };
+
+        let mut template = serde_json::to_value(self).unwrap();
+        let mut other = serde_json::to_value(other).unwrap();
+        if let Value::Object(other) = &mut other {
+            other.remove("data");
+        }
+        merge_json(&mut template, other);
+
+        let mut template: Template =
+            serde_json::from_value(template).expect("merged templates are valid templates");
+        template.data = data;
+        template
+    }
+}
+
+/// The default attributes of the traces of each type, keyed by trace type. When several traces of
+/// the same type are given, they are cycled through for the traces of the plot, in order.
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(transparent)]
+pub struct TemplateData {
+    traces: BTreeMap<String, Vec<Value>>,
+}
+
+impl TemplateData {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds the default attributes for traces of the same type as `trace`, e.g. a `Bar` holding
+    /// the defaults of every bar trace. Any data set on `trace` is ignored by Plotly.js.
+    pub fn trace(mut self, trace: Box<dyn Trace>) -> Self {
+        let trace = serde_json::to_value(trace).unwrap();
+        let plot_type = trace["type"].as_str().unwrap_or("scatter").to_string();
+        self.traces.entry(plot_type).or_default().push(trace);
+        self
+    }
+
+    fn merge(&mut self, other: &TemplateData) {
+        for (plot_type, other) in &other.traces {
+            let traces = self.traces.entry(plot_type.clone()).or_default();
+            if traces.is_empty() || other.is_empty() {
+                traces.extend(other.iter().cloned());
+                continue;
+            }
+
+            // Cycle both lists up to their least common multiple, so that every combination of
+            // base and overlay trace appears, as plotly.py does.
+            let (mut a, mut b) = (traces.len(), other.len());
+            while b != 0 {
+                let r = a % b;
+                a = b;
+                b = r;
+            }
+            let len = traces.len() / a * other.len();
+
+            *traces = traces.iter().cycle().take(len).cloned().collect();
+            for (trace, overlay) in traces.iter_mut().zip(other.iter().cycle()) {
+                merge_json(trace, overlay.clone());
+            }
+        }
+    }
+}
+
+/// Recursively overlays `overlay` on `base`: objects are merged key by key, any other value in
+/// `overlay` replaces the one in `base`.
+fn merge_json(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(base) => merge_json(base, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Cow<'static, Template>> for Template {
+    fn into(self) -> Cow<'static, Template> {
+        Cow::Owned(self)
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<Cow<'static, Template>> for &'static Template {
+    fn into(self) -> Cow<'static, Template> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl Serialize for LayoutTemplate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.unread.is_empty() {
+            return LayoutTemplate::serialize(self, serializer);
+        }
+        let layout = LayoutTemplate::serialize(self, serde_json::value::Serializer);
+        serialize_with_unread(layout, &self.unread, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LayoutTemplate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (mut layout, unread) =
+            read_leniently(Extra::deserialize(deserializer)?, |attributes| {
+                LayoutTemplate::deserialize(attributes)
+            })
+            .map_err(de::Error::custom)?;
+        layout.unread = unread;
+        Ok(layout)
+    }
+}
+
+// LayoutTemplate matches Layout except it lacks a field for template
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(remote = "Self")]
+pub struct LayoutTemplate {
+    title: Option<Title>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    legend: Option<Legend>,
+    margin: Option<Margin>,
+    #[serde(rename = "autosize")]
+    auto_size: Option<bool>,
+    width: Option<usize>,
+    height: Option<usize>,
+    font: Option<Font>,
+    #[serde(rename = "uniformtext")]
+    uniform_text: Option<UniformText>,
+    separators: Option<String>,
+    #[serde(rename = "paper_bgcolor")]
+    paper_background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "plot_bgcolor")]
+    plot_background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<LayoutColorScale>,
+    colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<ColorAxis>,
+    #[serde(rename = "modebar")]
+    mode_bar: Option<ModeBar>,
+    #[serde(rename = "hovermode")]
+    hover_mode: Option<HoverMode>,
+    #[serde(rename = "clickmode")]
+    click_mode: Option<ClickMode>,
+    #[serde(rename = "dragmode")]
+    drag_mode: Option<DragMode>,
+    #[serde(rename = "selectdirection")]
+    select_direction: Option<SelectDirection>,
+    #[serde(rename = "hoverdistance")]
+    hover_distance: Option<i32>,
+    #[serde(rename = "spikedistance")]
+    spike_distance: Option<i32>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    transition: Option<Transition>,
+
+    grid: Option<LayoutGrid>,
+    calendar: Option<Calendar>,
+
+    #[serde(flatten)]
+    subplots: LayoutSubplots,
+
+    ternary: Option<Box<LayoutTernary>>,
+    mapbox: Option<LayoutMapbox>,
+    annotations: Option<Vec<Annotation>>,
+    shapes: Option<Vec<Shape>>,
+    #[serde(rename = "newshape")]
+    new_shape: Option<NewShape>,
+    #[serde(rename = "activeshape")]
+    active_shape: Option<ActiveShape>,
+    #[serde(rename = "updatemenus")]
+    update_menus: Option<Vec<UpdateMenu>>,
+    sliders: Option<Vec<Slider>>,
+
+    #[serde(rename = "boxmode")]
+    box_mode: Option<BoxMode>,
+    #[serde(rename = "boxgap")]
+    box_gap: Option<f64>,
+    #[serde(rename = "boxgroupgap")]
+    box_group_gap: Option<f64>,
+
+    #[serde(rename = "barmode")]
+    bar_mode: Option<BarMode>,
+    #[serde(rename = "barnorm")]
+    bar_norm: Option<BarNorm>,
+    #[serde(rename = "bargap")]
+    bar_gap: Option<f64>,
+    #[serde(rename = "bargroupgap")]
+    bar_group_gap: Option<f64>,
+
+    #[serde(rename = "violinmode")]
+    violin_mode: Option<ViolinMode>,
+    #[serde(rename = "violingap")]
+    violin_gap: Option<f64>,
+    #[serde(rename = "violingroupgap")]
+    violin_group_gap: Option<f64>,
+
+    #[serde(rename = "waterfallmode")]
+    waterfall_mode: Option<WaterfallMode>,
+    #[serde(rename = "waterfallgap")]
+    waterfall_gap: Option<f64>,
+    #[serde(rename = "waterfallgroupgap")]
+    waterfall_group_gap: Option<f64>,
+
+    #[serde(rename = "piecolorway")]
+    pie_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendpiecolors")]
+    extend_pie_colors: Option<bool>,
+
+    #[serde(rename = "sunburstcolorway")]
+    sunburst_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendsunburstcolors")]
+    extend_sunburst_colors: Option<bool>,
+
+    #[serde(rename = "treemapcolorway")]
+    treemap_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendtreemapcolors")]
+    extend_treemap_colors: Option<bool>,
+
+    #[serde(rename = "iciclecolorway")]
+    icicle_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendiciclecolors")]
+    extend_icicle_colors: Option<bool>,
+
+    #[serde(skip)]
+    unread: BTreeMap<String, UnreadAttribute>,
+}
+
+impl LayoutTemplate {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn title(mut self, title: Title) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = Some(show_legend);
+        self
+    }
+
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend = Some(legend);
+        self
+    }
+
+    pub fn margin(mut self, margin: Margin) -> Self {
+        self.margin = Some(margin);
+        self
+    }
+
+    pub fn auto_size(mut self, auto_size: bool) -> Self {
+        self.auto_size = Some(auto_size);
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn uniform_text(mut self, uniform_text: UniformText) -> Self {
+        self.uniform_text = Some(uniform_text);
+        self
+    }
+
+    pub fn separators(mut self, separators: &str) -> Self {
+        self.separators = Some(separators.to_owned());
+        self
+    }
+
+    pub fn paper_background_color<C: Color>(mut self, paper_background_color: C) -> Self {
+        self.paper_background_color = Some(Box::new(paper_background_color));
+        self
+    }
+
+    pub fn plot_background_color<C: Color>(mut self, plot_background_color: C) -> Self {
+        self.plot_background_color = Some(Box::new(plot_background_color));
+        self
+    }
+
+    pub fn color_scale(mut self, color_scale: LayoutColorScale) -> Self {
+        self.color_scale = Some(color_scale);
+        self
+    }
+
+    pub fn colorway<C: Color>(mut self, colorway: Vec<C>) -> Self {
+        self.colorway = Some(ColorArray(colorway).into());
+        self
+    }
+
+    pub fn color_axis(mut self, color_axis: ColorAxis) -> Self {
+        self.color_axis = Some(color_axis);
+        self
+    }
+
+    pub fn mode_bar(mut self, mode_bar: ModeBar) -> Self {
+        self.mode_bar = Some(mode_bar);
+        self
+    }
+
+    /// Determines the mode of hover interactions. If "closest", a single hoverlabel will appear for the "closest"
+    /// point within the `hoverdistance`. If "x" (or "y"), multiple hoverlabels will appear for multiple points at
+    /// the "closest" x- (or y-) coordinate within the `hoverdistance`, with the caveat that no more than one hoverlabel
+    /// will appear per trace. If "x unified" (or "y unified"), a single hoverlabel will appear multiple points at
+    /// the closest x- (or y-) coordinate within the `hoverdistance` with the caveat that no more than one hoverlabel
+    /// will appear per trace. In this mode, spikelines are enabled by default perpendicular to the specified axis.
+    /// If false, hover interactions are disabled. If `clickmode` includes the "select" flag, `hovermode` defaults to
+    /// "closest". If `clickmode` lacks the "select" flag, it defaults to "x" or "y"
+    /// (depending on the trace's `orientation` value) for plots based on cartesian coordinates. For anything
+    /// else the default value is "closest".
+    pub fn hover_mode(mut self, hover_mode: HoverMode) -> Self {
+        self.hover_mode = Some(hover_mode);
+        self
+    }
+
+    pub fn click_mode(mut self, click_mode: ClickMode) -> Self {
+        self.click_mode = Some(click_mode);
+        self
+    }
+
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = Some(drag_mode);
+        self
+    }
+
+    pub fn select_direction(mut self, select_direction: SelectDirection) -> Self {
+        self.select_direction = Some(select_direction);
+        self
+    }
+
+    pub fn hover_distance(mut self, hover_distance: i32) -> Self {
+        self.hover_distance = Some(hover_distance);
+        self
+    }
+
+    pub fn spike_distance(mut self, spike_distance: i32) -> Self {
+        self.spike_distance = Some(spike_distance);
+        self
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Self {
+        self.hover_label = Some(hover_label);
+        self
+    }
+
+    /// Sets the transition used when the plot is animated from one `Frame` to the next.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    pub fn grid(mut self, grid: LayoutGrid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    pub fn calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    pub fn x_axis(self, xaxis: Axis) -> Self {
+        self.x_axis_n(1, xaxis)
+    }
+
+    pub fn y_axis(self, yaxis: Axis) -> Self {
+        self.y_axis_n(1, yaxis)
+    }
+
+    pub fn z_axis(self, zaxis: Axis) -> Self {
+        self.z_axis_n(1, zaxis)
+    }
+
+    /// Sets the `n`th x axis, counted from 1, which traces refer to with `XAxisRef::new(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn x_axis_n(mut self, n: usize, xaxis: Axis) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        self.subplots.x.insert(n, Box::new(xaxis));
+        self
+    }
+
+    /// Sets the `n`th y axis, counted from 1, which traces refer to with `YAxisRef::new(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn y_axis_n(mut self, n: usize, yaxis: Axis) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        self.subplots.y.insert(n, Box::new(yaxis));
+        self
+    }
+
+    /// Sets the `n`th z axis, counted from 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn z_axis_n(mut self, n: usize, zaxis: Axis) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        self.subplots.z.insert(n, Box::new(zaxis));
+        self
+    }
+
+    pub fn x_axis2(self, xaxis: Axis) -> Self {
+        self.x_axis_n(2, xaxis)
+    }
+
+    pub fn y_axis2(self, yaxis: Axis) -> Self {
+        self.y_axis_n(2, yaxis)
+    }
+
+    pub fn x_axis3(self, xaxis: Axis) -> Self {
+        self.x_axis_n(3, xaxis)
+    }
+
+    pub fn y_axis3(self, yaxis: Axis) -> Self {
+        self.y_axis_n(3, yaxis)
+    }
+
+    pub fn x_axis4(self, xaxis: Axis) -> Self {
+        self.x_axis_n(4, xaxis)
+    }
+
+    pub fn y_axis4(self, yaxis: Axis) -> Self {
+        self.y_axis_n(4, yaxis)
+    }
+
+    pub fn x_axis5(self, xaxis: Axis) -> Self {
+        self.x_axis_n(5, xaxis)
+    }
+
+    pub fn y_axis5(self, yaxis: Axis) -> Self {
+        self.y_axis_n(5, yaxis)
+    }
+
+    pub fn x_axis6(self, xaxis: Axis) -> Self {
+        self.x_axis_n(6, xaxis)
+    }
+
+    pub fn y_axis6(self, yaxis: Axis) -> Self {
+        self.y_axis_n(6, yaxis)
+    }
+
+    pub fn x_axis7(self, xaxis: Axis) -> Self {
+        self.x_axis_n(7, xaxis)
+    }
+
+    pub fn y_axis7(self, yaxis: Axis) -> Self {
+        self.y_axis_n(7, yaxis)
+    }
+
+    pub fn x_axis8(self, xaxis: Axis) -> Self {
+        self.x_axis_n(8, xaxis)
+    }
+
+    pub fn y_axis8(self, yaxis: Axis) -> Self {
+        self.y_axis_n(8, yaxis)
+    }
+
+    pub fn ternary(mut self, ternary: LayoutTernary) -> Self {
+        self.ternary = Some(Box::new(ternary));
+        self
+    }
+
+    pub fn scene(self, scene: LayoutScene) -> Self {
+        self.scene_n(1, scene)
+    }
+
+    /// Sets the `n`th 3D scene, counted from 1, which traces refer to with `.scene("sceneN")`, or
+    /// `.scene("scene")` for the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn scene_n(mut self, n: usize, scene: LayoutScene) -> Self {
+        assert!(n > 0, "scenes are numbered from 1");
+        self.subplots.scene.insert(n, Box::new(scene));
+        self
+    }
+
+    pub fn scene2(self, scene: LayoutScene) -> Self {
+        self.scene_n(2, scene)
+    }
+
+    pub fn scene3(self, scene: LayoutScene) -> Self {
+        self.scene_n(3, scene)
+    }
+
+    pub fn scene4(self, scene: LayoutScene) -> Self {
+        self.scene_n(4, scene)
+    }
+
+    pub fn scene5(self, scene: LayoutScene) -> Self {
+        self.scene_n(5, scene)
+    }
+
+    pub fn scene6(self, scene: LayoutScene) -> Self {
+        self.scene_n(6, scene)
+    }
+
+    pub fn scene7(self, scene: LayoutScene) -> Self {
+        self.scene_n(7, scene)
+    }
+
+    pub fn scene8(self, scene: LayoutScene) -> Self {
+        self.scene_n(8, scene)
+    }
+
+    pub fn polar(self, polar: LayoutPolar) -> Self {
+        self.polar_n(1, polar)
+    }
+
+    /// Sets the `n`th polar subplot, counted from 1, which traces refer to with
+    /// `.subplot("polarN")`, or `.subplot("polar")` for the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn polar_n(mut self, n: usize, polar: LayoutPolar) -> Self {
+        assert!(n > 0, "polar subplots are numbered from 1");
+        self.subplots.polar.insert(n, Box::new(polar));
+        self
+    }
+
+    pub fn polar2(self, polar: LayoutPolar) -> Self {
+        self.polar_n(2, polar)
+    }
+
+    pub fn polar3(self, polar: LayoutPolar) -> Self {
+        self.polar_n(3, polar)
+    }
+
+    pub fn polar4(self, polar: LayoutPolar) -> Self {
+        self.polar_n(4, polar)
+    }
+
+    pub fn polar5(self, polar: LayoutPolar) -> Self {
+        self.polar_n(5, polar)
+    }
+
+    pub fn polar6(self, polar: LayoutPolar) -> Self {
+        self.polar_n(6, polar)
+    }
+
+    pub fn polar7(self, polar: LayoutPolar) -> Self {
+        self.polar_n(7, polar)
+    }
+
+    pub fn polar8(self, polar: LayoutPolar) -> Self {
+        self.polar_n(8, polar)
+    }
+
+    pub fn geo(self, geo: LayoutGeo) -> Self {
+        self.geo_n(1, geo)
+    }
+
+    /// Sets the `n`th geographic map, counted from 1, which traces refer to with `.geo("geoN")`,
+    /// or `.geo("geo")` for the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn geo_n(mut self, n: usize, geo: LayoutGeo) -> Self {
+        assert!(n > 0, "geographic maps are numbered from 1");
+        self.subplots.geo.insert(n, Box::new(geo));
+        self
+    }
+
+    pub fn mapbox(mut self, mapbox: LayoutMapbox) -> Self {
+        self.mapbox = Some(mapbox);
+        self
+    }
+
+    pub fn annotations(mut self, annotations: Vec<Annotation>) -> Self {
+        self.annotations = Some(annotations);
+        self
+    }
+
+    pub fn add_annotation(&mut self, annotation: Annotation) {
+        if self.annotations.is_none() {
+            self.annotations = Some(Vec::new());
+        }
+        self.annotations.as_mut().unwrap().push(annotation);
+    }
+
+    pub fn shapes(mut self, shapes: Vec<Shape>) -> Self {
+        self.shapes = Some(shapes);
+        self
+    }
+
+    pub fn add_shape(&mut self, shape: Shape) {
+        if self.shapes.is_none() {
+            self.shapes = Some(Vec::new());
+        }
+        self.shapes.as_mut().unwrap().push(shape);
+    }
+
+    pub fn new_shape(mut self, new_shape: NewShape) -> Self {
+        self.new_shape = Some(new_shape);
+        self
+    }
+
+    pub fn active_shape(mut self, active_shape: ActiveShape) -> Self {
+        self.active_shape = Some(active_shape);
+        self
+    }
+
+    pub fn update_menus(mut self, update_menus: Vec<UpdateMenu>) -> Self {
+        self.update_menus = Some(update_menus);
+        self
+    }
+
+    pub fn sliders(mut self, sliders: Vec<Slider>) -> Self {
+        self.sliders = Some(sliders);
+        self
+    }
+
+    pub fn box_mode(mut self, box_mode: BoxMode) -> Self {
+        self.box_mode = Some(box_mode);
+        self
+    }
+
+    pub fn box_gap(mut self, box_gap: f64) -> Self {
+        self.box_gap = Some(box_gap);
+        self
+    }
+
+    pub fn box_group_gap(mut self, box_group_gap: f64) -> Self {
+        self.box_group_gap = Some(box_group_gap);
+        self
+    }
+
+    pub fn bar_mode(mut self, bar_mode: BarMode) -> Self {
+        self.bar_mode = Some(bar_mode);
+        self
+    }
+
+    pub fn bar_norm(mut self, bar_norm: BarNorm) -> Self {
+        self.bar_norm = Some(bar_norm);
+        self
+    }
+
+    pub fn bar_gap(mut self, bar_gap: f64) -> Self {
+        self.bar_gap = Some(bar_gap);
+        self
+    }
+
+    pub fn bar_group_gap(mut self, bar_group_gap: f64) -> Self {
+        self.bar_group_gap = Some(bar_group_gap);
+        self
+    }
+
+    pub fn violin_mode(mut self, violin_mode: ViolinMode) -> Self {
+        self.violin_mode = Some(violin_mode);
+        self
+    }
+
+    pub fn violin_gap(mut self, violin_gap: f64) -> Self {
+        self.violin_gap = Some(violin_gap);
+        self
+    }
+
+    pub fn violin_group_gap(mut self, violin_group_gap: f64) -> Self {
+        self.violin_group_gap = Some(violin_group_gap);
+        self
+    }
+
+    pub fn waterfall_mode(mut self, waterfall_mode: WaterfallMode) -> Self {
+        self.waterfall_mode = Some(waterfall_mode);
+        self
+    }
+
+    pub fn waterfall_gap(mut self, waterfall_gap: f64) -> Self {
+        self.waterfall_gap = Some(waterfall_gap);
+        self
+    }
+
+    pub fn waterfall_group_gap(mut self, waterfall_group_gap: f64) -> Self {
+        self.waterfall_group_gap = Some(waterfall_group_gap);
+        self
+    }
+
+    pub fn pie_colorway<C: Color>(mut self, pie_colorway: Vec<C>) -> Self {
+        self.pie_colorway = Some(ColorArray(pie_colorway).into());
+        self
+    }
+
+    pub fn extend_pie_colors(mut self, extend_pie_colors: bool) -> Self {
+        self.extend_pie_colors = Some(extend_pie_colors);
+        self
+    }
+
+    pub fn sunburst_colorway<C: Color>(mut self, sunburst_colorway: Vec<C>) -> Self {
+        self.sunburst_colorway = Some(ColorArray(sunburst_colorway).into());
+        self
+    }
+
+    pub fn extend_sunburst_colors(mut self, extend_sunburst_colors: bool) -> Self {
+        self.extend_sunburst_colors = Some(extend_sunburst_colors);
+        self
+    }
+
+    pub fn treemap_colorway<C: Color>(mut self, treemap_colorway: Vec<C>) -> Self {
+        self.treemap_colorway = Some(ColorArray(treemap_colorway).into());
+        self
+    }
+
+    pub fn extend_treemap_colors(mut self, extend_treemap_colors: bool) -> Self {
+        self.extend_treemap_colors = Some(extend_treemap_colors);
+        self
+    }
+
+    pub fn icicle_colorway<C: Color>(mut self, icicle_colorway: Vec<C>) -> Self {
+        self.icicle_colorway = Some(ColorArray(icicle_colorway).into());
+        self
+    }
+
+    pub fn extend_icicle_colors(mut self, extend_icicle_colors: bool) -> Self {
+        self.extend_icicle_colors = Some(extend_icicle_colors);
+        self
+    }
+}
+
+/// The numbered axes and subplots of a `Layout` or `LayoutTemplate`, serialized as `xaxis`,
+/// `xaxis2`, `xaxis3`, ..., `scene`, `scene2`, ..., `polar`, `polar2`, ..., `geo`, `geo2`, ... for
+/// any number of them. It also holds the attributes the layout has no field for: serde hands those
+/// to every flattened field of a struct, so they must all be read by the same one.
+#[derive(Debug, Default, Clone)]
+struct LayoutSubplots {
+    x: BTreeMap<usize, Box<Axis>>,
+    y: BTreeMap<usize, Box<Axis>>,
+    z: BTreeMap<usize, Box<Axis>>,
+    scene: BTreeMap<usize, Box<LayoutScene>>,
+    polar: BTreeMap<usize, Box<LayoutPolar>>,
+    geo: BTreeMap<usize, Box<LayoutGeo>>,
+    extra: Extra,
+}
+
+impl Serialize for LayoutSubplots {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        let numbers: BTreeSet<usize> = self
+            .x
+            .keys()
+            .chain(self.y.keys())
+            .chain(self.z.keys())
+            .copied()
+            .collect();
+        for n in numbers {
+            if let Some(axis) = self.x.get(&n) {
+                map.serialize_entry(&private::axis_id("xaxis", n), axis)?;
+            }
+            if let Some(axis) = self.y.get(&n) {
+                map.serialize_entry(&private::axis_id("yaxis", n), axis)?;
+            }
+            if let Some(axis) = self.z.get(&n) {
+                map.serialize_entry(&private::axis_id("zaxis", n), axis)?;
+            }
+        }
+        for (n, scene) in &self.scene {
+            map.serialize_entry(&private::axis_id("scene", *n), scene)?;
+        }
+        for (n, polar) in &self.polar {
+            map.serialize_entry(&private::axis_id("polar", *n), polar)?;
+        }
+        for (n, geo) in &self.geo {
+            map.serialize_entry(&private::axis_id("geo", *n), geo)?;
+        }
+        for (key, value) in &self.extra {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for LayoutSubplots {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LayoutSubplotsVisitor;
+
+        impl<'de> de::Visitor<'de> for LayoutSubplotsVisitor {
+            type Value = LayoutSubplots;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("the attributes of a layout")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut subplots = LayoutSubplots::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    if let Some(n) = private::parse_axis_id("xaxis", &key) {
+                        subplots.x.insert(n, map.next_value()?);
+                    } else if let Some(n) = private::parse_axis_id("yaxis", &key) {
+                        subplots.y.insert(n, map.next_value()?);
+                    } else if let Some(n) = private::parse_axis_id("zaxis", &key) {
+                        subplots.z.insert(n, map.next_value()?);
+                    } else if let Some(n) = private::parse_axis_id("scene", &key) {
+                        subplots.scene.insert(n, map.next_value()?);
+                    } else if let Some(n) = private::parse_axis_id("polar", &key) {
+                        subplots.polar.insert(n, map.next_value()?);
+                    } else if let Some(n) = private::parse_axis_id("geo", &key) {
+                        subplots.geo.insert(n, map.next_value()?);
+                    } else {
+                        subplots.extra.insert(key, map.next_value()?);
+                    }
+                }
+                Ok(subplots)
+            }
+        }
+
+        deserializer.deserialize_map(LayoutSubplotsVisitor)
+    }
+}
+
+/// An attribute of a layout kept as raw JSON, as its value does not fit its field.
+#[derive(Debug, Clone)]
+struct UnreadAttribute {
+    value: Value,
+    error: String,
+}
+
+/// Reads the attributes of a layout with `read`. A layout written by a newer Plotly, or holding a
+/// value this crate reads more strictly than Plotly does, is still read in full: the attributes
+/// that `read` fails on are returned apart, as is done for traces. Only then is each attribute
+/// read on its own to find those.
+fn read_leniently<T>(
+    attributes: Extra,
+    read: impl Fn(&Value) -> Result<T, serde_json::Error>,
+) -> Result<(T, BTreeMap<String, UnreadAttribute>), serde_json::Error> {
+    let attributes = Value::Object(attributes);
+    if let Ok(layout) = read(&attributes) {
+        return Ok((layout, BTreeMap::new()));
+    }
+
+    let mut typed = Extra::new();
+    let mut unread = BTreeMap::new();
+    if let Value::Object(attributes) = attributes {
+        for (key, value) in attributes {
+            let attribute = Value::Object(std::iter::once((key, value)).collect());
+            let result = read(&attribute);
+            let (key, value) = match attribute {
+                Value::Object(attribute) => attribute.into_iter().next().unwrap(),
+                _ => unreachable!(),
+            };
+            match result {
+                Ok(_) => {
+                    typed.insert(key, value);
+                }
+                Err(e) => {
+                    let error = e.to_string();
+                    unread.insert(key, UnreadAttribute { value, error });
+                }
+            }
+        }
+    }
+    Ok((read(&Value::Object(typed))?, unread))
+}
+
+/// Serializes a layout along with the attributes it kept as raw JSON when it was read, except for
+/// those that have been set since.
+fn serialize_with_unread<S>(
+    layout: Result<Value, serde_json::Error>,
+    unread: &BTreeMap<String, UnreadAttribute>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut layout = layout.map_err(serde::ser::Error::custom)?;
+    if let Value::Object(attributes) = &mut layout {
+        for (key, attribute) in unread {
+            attributes
+                .entry(key.as_str())
+                .or_insert_with(|| attribute.value.clone());
+        }
+    }
+    layout.serialize(serializer)
+}
+
+fn unread_errors(unread: &BTreeMap<String, UnreadAttribute>) -> impl Iterator<Item = String> + '_ {
+    unread
+        .iter()
+        .map(|(key, attribute)| format!("{}: {}", key, attribute.error))
+}
+
+impl Serialize for Layout {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.unread.is_empty() {
+            return Layout::serialize(self, serializer);
+        }
+        let layout = Layout::serialize(self, serde_json::value::Serializer);
+        serialize_with_unread(layout, &self.unread, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Layout {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (mut layout, unread) =
+            read_leniently(Extra::deserialize(deserializer)?, |attributes| {
+                Layout::deserialize(attributes)
+            })
+            .map_err(de::Error::custom)?;
+        layout.unread = unread;
+        Ok(layout)
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+#[serde(remote = "Self")]
+pub struct Layout {
+    title: Option<Title>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    legend: Option<Legend>,
+    margin: Option<Margin>,
+    #[serde(rename = "autosize")]
+    auto_size: Option<bool>,
+    width: Option<usize>,
+    height: Option<usize>,
+    font: Option<Font>,
+    #[serde(rename = "uniformtext")]
+    uniform_text: Option<UniformText>,
+    separators: Option<String>,
+    #[serde(rename = "paper_bgcolor")]
+    paper_background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "plot_bgcolor")]
+    plot_background_color: Option<Box<dyn Color>>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<LayoutColorScale>,
+    colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "coloraxis")]
+    color_axis: Option<ColorAxis>,
+    #[serde(rename = "modebar")]
+    mode_bar: Option<ModeBar>,
+    #[serde(rename = "hovermode")]
+    hover_mode: Option<HoverMode>,
+    #[serde(rename = "clickmode")]
+    click_mode: Option<ClickMode>,
+    #[serde(rename = "dragmode")]
+    drag_mode: Option<DragMode>,
+    #[serde(rename = "selectdirection")]
+    select_direction: Option<SelectDirection>,
+    #[serde(rename = "hoverdistance")]
+    hover_distance: Option<i32>,
+    #[serde(rename = "spikedistance")]
+    spike_distance: Option<i32>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    transition: Option<Transition>,
+
+    template: Option<Box<Cow<'static, Template>>>,
+
+    grid: Option<LayoutGrid>,
+    calendar: Option<Calendar>,
+
+    #[serde(flatten)]
+    subplots: LayoutSubplots,
+
+    ternary: Option<Box<LayoutTernary>>,
+    mapbox: Option<LayoutMapbox>,
+    annotations: Option<Vec<Annotation>>,
+    shapes: Option<Vec<Shape>>,
+    #[serde(rename = "newshape")]
+    new_shape: Option<NewShape>,
+    #[serde(rename = "activeshape")]
+    active_shape: Option<ActiveShape>,
+    #[serde(rename = "updatemenus")]
+    update_menus: Option<Vec<UpdateMenu>>,
+    sliders: Option<Vec<Slider>>,
+
+    #[serde(rename = "boxmode")]
+    box_mode: Option<BoxMode>,
+    #[serde(rename = "boxgap")]
+    box_gap: Option<f64>,
+    #[serde(rename = "boxgroupgap")]
+    box_group_gap: Option<f64>,
+
+    #[serde(rename = "barmode")]
+    bar_mode: Option<BarMode>,
+    #[serde(rename = "barnorm")]
+    bar_norm: Option<BarNorm>,
+    #[serde(rename = "bargap")]
+    bar_gap: Option<f64>,
+    #[serde(rename = "bargroupgap")]
+    bar_group_gap: Option<f64>,
+
+    #[serde(rename = "violinmode")]
+    violin_mode: Option<ViolinMode>,
+    #[serde(rename = "violingap")]
+    violin_gap: Option<f64>,
+    #[serde(rename = "violingroupgap")]
+    violin_group_gap: Option<f64>,
+
+    #[serde(rename = "waterfallmode")]
+    waterfall_mode: Option<WaterfallMode>,
+    #[serde(rename = "waterfallgap")]
+    waterfall_gap: Option<f64>,
+    #[serde(rename = "waterfallgroupgap")]
+    waterfall_group_gap: Option<f64>,
+
+    #[serde(rename = "piecolorway")]
+    pie_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendpiecolors")]
+    extend_pie_colors: Option<bool>,
+
+    #[serde(rename = "sunburstcolorway")]
+    sunburst_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendsunburstcolors")]
+    extend_sunburst_colors: Option<bool>,
+
+    #[serde(rename = "treemapcolorway")]
+    treemap_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendtreemapcolors")]
+    extend_treemap_colors: Option<bool>,
+
+    #[serde(rename = "iciclecolorway")]
+    icicle_colorway: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "extendiciclecolors")]
+    extend_icicle_colors: Option<bool>,
+
+    #[serde(skip)]
+    unread: BTreeMap<String, UnreadAttribute>,
+}
+
+impl Layout {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// The geographic map with the given id, e.g. "geo2", which traces refer to with `.geo(..)`.
+    pub(crate) fn geo_subplot(&self, id: &str) -> Option<&LayoutGeo> {
+        let n = private::parse_axis_id("geo", id)?;
+        self.subplots.geo.get(&n).map(|geo| &**geo)
+    }
+
+    /// The errors met reading the attributes that were kept as raw JSON when this layout was read,
+    /// as their value does not fit their field, each prefixed with the name of the attribute. Those
+    /// attributes are written back out unchanged, unless they have been set since.
+    pub fn read_errors(&self) -> Vec<String> {
+        let mut errors: Vec<String> = unread_errors(&self.unread).collect();
+        if let Some(layout) = self.template.as_ref().and_then(|t| t.layout.as_ref()) {
+            errors.extend(unread_errors(&layout.unread).map(|e| format!("template.layout.{}", e)));
+        }
+        errors
+    }
+
+    pub fn title(mut self, title: Title) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = Some(show_legend);
+        self
+    }
+
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend = Some(legend);
+        self
+    }
+
+    pub fn margin(mut self, margin: Margin) -> Self {
+        self.margin = Some(margin);
+        self
+    }
+
+    pub fn auto_size(mut self, auto_size: bool) -> Self {
+        self.auto_size = Some(auto_size);
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn uniform_text(mut self, uniform_text: UniformText) -> Self {
+        self.uniform_text = Some(uniform_text);
+        self
+    }
+
+    pub fn separators(mut self, separators: &str) -> Self {
+        self.separators = Some(separators.to_owned());
+        self
+    }
+
+    pub fn paper_background_color<C: Color>(mut self, paper_background_color: C) -> Self {
+        self.paper_background_color = Some(Box::new(paper_background_color));
+        self
+    }
+
+    pub fn plot_background_color<C: Color>(mut self, plot_background_color: C) -> Self {
+        self.plot_background_color = Some(Box::new(plot_background_color));
+        self
+    }
+
+    pub fn color_scale(mut self, color_scale: LayoutColorScale) -> Self {
+        self.color_scale = Some(color_scale);
+        self
+    }
+
+    pub fn colorway<C: Color>(mut self, colorway: Vec<C>) -> Self {
+        self.colorway = Some(ColorArray(colorway).into());
+        self
+    }
+
+    pub fn color_axis(mut self, color_axis: ColorAxis) -> Self {
+        self.color_axis = Some(color_axis);
+        self
+    }
+
+    pub fn mode_bar(mut self, mode_bar: ModeBar) -> Self {
+        self.mode_bar = Some(mode_bar);
+        self
+    }
+
+    /// Determines the mode of hover interactions. If "closest", a single hoverlabel will appear for the "closest"
+    /// point within the `hoverdistance`. If "x" (or "y"), multiple hoverlabels will appear for multiple points at
+    /// the "closest" x- (or y-) coordinate within the `hoverdistance`, with the caveat that no more than one hoverlabel
+    /// will appear per trace. If "x unified" (or "y unified"), a single hoverlabel will appear multiple points at
+    /// the closest x- (or y-) coordinate within the `hoverdistance` with the caveat that no more than one hoverlabel
+    /// will appear per trace. In this mode, spikelines are enabled by default perpendicular to the specified axis.
+    /// If false, hover interactions are disabled. If `clickmode` includes the "select" flag, `hovermode` defaults to
+    /// "closest". If `clickmode` lacks the "select" flag, it defaults to "x" or "y"
+    /// (depending on the trace's `orientation` value) for plots based on cartesian coordinates. For anything
+    /// else the default value is "closest".
+    pub fn hover_mode(mut self, hover_mode: HoverMode) -> Self {
+        self.hover_mode = Some(hover_mode);
+        self
+    }
+
+    pub fn click_mode(mut self, click_mode: ClickMode) -> Self {
+        self.click_mode = Some(click_mode);
+        self
+    }
+
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = Some(drag_mode);
+        self
+    }
+
+    pub fn select_direction(mut self, select_direction: SelectDirection) -> Self {
+        self.select_direction = Some(select_direction);
+        self
+    }
+
+    pub fn hover_distance(mut self, hover_distance: i32) -> Self {
+        self.hover_distance = Some(hover_distance);
+        self
+    }
+
+    pub fn spike_distance(mut self, spike_distance: i32) -> Self {
+        self.spike_distance = Some(spike_distance);
+        self
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Self {
+        self.hover_label = Some(hover_label);
+        self
+    }
+
+    /// Sets the transition used when the plot is animated from one `Frame` to the next.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    pub fn grid(mut self, grid: LayoutGrid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    pub fn calendar(mut self, calendar: Calendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    pub fn x_axis(self, xaxis: Axis) -> Self {
+        self.x_axis_n(1, xaxis)
+    }
+
+    pub fn y_axis(self, yaxis: Axis) -> Self {
+        self.y_axis_n(1, yaxis)
+    }
+
+    pub fn z_axis(self, zaxis: Axis) -> Self {
+        self.z_axis_n(1, zaxis)
+    }
+
+    /// Sets the `n`th x axis, counted from 1, which traces refer to with `XAxisRef::new(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn x_axis_n(mut self, n: usize, xaxis: Axis) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        self.subplots.x.insert(n, Box::new(xaxis));
+        self
+    }
+
+    /// Sets the `n`th y axis, counted from 1, which traces refer to with `YAxisRef::new(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn y_axis_n(mut self, n: usize, yaxis: Axis) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        self.subplots.y.insert(n, Box::new(yaxis));
+        self
+    }
+
+    /// Sets the `n`th z axis, counted from 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn z_axis_n(mut self, n: usize, zaxis: Axis) -> Self {
+        assert!(n > 0, "axes are numbered from 1");
+        self.subplots.z.insert(n, Box::new(zaxis));
+        self
+    }
+
+    pub fn x_axis2(self, xaxis: Axis) -> Self {
+        self.x_axis_n(2, xaxis)
+    }
+
+    pub fn y_axis2(self, yaxis: Axis) -> Self {
+        self.y_axis_n(2, yaxis)
+    }
+
+    pub fn x_axis3(self, xaxis: Axis) -> Self {
+        self.x_axis_n(3, xaxis)
+    }
+
+    pub fn y_axis3(self, yaxis: Axis) -> Self {
+        self.y_axis_n(3, yaxis)
+    }
+
+    pub fn x_axis4(self, xaxis: Axis) -> Self {
+        self.x_axis_n(4, xaxis)
+    }
+
+    pub fn y_axis4(self, yaxis: Axis) -> Self {
+        self.y_axis_n(4, yaxis)
+    }
+
+    pub fn x_axis5(self, xaxis: Axis) -> Self {
+        self.x_axis_n(5, xaxis)
+    }
+
+    pub fn y_axis5(self, yaxis: Axis) -> Self {
+        self.y_axis_n(5, yaxis)
+    }
+
+    pub fn x_axis6(self, xaxis: Axis) -> Self {
+        self.x_axis_n(6, xaxis)
+    }
+
+    pub fn y_axis6(self, yaxis: Axis) -> Self {
+        self.y_axis_n(6, yaxis)
+    }
+
+    pub fn x_axis7(self, xaxis: Axis) -> Self {
+        self.x_axis_n(7, xaxis)
+    }
+
+    pub fn y_axis7(self, yaxis: Axis) -> Self {
+        self.y_axis_n(7, yaxis)
+    }
+
+    pub fn x_axis8(self, xaxis: Axis) -> Self {
+        self.x_axis_n(8, xaxis)
+    }
+
+    pub fn y_axis8(self, yaxis: Axis) -> Self {
+        self.y_axis_n(8, yaxis)
+    }
+
+    pub fn ternary(mut self, ternary: LayoutTernary) -> Self {
+        self.ternary = Some(Box::new(ternary));
+        self
+    }
+
+    pub fn scene(self, scene: LayoutScene) -> Self {
+        self.scene_n(1, scene)
+    }
+
+    /// Sets the `n`th 3D scene, counted from 1, which traces refer to with `.scene("sceneN")`, or
+    /// `.scene("scene")` for the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn scene_n(mut self, n: usize, scene: LayoutScene) -> Self {
+        assert!(n > 0, "scenes are numbered from 1");
+        self.subplots.scene.insert(n, Box::new(scene));
+        self
+    }
+
+    pub fn scene2(self, scene: LayoutScene) -> Self {
+        self.scene_n(2, scene)
+    }
+
+    pub fn scene3(self, scene: LayoutScene) -> Self {
+        self.scene_n(3, scene)
+    }
+
+    pub fn scene4(self, scene: LayoutScene) -> Self {
+        self.scene_n(4, scene)
+    }
+
+    pub fn scene5(self, scene: LayoutScene) -> Self {
+        self.scene_n(5, scene)
+    }
+
+    pub fn scene6(self, scene: LayoutScene) -> Self {
+        self.scene_n(6, scene)
+    }
+
+    pub fn scene7(self, scene: LayoutScene) -> Self {
+        self.scene_n(7, scene)
+    }
+
+    pub fn scene8(self, scene: LayoutScene) -> Self {
+        self.scene_n(8, scene)
+    }
+
+    pub fn polar(self, polar: LayoutPolar) -> Self {
+        self.polar_n(1, polar)
+    }
+
+    /// Sets the `n`th polar subplot, counted from 1, which traces refer to with
+    /// `.subplot("polarN")`, or `.subplot("polar")` for the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn polar_n(mut self, n: usize, polar: LayoutPolar) -> Self {
+        assert!(n > 0, "polar subplots are numbered from 1");
+        self.subplots.polar.insert(n, Box::new(polar));
+        self
+    }
+
+    pub fn polar2(self, polar: LayoutPolar) -> Self {
+        self.polar_n(2, polar)
+    }
+
+    pub fn polar3(self, polar: LayoutPolar) -> Self {
+        self.polar_n(3, polar)
+    }
+
+    pub fn polar4(self, polar: LayoutPolar) -> Self {
+        self.polar_n(4, polar)
+    }
+
+    pub fn polar5(self, polar: LayoutPolar) -> Self {
+        self.polar_n(5, polar)
+    }
+
+    pub fn polar6(self, polar: LayoutPolar) -> Self {
+        self.polar_n(6, polar)
+    }
+
+    pub fn polar7(self, polar: LayoutPolar) -> Self {
+        self.polar_n(7, polar)
+    }
+
+    pub fn polar8(self, polar: LayoutPolar) -> Self {
+        self.polar_n(8, polar)
+    }
+
+    pub fn geo(self, geo: LayoutGeo) -> Self {
+        self.geo_n(1, geo)
+    }
+
+    /// Sets the `n`th geographic map, counted from 1, which traces refer to with `.geo("geoN")`,
+    /// or `.geo("geo")` for the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn geo_n(mut self, n: usize, geo: LayoutGeo) -> Self {
+        assert!(n > 0, "geographic maps are numbered from 1");
+        self.subplots.geo.insert(n, Box::new(geo));
+        self
+    }
+
+    pub fn mapbox(mut self, mapbox: LayoutMapbox) -> Self {
+        self.mapbox = Some(mapbox);
+        self
+    }
+
+    pub fn annotations(mut self, annotations: Vec<Annotation>) -> Self {
+        self.annotations = Some(annotations);
+        self
+    }
+
+    pub fn add_annotation(&mut self, annotation: Annotation) {
+        if self.annotations.is_none() {
+            self.annotations = Some(Vec::new());
+        }
+        self.annotations.as_mut().unwrap().push(annotation);
+    }
+
+    pub fn shapes(mut self, shapes: Vec<Shape>) -> Self {
+        self.shapes = Some(shapes);
+        self
+    }
+
+    pub fn add_shape(&mut self, shape: Shape) {
+        if self.shapes.is_none() {
+            self.shapes = Some(Vec::new());
+        }
+        self.shapes.as_mut().unwrap().push(shape);
+    }
+
+    pub fn new_shape(mut self, new_shape: NewShape) -> Self {
+        self.new_shape = Some(new_shape);
+        self
+    }
+
+    pub fn active_shape(mut self, active_shape: ActiveShape) -> Self {
+        self.active_shape = Some(active_shape);
+        self
+    }
+
+    pub fn update_menus(mut self, update_menus: Vec<UpdateMenu>) -> Self {
+        self.update_menus = Some(update_menus);
+        self
+    }
+
+    pub fn sliders(mut self, sliders: Vec<Slider>) -> Self {
+        self.sliders = Some(sliders);
+        self
+    }
+
+    pub fn template<T>(mut self, template: T) -> Layout
+    where
+        T: Into<Cow<'static, Template>>,
+    {
+        self.template = Some(Box::new(template.into()));
+        self
+    }
+
+    pub fn box_mode(mut self, box_mode: BoxMode) -> Self {
+        self.box_mode = Some(box_mode);
+        self
+    }
+
+    pub fn box_gap(mut self, box_gap: f64) -> Self {
+        self.box_gap = Some(box_gap);
+        self
+    }
+
+    pub fn box_group_gap(mut self, box_group_gap: f64) -> Self {
+        self.box_group_gap = Some(box_group_gap);
+        self
+    }
+
+    pub fn bar_mode(mut self, bar_mode: BarMode) -> Self {
+        self.bar_mode = Some(bar_mode);
+        self
+    }
+
+    pub fn bar_norm(mut self, bar_norm: BarNorm) -> Self {
+        self.bar_norm = Some(bar_norm);
+        self
+    }
+
+    pub fn bar_gap(mut self, bar_gap: f64) -> Self {
+        self.bar_gap = Some(bar_gap);
+        self
+    }
+
+    pub fn bar_group_gap(mut self, bar_group_gap: f64) -> Self {
+        self.bar_group_gap = Some(bar_group_gap);
+        self
+    }
+
+    pub fn violin_mode(mut self, violin_mode: ViolinMode) -> Self {
+        self.violin_mode = Some(violin_mode);
+        self
+    }
+
+    pub fn violin_gap(mut self, violin_gap: f64) -> Self {
+        self.violin_gap = Some(violin_gap);
+        self
+    }
+
+    pub fn violin_group_gap(mut self, violin_group_gap: f64) -> Self {
+        self.violin_group_gap = Some(violin_group_gap);
+        self
+    }
+
+    pub fn waterfall_mode(mut self, waterfall_mode: WaterfallMode) -> Self {
+        self.waterfall_mode = Some(waterfall_mode);
+        self
+    }
+
+    pub fn waterfall_gap(mut self, waterfall_gap: f64) -> Self {
+        self.waterfall_gap = Some(waterfall_gap);
+        self
+    }
+
+    pub fn waterfall_group_gap(mut self, waterfall_group_gap: f64) -> Self {
+        self.waterfall_group_gap = Some(waterfall_group_gap);
+        self
+    }
+
+    pub fn pie_colorway<C: Color>(mut self, pie_colorway: Vec<C>) -> Self {
+        self.pie_colorway = Some(ColorArray(pie_colorway).into());
+        self
+    }
+
+    pub fn extend_pie_colors(mut self, extend_pie_colors: bool) -> Self {
+        self.extend_pie_colors = Some(extend_pie_colors);
+        self
+    }
+
+    pub fn sunburst_colorway<C: Color>(mut self, sunburst_colorway: Vec<C>) -> Self {
+        self.sunburst_colorway = Some(ColorArray(sunburst_colorway).into());
+        self
+    }
+
+    pub fn extend_sunburst_colors(mut self, extend_sunburst_colors: bool) -> Self {
+        self.extend_sunburst_colors = Some(extend_sunburst_colors);
+        self
+    }
+
+    pub fn treemap_colorway<C: Color>(mut self, treemap_colorway: Vec<C>) -> Self {
+        self.treemap_colorway = Some(ColorArray(treemap_colorway).into());
+        self
+    }
+
+    pub fn extend_treemap_colors(mut self, extend_treemap_colors: bool) -> Self {
+        self.extend_treemap_colors = Some(extend_treemap_colors);
+        self
+    }
+
+    pub fn icicle_colorway<C: Color>(mut self, icicle_colorway: Vec<C>) -> Self {
+        self.icicle_colorway = Some(ColorArray(icicle_colorway).into());
+        self
+    }
+
+    pub fn extend_icicle_colors(mut self, extend_icicle_colors: bool) -> Self {
+        self.extend_icicle_colors = Some(extend_icicle_colors);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, to_value};
+
+    use super::*;
+    use crate::common::{ColorScalePalette, Line, Mode};
+    use crate::{Bar, Scatter};
+
+    #[test]
+    fn test_serialize_uniform_text_mode() {
+        assert_eq!(to_value(UniformTextMode::False).unwrap(), json!(false));
+        assert_eq!(to_value(UniformTextMode::Hide).unwrap(), json!("hide"));
+        assert_eq!(to_value(UniformTextMode::Show).unwrap(), json!("show"));
+    }
+
+    #[test]
+    fn test_deserialize_uniform_text_mode() {
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            UniformTextMode::False
+        ));
+        assert!(matches!(
+            from_value(json!("hide")).unwrap(),
+            UniformTextMode::Hide
+        ));
+        assert!(matches!(
+            from_value(json!("show")).unwrap(),
+            UniformTextMode::Show
+        ));
+        assert!(from_value::<UniformTextMode>(json!(true)).is_err());
+    }
+
+    #[test]
+    fn test_serialize_click_to_show() {
+        assert_eq!(to_value(ClickToShow::False).unwrap(), json!(false));
+        assert_eq!(to_value(ClickToShow::OnOff).unwrap(), json!("onoff"));
+        assert_eq!(to_value(ClickToShow::OnOut).unwrap(), json!("onout"));
+    }
+
+    #[test]
+    fn test_deserialize_click_to_show() {
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            ClickToShow::False
+        ));
+        assert!(matches!(
+            from_value(json!("onoff")).unwrap(),
+            ClickToShow::OnOff
+        ));
+        assert!(matches!(
+            from_value(json!("onout")).unwrap(),
+            ClickToShow::OnOut
+        ));
+        assert!(from_value::<ClickToShow>(json!(true)).is_err());
+    }
+
+    #[test]
+    fn test_serialize_hover_mode() {
+        assert_eq!(to_value(HoverMode::X).unwrap(), json!("x"));
+        assert_eq!(to_value(HoverMode::Y).unwrap(), json!("y"));
+        assert_eq!(to_value(HoverMode::Closest).unwrap(), json!("closest"));
+        assert_eq!(to_value(HoverMode::False).unwrap(), json!(false));
+        assert_eq!(to_value(HoverMode::XUnified).unwrap(), json!("x unified"));
+        assert_eq!(to_value(HoverMode::YUnified).unwrap(), json!("y unified"));
+    }
+
+    #[test]
+    fn test_deserialize_hover_mode() {
+        assert!(matches!(from_value(json!("x")).unwrap(), HoverMode::X));
+        assert!(matches!(from_value(json!("y")).unwrap(), HoverMode::Y));
+        assert!(matches!(
+            from_value(json!("closest")).unwrap(),
+            HoverMode::Closest
+        ));
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            HoverMode::False
+        ));
+        assert!(matches!(
+            from_value(json!("x unified")).unwrap(),
+            HoverMode::XUnified
+        ));
+        assert!(matches!(
+            from_value(json!("y unified")).unwrap(),
+            HoverMode::YUnified
+        ));
+        assert!(from_value::<HoverMode>(json!(true)).is_err());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_axis_type() {
+        assert_eq!(to_value(AxisType::Default).unwrap(), json!("-"));
+        assert_eq!(to_value(AxisType::Linear).unwrap(), json!("linear"));
+        assert_eq!(to_value(AxisType::Log).unwrap(), json!("log"));
+        assert_eq!(to_value(AxisType::Date).unwrap(), json!("date"));
+        assert_eq!(to_value(AxisType::Category).unwrap(), json!("category"));
+        assert_eq!(to_value(AxisType::MultiCategory).unwrap(), json!("multicategory"));
+    }
+
+    #[test]
+    fn test_serialize_axis_constrain() {
+        assert_eq!(to_value(AxisConstrain::Range).unwrap(), json!("range"));
+        assert_eq!(to_value(AxisConstrain::Domain).unwrap(), json!("domain"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_constrain_direction() {
+        assert_eq!(to_value(ConstrainDirection::Left).unwrap(), json!("left"));
+        assert_eq!(to_value(ConstrainDirection::Center).unwrap(), json!("center"));
+        assert_eq!(to_value(ConstrainDirection::Right).unwrap(), json!("right"));
+        assert_eq!(to_value(ConstrainDirection::Top).unwrap(), json!("top"));
+        assert_eq!(to_value(ConstrainDirection::Middle).unwrap(), json!("middle"));
+        assert_eq!(to_value(ConstrainDirection::Bottom).unwrap(), json!("bottom"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_range_mode() {
+        assert_eq!(to_value(RangeMode::Normal).unwrap(), json!("normal"));
+        assert_eq!(to_value(RangeMode::ToZero).unwrap(), json!("tozero"));
+        assert_eq!(to_value(RangeMode::NonNegative).unwrap(), json!("nonnegative"));
+    }
+
+    #[test]
+    fn test_serialize_ticks_direction() {
+        assert_eq!(to_value(TicksDirection::Outside).unwrap(), json!("outside"));
+        assert_eq!(to_value(TicksDirection::Inside).unwrap(), json!("inside"));
+        assert_eq!(to_value(TicksDirection::None).unwrap(), json!(""));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_ticks_position() {
+        assert_eq!(to_value(TicksPosition::Labels).unwrap(), json!("labels"));
+        assert_eq!(to_value(TicksPosition::Boundaries).unwrap(), json!("boundaries"));
+    }
+
+    #[test]
+    fn test_serialize_array_show() {
+        assert_eq!(to_value(ArrayShow::All).unwrap(), json!("all"));
+        assert_eq!(to_value(ArrayShow::First).unwrap(), json!("first"));
+        assert_eq!(to_value(ArrayShow::Last).unwrap(), json!("last"));
+        assert_eq!(to_value(ArrayShow::None).unwrap(), json!("none"));
+    }
+
+    #[test]
+    fn test_serialize_bar_mode() {
+        assert_eq!(to_value(BarMode::Stack).unwrap(), json!("stack"));
+        assert_eq!(to_value(BarMode::Group).unwrap(), json!("group"));
+        assert_eq!(to_value(BarMode::Overlay).unwrap(), json!("overlay"));
+        assert_eq!(to_value(BarMode::Relative).unwrap(), json!("relative"));
+    }
+
+    #[test]
+    fn test_serialize_bar_norm() {
+        assert_eq!(to_value(BarNorm::Empty).unwrap(), json!(""));
+        assert_eq!(to_value(BarNorm::Fraction).unwrap(), json!("fraction"));
+        assert_eq!(to_value(BarNorm::Percent).unwrap(), json!("percent"));
+    }
+
+    #[test]
+    fn test_serialize_box_mode() {
+        assert_eq!(to_value(BoxMode::Group).unwrap(), json!("group"));
+        assert_eq!(to_value(BoxMode::Overlay).unwrap(), json!("overlay"));
+    }
+
+    #[test]
+    fn test_serialize_violin_mode() {
+        assert_eq!(to_value(ViolinMode::Group).unwrap(), json!("group"));
+        assert_eq!(to_value(ViolinMode::Overlay).unwrap(), json!("overlay"));
+    }
+
+    #[test]
+    fn test_serialize_waterfall_mode() {
+        assert_eq!(to_value(WaterfallMode::Group).unwrap(), json!("group"));
+        assert_eq!(to_value(WaterfallMode::Overlay).unwrap(), json!("overlay"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_trace_order() {
+        assert_eq!(to_value(TraceOrder::Reversed).unwrap(), json!("reversed"));
+        assert_eq!(to_value(TraceOrder::Grouped).unwrap(), json!("grouped"));
+        assert_eq!(to_value(TraceOrder::ReversedGrouped).unwrap(), json!("reversed+grouped"));
+        assert_eq!(to_value(TraceOrder::Normal).unwrap(), json!("normal"));
+    }
+
+    #[test]
+    fn test_serialize_item_sizing() {
+        assert_eq!(to_value(ItemSizing::Trace).unwrap(), json!("trace"));
+        assert_eq!(to_value(ItemSizing::Constant).unwrap(), json!("constant"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_item_click() {
+        assert_eq!(to_value(ItemClick::Toggle).unwrap(), json!("toggle"));
+        assert_eq!(to_value(ItemClick::ToggleOthers).unwrap(), json!("toggleothers"));
+        assert_eq!(to_value(ItemClick::False).unwrap(), json!(false));
+    }
+
+    #[test]
+    fn test_deserialize_item_click() {
+        assert!(matches!(
+            from_value(json!("toggle")).unwrap(),
+            ItemClick::Toggle
+        ));
+        assert!(matches!(
+            from_value(json!("toggleothers")).unwrap(),
+            ItemClick::ToggleOthers
+        ));
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            ItemClick::False
+        ));
+        assert!(from_value::<ItemClick>(json!(true)).is_err());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_group_click() {
+        assert_eq!(to_value(GroupClick::ToggleItem).unwrap(), json!("toggleitem"));
+        assert_eq!(to_value(GroupClick::ToggleGroup).unwrap(), json!("togglegroup"));
+    }
+
+    #[test]
+    fn test_serialize_legend() {
+        let legend = Legend::new()
+            .background_color("#123123")
+            .border_color("#321321")
+            .border_width(500)
+            .font(Font::new())
+            .orientation(Orientation::Vertical)
+            .trace_order(TraceOrder::Normal)
+            .trace_group_gap(10)
+            .item_sizing(ItemSizing::Trace)
+            .item_click(ItemClick::Toggle)
+            .item_double_click(ItemClick::False)
+            .x(1.0)
+            .x_anchor(Anchor::Auto)
+            .y(2.0)
+            .y_anchor(Anchor::Left)
+            .valign(VAlign::Middle)
+            .title(Title::new("title"))
+            .group_click(GroupClick::ToggleItem)
+            .item_width(50);
+
+        let expected = json!({
+            "bgcolor": "#123123",
+            "bordercolor": "#321321",
+            "borderwidth": 500,
+            "font": {},
+            "orientation": "v",
+            "traceorder": "normal",
+            "tracegroupgap": 10,
+            "itemsizing": "trace",
+            "itemclick": "toggle",
+            "itemdoubleclick": false,
+            "x": 1.0,
+            "xanchor": "auto",
+            "y": 2.0,
+            "yanchor": "left",
+            "valign": "middle",
+            "title": {"text": "title"},
+            "groupclick": "toggleitem",
+            "itemwidth": 50
+        });
+
+        assert_eq!(to_value(legend).unwrap(), expected)
+    }
+
+    #[test]
+    fn test_serialize_valign() {
+        assert_eq!(to_value(VAlign::Top).unwrap(), json!("top"));
+        assert_eq!(to_value(VAlign::Middle).unwrap(), json!("middle"));
+        assert_eq!(to_value(VAlign::Bottom).unwrap(), json!("bottom"));
+    }
+
+    #[test]
+    fn test_serialize_halign() {
+        assert_eq!(to_value(HAlign::Left).unwrap(), json!("left"));
+        assert_eq!(to_value(HAlign::Center).unwrap(), json!("center"));
+        assert_eq!(to_value(HAlign::Right).unwrap(), json!("right"));
+    }
+
+    #[test]
+    fn test_serialize_margin() {
+        let margin = Margin::new()
+            .left(1)
+            .right(2)
+            .top(3)
+            .bottom(4)
+            .pad(5)
+            .auto_expand(true);
+        let expected = json!({
+            "l": 1,
+            "r": 2,
+            "t": 3,
+            "b": 4,
+            "pad": 5,
+            "autoexpand": true,
+        });
+
+        assert_eq!(to_value(margin).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_color_scale() {
+        let layout_color_scale = LayoutColorScale::new()
+            .sequential(ColorScale::Palette(ColorScalePalette::Greys))
+            .sequential_minus(ColorScale::Palette(ColorScalePalette::Blues))
+            .diverging(ColorScale::Palette(ColorScalePalette::Hot));
+        let expected = json!({
+            "sequential": "Greys",
+            "sequentialminus": "Blues",
+            "diverging": "Hot"
+        });
+
+        assert_eq!(to_value(layout_color_scale).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_slider_range_mode() {
+        assert_eq!(to_value(SliderRangeMode::Auto).unwrap(), json!("auto"));
+        assert_eq!(to_value(SliderRangeMode::Fixed).unwrap(), json!("fixed"));
+        assert_eq!(to_value(SliderRangeMode::Match).unwrap(), json!("match"));
+    }
+
+    #[test]
+    fn test_serialize_range_slider_y_axis() {
+        let range_slider_y_axis = RangeSliderYAxis::new()
+            .range_mode(SliderRangeMode::Match)
+            .range(vec![0.2]);
+        let expected = json!({
+            "rangemode": "match",
+            "range": [0.2]
+        });
+
+        assert_eq!(to_value(range_slider_y_axis).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_range_slider() {
+        let range_slider = RangeSlider::new()
+            .background_color("#123ABC")
+            .border_color("#ABC123")
+            .border_width(1000)
+            .auto_range(false)
+            .range(vec![5_i32])
+            .thickness(2000.)
+            .visible(true)
+            .y_axis(RangeSliderYAxis::new());
+
+        let expected = json!({
+            "bgcolor": "#123ABC",
+            "bordercolor": "#ABC123",
+            "borderwidth": 1000,
+            "autorange": false,
+            "range": [5],
+            "thickness": 2000.0,
+            "visible": true,
+            "yaxis": {}
+        });
+
+        assert_eq!(to_value(range_slider).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_selector_step() {
+        assert_eq!(to_value(SelectorStep::Month).unwrap(), json!("month"));
+        assert_eq!(to_value(SelectorStep::Year).unwrap(), json!("year"));
+        assert_eq!(to_value(SelectorStep::Day).unwrap(), json!("day"));
+        assert_eq!(to_value(SelectorStep::Hour).unwrap(), json!("hour"));
+        assert_eq!(to_value(SelectorStep::Minute).unwrap(), json!("minute"));
+        assert_eq!(to_value(SelectorStep::Second).unwrap(), json!("second"));
+        assert_eq!(to_value(SelectorStep::All).unwrap(), json!("all"));
+    }
+
+    #[test]
+    fn test_serialize_step_mode() {
+        assert_eq!(to_value(StepMode::Backward).unwrap(), json!("backward"));
+        assert_eq!(to_value(StepMode::ToDate).unwrap(), json!("todate"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_spike_mode() {
+        assert_eq!(to_value(SpikeMode::ToAxis).unwrap(), json!("toaxis"));
+        assert_eq!(to_value(SpikeMode::Across).unwrap(), json!("across"));
+        assert_eq!(to_value(SpikeMode::Marker).unwrap(), json!("marker"));
+        assert_eq!(to_value(SpikeMode::ToaxisAcross).unwrap(), json!("toaxis+across"));
+        assert_eq!(to_value(SpikeMode::ToAxisMarker).unwrap(), json!("toaxis+marker"));
+        assert_eq!(to_value(SpikeMode::AcrossMarker).unwrap(), json!("across+marker"));
+        assert_eq!(to_value(SpikeMode::ToaxisAcrossMarker).unwrap(), json!("toaxis+across+marker"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_spike_snap() {
+        assert_eq!(to_value(SpikeSnap::Data).unwrap(), json!("data"));
+        assert_eq!(to_value(SpikeSnap::Cursor).unwrap(), json!("cursor"));
+        assert_eq!(to_value(SpikeSnap::HoveredData).unwrap(), json!("hovered data"));
+    }
+
+    #[test]
+    fn test_serialize_selector_button() {
+        let selector_button = SelectorButton::new()
+            .visible(false)
+            .step(SelectorStep::Hour)
+            .step_mode(StepMode::ToDate)
+            .count(42)
+            .label("label")
+            .name("name")
+            .template_item_name("something");
+
+        let expected = json!({
+            "visible": false,
+            "step": "hour",
+            "stepmode": "todate",
+            "count": 42,
+            "label": "label",
+            "name": "name",
+            "templateitemname": "something",
+        });
+
+        assert_eq!(to_value(selector_button).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_range_selector() {
+        let range_selector = RangeSelector::new()
+            .visible(true)
+            .buttons(vec![SelectorButton::new()])
+            .x(2.0)
+            .x_anchor(Anchor::Middle)
+            .y(4.0)
+            .y_anchor(Anchor::Top)
+            .font(Font::new())
+            .background_color("#123ABC")
+            .border_color("#ABC123")
+            .border_width(1000)
+            .active_color("#888999");
+
+        let expected = json!({
+            "visible": true,
+            "buttons": [{}],
+            "x": 2.0,
+            "xanchor": "middle",
+            "y": 4.0,
+            "yanchor": "top",
+            "font": {},
+            "bgcolor": "#123ABC",
+            "bordercolor": "#ABC123",
+            "borderwidth": 1000,
+            "activecolor": "#888999",
+        });
+
+        assert_eq!(to_value(range_selector).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_range_break_pattern() {
+        assert_eq!(
+            to_value(RangeBreakPattern::DayOfWeek).unwrap(),
+            json!("day of week")
+        );
+        assert_eq!(to_value(RangeBreakPattern::Hour).unwrap(), json!("hour"));
+        assert_eq!(to_value(RangeBreakPattern::None).unwrap(), json!(""));
+    }
+
+    #[test]
+    fn test_serialize_range_break() {
+        let range_break = RangeBreak::new()
+            .enabled(true)
+            .bounds(vec![17, 9])
+            .pattern(RangeBreakPattern::Hour)
+            .values(vec!["2020-12-25"])
+            .dvalue(3_600_000.0)
+            .name("name")
+            .template_item_name("something");
+
+        let expected = json!({
+            "enabled": true,
+            "bounds": [17, 9],
+            "pattern": "hour",
+            "values": ["2020-12-25"],
+            "dvalue": 3_600_000.0,
+            "name": "name",
+            "templateitemname": "something",
+        });
+
+        assert_eq!(to_value(range_break).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_range_breaks_from_daily_gaps() {
+        // Thursday to Wednesday, with the Monday being a holiday.
+        let x = [
+            "2017-01-12",
+            "2017-01-13",
+            "2017-01-17",
+            "2017-01-18",
+            "not a date",
+        ];
+        let breaks = RangeBreak::from_gaps(&x[..4]);
+        let expected = json!([
+            {"bounds": ["sat", "mon"], "pattern": "day of week"},
+            {"values": ["2017-01-16"], "dvalue": 86_400_000.0},
+        ]);
+        assert_eq!(to_value(breaks).unwrap(), expected);
+
+        // Other values are ignored, and the dates need neither be sorted nor unique.
+        let breaks = RangeBreak::from_gaps(&[x[3], x[4], x[0], x[2], x[1], x[2]]);
+        assert_eq!(to_value(breaks).unwrap(), expected);
+
+        // With data on a weekend, weekends are not left out as such.
+        let breaks = RangeBreak::from_gaps(&["2017-01-13", "2017-01-14", "2017-01-17"]);
+        let expected = json!([{"values": ["2017-01-15"], "dvalue": 172_800_000.0}]);
+        assert_eq!(to_value(breaks).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_range_breaks_from_years_of_daily_gaps() {
+        // Every weekday of 2015 to 2017, but for one holiday a month.
+        let mut x = Vec::new();
+        for day in days_from_civil(2015, 1, 1)..days_from_civil(2018, 1, 1) {
+            let (_, _, day_of_month) = civil_from_days(day);
+            if !is_weekend(day) && day_of_month != 10 {
+                x.push(format_date_millis(day * MILLIS_PER_DAY));
+            }
+        }
+        let breaks = to_value(RangeBreak::from_gaps(&x)).unwrap();
+
+        assert_eq!(breaks.as_array().unwrap().len(), 2);
+        assert_eq!(
+            breaks[0],
+            json!({"bounds": ["sat", "mon"], "pattern": "day of week"})
+        );
+        assert_eq!(breaks[1]["dvalue"], json!(86_400_000.0));
+        // The 10th falls on a weekend in 11 of the 36 months.
+        assert_eq!(breaks[1]["values"].as_array().unwrap().len(), 25);
+        assert_eq!(breaks[1]["values"][0], json!("2015-02-10"));
+    }
+
+    #[test]
+    fn test_range_breaks_from_intraday_gaps() {
+        // Trading from 9:00 to 16:00, with an extra print at 15:59 on the Friday.
+        let mut x = Vec::new();
+        for day in &["2020-02-27", "2020-02-28", "2020-03-02"] {
+            for hour in 9..=16 {
+                x.push(format!("{} {:02}:00", day, hour));
+            }
+        }
+        x.push("2020-02-28T15:59:00".to_string());
+        let breaks = RangeBreak::from_gaps(&x);
+        let expected = json!([
+            {"bounds": ["sat", "mon"], "pattern": "day of week"},
+            {"values": ["2020-02-28 17:00:00"], "dvalue": 25_200_000.0},
+            {"values": ["2020-03-02"], "dvalue": 32_400_000.0},
+            {"values": ["2020-02-27 17:00:00"], "dvalue": 57_600_000.0},
+        ]);
+        assert_eq!(to_value(breaks).unwrap(), expected);
+
+        assert!(RangeBreak::from_gaps(&["2020-02-28"]).is_empty());
+        assert!(RangeBreak::from_gaps::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_and_format_dates() {
+        assert_eq!(parse_date_millis("1970-01-01"), Some(0));
+        assert_eq!(parse_date_millis("1969-12-31 23:59:59.9"), Some(-100));
+        assert_eq!(parse_date_millis("2000-02-29"), Some(951_782_400_000));
+        assert_eq!(parse_date_millis("2000-02-29T12:30"), Some(951_827_400_000));
+        assert_eq!(parse_date_millis("2000-2-29"), None);
+        assert_eq!(parse_date_millis("2000-13-01"), None);
+        assert_eq!(parse_date_millis("2021-02-31"), None);
+        assert_eq!(parse_date_millis("2021-02-29"), None);
+        assert_eq!(parse_date_millis("2021-04-31"), None);
+        assert_eq!(parse_date_millis("2000-01-00"), None);
+        assert_eq!(parse_date_millis("2000-01-01 24:00"), None);
+        assert_eq!(parse_date_millis("2000-01-01 12"), None);
+        assert_eq!(parse_date_millis("2000-01-01 12:00."), None);
+        assert_eq!(parse_date_millis("sat"), None);
+
+        assert_eq!(format_date_millis(0), "1970-01-01");
+        assert_eq!(format_date_millis(-100), "1969-12-31 23:59:59.900");
+        assert_eq!(format_date_millis(951_827_400_000), "2000-02-29 12:30:00");
+        for date in &["1600-03-01", "1900-02-28", "2016-12-31", "2400-02-29"] {
+            assert_eq!(format_date_millis(parse_date_millis(date).unwrap()), *date);
+        }
+    }
+
+    #[test]
+    fn test_serialize_color_axis() {
+        let color_axis = ColorAxis::new()
+            .auto_color_scale(false)
+            .cauto(true)
+            .cmax(1.0)
+            .cmid(0.5)
+            .cmin(0.0)
+            .color_bar(ColorBar::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Greens))
+            .reverse_scale(false)
+            .show_scale(true);
+
+        let expected = json!({
+            "autocolorscale": false,
+            "cauto": true,
+            "cmin": 0.0,
+            "cmid": 0.5,
+            "cmax": 1.0,
+            "colorbar": {},
+            "colorscale": "Greens",
+            "reversescale": false,
+            "showscale": true,
+        });
+
+        assert_eq!(to_value(color_axis).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_axis() {
+        let axis = Axis::new()
+            .visible(false)
+            .color("#678123")
+            .title(Title::new("title"))
+            .type_(AxisType::Date)
+            .auto_range(false)
+            .range_mode(RangeMode::NonNegative)
+            .range(vec![2.0])
+            .fixed_range(true)
+            .constrain(AxisConstrain::Range)
+            .constrain_toward(ConstrainDirection::Middle)
+            .tick_mode(TickMode::Auto)
+            .n_ticks(600)
+            .tick0(5.0)
+            .dtick(10.0)
+            .matches(true)
+            .tick_values(vec![1.0, 2.0])
+            .tick_text(vec!["one".to_string(), "two".to_string()])
+            .ticks(TicksDirection::Inside)
+            .ticks_on(TicksPosition::Boundaries)
+            .mirror(false)
+            .tick_length(77)
+            .tick_width(99)
+            .tick_color("#101010")
+            .show_tick_labels(false)
+            .auto_margin(true)
+            .show_spikes(false)
+            .spike_color("#ABABAB")
+            .spike_thickness(501)
+            .spike_dash(DashType::DashDot)
+            .spike_mode(SpikeMode::AcrossMarker)
+            .spike_snap(SpikeSnap::Data)
+            .tick_font(Font::new())
+            .tick_angle(2.1)
+            .tick_prefix("prefix")
+            .show_tick_prefix(ArrayShow::Last)
+            .tick_suffix("suffix")
+            .show_tick_suffix(ArrayShow::None)
+            .show_exponent(ArrayShow::All)
+            .exponent_format(ExponentFormat::SmallE)
+            .separate_thousands(false)
+            .tick_format("tickfmt")
+            .tick_format_stops(vec![TickFormatStop::new()])
+            .hover_format("hoverfmt")
+            .show_line(true)
+            .line_color("#CCCDDD")
+            .line_width(9)
+            .show_grid(false)
+            .grid_color("#fff000")
+            .grid_width(8)
+            .zero_line(true)
+            .zero_line_color("#f0f0f0")
+            .zero_line_width(7)
+            .show_dividers(false)
+            .divider_color("#AFAFAF")
+            .divider_width(55)
+            .anchor("anchor")
+            .side(AxisSide::Right)
+            .overlaying("overlaying")
+            .domain(&[0.0, 1.0])
+            .position(0.6)
+            .range_slider(RangeSlider::new())
+            .range_selector(RangeSelector::new())
+            .range_breaks(vec![RangeBreak::new()])
+            .calendar(Calendar::Coptic)
+            .show_background(true)
+            .background_color("#ffffff");
+
+        let expected = json!({
+            "visible": false,
+            "color": "#678123",
+            "title": {"text": "title"},
+            "type": "date",
+            "autorange": false,
+            "rangemode": "nonnegative",
+            "range": [2.0],
+            "fixedrange": true,
+            "constrain": "range",
+            "constraintoward": "middle",
+            "tickmode": "auto",
+            "nticks": 600,
+            "tick0": 5.0,
+            "dtick": 10.0,
+            "matches": "x",
+            "tickvals": [1.0, 2.0],
+            "ticktext": ["one", "two"],
+            "ticks": "inside",
+            "tickson": "boundaries",
+            "mirror": false,
+            "ticklen": 77,
+            "tickwidth": 99,
+            "tickcolor": "#101010",
+            "showticklabels": false,
+            "automargin": true,
+            "showspikes": false,
+            "spikecolor": "#ABABAB",
+            "spikethickness": 501,
+            "spikedash": "dashdot",
+            "spikemode": "across+marker",
+            "spikesnap": "data",
+            "tickfont": {},
+            "tickangle": 2.1,
+            "tickprefix": "prefix",
+            "showtickprefix": "last",
+            "ticksuffix": "suffix",
+            "showticksuffix": "none",
+            "showexponent": "all",
+            "exponentformat": "e",
+            "separatethousands": false,
+            "tickformat": "tickfmt",
+            "tickformatstops": [{"enabled": true}],
+            "hoverformat": "hoverfmt",
+            "showline": true,
+            "linecolor": "#CCCDDD",
+            "linewidth": 9,
+            "showgrid": false,
+            "gridcolor": "#fff000",
+            "gridwidth": 8,
+            "zeroline": true,
+            "zerolinecolor": "#f0f0f0",
+            "zerolinewidth": 7,
+            "showdividers": false,
+            "dividercolor": "#AFAFAF",
+            "dividerwidth": 55,
+            "anchor": "anchor",
+            "side": "right",
+            "overlaying": "overlaying",
+            "domain": [0.0, 1.0],
+            "position": 0.6,
+            "rangeslider": {},
+            "rangeselector": {},
+            "rangebreaks": [{}],
+            "calendar": "coptic",
+            "showbackground": true,
+            "backgroundcolor": "#ffffff",
+        });
+
+        assert_eq!(to_value(axis).unwrap(), expected);
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_row_order() {
+        assert_eq!(to_value(RowOrder::TopToBottom).unwrap(), json!("top to bottom"));
+        assert_eq!(to_value(RowOrder::BottomToTop).unwrap(), json!("bottom to top"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_grid_pattern() {
+        assert_eq!(to_value(GridPattern::Independent).unwrap(), json!("independent"));
+        assert_eq!(to_value(GridPattern::Coupled).unwrap(), json!("coupled"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_grid_x_side() {
+        assert_eq!(to_value(GridXSide::Bottom).unwrap(), json!("bottom"));
+        assert_eq!(to_value(GridXSide::BottomPlot).unwrap(), json!("bottom plot"));
+        assert_eq!(to_value(GridXSide::Top).unwrap(), json!("top"));
+        assert_eq!(to_value(GridXSide::TopPlot).unwrap(), json!("top plot"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_grid_y_side() {
+        assert_eq!(to_value(GridYSide::Left).unwrap(), json!("left"));
+        assert_eq!(to_value(GridYSide::LeftPlot).unwrap(), json!("left plot"));
+        assert_eq!(to_value(GridYSide::Right).unwrap(), json!("right"));
+        assert_eq!(to_value(GridYSide::RightPlot).unwrap(), json!("right plot"));
+    }
+
+    #[test]
+    fn test_serialize_grid_domain() {
+        let grid_domain = GridDomain::new().x(vec![0.0]).y(vec![1.0]);
+        let expected = json!({
+            "x": [0.0],
+            "y": [1.0]
+        });
+
+        assert_eq!(to_value(grid_domain).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_grid() {
+        let layout_grid = LayoutGrid::new()
+            .rows(224)
+            .row_order(RowOrder::BottomToTop)
+            .columns(501)
+            .sub_plots(vec!["subplots".to_string()])
+            .x_axes(vec!["xaxes".to_string()])
+            .y_axes(vec!["yaxes".to_string()])
+            .pattern(GridPattern::Coupled)
+            .x_gap(2.2)
+            .y_gap(4.4)
+            .domain(GridDomain::new())
+            .x_side(GridXSide::Top)
+            .y_side(GridYSide::Right);
+
+        let expected = json!({
+            "rows": 224,
+            "roworder": "bottom to top",
+            "columns": 501,
+            "subplots": ["subplots"],
+            "xaxes": ["xaxes"],
+            "yaxes": ["yaxes"],
+            "pattern": "coupled",
+            "xgap": 2.2,
+            "ygap": 4.4,
+            "domain": {},
+            "xside": "top",
+            "yside": "right",
+        });
 
-    #[test]
-    fn test_serialize_axis_constrain() {
-        assert_eq!(to_value(AxisConstrain::Range).unwrap(), json!("range"));
-        assert_eq!(to_value(AxisConstrain::Domain).unwrap(), json!("domain"));
+        assert_eq!(to_value(layout_grid).unwrap(), expected);
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn test_serialize_constrain_direction() {
-        assert_eq!(to_value(ConstrainDirection::Left).unwrap(), json!("left"));
-        assert_eq!(to_value(ConstrainDirection::Center).unwrap(), json!("center"));
-        assert_eq!(to_value(ConstrainDirection::Right).unwrap(), json!("right"));
-        assert_eq!(to_value(ConstrainDirection::Top).unwrap(), json!("top"));
-        assert_eq!(to_value(ConstrainDirection::Middle).unwrap(), json!("middle"));
-        assert_eq!(to_value(ConstrainDirection::Bottom).unwrap(), json!("bottom"));
+    fn test_serialize_aspect_mode() {
+        assert_eq!(to_value(AspectMode::Auto).unwrap(), json!("auto"));
+        assert_eq!(to_value(AspectMode::Cube).unwrap(), json!("cube"));
+        assert_eq!(to_value(AspectMode::Data).unwrap(), json!("data"));
+        assert_eq!(to_value(AspectMode::Manual).unwrap(), json!("manual"));
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn test_serialize_range_mode() {
-        assert_eq!(to_value(RangeMode::Normal).unwrap(), json!("normal"));
-        assert_eq!(to_value(RangeMode::ToZero).unwrap(), json!("tozero"));
-        assert_eq!(to_value(RangeMode::NonNegative).unwrap(), json!("nonnegative"));
-    }
+    fn test_serialize_aspect_ratio() {
+        let aspect_ratio = AspectRatio::new().x(1.0).y(2.0).z(0.5);
+        let expected = json!({"x": 1.0, "y": 2.0, "z": 0.5});
 
-    #[test]
-    fn test_serialize_ticks_direction() {
-        assert_eq!(to_value(TicksDirection::Outside).unwrap(), json!("outside"));
-        assert_eq!(to_value(TicksDirection::Inside).unwrap(), json!("inside"));
+        assert_eq!(to_value(aspect_ratio).unwrap(), expected);
     }
 
     #[test]
     #[rustfmt::skip]
-    fn test_serialize_ticks_position() {
-        assert_eq!(to_value(TicksPosition::Labels).unwrap(), json!("labels"));
-        assert_eq!(to_value(TicksPosition::Boundaries).unwrap(), json!("boundaries"));
-    }
-
-    #[test]
-    fn test_serialize_array_show() {
-        assert_eq!(to_value(ArrayShow::All).unwrap(), json!("all"));
-        assert_eq!(to_value(ArrayShow::First).unwrap(), json!("first"));
-        assert_eq!(to_value(ArrayShow::Last).unwrap(), json!("last"));
-        assert_eq!(to_value(ArrayShow::None).unwrap(), json!("none"));
+    fn test_serialize_camera_projection_type() {
+        assert_eq!(to_value(CameraProjectionType::Perspective).unwrap(), json!("perspective"));
+        assert_eq!(to_value(CameraProjectionType::Orthographic).unwrap(), json!("orthographic"));
     }
 
     #[test]
-    fn test_serialize_bar_mode() {
-        assert_eq!(to_value(BarMode::Stack).unwrap(), json!("stack"));
-        assert_eq!(to_value(BarMode::Group).unwrap(), json!("group"));
-        assert_eq!(to_value(BarMode::Overlay).unwrap(), json!("overlay"));
-        assert_eq!(to_value(BarMode::Relative).unwrap(), json!("relative"));
-    }
+    fn test_serialize_camera() {
+        let camera = Camera::new()
+            .center(CameraCenter::new().x(0.0).y(0.1).z(0.2))
+            .eye(Eye::new().x(1.25).y(1.5).z(1.75))
+            .up(Up::new().x(0.0).y(0.0).z(1.0))
+            .projection(
+                CameraProjection::new().projection_type(CameraProjectionType::Orthographic),
+            );
 
-    #[test]
-    fn test_serialize_bar_norm() {
-        assert_eq!(to_value(BarNorm::Empty).unwrap(), json!(""));
-        assert_eq!(to_value(BarNorm::Fraction).unwrap(), json!("fraction"));
-        assert_eq!(to_value(BarNorm::Percent).unwrap(), json!("percent"));
-    }
+        let expected = json!({
+            "center": {"x": 0.0, "y": 0.1, "z": 0.2},
+            "eye": {"x": 1.25, "y": 1.5, "z": 1.75},
+            "up": {"x": 0.0, "y": 0.0, "z": 1.0},
+            "projection": {"type": "orthographic"}
+        });
 
-    #[test]
-    fn test_serialize_box_mode() {
-        assert_eq!(to_value(BoxMode::Group).unwrap(), json!("group"));
-        assert_eq!(to_value(BoxMode::Overlay).unwrap(), json!("overlay"));
+        assert_eq!(to_value(camera).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_violin_mode() {
-        assert_eq!(to_value(ViolinMode::Group).unwrap(), json!("group"));
-        assert_eq!(to_value(ViolinMode::Overlay).unwrap(), json!("overlay"));
-    }
+    fn test_serialize_layout_scene() {
+        let scene = LayoutScene::new()
+            .domain(Domain::new().x(&[0.5, 1.0]))
+            .background_color("#123456")
+            .camera(Camera::new())
+            .aspect_mode(AspectMode::Manual)
+            .aspect_ratio(AspectRatio::new().z(0.5))
+            .x_axis(Axis::new())
+            .y_axis(Axis::new())
+            .z_axis(
+                Axis::new()
+                    .show_background(true)
+                    .background_color("#abcdef"),
+            )
+            .drag_mode(DragMode::Turntable)
+            .hover_mode(HoverMode::Closest)
+            .annotations(vec![Annotation::new().x(1).y(2).z(3)]);
 
-    #[test]
-    fn test_serialize_waterfall_mode() {
-        assert_eq!(to_value(WaterfallMode::Group).unwrap(), json!("group"));
-        assert_eq!(to_value(WaterfallMode::Overlay).unwrap(), json!("overlay"));
-    }
+        let expected = json!({
+            "domain": {"x": [0.5, 1.0]},
+            "bgcolor": "#123456",
+            "camera": {},
+            "aspectmode": "manual",
+            "aspectratio": {"z": 0.5},
+            "xaxis": {},
+            "yaxis": {},
+            "zaxis": {"showbackground": true, "backgroundcolor": "#abcdef"},
+            "dragmode": "turntable",
+            "hovermode": "closest",
+            "annotations": [{"x": 1, "y": 2, "z": 3}]
+        });
 
-    #[test]
-    #[rustfmt::skip]
-    fn test_serialize_trace_order() {
-        assert_eq!(to_value(TraceOrder::Reversed).unwrap(), json!("reversed"));
-        assert_eq!(to_value(TraceOrder::Grouped).unwrap(), json!("grouped"));
-        assert_eq!(to_value(TraceOrder::ReversedGrouped).unwrap(), json!("reversed+grouped"));
-        assert_eq!(to_value(TraceOrder::Normal).unwrap(), json!("normal"));
+        assert_eq!(to_value(scene).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_item_sizing() {
-        assert_eq!(to_value(ItemSizing::Trace).unwrap(), json!("trace"));
-        assert_eq!(to_value(ItemSizing::Constant).unwrap(), json!("constant"));
-    }
+    fn test_serialize_ternary_axis() {
+        let axis = TernaryAxis::new()
+            .color("#000001")
+            .title(Title::new("a"))
+            .tick_mode(TickMode::Linear)
+            .n_ticks(5)
+            .tick0(0.0)
+            .dtick(0.2)
+            .tick_values(vec![0.2, 0.4])
+            .tick_text(vec!["0.2".to_string(), "0.4".to_string()])
+            .ticks(TicksDirection::Outside)
+            .tick_length(5)
+            .tick_width(1)
+            .tick_color("#000002")
+            .show_tick_labels(true)
+            .tick_font(Font::new())
+            .tick_angle(45.0)
+            .tick_prefix("prefix")
+            .show_tick_prefix(ArrayShow::First)
+            .tick_suffix("suffix")
+            .show_tick_suffix(ArrayShow::Last)
+            .show_exponent(ArrayShow::All)
+            .exponent_format(ExponentFormat::SmallE)
+            .separate_thousands(false)
+            .tick_format("tick_format")
+            .tick_format_stops(vec![TickFormatStop::new()])
+            .hover_format("hover_format")
+            .show_line(true)
+            .line_color("#000003")
+            .line_width(2)
+            .show_grid(false)
+            .grid_color("#000004")
+            .grid_width(3)
+            .min(0.1);
 
-    #[test]
-    #[rustfmt::skip]
-    fn test_serialize_item_click() {
-        assert_eq!(to_value(ItemClick::Toggle).unwrap(), json!("toggle"));
-        assert_eq!(to_value(ItemClick::ToggleOthers).unwrap(), json!("toggleothers"));
-        assert_eq!(to_value(ItemClick::False).unwrap(), json!(false));
-    }
+        let expected = json!({
+            "color": "#000001",
+            "title": {"text": "a"},
+            "tickmode": "linear",
+            "nticks": 5,
+            "tick0": 0.0,
+            "dtick": 0.2,
+            "tickvals": [0.2, 0.4],
+            "ticktext": ["0.2", "0.4"],
+            "ticks": "outside",
+            "ticklen": 5,
+            "tickwidth": 1,
+            "tickcolor": "#000002",
+            "showticklabels": true,
+            "tickfont": {},
+            "tickangle": 45.0,
+            "tickprefix": "prefix",
+            "showtickprefix": "first",
+            "ticksuffix": "suffix",
+            "showticksuffix": "last",
+            "showexponent": "all",
+            "exponentformat": "e",
+            "separatethousands": false,
+            "tickformat": "tick_format",
+            "tickformatstops": [{"enabled": true}],
+            "hoverformat": "hover_format",
+            "showline": true,
+            "linecolor": "#000003",
+            "linewidth": 2,
+            "showgrid": false,
+            "gridcolor": "#000004",
+            "gridwidth": 3,
+            "min": 0.1
+        });
 
-    #[test]
-    #[rustfmt::skip]
-    fn test_serialize_group_click() {
-        assert_eq!(to_value(GroupClick::ToggleItem).unwrap(), json!("toggleitem"));
-        assert_eq!(to_value(GroupClick::ToggleGroup).unwrap(), json!("togglegroup"));
+        assert_eq!(to_value(axis).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_legend() {
-        let legend = Legend::new()
-            .background_color("#123123")
-            .border_color("#321321")
-            .border_width(500)
-            .font(Font::new())
-            .orientation(Orientation::Vertical)
-            .trace_order(TraceOrder::Normal)
-            .trace_group_gap(10)
-            .item_sizing(ItemSizing::Trace)
-            .item_click(ItemClick::Toggle)
-            .item_double_click(ItemClick::False)
-            .x(1.0)
-            .x_anchor(Anchor::Auto)
-            .y(2.0)
-            .y_anchor(Anchor::Left)
-            .valign(VAlign::Middle)
-            .title(Title::new("title"))
-            .group_click(GroupClick::ToggleItem)
-            .item_width(50);
+    fn test_serialize_layout_ternary() {
+        let ternary = LayoutTernary::new()
+            .domain(Domain::new().x(&[0.0, 0.5]))
+            .sum(100.0)
+            .background_color("#123456")
+            .a_axis(TernaryAxis::new())
+            .b_axis(TernaryAxis::new())
+            .c_axis(TernaryAxis::new());
 
         let expected = json!({
-            "bgcolor": "#123123",
-            "bordercolor": "#321321",
-            "borderwidth": 500,
-            "font": {},
-            "orientation": "v",
-            "traceorder": "normal",
-            "tracegroupgap": 10,
-            "itemsizing": "trace",
-            "itemclick": "toggle",
-            "itemdoubleclick": false,
-            "x": 1.0,
-            "xanchor": "auto",
-            "y": 2.0,
-            "yanchor": "left",
-            "valign": "middle",
-            "title": {"text": "title"},
-            "groupclick": "toggleitem",
-            "itemwidth": 50
+            "domain": {"x": [0.0, 0.5]},
+            "sum": 100.0,
+            "bgcolor": "#123456",
+            "aaxis": {},
+            "baxis": {},
+            "caxis": {}
         });
 
-        assert_eq!(to_value(legend).unwrap(), expected)
+        assert_eq!(to_value(ternary).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_valign() {
-        assert_eq!(to_value(VAlign::Top).unwrap(), json!("top"));
-        assert_eq!(to_value(VAlign::Middle).unwrap(), json!("middle"));
-        assert_eq!(to_value(VAlign::Bottom).unwrap(), json!("bottom"));
+    #[rustfmt::skip]
+    fn test_serialize_polar_direction() {
+        assert_eq!(to_value(PolarDirection::Clockwise).unwrap(), json!("clockwise"));
+        assert_eq!(to_value(PolarDirection::CounterClockwise).unwrap(), json!("counterclockwise"));
     }
 
     #[test]
-    fn test_serialize_halign() {
-        assert_eq!(to_value(HAlign::Left).unwrap(), json!("left"));
-        assert_eq!(to_value(HAlign::Center).unwrap(), json!("center"));
-        assert_eq!(to_value(HAlign::Right).unwrap(), json!("right"));
+    fn test_serialize_theta_unit() {
+        assert_eq!(to_value(ThetaUnit::Radians).unwrap(), json!("radians"));
+        assert_eq!(to_value(ThetaUnit::Degrees).unwrap(), json!("degrees"));
     }
 
     #[test]
-    fn test_serialize_margin() {
-        let margin = Margin::new()
-            .left(1)
-            .right(2)
-            .top(3)
-            .bottom(4)
-            .pad(5)
-            .auto_expand(true);
-        let expected = json!({
-            "l": 1,
-            "r": 2,
-            "t": 3,
-            "b": 4,
-            "pad": 5,
-            "autoexpand": true,
-        });
+    fn test_serialize_axis_layer() {
+        assert_eq!(
+            to_value(AxisLayer::AboveTraces).unwrap(),
+            json!("above traces")
+        );
+        assert_eq!(
+            to_value(AxisLayer::BelowTraces).unwrap(),
+            json!("below traces")
+        );
+    }
 
-        assert_eq!(to_value(margin).unwrap(), expected);
+    #[test]
+    fn test_serialize_polar_grid_shape() {
+        assert_eq!(
+            to_value(PolarGridShape::Circular).unwrap(),
+            json!("circular")
+        );
+        assert_eq!(to_value(PolarGridShape::Linear).unwrap(), json!("linear"));
     }
 
     #[test]
-    fn test_serialize_layout_color_scale() {
-        let layout_color_scale = LayoutColorScale::new()
-            .sequential(ColorScale::Palette(ColorScalePalette::Greys))
-            .sequential_minus(ColorScale::Palette(ColorScalePalette::Blues))
-            .diverging(ColorScale::Palette(ColorScalePalette::Hot));
+    fn test_serialize_radial_axis() {
+        let axis = RadialAxis::new()
+            .visible(true)
+            .color("#000001")
+            .title(Title::new("a"))
+            .type_(AxisType::Linear)
+            .auto_range(false)
+            .range_mode(RangeMode::ToZero)
+            .range(vec![0.0, 10.0])
+            .angle(45.0)
+            .side(PolarDirection::Clockwise)
+            .tick_mode(TickMode::Linear)
+            .n_ticks(5)
+            .tick0(0.0)
+            .dtick(0.2)
+            .tick_values(vec![0.2, 0.4])
+            .tick_text(vec!["0.2".to_string(), "0.4".to_string()])
+            .ticks(TicksDirection::Outside)
+            .tick_length(5)
+            .tick_width(1)
+            .tick_color("#000002")
+            .show_tick_labels(true)
+            .tick_font(Font::new())
+            .tick_angle(45.0)
+            .tick_prefix("prefix")
+            .show_tick_prefix(ArrayShow::First)
+            .tick_suffix("suffix")
+            .show_tick_suffix(ArrayShow::Last)
+            .show_exponent(ArrayShow::All)
+            .exponent_format(ExponentFormat::SmallE)
+            .separate_thousands(false)
+            .tick_format("tick_format")
+            .tick_format_stops(vec![TickFormatStop::new()])
+            .hover_format("hover_format")
+            .show_line(true)
+            .line_color("#000003")
+            .line_width(2)
+            .show_grid(false)
+            .grid_color("#000004")
+            .grid_width(3)
+            .layer(AxisLayer::BelowTraces);
+
         let expected = json!({
-            "sequential": "Greys",
-            "sequentialminus": "Blues",
-            "diverging": "Hot"
+            "visible": true,
+            "color": "#000001",
+            "title": {"text": "a"},
+            "type": "linear",
+            "autorange": false,
+            "rangemode": "tozero",
+            "range": [0.0, 10.0],
+            "angle": 45.0,
+            "side": "clockwise",
+            "tickmode": "linear",
+            "nticks": 5,
+            "tick0": 0.0,
+            "dtick": 0.2,
+            "tickvals": [0.2, 0.4],
+            "ticktext": ["0.2", "0.4"],
+            "ticks": "outside",
+            "ticklen": 5,
+            "tickwidth": 1,
+            "tickcolor": "#000002",
+            "showticklabels": true,
+            "tickfont": {},
+            "tickangle": 45.0,
+            "tickprefix": "prefix",
+            "showtickprefix": "first",
+            "ticksuffix": "suffix",
+            "showticksuffix": "last",
+            "showexponent": "all",
+            "exponentformat": "e",
+            "separatethousands": false,
+            "tickformat": "tick_format",
+            "tickformatstops": [{"enabled": true}],
+            "hoverformat": "hover_format",
+            "showline": true,
+            "linecolor": "#000003",
+            "linewidth": 2,
+            "showgrid": false,
+            "gridcolor": "#000004",
+            "gridwidth": 3,
+            "layer": "below traces"
         });
 
-        assert_eq!(to_value(layout_color_scale).unwrap(), expected);
+        assert_eq!(to_value(axis).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_slider_range_mode() {
-        assert_eq!(to_value(SliderRangeMode::Auto).unwrap(), json!("auto"));
-        assert_eq!(to_value(SliderRangeMode::Fixed).unwrap(), json!("fixed"));
-        assert_eq!(to_value(SliderRangeMode::Match).unwrap(), json!("match"));
-    }
+    fn test_serialize_angular_axis() {
+        let axis = AngularAxis::new()
+            .visible(true)
+            .color("#000001")
+            .type_(AxisType::Linear)
+            .theta_unit(ThetaUnit::Radians)
+            .period(12.0)
+            .direction(PolarDirection::Clockwise)
+            .rotation(90.0)
+            .tick_mode(TickMode::Linear)
+            .n_ticks(5)
+            .tick0(0.0)
+            .dtick(0.2)
+            .tick_values(vec![0.2, 0.4])
+            .tick_text(vec!["0.2".to_string(), "0.4".to_string()])
+            .ticks(TicksDirection::Outside)
+            .tick_length(5)
+            .tick_width(1)
+            .tick_color("#000002")
+            .show_tick_labels(true)
+            .tick_font(Font::new())
+            .tick_angle(45.0)
+            .tick_prefix("prefix")
+            .show_tick_prefix(ArrayShow::First)
+            .tick_suffix("suffix")
+            .show_tick_suffix(ArrayShow::Last)
+            .show_exponent(ArrayShow::All)
+            .exponent_format(ExponentFormat::SmallE)
+            .separate_thousands(false)
+            .tick_format("tick_format")
+            .tick_format_stops(vec![TickFormatStop::new()])
+            .hover_format("hover_format")
+            .show_line(true)
+            .line_color("#000003")
+            .line_width(2)
+            .show_grid(false)
+            .grid_color("#000004")
+            .grid_width(3)
+            .layer(AxisLayer::BelowTraces);
 
-    #[test]
-    fn test_serialize_range_slider_y_axis() {
-        let range_slider_y_axis = RangeSliderYAxis::new()
-            .range_mode(SliderRangeMode::Match)
-            .range(vec![0.2]);
         let expected = json!({
-            "rangemode": "match",
-            "range": [0.2]
+            "visible": true,
+            "color": "#000001",
+            "type": "linear",
+            "thetaunit": "radians",
+            "period": 12.0,
+            "direction": "clockwise",
+            "rotation": 90.0,
+            "tickmode": "linear",
+            "nticks": 5,
+            "tick0": 0.0,
+            "dtick": 0.2,
+            "tickvals": [0.2, 0.4],
+            "ticktext": ["0.2", "0.4"],
+            "ticks": "outside",
+            "ticklen": 5,
+            "tickwidth": 1,
+            "tickcolor": "#000002",
+            "showticklabels": true,
+            "tickfont": {},
+            "tickangle": 45.0,
+            "tickprefix": "prefix",
+            "showtickprefix": "first",
+            "ticksuffix": "suffix",
+            "showticksuffix": "last",
+            "showexponent": "all",
+            "exponentformat": "e",
+            "separatethousands": false,
+            "tickformat": "tick_format",
+            "tickformatstops": [{"enabled": true}],
+            "hoverformat": "hover_format",
+            "showline": true,
+            "linecolor": "#000003",
+            "linewidth": 2,
+            "showgrid": false,
+            "gridcolor": "#000004",
+            "gridwidth": 3,
+            "layer": "below traces"
         });
 
-        assert_eq!(to_value(range_slider_y_axis).unwrap(), expected);
+        assert_eq!(to_value(axis).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_range_slider() {
-        let range_slider = RangeSlider::new()
-            .background_color("#123ABC")
-            .border_color("#ABC123")
-            .border_width(1000)
-            .auto_range(false)
-            .range(vec![5_i32])
-            .thickness(2000.)
-            .visible(true)
-            .y_axis(RangeSliderYAxis::new());
+    fn test_serialize_layout_polar() {
+        let polar = LayoutPolar::new()
+            .domain(Domain::new().x(&[0.0, 0.5]))
+            .sector([0.0, 180.0])
+            .hole(0.2)
+            .background_color("#123456")
+            .radial_axis(RadialAxis::new())
+            .angular_axis(AngularAxis::new())
+            .grid_shape(PolarGridShape::Linear)
+            .bar_mode(BarMode::Overlay)
+            .bar_gap(0.1);
 
         let expected = json!({
-            "bgcolor": "#123ABC",
-            "bordercolor": "#ABC123",
-            "borderwidth": 1000,
-            "autorange": false,
-            "range": [5],
-            "thickness": 2000.0,
-            "visible": true,
-            "yaxis": {}
+            "domain": {"x": [0.0, 0.5]},
+            "sector": [0.0, 180.0],
+            "hole": 0.2,
+            "bgcolor": "#123456",
+            "radialaxis": {},
+            "angularaxis": {},
+            "gridshape": "linear",
+            "barmode": "overlay",
+            "bargap": 0.1
         });
 
-        assert_eq!(to_value(range_slider).unwrap(), expected);
+        assert_eq!(to_value(polar).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_selector_step() {
-        assert_eq!(to_value(SelectorStep::Month).unwrap(), json!("month"));
-        assert_eq!(to_value(SelectorStep::Year).unwrap(), json!("year"));
-        assert_eq!(to_value(SelectorStep::Day).unwrap(), json!("day"));
-        assert_eq!(to_value(SelectorStep::Hour).unwrap(), json!("hour"));
-        assert_eq!(to_value(SelectorStep::Minute).unwrap(), json!("minute"));
-        assert_eq!(to_value(SelectorStep::Second).unwrap(), json!("second"));
-        assert_eq!(to_value(SelectorStep::All).unwrap(), json!("all"));
+    fn test_serialize_geo_scope() {
+        assert_eq!(to_value(GeoScope::World).unwrap(), json!("world"));
+        assert_eq!(to_value(GeoScope::Usa).unwrap(), json!("usa"));
+        assert_eq!(to_value(GeoScope::Europe).unwrap(), json!("europe"));
+        assert_eq!(to_value(GeoScope::Asia).unwrap(), json!("asia"));
+        assert_eq!(to_value(GeoScope::Africa).unwrap(), json!("africa"));
+        assert_eq!(
+            to_value(GeoScope::NorthAmerica).unwrap(),
+            json!("north america")
+        );
+        assert_eq!(
+            to_value(GeoScope::SouthAmerica).unwrap(),
+            json!("south america")
+        );
     }
 
     #[test]
-    fn test_serialize_step_mode() {
-        assert_eq!(to_value(StepMode::Backward).unwrap(), json!("backward"));
-        assert_eq!(to_value(StepMode::ToDate).unwrap(), json!("todate"));
+    fn test_serialize_geo_resolution() {
+        assert_eq!(to_value(GeoResolution::High).unwrap(), json!(50));
+        assert_eq!(to_value(GeoResolution::Low).unwrap(), json!(110));
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn test_serialize_spike_mode() {
-        assert_eq!(to_value(SpikeMode::ToAxis).unwrap(), json!("toaxis"));
-        assert_eq!(to_value(SpikeMode::Across).unwrap(), json!("across"));
-        assert_eq!(to_value(SpikeMode::Marker).unwrap(), json!("marker"));
-        assert_eq!(to_value(SpikeMode::ToaxisAcross).unwrap(), json!("toaxis+across"));
-        assert_eq!(to_value(SpikeMode::ToAxisMarker).unwrap(), json!("toaxis+marker"));
-        assert_eq!(to_value(SpikeMode::AcrossMarker).unwrap(), json!("across+marker"));
-        assert_eq!(to_value(SpikeMode::ToaxisAcrossMarker).unwrap(), json!("toaxis+across+marker"));
+    fn test_serialize_fit_bounds() {
+        assert_eq!(to_value(FitBounds::False).unwrap(), json!(false));
+        assert_eq!(to_value(FitBounds::Locations).unwrap(), json!("locations"));
+        assert_eq!(to_value(FitBounds::GeoJson).unwrap(), json!("geojson"));
+    }
+
+    #[test]
+    fn test_deserialize_fit_bounds() {
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            FitBounds::False
+        ));
+        assert!(matches!(
+            from_value(json!("locations")).unwrap(),
+            FitBounds::Locations
+        ));
+        assert!(matches!(
+            from_value(json!("geojson")).unwrap(),
+            FitBounds::GeoJson
+        ));
+        assert!(from_value::<FitBounds>(json!(true)).is_err());
     }
 
     #[test]
     #[rustfmt::skip]
-    fn test_serialize_spike_snap() {
-        assert_eq!(to_value(SpikeSnap::Data).unwrap(), json!("data"));
-        assert_eq!(to_value(SpikeSnap::Cursor).unwrap(), json!("cursor"));
-        assert_eq!(to_value(SpikeSnap::HoveredData).unwrap(), json!("hovered data"));
+    fn test_serialize_projection_type() {
+        assert_eq!(to_value(ProjectionType::Equirectangular).unwrap(), json!("equirectangular"));
+        assert_eq!(to_value(ProjectionType::Mercator).unwrap(), json!("mercator"));
+        assert_eq!(to_value(ProjectionType::Orthographic).unwrap(), json!("orthographic"));
+        assert_eq!(to_value(ProjectionType::NaturalEarth).unwrap(), json!("natural earth"));
+        assert_eq!(to_value(ProjectionType::Kavrayskiy7).unwrap(), json!("kavrayskiy7"));
+        assert_eq!(to_value(ProjectionType::Miller).unwrap(), json!("miller"));
+        assert_eq!(to_value(ProjectionType::Robinson).unwrap(), json!("robinson"));
+        assert_eq!(to_value(ProjectionType::Eckert4).unwrap(), json!("eckert4"));
+        assert_eq!(to_value(ProjectionType::AzimuthalEqualArea).unwrap(), json!("azimuthal equal area"));
+        assert_eq!(to_value(ProjectionType::AzimuthalEquidistant).unwrap(), json!("azimuthal equidistant"));
+        assert_eq!(to_value(ProjectionType::ConicEqualArea).unwrap(), json!("conic equal area"));
+        assert_eq!(to_value(ProjectionType::ConicConformal).unwrap(), json!("conic conformal"));
+        assert_eq!(to_value(ProjectionType::ConicEquidistant).unwrap(), json!("conic equidistant"));
+        assert_eq!(to_value(ProjectionType::Gnomonic).unwrap(), json!("gnomonic"));
+        assert_eq!(to_value(ProjectionType::Stereographic).unwrap(), json!("stereographic"));
+        assert_eq!(to_value(ProjectionType::Mollweide).unwrap(), json!("mollweide"));
+        assert_eq!(to_value(ProjectionType::Hammer).unwrap(), json!("hammer"));
+        assert_eq!(to_value(ProjectionType::TransverseMercator).unwrap(), json!("transverse mercator"));
+        assert_eq!(to_value(ProjectionType::AlbersUsa).unwrap(), json!("albers usa"));
+        assert_eq!(to_value(ProjectionType::WinkelTripel).unwrap(), json!("winkel tripel"));
+        assert_eq!(to_value(ProjectionType::Aitoff).unwrap(), json!("aitoff"));
+        assert_eq!(to_value(ProjectionType::Sinusoidal).unwrap(), json!("sinusoidal"));
     }
 
     #[test]
-    fn test_serialize_selector_button() {
-        let selector_button = SelectorButton::new()
-            .visible(false)
-            .step(SelectorStep::Hour)
-            .step_mode(StepMode::ToDate)
-            .count(42)
-            .label("label")
-            .name("name")
-            .template_item_name("something");
+    fn test_serialize_geo_projection() {
+        let projection = GeoProjection::new()
+            .projection_type(ProjectionType::Orthographic)
+            .rotation(ProjectionRotation::new().lon(10.0).lat(20.0).roll(30.0))
+            .parallels([40.0, 50.0])
+            .scale(2.0);
 
         let expected = json!({
-            "visible": false,
-            "step": "hour",
-            "stepmode": "todate",
-            "count": 42,
-            "label": "label",
-            "name": "name",
-            "templateitemname": "something",
+            "type": "orthographic",
+            "rotation": {"lon": 10.0, "lat": 20.0, "roll": 30.0},
+            "parallels": [40.0, 50.0],
+            "scale": 2.0
         });
 
-        assert_eq!(to_value(selector_button).unwrap(), expected);
+        assert_eq!(to_value(projection).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_range_selector() {
-        let range_selector = RangeSelector::new()
-            .visible(true)
-            .buttons(vec![SelectorButton::new()])
-            .x(2.0)
-            .x_anchor(Anchor::Middle)
-            .y(4.0)
-            .y_anchor(Anchor::Top)
-            .font(Font::new())
-            .background_color("#123ABC")
-            .border_color("#ABC123")
-            .border_width(1000)
-            .active_color("#888999");
+    fn test_serialize_geo_center() {
+        let center = GeoCenter::new().lon(-0.1).lat(51.5);
+        let expected = json!({"lon": -0.1, "lat": 51.5});
+
+        assert_eq!(to_value(center).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_geo_axis() {
+        let axis = GeoAxis::new()
+            .range([-30.0, 50.0])
+            .show_grid(true)
+            .tick0(0.0)
+            .dtick(10.0)
+            .grid_color("#cccccc")
+            .grid_width(0.5);
 
         let expected = json!({
-            "visible": true,
-            "buttons": [{}],
-            "x": 2.0,
-            "xanchor": "middle",
-            "y": 4.0,
-            "yanchor": "top",
-            "font": {},
-            "bgcolor": "#123ABC",
-            "bordercolor": "#ABC123",
-            "borderwidth": 1000,
-            "activecolor": "#888999",
+            "range": [-30.0, 50.0],
+            "showgrid": true,
+            "tick0": 0.0,
+            "dtick": 10.0,
+            "gridcolor": "#cccccc",
+            "gridwidth": 0.5
         });
 
-        assert_eq!(to_value(range_selector).unwrap(), expected);
+        assert_eq!(to_value(axis).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_color_axis() {
-        let color_axis = ColorAxis::new()
-            .auto_color_scale(false)
-            .cauto(true)
-            .cmax(1.0)
-            .cmid(0.5)
-            .cmin(0.0)
-            .color_bar(ColorBar::new())
-            .color_scale(ColorScale::Palette(ColorScalePalette::Greens))
-            .reverse_scale(false)
-            .show_scale(true);
+    fn test_serialize_layout_geo() {
+        let geo = LayoutGeo::new()
+            .domain(Domain::new().row(0).column(1))
+            .fit_bounds(FitBounds::Locations)
+            .resolution(GeoResolution::High)
+            .scope(GeoScope::Europe)
+            .projection(GeoProjection::new())
+            .center(GeoCenter::new())
+            .visible(true)
+            .show_coastlines(true)
+            .coastline_color("#000001")
+            .coastline_width(1.0)
+            .show_land(true)
+            .land_color("#000002")
+            .show_ocean(false)
+            .ocean_color("#000003")
+            .show_lakes(true)
+            .lake_color("#000004")
+            .show_rivers(false)
+            .river_color("#000005")
+            .river_width(2.0)
+            .show_countries(true)
+            .country_color("#000006")
+            .country_width(3.0)
+            .show_subunits(false)
+            .subunit_color("#000007")
+            .subunit_width(4.0)
+            .show_frame(true)
+            .frame_color("#000008")
+            .frame_width(5.0)
+            .background_color("#000009")
+            .lat_axis(GeoAxis::new())
+            .lon_axis(GeoAxis::new());
 
         let expected = json!({
-            "autocolorscale": false,
-            "cauto": true,
-            "cmin": 0.0,
-            "cmid": 0.5,
-            "cmax": 1.0,
-            "colorbar": {},
-            "colorscale": "Greens",
-            "reversescale": false,
-            "showscale": true,
+            "domain": {"row": 0, "column": 1},
+            "fitbounds": "locations",
+            "resolution": 50,
+            "scope": "europe",
+            "projection": {},
+            "center": {},
+            "visible": true,
+            "showcoastlines": true,
+            "coastlinecolor": "#000001",
+            "coastlinewidth": 1.0,
+            "showland": true,
+            "landcolor": "#000002",
+            "showocean": false,
+            "oceancolor": "#000003",
+            "showlakes": true,
+            "lakecolor": "#000004",
+            "showrivers": false,
+            "rivercolor": "#000005",
+            "riverwidth": 2.0,
+            "showcountries": true,
+            "countrycolor": "#000006",
+            "countrywidth": 3.0,
+            "showsubunits": false,
+            "subunitcolor": "#000007",
+            "subunitwidth": 4.0,
+            "showframe": true,
+            "framecolor": "#000008",
+            "framewidth": 5.0,
+            "bgcolor": "#000009",
+            "lataxis": {},
+            "lonaxis": {}
         });
 
-        assert_eq!(to_value(color_axis).unwrap(), expected);
+        assert_eq!(to_value(geo).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_axis() {
-        let axis = Axis::new()
-            .visible(false)
-            .color("#678123")
-            .title(Title::new("title"))
-            .type_(AxisType::Date)
-            .auto_range(false)
-            .range_mode(RangeMode::NonNegative)
-            .range(vec![2.0])
-            .fixed_range(true)
-            .constrain(AxisConstrain::Range)
-            .constrain_toward(ConstrainDirection::Middle)
-            .tick_mode(TickMode::Auto)
-            .n_ticks(600)
-            .tick0(5.0)
-            .dtick(10.0)
-            .matches(true)
-            .tick_values(vec![1.0, 2.0])
-            .tick_text(vec!["one".to_string(), "two".to_string()])
-            .ticks(TicksDirection::Inside)
-            .ticks_on(TicksPosition::Boundaries)
-            .mirror(false)
-            .tick_length(77)
-            .tick_width(99)
-            .tick_color("#101010")
-            .show_tick_labels(false)
-            .auto_margin(true)
-            .show_spikes(false)
-            .spike_color("#ABABAB")
-            .spike_thickness(501)
-            .spike_dash(DashType::DashDot)
-            .spike_mode(SpikeMode::AcrossMarker)
-            .spike_snap(SpikeSnap::Data)
-            .tick_font(Font::new())
-            .tick_angle(2.1)
-            .tick_prefix("prefix")
-            .show_tick_prefix(ArrayShow::Last)
-            .tick_suffix("suffix")
-            .show_tick_suffix(ArrayShow::None)
-            .show_exponent(ArrayShow::All)
-            .exponent_format(ExponentFormat::SmallE)
-            .separate_thousands(false)
-            .tick_format("tickfmt")
-            .tick_format_stops(vec![TickFormatStop::new()])
-            .hover_format("hoverfmt")
-            .show_line(true)
-            .line_color("#CCCDDD")
-            .line_width(9)
-            .show_grid(false)
-            .grid_color("#fff000")
-            .grid_width(8)
-            .zero_line(true)
-            .zero_line_color("#f0f0f0")
-            .zero_line_width(7)
-            .show_dividers(false)
-            .divider_color("#AFAFAF")
-            .divider_width(55)
-            .anchor("anchor")
-            .side(AxisSide::Right)
-            .overlaying("overlaying")
-            .domain(&[0.0, 1.0])
-            .position(0.6)
-            .range_slider(RangeSlider::new())
-            .range_selector(RangeSelector::new())
-            .calendar(Calendar::Coptic);
+    fn test_serialize_mapbox_style() {
+        assert_eq!(to_value(MapboxStyle::WhiteBg).unwrap(), json!("white-bg"));
+        assert_eq!(
+            to_value(MapboxStyle::OpenStreetMap).unwrap(),
+            json!("open-street-map")
+        );
+        assert_eq!(
+            to_value(MapboxStyle::CartoPositron).unwrap(),
+            json!("carto-positron")
+        );
+        assert_eq!(
+            to_value(MapboxStyle::CartoDarkMatter).unwrap(),
+            json!("carto-darkmatter")
+        );
+        assert_eq!(
+            to_value(MapboxStyle::StamenTerrain).unwrap(),
+            json!("stamen-terrain")
+        );
+        assert_eq!(
+            to_value(MapboxStyle::StamenToner).unwrap(),
+            json!("stamen-toner")
+        );
+        assert_eq!(
+            to_value(MapboxStyle::StamenWatercolor).unwrap(),
+            json!("stamen-watercolor")
+        );
+        assert_eq!(to_value(MapboxStyle::Basic).unwrap(), json!("basic"));
+        assert_eq!(to_value(MapboxStyle::Streets).unwrap(), json!("streets"));
+        assert_eq!(to_value(MapboxStyle::Outdoors).unwrap(), json!("outdoors"));
+        assert_eq!(to_value(MapboxStyle::Light).unwrap(), json!("light"));
+        assert_eq!(to_value(MapboxStyle::Dark).unwrap(), json!("dark"));
+        assert_eq!(
+            to_value(MapboxStyle::Satellite).unwrap(),
+            json!("satellite")
+        );
+        assert_eq!(
+            to_value(MapboxStyle::SatelliteStreets).unwrap(),
+            json!("satellite-streets")
+        );
+        assert_eq!(
+            to_value(MapboxStyle::Url(
+                "http://localhost:8080/style.json".to_string()
+            ))
+            .unwrap(),
+            json!("http://localhost:8080/style.json")
+        );
+    }
 
-        let expected = json!({
-            "visible": false,
-            "color": "#678123",
-            "title": {"text": "title"},
-            "type": "date",
-            "autorange": false,
-            "rangemode": "nonnegative",
-            "range": [2.0],
-            "fixedrange": true,
-            "constrain": "range",
-            "constraintoward": "middle",
-            "tickmode": "auto",
-            "nticks": 600,
-            "tick0": 5.0,
-            "dtick": 10.0,
-            "matches": "x",
-            "tickvals": [1.0, 2.0],
-            "ticktext": ["one", "two"],
-            "ticks": "inside",
-            "tickson": "boundaries",
-            "mirror": false,
-            "ticklen": 77,
-            "tickwidth": 99,
-            "tickcolor": "#101010",
-            "showticklabels": false,
-            "automargin": true,
-            "showspikes": false,
-            "spikecolor": "#ABABAB",
-            "spikethickness": 501,
-            "spikedash": "dashdot",
-            "spikemode": "across+marker",
-            "spikesnap": "data",
-            "tickfont": {},
-            "tickangle": 2.1,
-            "tickprefix": "prefix",
-            "showtickprefix": "last",
-            "ticksuffix": "suffix",
-            "showticksuffix": "none",
-            "showexponent": "all",
-            "exponentformat": "e",
-            "separatethousands": false,
-            "tickformat": "tickfmt",
-            "tickformatstops": [{"enabled": true}],
-            "hoverformat": "hoverfmt",
-            "showline": true,
-            "linecolor": "#CCCDDD",
-            "linewidth": 9,
-            "showgrid": false,
-            "gridcolor": "#fff000",
-            "gridwidth": 8,
-            "zeroline": true,
-            "zerolinecolor": "#f0f0f0",
-            "zerolinewidth": 7,
-            "showdividers": false,
-            "dividercolor": "#AFAFAF",
-            "dividerwidth": 55,
-            "anchor": "anchor",
-            "side": "right",
-            "overlaying": "overlaying",
-            "domain": [0.0, 1.0],
-            "position": 0.6,
-            "rangeslider": {},
-            "rangeselector": {},
-            "calendar": "coptic",
-        });
+    #[test]
+    fn test_deserialize_mapbox_style() {
+        assert!(matches!(
+            from_value(json!("white-bg")).unwrap(),
+            MapboxStyle::WhiteBg
+        ));
+        assert!(matches!(
+            from_value(json!("open-street-map")).unwrap(),
+            MapboxStyle::OpenStreetMap
+        ));
+        assert!(matches!(
+            from_value(json!("carto-darkmatter")).unwrap(),
+            MapboxStyle::CartoDarkMatter
+        ));
+        assert!(matches!(
+            from_value(json!("satellite-streets")).unwrap(),
+            MapboxStyle::SatelliteStreets
+        ));
+        assert!(matches!(
+            from_value(json!("mapbox://styles/mapbox/streets-v11")).unwrap(),
+            MapboxStyle::Url(url) if url == "mapbox://styles/mapbox/streets-v11"
+        ));
+    }
+
+    #[test]
+    fn test_serialize_mapbox_layer_source_type() {
+        assert_eq!(
+            to_value(MapboxLayerSourceType::GeoJson).unwrap(),
+            json!("geojson")
+        );
+        assert_eq!(
+            to_value(MapboxLayerSourceType::Vector).unwrap(),
+            json!("vector")
+        );
+        assert_eq!(
+            to_value(MapboxLayerSourceType::Raster).unwrap(),
+            json!("raster")
+        );
+        assert_eq!(
+            to_value(MapboxLayerSourceType::Image).unwrap(),
+            json!("image")
+        );
+    }
 
-        assert_eq!(to_value(axis).unwrap(), expected);
+    #[test]
+    fn test_serialize_mapbox_layer_type() {
+        assert_eq!(to_value(MapboxLayerType::Circle).unwrap(), json!("circle"));
+        assert_eq!(to_value(MapboxLayerType::Line).unwrap(), json!("line"));
+        assert_eq!(to_value(MapboxLayerType::Fill).unwrap(), json!("fill"));
+        assert_eq!(to_value(MapboxLayerType::Symbol).unwrap(), json!("symbol"));
+        assert_eq!(to_value(MapboxLayerType::Raster).unwrap(), json!("raster"));
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn test_serialize_row_order() {
-        assert_eq!(to_value(RowOrder::TopToBottom).unwrap(), json!("top to bottom"));
-        assert_eq!(to_value(RowOrder::BottomToTop).unwrap(), json!("bottom to top"));
+    fn test_serialize_symbol_placement() {
+        assert_eq!(to_value(SymbolPlacement::Point).unwrap(), json!("point"));
+        assert_eq!(to_value(SymbolPlacement::Line).unwrap(), json!("line"));
+        assert_eq!(
+            to_value(SymbolPlacement::LineCenter).unwrap(),
+            json!("line-center")
+        );
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn test_serialize_grid_pattern() {
-        assert_eq!(to_value(GridPattern::Independent).unwrap(), json!("independent"));
-        assert_eq!(to_value(GridPattern::Coupled).unwrap(), json!("coupled"));
+    fn test_serialize_layer_circle() {
+        let circle = LayerCircle::new().radius(5.0);
+        let expected = json!({"radius": 5.0});
+
+        assert_eq!(to_value(circle).unwrap(), expected);
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn test_serialize_grid_x_side() {
-        assert_eq!(to_value(GridXSide::Bottom).unwrap(), json!("bottom"));
-        assert_eq!(to_value(GridXSide::BottomPlot).unwrap(), json!("bottom plot"));
-        assert_eq!(to_value(GridXSide::Top).unwrap(), json!("top"));
-        assert_eq!(to_value(GridXSide::TopPlot).unwrap(), json!("top plot"));
+    fn test_serialize_layer_line() {
+        let line = LayerLine::new().width(2.0).dash(vec![2.0, 1.0]);
+        let expected = json!({"width": 2.0, "dash": [2.0, 1.0]});
+
+        assert_eq!(to_value(line).unwrap(), expected);
     }
 
     #[test]
-    #[rustfmt::skip]
-    fn test_serialize_grid_y_side() {
-        assert_eq!(to_value(GridYSide::Left).unwrap(), json!("left"));
-        assert_eq!(to_value(GridYSide::LeftPlot).unwrap(), json!("left plot"));
-        assert_eq!(to_value(GridYSide::Right).unwrap(), json!("right"));
-        assert_eq!(to_value(GridYSide::RightPlot).unwrap(), json!("right plot"));
+    fn test_serialize_layer_fill() {
+        let fill = LayerFill::new().outline_color("#123456");
+        let expected = json!({"outlinecolor": "#123456"});
+
+        assert_eq!(to_value(fill).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_grid_domain() {
-        let grid_domain = GridDomain::new().x(vec![0.0]).y(vec![1.0]);
+    fn test_serialize_layer_symbol() {
+        let symbol = LayerSymbol::new()
+            .icon("marker")
+            .icon_size(10.0)
+            .text("text")
+            .placement(SymbolPlacement::LineCenter)
+            .text_font(Font::new())
+            .text_position(Position::TopCenter);
+
         let expected = json!({
-            "x": [0.0],
-            "y": [1.0]
+            "icon": "marker",
+            "iconsize": 10.0,
+            "text": "text",
+            "placement": "line-center",
+            "textfont": {},
+            "textposition": "top center"
         });
 
-        assert_eq!(to_value(grid_domain).unwrap(), expected);
+        assert_eq!(to_value(symbol).unwrap(), expected);
     }
 
     #[test]
-    fn test_serialize_layout_grid() {
-        let layout_grid = LayoutGrid::new()
-            .rows(224)
-            .row_order(RowOrder::BottomToTop)
-            .columns(501)
-            .sub_plots(vec!["subplots".to_string()])
-            .x_axes(vec!["xaxes".to_string()])
-            .y_axes(vec!["yaxes".to_string()])
-            .pattern(GridPattern::Coupled)
-            .x_gap(2.2)
-            .y_gap(4.4)
-            .domain(GridDomain::new())
-            .x_side(GridXSide::Top)
-            .y_side(GridYSide::Right);
+    fn test_serialize_mapbox_layer() {
+        let layer = MapboxLayer::new()
+            .visible(true)
+            .source_type(MapboxLayerSourceType::Vector)
+            .source(vec!["http://localhost:8080/{z}/{x}/{y}.pbf"])
+            .source_layer("roads")
+            .source_attribution("attribution")
+            .layer_type(MapboxLayerType::Line)
+            .coordinates(vec![[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]])
+            .below("traces")
+            .color("#654321")
+            .opacity(0.5)
+            .min_zoom(2.0)
+            .max_zoom(18.0)
+            .circle(LayerCircle::new())
+            .line(LayerLine::new())
+            .fill(LayerFill::new())
+            .symbol(LayerSymbol::new())
+            .name("name");
+
+        let expected = json!({
+            "visible": true,
+            "sourcetype": "vector",
+            "source": ["http://localhost:8080/{z}/{x}/{y}.pbf"],
+            "sourcelayer": "roads",
+            "sourceattribution": "attribution",
+            "type": "line",
+            "coordinates": [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]],
+            "below": "traces",
+            "color": "#654321",
+            "opacity": 0.5,
+            "minzoom": 2.0,
+            "maxzoom": 18.0,
+            "circle": {},
+            "line": {},
+            "fill": {},
+            "symbol": {},
+            "name": "name"
+        });
+
+        assert_eq!(to_value(layer).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_mapbox() {
+        let mapbox = LayoutMapbox::new()
+            .domain(Domain::new())
+            .access_token("token")
+            .style(MapboxStyle::WhiteBg)
+            .center(GeoCenter::new().lat(45.5).lon(-73.6))
+            .zoom(10.0)
+            .bearing(20.0)
+            .pitch(30.0)
+            .layers(vec![MapboxLayer::new()]);
 
         let expected = json!({
-            "rows": 224,
-            "roworder": "bottom to top",
-            "columns": 501,
-            "subplots": ["subplots"],
-            "xaxes": ["xaxes"],
-            "yaxes": ["yaxes"],
-            "pattern": "coupled",
-            "xgap": 2.2,
-            "ygap": 4.4,
             "domain": {},
-            "xside": "top",
-            "yside": "right",
+            "accesstoken": "token",
+            "style": "white-bg",
+            "center": {"lat": 45.5, "lon": -73.6},
+            "zoom": 10.0,
+            "bearing": 20.0,
+            "pitch": 30.0,
+            "layers": [{}]
         });
 
-        assert_eq!(to_value(layout_grid).unwrap(), expected);
+        assert_eq!(to_value(mapbox).unwrap(), expected);
     }
 
     #[test]
@@ -4163,7 +9527,8 @@ mod tests {
             .y("y")
             .y_anchor(Anchor::Bottom)
             .y_click("yclick")
-            .y_shift(6.3);
+            .y_shift(6.3)
+            .z(1.5);
 
         let expected = json!({
             "visible": true,
@@ -4201,6 +9566,7 @@ mod tests {
             "yanchor": "bottom",
             "xshift": 4.0,
             "yshift": 6.3,
+            "z": 1.5,
             "clicktoshow": "onoff",
             "xclick": "xclick",
             "yclick": "yclick",
@@ -4214,6 +9580,220 @@ mod tests {
         assert_eq!(to_value(annotation).unwrap(), expected);
     }
 
+    #[test]
+    fn test_serialize_button_method() {
+        assert_eq!(to_value(ButtonMethod::Restyle).unwrap(), json!("restyle"));
+        assert_eq!(to_value(ButtonMethod::Relayout).unwrap(), json!("relayout"));
+        assert_eq!(to_value(ButtonMethod::Animate).unwrap(), json!("animate"));
+        assert_eq!(to_value(ButtonMethod::Update).unwrap(), json!("update"));
+        assert_eq!(to_value(ButtonMethod::Skip).unwrap(), json!("skip"));
+    }
+
+    #[test]
+    fn test_serialize_button() {
+        let button = Button::new()
+            .visible(true)
+            .label("label")
+            .method(ButtonMethod::Relayout)
+            .args(json!([{"yaxis.type": "log"}]))
+            .args2(json!([{"yaxis.type": "linear"}]))
+            .execute(false)
+            .name("name")
+            .template_item_name("template_item_name");
+
+        let expected = json!({
+            "visible": true,
+            "label": "label",
+            "method": "relayout",
+            "args": [{"yaxis.type": "log"}],
+            "args2": [{"yaxis.type": "linear"}],
+            "execute": false,
+            "name": "name",
+            "templateitemname": "template_item_name"
+        });
+
+        assert_eq!(to_value(button).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_update_menu_type() {
+        assert_eq!(
+            to_value(UpdateMenuType::Dropdown).unwrap(),
+            json!("dropdown")
+        );
+        assert_eq!(to_value(UpdateMenuType::Buttons).unwrap(), json!("buttons"));
+    }
+
+    #[test]
+    fn test_serialize_update_menu_direction() {
+        assert_eq!(to_value(UpdateMenuDirection::Left).unwrap(), json!("left"));
+        assert_eq!(
+            to_value(UpdateMenuDirection::Right).unwrap(),
+            json!("right")
+        );
+        assert_eq!(to_value(UpdateMenuDirection::Up).unwrap(), json!("up"));
+        assert_eq!(to_value(UpdateMenuDirection::Down).unwrap(), json!("down"));
+    }
+
+    #[test]
+    fn test_serialize_update_menu() {
+        let update_menu = UpdateMenu::new()
+            .visible(true)
+            .type_(UpdateMenuType::Buttons)
+            .direction(UpdateMenuDirection::Right)
+            .active(1)
+            .show_active(false)
+            .buttons(vec![Button::new()])
+            .x(0.1)
+            .x_anchor(Anchor::Left)
+            .y(1.1)
+            .y_anchor(Anchor::Top)
+            .pad(Pad::new(10, 5, 2))
+            .font(Font::new())
+            .background_color("#123456")
+            .border_color("#654321")
+            .border_width(2)
+            .name("name")
+            .template_item_name("template_item_name");
+
+        let expected = json!({
+            "visible": true,
+            "type": "buttons",
+            "direction": "right",
+            "active": 1,
+            "showactive": false,
+            "buttons": [{}],
+            "x": 0.1,
+            "xanchor": "left",
+            "y": 1.1,
+            "yanchor": "top",
+            "pad": {"t": 10, "b": 5, "l": 2},
+            "font": {},
+            "bgcolor": "#123456",
+            "bordercolor": "#654321",
+            "borderwidth": 2,
+            "name": "name",
+            "templateitemname": "template_item_name"
+        });
+
+        assert_eq!(to_value(update_menu).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_slider_step() {
+        let slider_step = SliderStep::new()
+            .visible(true)
+            .method(ButtonMethod::Animate)
+            .args(json!([["frame"], {"mode": "immediate"}]))
+            .label("label")
+            .value("value")
+            .execute(false)
+            .name("name")
+            .template_item_name("template_item_name");
+
+        let expected = json!({
+            "visible": true,
+            "method": "animate",
+            "args": [["frame"], {"mode": "immediate"}],
+            "label": "label",
+            "value": "value",
+            "execute": false,
+            "name": "name",
+            "templateitemname": "template_item_name"
+        });
+
+        assert_eq!(to_value(slider_step).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_slider_current_value() {
+        let current_value = SliderCurrentValue::new()
+            .visible(true)
+            .x_anchor(Anchor::Right)
+            .offset(10)
+            .prefix("prefix")
+            .suffix("suffix")
+            .font(Font::new());
+
+        let expected = json!({
+            "visible": true,
+            "xanchor": "right",
+            "offset": 10,
+            "prefix": "prefix",
+            "suffix": "suffix",
+            "font": {}
+        });
+
+        assert_eq!(to_value(current_value).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_slider_transition() {
+        let transition = SliderTransition::new()
+            .duration(150)
+            .easing(TransitionEasing::CubicInOut);
+
+        let expected = json!({"duration": 150, "easing": "cubic-in-out"});
+
+        assert_eq!(to_value(transition).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_slider() {
+        let slider = Slider::new()
+            .visible(true)
+            .active(2)
+            .steps(vec![SliderStep::new()])
+            .len_mode(ThicknessMode::Fraction)
+            .len(0.9)
+            .x(0.1)
+            .x_anchor(Anchor::Left)
+            .y(0.0)
+            .y_anchor(Anchor::Top)
+            .pad(Pad::new(50, 0, 0))
+            .transition(SliderTransition::new())
+            .current_value(SliderCurrentValue::new())
+            .font(Font::new())
+            .active_background_color("#111111")
+            .background_color("#222222")
+            .border_color("#333333")
+            .border_width(1)
+            .tick_length(7)
+            .tick_color("#444444")
+            .tick_width(1)
+            .minor_tick_length(4)
+            .name("name")
+            .template_item_name("template_item_name");
+
+        let expected = json!({
+            "visible": true,
+            "active": 2,
+            "steps": [{}],
+            "lenmode": "fraction",
+            "len": 0.9,
+            "x": 0.1,
+            "xanchor": "left",
+            "y": 0.0,
+            "yanchor": "top",
+            "pad": {"t": 50, "b": 0, "l": 0},
+            "transition": {},
+            "currentvalue": {},
+            "font": {},
+            "activebgcolor": "#111111",
+            "bgcolor": "#222222",
+            "bordercolor": "#333333",
+            "borderwidth": 1,
+            "ticklen": 7,
+            "tickcolor": "#444444",
+            "tickwidth": 1,
+            "minorticklen": 4,
+            "name": "name",
+            "templateitemname": "template_item_name"
+        });
+
+        assert_eq!(to_value(slider).unwrap(), expected);
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_serialize_click_mode() {
@@ -4240,6 +9820,50 @@ mod tests {
         assert_eq!(to_value(DragMode::False).unwrap(), json!(false));
     }
 
+    #[test]
+    fn test_deserialize_drag_mode() {
+        assert!(matches!(from_value(json!("zoom")).unwrap(), DragMode::Zoom));
+        assert!(matches!(from_value(json!("pan")).unwrap(), DragMode::Pan));
+        assert!(matches!(
+            from_value(json!("select")).unwrap(),
+            DragMode::Select
+        ));
+        assert!(matches!(
+            from_value(json!("lasso")).unwrap(),
+            DragMode::Lasso
+        ));
+        assert!(matches!(
+            from_value(json!("drawclosedpath")).unwrap(),
+            DragMode::DrawClosedPath
+        ));
+        assert!(matches!(
+            from_value(json!("drawopenpath")).unwrap(),
+            DragMode::DrawOpenPath
+        ));
+        assert!(matches!(
+            from_value(json!("drawline")).unwrap(),
+            DragMode::DrawLine
+        ));
+        assert!(matches!(
+            from_value(json!("drawrect")).unwrap(),
+            DragMode::DrawRect
+        ));
+        assert!(matches!(
+            from_value(json!("drawcircle")).unwrap(),
+            DragMode::DrawCircle
+        ));
+        assert!(matches!(
+            from_value(json!("orbit")).unwrap(),
+            DragMode::Orbit
+        ));
+        assert!(matches!(
+            from_value(json!("turntable")).unwrap(),
+            DragMode::Turntable
+        ));
+        assert!(matches!(from_value(json!(false)).unwrap(), DragMode::False));
+        assert!(from_value::<DragMode>(json!(true)).is_err());
+    }
+
     #[test]
     fn test_serialize_select_direction() {
         assert_eq!(to_value(SelectDirection::Horizontal).unwrap(), json!("h"));
@@ -4248,6 +9872,74 @@ mod tests {
         assert_eq!(to_value(SelectDirection::Any).unwrap(), json!("any"));
     }
 
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_transition_easing() {
+        assert_eq!(to_value(TransitionEasing::Linear).unwrap(), json!("linear"));
+        assert_eq!(to_value(TransitionEasing::Quad).unwrap(), json!("quad"));
+        assert_eq!(to_value(TransitionEasing::Cubic).unwrap(), json!("cubic"));
+        assert_eq!(to_value(TransitionEasing::Sin).unwrap(), json!("sin"));
+        assert_eq!(to_value(TransitionEasing::Exp).unwrap(), json!("exp"));
+        assert_eq!(to_value(TransitionEasing::Circle).unwrap(), json!("circle"));
+        assert_eq!(to_value(TransitionEasing::Elastic).unwrap(), json!("elastic"));
+        assert_eq!(to_value(TransitionEasing::Back).unwrap(), json!("back"));
+        assert_eq!(to_value(TransitionEasing::Bounce).unwrap(), json!("bounce"));
+        assert_eq!(to_value(TransitionEasing::LinearIn).unwrap(), json!("linear-in"));
+        assert_eq!(to_value(TransitionEasing::QuadIn).unwrap(), json!("quad-in"));
+        assert_eq!(to_value(TransitionEasing::CubicIn).unwrap(), json!("cubic-in"));
+        assert_eq!(to_value(TransitionEasing::SinIn).unwrap(), json!("sin-in"));
+        assert_eq!(to_value(TransitionEasing::ExpIn).unwrap(), json!("exp-in"));
+        assert_eq!(to_value(TransitionEasing::CircleIn).unwrap(), json!("circle-in"));
+        assert_eq!(to_value(TransitionEasing::ElasticIn).unwrap(), json!("elastic-in"));
+        assert_eq!(to_value(TransitionEasing::BackIn).unwrap(), json!("back-in"));
+        assert_eq!(to_value(TransitionEasing::BounceIn).unwrap(), json!("bounce-in"));
+        assert_eq!(to_value(TransitionEasing::LinearOut).unwrap(), json!("linear-out"));
+        assert_eq!(to_value(TransitionEasing::QuadOut).unwrap(), json!("quad-out"));
+        assert_eq!(to_value(TransitionEasing::CubicOut).unwrap(), json!("cubic-out"));
+        assert_eq!(to_value(TransitionEasing::SinOut).unwrap(), json!("sin-out"));
+        assert_eq!(to_value(TransitionEasing::ExpOut).unwrap(), json!("exp-out"));
+        assert_eq!(to_value(TransitionEasing::CircleOut).unwrap(), json!("circle-out"));
+        assert_eq!(to_value(TransitionEasing::ElasticOut).unwrap(), json!("elastic-out"));
+        assert_eq!(to_value(TransitionEasing::BackOut).unwrap(), json!("back-out"));
+        assert_eq!(to_value(TransitionEasing::BounceOut).unwrap(), json!("bounce-out"));
+        assert_eq!(to_value(TransitionEasing::LinearInOut).unwrap(), json!("linear-in-out"));
+        assert_eq!(to_value(TransitionEasing::QuadInOut).unwrap(), json!("quad-in-out"));
+        assert_eq!(to_value(TransitionEasing::CubicInOut).unwrap(), json!("cubic-in-out"));
+        assert_eq!(to_value(TransitionEasing::SinInOut).unwrap(), json!("sin-in-out"));
+        assert_eq!(to_value(TransitionEasing::ExpInOut).unwrap(), json!("exp-in-out"));
+        assert_eq!(to_value(TransitionEasing::CircleInOut).unwrap(), json!("circle-in-out"));
+        assert_eq!(to_value(TransitionEasing::ElasticInOut).unwrap(), json!("elastic-in-out"));
+        assert_eq!(to_value(TransitionEasing::BackInOut).unwrap(), json!("back-in-out"));
+        assert_eq!(to_value(TransitionEasing::BounceInOut).unwrap(), json!("bounce-in-out"));
+    }
+
+    #[test]
+    fn test_serialize_transition_ordering() {
+        assert_eq!(
+            to_value(TransitionOrdering::LayoutFirst).unwrap(),
+            json!("layout first")
+        );
+        assert_eq!(
+            to_value(TransitionOrdering::TracesFirst).unwrap(),
+            json!("traces first")
+        );
+    }
+
+    #[test]
+    fn test_serialize_transition() {
+        let transition = Transition::new()
+            .duration(500)
+            .easing(TransitionEasing::CubicInOut)
+            .ordering(TransitionOrdering::TracesFirst);
+        let expected = json!({
+            "duration": 500,
+            "easing": "cubic-in-out",
+            "ordering": "traces first"
+        });
+
+        assert_eq!(to_value(transition).unwrap(), expected);
+    }
+
     #[test]
     fn test_serialize_layout_template() {
         let layout_template = LayoutTemplate::new()
@@ -4274,6 +9966,7 @@ mod tests {
             .hover_distance(321)
             .spike_distance(12)
             .hover_label(Label::new())
+            .transition(Transition::new())
             .grid(LayoutGrid::new())
             .calendar(Calendar::Jalali)
             .x_axis(Axis::new())
@@ -4292,10 +9985,17 @@ mod tests {
             .y_axis6(Axis::new())
             .y_axis7(Axis::new())
             .y_axis8(Axis::new())
+            .ternary(LayoutTernary::new())
+            .scene(LayoutScene::new())
+            .polar(LayoutPolar::new())
+            .geo(LayoutGeo::new())
+            .mapbox(LayoutMapbox::new())
             .annotations(vec![Annotation::new()])
             .shapes(vec![Shape::new()])
             .new_shape(NewShape::new())
             .active_shape(ActiveShape::new())
+            .update_menus(vec![UpdateMenu::new()])
+            .sliders(vec![Slider::new()])
             .box_mode(BoxMode::Group)
             .box_gap(1.)
             .box_group_gap(2.)
@@ -4312,7 +10012,11 @@ mod tests {
             .pie_colorway(vec!["#789789"])
             .extend_pie_colors(true)
             .sunburst_colorway(vec!["#654654"])
-            .extend_sunburst_colors(false);
+            .extend_sunburst_colors(false)
+            .treemap_colorway(vec!["#321321"])
+            .extend_treemap_colors(true)
+            .icicle_colorway(vec!["#987987"])
+            .extend_icicle_colors(false);
 
         let expected = json!({
             "title": {"text": "Title"},
@@ -4338,6 +10042,7 @@ mod tests {
             "hoverdistance": 321,
             "spikedistance": 12,
             "hoverlabel": {},
+            "transition": {},
             "grid": {},
             "calendar": "jalali",
             "xaxis": {},
@@ -4356,10 +10061,17 @@ mod tests {
             "yaxis6": {},
             "yaxis7": {},
             "yaxis8": {},
+            "ternary": {},
+            "scene": {},
+            "polar": {},
+            "geo": {},
+            "mapbox": {},
             "annotations": [{}],
             "shapes": [{}],
             "newshape": {},
             "activeshape": {},
+            "updatemenus": [{}],
+            "sliders": [{}],
             "boxmode": "group",
             "boxgap": 1.0,
             "boxgroupgap": 2.0,
@@ -4377,11 +10089,53 @@ mod tests {
             "extendpiecolors": true,
             "sunburstcolorway": ["#654654"],
             "extendsunburstcolors": false,
+            "treemapcolorway": ["#321321"],
+            "extendtreemapcolors": true,
+            "iciclecolorway": ["#987987"],
+            "extendiciclecolors": false,
         });
 
         assert_eq!(to_value(layout_template).unwrap(), expected);
     }
 
+    #[test]
+    fn test_serialize_layout_template_numbered_subplots() {
+        let layout = LayoutTemplate::new()
+            .scene2(LayoutScene::new())
+            .scene3(LayoutScene::new())
+            .scene4(LayoutScene::new())
+            .scene5(LayoutScene::new())
+            .scene6(LayoutScene::new())
+            .scene7(LayoutScene::new())
+            .scene8(LayoutScene::new())
+            .polar2(LayoutPolar::new())
+            .polar3(LayoutPolar::new())
+            .polar4(LayoutPolar::new())
+            .polar5(LayoutPolar::new())
+            .polar6(LayoutPolar::new())
+            .polar7(LayoutPolar::new())
+            .polar8(LayoutPolar::new());
+
+        let expected = json!({
+            "scene2": {},
+            "scene3": {},
+            "scene4": {},
+            "scene5": {},
+            "scene6": {},
+            "scene7": {},
+            "scene8": {},
+            "polar2": {},
+            "polar3": {},
+            "polar4": {},
+            "polar5": {},
+            "polar6": {},
+            "polar7": {},
+            "polar8": {},
+        });
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
     #[test]
     fn test_serialize_template() {
         let template = Template::new().layout(LayoutTemplate::new());
@@ -4390,6 +10144,248 @@ mod tests {
         assert_eq!(to_value(template).unwrap(), expected);
     }
 
+    #[test]
+    fn test_serialize_template_data() {
+        let data = TemplateData::new()
+            .trace(Bar::<f64, f64>::default().opacity(0.8))
+            .trace(Scatter::<f64, f64>::default().mode(Mode::Lines))
+            .trace(Bar::<f64, f64>::default().opacity(0.5));
+        let template = Template::new().data(data);
+        let expected = json!({
+            "data": {
+                "bar": [{"type": "bar", "opacity": 0.8}, {"type": "bar", "opacity": 0.5}],
+                "scatter": [{"type": "scatter", "mode": "lines"}]
+            }
+        });
+
+        assert_eq!(to_value(template).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_template_from_json() {
+        let json = json!({
+            "data": {
+                "bar": [{"marker": {"line": {"color": "#E5ECF6", "width": 0.5}}}],
+                "carpet": [{"aaxis": {"gridcolor": "white"}, "type": "carpet"}]
+            },
+            "layout": {"colorway": ["#636efa", "#EF553B"], "autotypenumbers": "strict"}
+        });
+        let template = Template::from_json(&json.to_string()).unwrap();
+
+        assert_eq!(to_value(template).unwrap(), json);
+        assert!(Template::from_json("{\"data\": []}").is_err());
+    }
+
+    #[test]
+    fn test_template_merge() {
+        let base = Template::from_json(
+            &json!({
+                "data": {
+                    "bar": [{"marker": {"line": {"width": 0.5}}}],
+                    "scatter": [{"mode": "lines"}, {"mode": "markers"}]
+                },
+                "layout": {
+                    "font": {"color": "#2a3f5f", "size": 12},
+                    "colorway": ["#636efa", "#EF553B"],
+                    "autotypenumbers": "strict"
+                }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let overlay = Template::new()
+            .layout(
+                LayoutTemplate::new()
+                    .font(Font::new().size(18))
+                    .colorway(vec!["#003366"]),
+            )
+            .data(
+                TemplateData::new()
+                    .trace(Bar::<f64, f64>::default().opacity(0.5))
+                    .trace(Scatter::<f64, f64>::default().line(Line::new().width(3.)))
+                    .trace(Scatter::<f64, f64>::default().line(Line::new().width(1.)))
+                    .trace(Scatter::<f64, f64>::default().line(Line::new().width(2.))),
+            );
+
+        let expected = json!({
+            "data": {
+                "bar": [{"marker": {"line": {"width": 0.5}}, "type": "bar", "opacity": 0.5}],
+                "scatter": [
+                    {"mode": "lines", "type": "scatter", "line": {"width": 3.0}},
+                    {"mode": "markers", "type": "scatter", "line": {"width": 1.0}},
+                    {"mode": "lines", "type": "scatter", "line": {"width": 2.0}},
+                    {"mode": "markers", "type": "scatter", "line": {"width": 3.0}},
+                    {"mode": "lines", "type": "scatter", "line": {"width": 1.0}},
+                    {"mode": "markers", "type": "scatter", "line": {"width": 2.0}},
+                ]
+            },
+            "layout": {
+                "font": {"color": "#2a3f5f", "size": 18},
+                "colorway": ["#003366"],
+                "autotypenumbers": "strict"
+            }
+        });
+
+        assert_eq!(to_value(base.merge(&overlay)).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_numbered_axes() {
+        let layout = Layout::new()
+            .x_axis(Axis::new().title("x".into()))
+            .y_axis_n(24, Axis::new().title("y24".into()))
+            .x_axis_n(9, Axis::new());
+        let expected = json!({
+            "xaxis": {"title": {"text": "x"}},
+            "xaxis9": {},
+            "yaxis24": {"title": {"text": "y24"}}
+        });
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize_layout_numbered_axes() {
+        let json = json!({
+            "xaxis": {"title": {"text": "x"}},
+            "yaxis24": {"anchor": "x"},
+            "xaxis1": {"title": {"text": "not an axis"}},
+            "hidesources": true
+        });
+        let layout: Layout = from_value(json.clone()).unwrap();
+
+        assert_eq!(layout.subplots.x.len(), 1);
+        assert_eq!(layout.subplots.y.len(), 1);
+        assert_eq!(layout.subplots.extra.len(), 2);
+        assert_eq!(to_value(layout).unwrap(), json);
+    }
+
+    #[test]
+    fn test_deserialize_layout_from_plotly() {
+        // As written by plotly.py for `fig.update_layout(title="x", font_size=12.5)` and an x axis
+        // set up with `title="abc"`, `zerolinewidth=1.5` and plotly.js 2's `tickmode="sync"`.
+        let json = json!({
+            "title": "x",
+            "font": {"size": 12.5},
+            "xaxis": {"title": "abc", "zerolinewidth": 1.5},
+            "yaxis2": {"overlaying": "y", "tickmode": "sync"},
+            "height": "tall"
+        });
+        let layout: Layout = from_value(json).unwrap();
+
+        assert_eq!(layout.subplots.x.len(), 1);
+        assert!(layout.subplots.y.is_empty());
+        assert!(layout.subplots.extra.is_empty());
+        assert_eq!(layout.unread.len(), 2);
+
+        let expected = json!({
+            "title": {"text": "x"},
+            "font": {"size": 12.5},
+            "xaxis": {"title": {"text": "abc"}, "zerolinewidth": 1.5},
+            "yaxis2": {"overlaying": "y", "tickmode": "sync"},
+            "height": "tall"
+        });
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_set_unread_layout_attribute() {
+        let json = json!({"height": "tall", "yaxis2": {"tickmode": "sync"}});
+        let layout: Layout = from_value(json).unwrap();
+
+        let errors = layout.read_errors();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("height: invalid type: string \"tall\""));
+        assert!(errors[1].starts_with("yaxis2: unknown variant `sync`"));
+
+        let layout = layout
+            .height(300)
+            .y_axis2(Axis::new().tick_mode(TickMode::Auto));
+        let expected = json!({"height": 300, "yaxis2": {"tickmode": "auto"}});
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_deserialize_layout_template_from_plotly() {
+        let template: Value =
+            serde_json::from_str(include_str!("../../templates/template.json")).unwrap();
+        let mut json = template["layout"].clone();
+        json["title"] = json!("x");
+        json["xaxis"]["tickmode"] = json!("sync");
+        let layout: LayoutTemplate = from_value(json.clone()).unwrap();
+
+        assert!(!layout.subplots.x.contains_key(&1));
+        assert_eq!(layout.unread["xaxis"].value, json["xaxis"]);
+        let layout = to_value(layout).unwrap();
+        assert_eq!(layout["title"], json!({"text": "x"}));
+        assert_eq!(layout["font"], template["layout"]["font"]);
+        assert_eq!(layout["yaxis"]["zerolinewidth"], json!(2));
+    }
+
+    #[test]
+    fn test_layout_template_numbered_axes() {
+        let json = json!({
+            "xaxis9": {"showgrid": false},
+            "yaxis12": {"showgrid": true},
+            "zaxis3": {"zeroline": false}
+        });
+        let layout: LayoutTemplate = from_value(json.clone()).unwrap();
+
+        assert!(layout.subplots.x.contains_key(&9));
+        assert!(layout.subplots.y.contains_key(&12));
+        assert!(layout.subplots.z.contains_key(&3));
+        assert!(layout.subplots.extra.is_empty());
+        assert_eq!(to_value(layout).unwrap(), json);
+
+        let layout = LayoutTemplate::new()
+            .x_axis_n(9, Axis::new().show_grid(false))
+            .y_axis_n(12, Axis::new().show_grid(true))
+            .z_axis_n(3, Axis::new().zero_line(false));
+        assert_eq!(to_value(layout).unwrap(), json);
+    }
+
+    #[test]
+    fn test_layout_numbered_scenes() {
+        let json = json!({
+            "scene": {"dragmode": "orbit"},
+            "scene12": {"dragmode": "turntable"}
+        });
+        let layout: Layout = from_value(json.clone()).unwrap();
+
+        assert_eq!(layout.subplots.scene.len(), 2);
+        assert!(layout.subplots.extra.is_empty());
+        assert_eq!(to_value(layout).unwrap(), json);
+
+        let layout = LayoutTemplate::new()
+            .scene(LayoutScene::new().drag_mode(DragMode::Orbit))
+            .scene_n(12, LayoutScene::new().drag_mode(DragMode::Turntable));
+        assert_eq!(to_value(layout).unwrap(), json);
+    }
+
+    #[test]
+    fn test_layout_numbered_polars() {
+        let json = json!({
+            "polar": {"hole": 0.1},
+            "polar12": {"hole": 0.2}
+        });
+        let layout: Layout = from_value(json.clone()).unwrap();
+
+        assert_eq!(layout.subplots.polar.len(), 2);
+        assert!(layout.subplots.extra.is_empty());
+        assert_eq!(to_value(layout).unwrap(), json);
+
+        let layout = LayoutTemplate::new()
+            .polar(LayoutPolar::new().hole(0.1))
+            .polar_n(12, LayoutPolar::new().hole(0.2));
+        assert_eq!(to_value(layout).unwrap(), json);
+    }
+
+    #[test]
+    #[should_panic(expected = "axes are numbered from 1")]
+    fn test_layout_axis_zero() {
+        Layout::new().x_axis_n(0, Axis::new());
+    }
+
     #[test]
     fn test_serialize_layout() {
         let layout = Layout::new()
@@ -4416,6 +10412,7 @@ mod tests {
             .hover_distance(321)
             .spike_distance(12)
             .hover_label(Label::new())
+            .transition(Transition::new())
             .template(Template::new())
             .grid(LayoutGrid::new())
             .calendar(Calendar::Jalali)
@@ -4435,10 +10432,17 @@ mod tests {
             .y_axis6(Axis::new())
             .y_axis7(Axis::new())
             .y_axis8(Axis::new())
+            .ternary(LayoutTernary::new())
+            .scene(LayoutScene::new())
+            .polar(LayoutPolar::new())
+            .geo(LayoutGeo::new())
+            .mapbox(LayoutMapbox::new())
             .annotations(vec![Annotation::new()])
             .shapes(vec![Shape::new()])
             .new_shape(NewShape::new())
             .active_shape(ActiveShape::new())
+            .update_menus(vec![UpdateMenu::new()])
+            .sliders(vec![Slider::new()])
             .box_mode(BoxMode::Group)
             .box_gap(1.)
             .box_group_gap(2.)
@@ -4456,6 +10460,10 @@ mod tests {
             .extend_pie_colors(true)
             .sunburst_colorway(vec!["#654654"])
             .extend_sunburst_colors(false)
+            .treemap_colorway(vec!["#321321"])
+            .extend_treemap_colors(true)
+            .icicle_colorway(vec!["#987987"])
+            .extend_icicle_colors(false)
             .z_axis(Axis::new());
 
         let expected = json!({
@@ -4482,6 +10490,7 @@ mod tests {
             "hoverdistance": 321,
             "spikedistance": 12,
             "hoverlabel": {},
+            "transition": {},
             "template": {},
             "grid": {},
             "calendar": "jalali",
@@ -4501,10 +10510,17 @@ mod tests {
             "yaxis6": {},
             "yaxis7": {},
             "yaxis8": {},
+            "ternary": {},
+            "scene": {},
+            "polar": {},
+            "geo": {},
+            "mapbox": {},
             "annotations": [{}],
             "shapes": [{}],
             "newshape": {},
             "activeshape": {},
+            "updatemenus": [{}],
+            "sliders": [{}],
             "boxmode": "group",
             "boxgap": 1.0,
             "boxgroupgap": 2.0,
@@ -4522,9 +10538,51 @@ mod tests {
             "extendpiecolors": true,
             "sunburstcolorway": ["#654654"],
             "extendsunburstcolors": false,
+            "treemapcolorway": ["#321321"],
+            "extendtreemapcolors": true,
+            "iciclecolorway": ["#987987"],
+            "extendiciclecolors": false,
             "zaxis": {},
         });
 
         assert_eq!(to_value(layout).unwrap(), expected);
     }
+
+    #[test]
+    fn test_serialize_layout_numbered_subplots() {
+        let layout = Layout::new()
+            .scene2(LayoutScene::new())
+            .scene3(LayoutScene::new())
+            .scene4(LayoutScene::new())
+            .scene5(LayoutScene::new())
+            .scene6(LayoutScene::new())
+            .scene7(LayoutScene::new())
+            .scene8(LayoutScene::new())
+            .polar2(LayoutPolar::new())
+            .polar3(LayoutPolar::new())
+            .polar4(LayoutPolar::new())
+            .polar5(LayoutPolar::new())
+            .polar6(LayoutPolar::new())
+            .polar7(LayoutPolar::new())
+            .polar8(LayoutPolar::new());
+
+        let expected = json!({
+            "scene2": {},
+            "scene3": {},
+            "scene4": {},
+            "scene5": {},
+            "scene6": {},
+            "scene7": {},
+            "scene8": {},
+            "polar2": {},
+            "polar3": {},
+            "polar4": {},
+            "polar5": {},
+            "polar6": {},
+            "polar7": {},
+            "polar8": {},
+        });
+
+        assert_eq!(to_value(layout).unwrap(), expected);
+    }
 }
@@ -0,0 +1,592 @@
+//! A builder for grids of subplots, in the style of plotly.py's `make_subplots`.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    common::{Anchor, AxisSide, Font, XAxisRef, YAxisRef},
+    layout::{Annotation, Axis, Layout},
+    traces, Plot, Trace,
+};
+
+/// The extent of one cell of a `Subplots` grid, and whether it has a secondary y axis.
+///
+/// A cell spanning several rows or columns covers the cells below it or to its right, which must
+/// be left empty (`None`) in `Subplots::specs`.
+#[derive(Debug, Clone)]
+pub struct SubplotSpec {
+    row_span: usize,
+    col_span: usize,
+    secondary_y: bool,
+}
+
+impl Default for SubplotSpec {
+    fn default() -> Self {
+        Self {
+            row_span: 1,
+            col_span: 1,
+            secondary_y: false,
+        }
+    }
+}
+
+impl SubplotSpec {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the number of rows spanned by the cell. Defaults to 1.
+    pub fn row_span(mut self, row_span: usize) -> Self {
+        self.row_span = row_span;
+        self
+    }
+
+    /// Sets the number of columns spanned by the cell. Defaults to 1.
+    pub fn col_span(mut self, col_span: usize) -> Self {
+        self.col_span = col_span;
+        self
+    }
+
+    /// Adds a y axis on the right of the cell, overlaying its primary y axis, for the traces added
+    /// with `Subplots::add_secondary_y_trace`.
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+}
+
+/// Lays out a grid of subplots, generating their axes and assigning traces to them.
+///
+/// Rows and columns are numbered from 1, starting at the top left cell. Once all traces are added,
+/// `into_plot` turns the grid into a `Plot`, with the axis references of the traces set, the axes
+/// of every cell placed in the layout, and the subplot titles added as annotations.
+///
+/// Only x-y subplots are supported.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::layout::subplots::Subplots;
+/// use plotly::Scatter;
+///
+/// let mut subplots = Subplots::new(2, 1)
+///     .shared_x_axes(true)
+///     .row_heights(vec![0.7, 0.3])
+///     .subplot_titles(vec!["Price", "Volume"]);
+/// subplots.add_trace(Scatter::new(vec![1, 2, 3], vec![10.0, 10.5, 9.8]), 1, 1);
+/// subplots.add_trace(Scatter::new(vec![1, 2, 3], vec![300, 120, 210]), 2, 1);
+///
+/// let plot = subplots.into_plot();
+/// ```
+#[derive(Clone)]
+pub struct Subplots {
+    rows: usize,
+    cols: usize,
+    shared_x_axes: bool,
+    shared_y_axes: bool,
+    row_heights: Option<Vec<f64>>,
+    column_widths: Option<Vec<f64>>,
+    horizontal_spacing: Option<f64>,
+    vertical_spacing: Option<f64>,
+    specs: Option<Vec<Vec<Option<SubplotSpec>>>>,
+    subplot_titles: Vec<String>,
+    layout: Layout,
+    x_axes: BTreeMap<(usize, usize), Axis>,
+    y_axes: BTreeMap<(usize, usize), Axis>,
+    secondary_y_axes: BTreeMap<(usize, usize), Axis>,
+    traces: Vec<(Box<dyn Trace>, usize, usize, bool)>,
+}
+
+/// The axes generated for a non-empty cell.
+struct Cell {
+    x: usize,
+    y: usize,
+    secondary_y: Option<usize>,
+    x_domain: [f64; 2],
+    y_domain: [f64; 2],
+}
+
+/// Splits `1 - spacing * (n - 1)` according to `weights`, returning the start and end of each of
+/// the `n` parts, from 0 to 1.
+fn split(weights: &Option<Vec<f64>>, n: usize, spacing: f64, what: &str) -> Vec<[f64; 2]> {
+    let weights = match weights {
+        Some(weights) => {
+            assert_eq!(weights.len(), n, "expected {} {}", n, what);
+            weights.clone()
+        }
+        None => vec![1.; n],
+    };
+    assert!(
+        n == 1 || spacing * ((n - 1) as f64) < 1.,
+        "the spacing between {} must be less than 1 / {}",
+        what,
+        n - 1
+    );
+
+    let total: f64 = weights.iter().sum();
+    let available = 1. - spacing * (n - 1) as f64;
+    let mut start = 0.;
+    let mut parts: Vec<[f64; 2]> = weights
+        .iter()
+        .map(|weight| {
+            let end = start + available * weight / total;
+            let part = [start, end];
+            start = end + spacing;
+            part
+        })
+        .collect();
+    // Avoid rounding errors past the edge, as Plotly.js rejects domains outside of [0, 1].
+    parts[n - 1][1] = 1.;
+    parts
+}
+
+impl Subplots {
+    /// Creates a grid of `rows` by `cols` cells, each holding one subplot.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        assert!(
+            rows > 0 && cols > 0,
+            "a grid needs at least one row and column"
+        );
+        Self {
+            rows,
+            cols,
+            shared_x_axes: false,
+            shared_y_axes: false,
+            row_heights: None,
+            column_widths: None,
+            horizontal_spacing: None,
+            vertical_spacing: None,
+            specs: None,
+            subplot_titles: Vec::new(),
+            layout: Layout::new(),
+            x_axes: BTreeMap::new(),
+            y_axes: BTreeMap::new(),
+            secondary_y_axes: BTreeMap::new(),
+            traces: Vec::new(),
+        }
+    }
+
+    /// Links the x axes of the subplots of each column, so that they zoom and pan together, and
+    /// only shows tick labels on the bottom one.
+    pub fn shared_x_axes(mut self, shared_x_axes: bool) -> Self {
+        self.shared_x_axes = shared_x_axes;
+        self
+    }
+
+    /// Links the y axes of the subplots of each row, so that they zoom and pan together, and only
+    /// shows tick labels on the leftmost one.
+    pub fn shared_y_axes(mut self, shared_y_axes: bool) -> Self {
+        self.shared_y_axes = shared_y_axes;
+        self
+    }
+
+    /// Sets the relative heights of the rows, from top to bottom. Defaults to equal heights.
+    pub fn row_heights(mut self, row_heights: Vec<f64>) -> Self {
+        self.row_heights = Some(row_heights);
+        self
+    }
+
+    /// Sets the relative widths of the columns, from left to right. Defaults to equal widths.
+    pub fn column_widths(mut self, column_widths: Vec<f64>) -> Self {
+        self.column_widths = Some(column_widths);
+        self
+    }
+
+    /// Sets the space between columns, as a fraction of the plot width. Defaults to `0.2 / cols`.
+    pub fn horizontal_spacing(mut self, horizontal_spacing: f64) -> Self {
+        self.horizontal_spacing = Some(horizontal_spacing);
+        self
+    }
+
+    /// Sets the space between rows, as a fraction of the plot height. Defaults to `0.3 / rows`.
+    pub fn vertical_spacing(mut self, vertical_spacing: f64) -> Self {
+        self.vertical_spacing = Some(vertical_spacing);
+        self
+    }
+
+    /// Sets the spec of every cell, row by row from the top. `None` leaves a cell empty, as must be
+    /// the cells covered by another cell's row or column span. Defaults to a `SubplotSpec::new()`
+    /// in every cell.
+    pub fn specs(mut self, specs: Vec<Vec<Option<SubplotSpec>>>) -> Self {
+        self.specs = Some(specs);
+        self
+    }
+
+    /// Sets the titles of the subplots, placed above them in the order of their cells, row by row
+    /// from the top. Empty cells are skipped.
+    pub fn subplot_titles<S: AsRef<str>>(mut self, subplot_titles: Vec<S>) -> Self {
+        self.subplot_titles = subplot_titles
+            .iter()
+            .map(|title| title.as_ref().to_string())
+            .collect();
+        self
+    }
+
+    /// Sets the layout the axes and subplot titles are added to, e.g. to give the plot a title.
+    /// Axes set on it are replaced by the generated ones; use `x_axis` and `y_axis` instead.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the x axis of the subplot at `row` and `col`, e.g. to give it a title. Its domain,
+    /// anchor and, with `shared_x_axes`, matching axis are set by the grid.
+    pub fn x_axis(mut self, row: usize, col: usize, axis: Axis) -> Self {
+        self.x_axes.insert((row, col), axis);
+        self
+    }
+
+    /// Sets the y axis of the subplot at `row` and `col`, e.g. to give it a title. Its domain,
+    /// anchor and, with `shared_y_axes`, matching axis are set by the grid.
+    pub fn y_axis(mut self, row: usize, col: usize, axis: Axis) -> Self {
+        self.y_axes.insert((row, col), axis);
+        self
+    }
+
+    /// Sets the secondary y axis of the subplot at `row` and `col`, whose spec must enable
+    /// `secondary_y`.
+    pub fn secondary_y_axis(mut self, row: usize, col: usize, axis: Axis) -> Self {
+        self.secondary_y_axes.insert((row, col), axis);
+        self
+    }
+
+    /// Adds a trace to the subplot at `row` and `col`, counted from 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell lies outside the grid. `into_plot` panics if the cell is empty.
+    pub fn add_trace(&mut self, trace: Box<dyn Trace>, row: usize, col: usize) {
+        self.check_cell(row, col);
+        self.traces.push((trace, row, col, false));
+    }
+
+    /// Adds a trace to the secondary y axis of the subplot at `row` and `col`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell lies outside the grid. `into_plot` panics if the cell is empty or has no
+    /// secondary y axis.
+    pub fn add_secondary_y_trace(&mut self, trace: Box<dyn Trace>, row: usize, col: usize) {
+        self.check_cell(row, col);
+        self.traces.push((trace, row, col, true));
+    }
+
+    fn check_cell(&self, row: usize, col: usize) {
+        assert!(
+            (1..=self.rows).contains(&row) && (1..=self.cols).contains(&col),
+            "no cell at row {} and column {} in a grid of {} by {}",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+    }
+
+    /// Returns the cells of the grid, indexed from 0, with the axes generated for them.
+    fn cells(&self) -> Vec<Vec<Option<Cell>>> {
+        let specs = match &self.specs {
+            Some(specs) => {
+                assert!(
+                    specs.len() == self.rows && specs.iter().all(|row| row.len() == self.cols),
+                    "expected specs for {} rows of {} columns",
+                    self.rows,
+                    self.cols
+                );
+                specs.clone()
+            }
+            None => vec![vec![Some(SubplotSpec::new()); self.cols]; self.rows],
+        };
+        let columns = split(
+            &self.column_widths,
+            self.cols,
+            self.horizontal_spacing.unwrap_or(0.2 / self.cols as f64),
+            "columns",
+        );
+        let rows = split(
+            &self.row_heights,
+            self.rows,
+            self.vertical_spacing.unwrap_or(0.3 / self.rows as f64),
+            "rows",
+        );
+
+        let (mut x, mut y) = (0, 0);
+        let mut cells = Vec::with_capacity(self.rows);
+        for (r, specs) in specs.iter().enumerate() {
+            let mut row = Vec::with_capacity(self.cols);
+            for (c, spec) in specs.iter().enumerate() {
+                let spec = match spec {
+                    Some(spec) => spec,
+                    None => {
+                        row.push(None);
+                        continue;
+                    }
+                };
+                let (last_row, last_col) = (r + spec.row_span - 1, c + spec.col_span - 1);
+                assert!(
+                    last_row < self.rows && last_col < self.cols,
+                    "the subplot at row {} and column {} spans past the grid",
+                    r + 1,
+                    c + 1
+                );
+
+                x += 1;
+                y += 1;
+                let primary_y = y;
+                let secondary_y = if spec.secondary_y {
+                    y += 1;
+                    Some(y)
+                } else {
+                    None
+                };
+                // Rows are laid out from the top of the plot, the y domains from its bottom.
+                row.push(Some(Cell {
+                    x,
+                    y: primary_y,
+                    secondary_y,
+                    x_domain: [columns[c][0], columns[last_col][1]],
+                    y_domain: [1. - rows[last_row][1], 1. - rows[r][0]],
+                }));
+            }
+            cells.push(row);
+        }
+        cells
+    }
+
+    /// Turns the grid into a plot, with the axes and subplot titles in its layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specs, row heights or column widths do not match the size of the grid, if
+    /// the spacing leaves no room for the subplots, or if a trace was added to an empty cell.
+    pub fn into_plot(self) -> Plot {
+        let cells = self.cells();
+        let mut layout = self.layout.clone();
+
+        // The axes that the others match when sharing: the bottom one of each column, and the
+        // leftmost one of each row.
+        let mut shared_x = BTreeMap::new();
+        let mut shared_y = BTreeMap::new();
+        for (r, row) in cells.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                if let Some(cell) = cell {
+                    shared_x.insert(c, cell.x);
+                    shared_y.entry(r).or_insert(cell.y);
+                }
+            }
+        }
+
+        let mut titles = self.subplot_titles.iter();
+        for (r, row) in cells.iter().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                let cell = match cell {
+                    Some(cell) => cell,
+                    None => continue,
+                };
+                let x_name = XAxisRef::new(cell.x).to_string();
+                let y_name = YAxisRef::new(cell.y).to_string();
+                let position = (r + 1, c + 1);
+
+                let mut x_axis = self.x_axes.get(&position).cloned().unwrap_or_default();
+                x_axis = x_axis.domain(&cell.x_domain).anchor(&y_name);
+                if self.shared_x_axes && shared_x[&c] != cell.x {
+                    x_axis.matches = Some(XAxisRef::new(shared_x[&c]).to_string());
+                    x_axis = x_axis.show_tick_labels(false);
+                }
+                layout = layout.x_axis_n(cell.x, x_axis);
+
+                let mut y_axis = self.y_axes.get(&position).cloned().unwrap_or_default();
+                y_axis = y_axis.domain(&cell.y_domain).anchor(&x_name);
+                if self.shared_y_axes && shared_y[&r] != cell.y {
+                    y_axis.matches = Some(YAxisRef::new(shared_y[&r]).to_string());
+                    y_axis = y_axis.show_tick_labels(false);
+                }
+                layout = layout.y_axis_n(cell.y, y_axis);
+
+                if let Some(secondary_y) = cell.secondary_y {
+                    let axis = self
+                        .secondary_y_axes
+                        .get(&position)
+                        .cloned()
+                        .unwrap_or_default()
+                        .anchor(&x_name)
+                        .overlaying(&y_name)
+                        .side(AxisSide::Right);
+                    layout = layout.y_axis_n(secondary_y, axis);
+                }
+
+                if let Some(title) = titles.next() {
+                    layout.add_annotation(
+                        Annotation::new()
+                            .text(title)
+                            .x((cell.x_domain[0] + cell.x_domain[1]) / 2.)
+                            .x_ref("paper")
+                            .x_anchor(Anchor::Center)
+                            .y(cell.y_domain[1])
+                            .y_ref("paper")
+                            .y_anchor(Anchor::Bottom)
+                            .show_arrow(false)
+                            .font(Font::new().size(16)),
+                    );
+                }
+            }
+        }
+
+        let mut plot = Plot::new();
+        for (trace, row, col, secondary) in self.traces {
+            let cell = cells[row - 1][col - 1].as_ref().unwrap_or_else(|| {
+                panic!(
+                    "a trace was added to the empty cell at row {} and column {}",
+                    row, col
+                )
+            });
+            let y = if secondary {
+                cell.secondary_y.unwrap_or_else(|| {
+                    panic!(
+                        "a trace was added to the secondary y axis of the cell at row {} and \
+                         column {}, which has none",
+                        row, col
+                    )
+                })
+            } else {
+                cell.y
+            };
+
+            let mut trace = serde_json::to_value(trace).unwrap();
+            trace["xaxis"] = XAxisRef::new(cell.x).to_string().into();
+            trace["yaxis"] = YAxisRef::new(y).to_string().into();
+            plot.add_trace(traces::from_value(trace).unwrap_or_else(|(trace, _)| trace));
+        }
+        plot.set_layout(layout);
+        plot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::{Bar, Scatter};
+
+    #[test]
+    fn test_subplots_grid() {
+        let mut subplots = Subplots::new(2, 2)
+            .horizontal_spacing(0.5)
+            .vertical_spacing(0.5)
+            .subplot_titles(vec!["A", "B"]);
+        subplots.add_trace(Scatter::new(vec![1], vec![1]), 1, 1);
+        subplots.add_trace(Bar::new(vec![1], vec![1]), 2, 2);
+        let plot = to_value(subplots.into_plot()).unwrap();
+
+        assert_eq!(plot["data"][0]["xaxis"], "x");
+        assert_eq!(plot["data"][0]["yaxis"], "y");
+        assert_eq!(plot["data"][1]["type"], "bar");
+        assert_eq!(plot["data"][1]["xaxis"], "x4");
+        assert_eq!(plot["data"][1]["yaxis"], "y4");
+
+        let layout = &plot["layout"];
+        assert_eq!(
+            layout["xaxis"],
+            json!({"domain": [0.0, 0.25], "anchor": "y"})
+        );
+        assert_eq!(
+            layout["yaxis"],
+            json!({"domain": [0.75, 1.0], "anchor": "x"})
+        );
+        assert_eq!(
+            layout["xaxis4"],
+            json!({"domain": [0.75, 1.0], "anchor": "y4"})
+        );
+        assert_eq!(
+            layout["yaxis4"],
+            json!({"domain": [0.0, 0.25], "anchor": "x4"})
+        );
+        assert_eq!(
+            layout["annotations"],
+            json!([
+                {
+                    "text": "A", "font": {"size": 16}, "showarrow": false,
+                    "xref": "paper", "x": 0.125, "xanchor": "center",
+                    "yref": "paper", "y": 1.0, "yanchor": "bottom"
+                },
+                {
+                    "text": "B", "font": {"size": 16}, "showarrow": false,
+                    "xref": "paper", "x": 0.875, "xanchor": "center",
+                    "yref": "paper", "y": 1.0, "yanchor": "bottom"
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_subplots_specs_and_secondary_y() {
+        let mut subplots = Subplots::new(2, 2)
+            .horizontal_spacing(0.)
+            .vertical_spacing(0.)
+            .column_widths(vec![3., 1.])
+            .specs(vec![
+                vec![Some(SubplotSpec::new().col_span(2).secondary_y(true)), None],
+                vec![Some(SubplotSpec::new()), None],
+            ])
+            .secondary_y_axis(1, 1, Axis::new().title("Volume".into()));
+        subplots.add_trace(Scatter::new(vec![1], vec![1]), 1, 1);
+        subplots.add_secondary_y_trace(Bar::new(vec![1], vec![1]), 1, 1);
+        subplots.add_trace(Scatter::new(vec![1], vec![1]), 2, 1);
+        let plot = to_value(subplots.into_plot()).unwrap();
+
+        assert_eq!(plot["data"][1]["xaxis"], "x");
+        assert_eq!(plot["data"][1]["yaxis"], "y2");
+        assert_eq!(plot["data"][2]["xaxis"], "x2");
+        assert_eq!(plot["data"][2]["yaxis"], "y3");
+
+        let layout = &plot["layout"];
+        assert_eq!(layout["xaxis"]["domain"], json!([0.0, 1.0]));
+        assert_eq!(layout["xaxis2"]["domain"], json!([0.0, 0.75]));
+        assert_eq!(
+            layout["yaxis2"],
+            json!({
+                "title": {"text": "Volume"},
+                "anchor": "x",
+                "side": "right",
+                "overlaying": "y"
+            })
+        );
+        assert!(layout.get("xaxis3").is_none());
+    }
+
+    #[test]
+    fn test_subplots_shared_axes() {
+        let plot = Subplots::new(2, 2)
+            .shared_x_axes(true)
+            .shared_y_axes(true)
+            .into_plot();
+        let layout = to_value(plot.layout()).unwrap();
+
+        assert_eq!(layout["xaxis"]["matches"], "x3");
+        assert_eq!(layout["xaxis"]["showticklabels"], false);
+        assert!(layout["xaxis3"].get("matches").is_none());
+        assert_eq!(layout["yaxis2"]["matches"], "y");
+        assert_eq!(layout["yaxis2"]["showticklabels"], false);
+        assert_eq!(layout["yaxis4"]["matches"], "y3");
+    }
+
+    #[test]
+    fn test_subplots_more_than_eight_axes() {
+        let mut subplots = Subplots::new(3, 3);
+        subplots.add_trace(Scatter::new(vec![1], vec![1]), 3, 3);
+        let plot = to_value(subplots.into_plot()).unwrap();
+
+        assert_eq!(plot["data"][0]["xaxis"], "x9");
+        assert_eq!(plot["data"][0]["yaxis"], "y9");
+        assert_eq!(plot["layout"]["xaxis9"]["anchor"], "y9");
+        assert_eq!(plot["layout"]["yaxis9"]["anchor"], "x9");
+    }
+
+    #[test]
+    #[should_panic(expected = "empty cell at row 1 and column 2")]
+    fn test_subplots_trace_in_empty_cell() {
+        let mut subplots =
+            Subplots::new(1, 2).specs(vec![vec![Some(SubplotSpec::new().col_span(2)), None]]);
+        subplots.add_trace(Scatter::new(vec![1], vec![1]), 1, 2);
+        subplots.into_plot();
+    }
+}
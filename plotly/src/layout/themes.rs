@@ -1,8 +1,11 @@
 use once_cell::sync::Lazy;
 
 use crate::{
-    common::{ColorBar, ColorScale, ColorScaleElement, Font, Label, Title},
-    layout::{Axis, ColorAxis, HoverMode, LayoutColorScale, LayoutTemplate, Template},
+    common::{ColorBar, ColorScale, ColorScaleElement, Font, Label, Line, Marker, Title},
+    layout::{
+        Axis, ColorAxis, HoverMode, LayoutColorScale, LayoutTemplate, Template, TemplateData,
+    },
+    Scatter, Scatter3D,
 };
 
 pub static DEFAULT: Lazy<Template> = Lazy::new(|| {
@@ -10,6 +13,13 @@ pub static DEFAULT: Lazy<Template> = Lazy::new(|| {
     Template::new().layout(layout_template)
 });
 
+/// Plotly's default template, read from `plotly/templates/template.json`. Unlike the other themes,
+/// it also sets the default styling of each trace type, e.g. the outline of bars.
+pub static PLOTLY: Lazy<Template> = Lazy::new(|| {
+    Template::from_json(include_str!("../../templates/template.json"))
+        .expect("the bundled template is valid")
+});
+
 pub static PLOTLY_WHITE: Lazy<Template> = Lazy::new(|| {
     let layout_template = LayoutTemplate::new()
         .color_axis(ColorAxis::new().color_bar(ColorBar::new().outline_width(0)))
@@ -160,11 +170,122 @@ pub static PLOTLY_DARK: Lazy<Template> = Lazy::new(|| {
     Template::new().layout(layout_template)
 });
 
+/// The ggplot2 theme of plotly.py, read from `plotly/templates/ggplot2.json`.
+pub static GGPLOT2: Lazy<Template> = Lazy::new(|| {
+    Template::from_json(include_str!("../../templates/ggplot2.json"))
+        .expect("the bundled template is valid")
+});
+
+/// The seaborn theme of plotly.py, read from `plotly/templates/seaborn.json`.
+pub static SEABORN: Lazy<Template> = Lazy::new(|| {
+    Template::from_json(include_str!("../../templates/seaborn.json"))
+        .expect("the bundled template is valid")
+});
+
+/// The simple_white theme of plotly.py, read from `plotly/templates/simple_white.json`.
+pub static SIMPLE_WHITE: Lazy<Template> = Lazy::new(|| {
+    Template::from_json(include_str!("../../templates/simple_white.json"))
+        .expect("the bundled template is valid")
+});
+
+/// Larger fonts, lines and markers, for slides. Meant to be merged onto another theme, e.g.
+/// `PLOTLY_WHITE.clone().merge(&PRESENTATION)`.
+pub static PRESENTATION: Lazy<Template> = Lazy::new(|| {
+    // the following are unimplemented: the header and cell heights of tables
+    let data = TemplateData::new()
+        .trace(
+            Scatter::<f64, f64>::default()
+                .line(Line::new().width(3.))
+                .marker(Marker::new().size(9)),
+        )
+        .trace(
+            Scatter::<f64, f64>::default()
+                .web_gl_mode(true)
+                .line(Line::new().width(3.))
+                .marker(Marker::new().size(9)),
+        )
+        .trace(
+            Scatter3D::<f64, f64, f64>::default()
+                .line(Line::new().width(6.))
+                .marker(Marker::new().size(9)),
+        );
+    Template::new()
+        .layout(LayoutTemplate::new().font(Font::new().size(18)))
+        .data(data)
+});
+
+/// Hides the x axis grid lines. Meant to be merged onto another theme.
+pub static XGRIDOFF: Lazy<Template> = Lazy::new(|| {
+    Template::new().layout(LayoutTemplate::new().x_axis(Axis::new().show_grid(false)))
+});
+
+/// Hides the y axis grid lines. Meant to be merged onto another theme.
+pub static YGRIDOFF: Lazy<Template> = Lazy::new(|| {
+    Template::new().layout(LayoutTemplate::new().y_axis(Axis::new().show_grid(false)))
+});
+
+/// Shows the grid lines of both axes. Meant to be merged onto another theme.
+pub static GRIDON: Lazy<Template> = Lazy::new(|| {
+    let layout_template = LayoutTemplate::new()
+        .x_axis(Axis::new().show_grid(true))
+        .y_axis(Axis::new().show_grid(true));
+    Template::new().layout(layout_template)
+});
+
+/// No styling at all, leaving Plotly.js' own defaults.
+pub static NONE: Lazy<Template> = Lazy::new(Template::new);
+
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::*;
     use crate::*;
 
+    /// Numbers are written back as floats, e.g. a colorscale position of `0` as `0.0`, so compare
+    /// them as such.
+    fn normalize(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Number(n) => json!(n.as_f64()),
+            serde_json::Value::Array(values) => values.into_iter().map(normalize).collect(),
+            serde_json::Value::Object(values) => values
+                .into_iter()
+                .map(|(key, value)| (key, normalize(value)))
+                .collect(),
+            value => value,
+        }
+    }
+
+    fn assert_template_eq(template: &Template, json: &str) {
+        let template = serde_json::to_value(template).unwrap();
+        let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+
+        assert_eq!(normalize(template), normalize(expected));
+    }
+
+    #[test]
+    fn test_plotly() {
+        assert_template_eq(&PLOTLY, include_str!("../../templates/template.json"));
+    }
+
+    #[test]
+    fn test_ggplot2() {
+        assert_template_eq(&GGPLOT2, include_str!("../../templates/ggplot2.json"));
+    }
+
+    #[test]
+    fn test_seaborn() {
+        assert_template_eq(&SEABORN, include_str!("../../templates/seaborn.json"));
+    }
+
+    #[test]
+    fn test_simple_white() {
+        assert_template_eq(
+            &SIMPLE_WHITE,
+            include_str!("../../templates/simple_white.json"),
+        );
+    }
+
     #[test]
     fn test_plotly_dark() {
         let template = &*PLOTLY_DARK;
@@ -177,4 +298,29 @@ mod tests {
             r##"{"template":{"layout":{"title":{"text":"","x":0.05},"font":{"color":"#f2f5fa"}"##; // etc...
         assert!(plot.to_json().contains(expected));
     }
+
+    #[test]
+    fn test_merge_themes() {
+        let template = SIMPLE_WHITE.clone().merge(&PRESENTATION).merge(&GRIDON);
+        let template = serde_json::to_value(template).unwrap();
+
+        assert_eq!(
+            template["layout"]["font"],
+            json!({"color": "rgb(36,36,36)", "size": 18})
+        );
+        assert_eq!(template["layout"]["xaxis"]["showgrid"], json!(true));
+        assert_eq!(template["layout"]["xaxis"]["showline"], json!(true));
+        assert_eq!(
+            template["data"]["scattergl"],
+            json!([{
+                "type": "scattergl",
+                "line": {"width": 3.0},
+                "marker": {
+                    "size": 9,
+                    "colorbar": {"outlinewidth": 1, "tickcolor": "rgb(36,36,36)", "ticks": "outside"}
+                }
+            }])
+        );
+        assert_eq!(serde_json::to_value(&*NONE).unwrap(), json!({}));
+    }
 }
@@ -14,22 +14,29 @@ pub mod bindings;
 
 pub mod common;
 pub mod configuration;
+mod error;
 pub mod layout;
 pub mod plot;
 mod traces;
 
 pub use common::color;
 pub use configuration::Configuration;
+pub use error::Error;
 pub use layout::Layout;
-pub use plot::{ImageFormat, Plot, Trace};
+pub use plot::{Frame, ImageFormat, Plot, Trace};
 
 // Bring the different trace types into the top-level scope
 pub use traces::{
-    Bar, BoxPlot, Candlestick, Contour, HeatMap, Histogram, Ohlc, Sankey, Scatter, Scatter3D,
-    ScatterPolar, Surface,
+    Bar, BarPolar, BoxPlot, Candlestick, Choropleth, ChoroplethMapbox, Cone, Contour,
+    DensityMapbox, HeatMap, Histogram, Histogram2d, Histogram2dContour, Icicle, Isosurface, Mesh3D,
+    Ohlc, Pie, Sankey, Scatter, Scatter3D, ScatterGeo, ScatterMapbox, ScatterPolar, ScatterTernary,
+    Streamtube, Sunburst, Surface, Treemap, Violin, Volume, Waterfall,
 };
 // Also provide easy access to modules which contain additional trace-specific types
-pub use traces::{box_plot, contour, histogram, sankey, surface};
+pub use traces::{
+    box_plot, cone, contour, hierarchy, histogram, mesh3d, pie, sankey, streamtube, surface,
+    violin, volume, waterfall,
+};
 
 #[cfg(feature = "plotly_ndarray")]
 pub use crate::ndarray::ArrayTraces;
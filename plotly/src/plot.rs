@@ -1,5 +1,8 @@
+use std::collections::{BTreeMap, BTreeSet};
+#[cfg(not(feature = "wasm"))]
 use std::fs::File;
-use std::io::Write;
+#[cfg(not(feature = "wasm"))]
+use std::io::{self, Write};
 use std::path::Path;
 
 use askama::Template;
@@ -9,21 +12,24 @@ use rand::{
     distributions::{Alphanumeric, DistString},
     thread_rng,
 };
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::{Configuration, Layout};
+use crate::{layout::LayoutGeo, private::Extra, traces, Configuration, Error, Layout};
 
 #[derive(Template)]
 #[template(path = "plot.html", escape = "none")]
 struct PlotTemplate<'a> {
     plot: &'a Plot,
+    topojson: BTreeMap<String, serde_json::Value>,
     remote_plotly_js: bool,
 }
 
+#[cfg(not(feature = "wasm"))]
 #[derive(Template)]
 #[template(path = "static_plot.html", escape = "none")]
 struct StaticPlotTemplate<'a> {
     plot: &'a Plot,
+    topojson: BTreeMap<String, serde_json::Value>,
     format: ImageFormat,
     remote_plotly_js: bool,
     width: usize,
@@ -34,6 +40,7 @@ struct StaticPlotTemplate<'a> {
 #[template(path = "inline_plot.html", escape = "none")]
 struct InlinePlotTemplate<'a> {
     plot: &'a Plot,
+    topojson: BTreeMap<String, serde_json::Value>,
     plot_div_id: &'a str,
 }
 
@@ -41,9 +48,16 @@ struct InlinePlotTemplate<'a> {
 #[template(path = "jupyter_notebook_plot.html", escape = "none")]
 struct JupyterNotebookPlotTemplate<'a> {
     plot: &'a Plot,
+    topojson: BTreeMap<String, serde_json::Value>,
     plot_div_id: &'a str,
 }
 
+/// The topojson files of Plotly.js, keyed by name, bundled at build time by the `topojson` feature.
+#[cfg(feature = "topojson")]
+const BUNDLED_TOPOJSON: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/topojson.rs"));
+#[cfg(not(feature = "topojson"))]
+const BUNDLED_TOPOJSON: &[(&str, &str)] = &[];
+
 #[cfg(not(feature = "wasm"))]
 const DEFAULT_HTML_APP_NOT_FOUND: &str = r#"Could not find default application for HTML files.
 Consider using the `to_html` method obtain a string representation instead. If using the `kaleido` feature the
@@ -94,9 +108,29 @@ impl std::fmt::Display for ImageFormat {
     }
 }
 
+impl ImageFormat {
+    /// The MIME type of the format, e.g. for the `Content-Type` of an HTTP response.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::PNG => "image/png",
+            Self::JPEG => "image/jpeg",
+            Self::WEBP => "image/webp",
+            Self::SVG => "image/svg+xml",
+            Self::PDF => "application/pdf",
+            Self::EPS => "application/postscript",
+        }
+    }
+}
+
 /// A struct that implements `Trace` can be serialized to json format that is understood by Plotly.js.
 pub trait Trace: DynClone + ErasedSerialize {
     fn to_json(&self) -> String;
+
+    /// The id of the geographic map the trace is drawn on, e.g. "geo2", if it is drawn on one.
+    #[doc(hidden)]
+    fn geo_subplot(&self) -> Option<&str> {
+        None
+    }
 }
 
 dyn_clone::clone_trait_object!(Trace);
@@ -106,12 +140,16 @@ erased_serde::serialize_trait_object!(Trace);
 #[serde(transparent)]
 pub struct Traces {
     traces: Vec<Box<dyn Trace>>,
+    // The index of each trace kept as it was read, with the error met reading it.
+    #[serde(skip)]
+    read_errors: Vec<(usize, String)>,
 }
 
 impl Traces {
     pub fn new() -> Self {
         Self {
             traces: Vec::with_capacity(1),
+            read_errors: Vec::new(),
         }
     }
 
@@ -136,6 +174,113 @@ impl Traces {
     }
 }
 
+impl<'de> Deserialize<'de> for Traces {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut traces = Traces::new();
+        for (index, trace) in Vec::<serde_json::Value>::deserialize(deserializer)?
+            .into_iter()
+            .enumerate()
+        {
+            match traces::from_value(trace) {
+                Ok(trace) => traces.push(trace),
+                Err((trace, e)) => {
+                    traces.push(trace);
+                    traces.read_errors.push((index, e.to_string()));
+                }
+            }
+        }
+        Ok(traces)
+    }
+}
+
+/// A single state of an animated `Plot`, as consumed by `Plotly.animate`.
+///
+/// The `data` and `layout` of a frame are merged into the plot when the frame is shown, so a frame
+/// only needs to hold what changes. By default its traces replace the plot's traces in order; use
+/// `Frame::traces` to target specific traces by index instead.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{Frame, Scatter};
+///
+/// let frame = Frame::new()
+///     .name("2007")
+///     .data(vec![Scatter::new(vec![1, 2], vec![3, 4])]);
+///
+/// let expected = serde_json::json!({
+///     "name": "2007",
+///     "data": [{"type": "scatter", "x": [1, 2], "y": [3, 4]}]
+/// });
+///
+/// assert_eq!(serde_json::to_value(frame).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Default, Serialize, Deserialize, Clone)]
+pub struct Frame {
+    name: Option<String>,
+    group: Option<String>,
+    #[serde(rename = "baseframe")]
+    base_frame: Option<String>,
+    traces: Option<Vec<usize>>,
+    data: Option<Traces>,
+    layout: Option<Layout>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Frame {
+    /// Create a new, empty `Frame`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the name of the frame, which is used to refer to it in `Plotly.animate` calls, as well
+    /// as in the `args` of buttons and slider steps.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the group of the frame, so that a group of frames can be animated together.
+    pub fn group(mut self, group: &str) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Set the name of the frame from which this frame gets its defaults, before its own `data`
+    /// and `layout` are applied.
+    pub fn base_frame(mut self, base_frame: &str) -> Self {
+        self.base_frame = Some(base_frame.to_string());
+        self
+    }
+
+    /// Set the indices of the plot's traces which are updated by the traces in `data`.
+    pub fn traces(mut self, traces: Vec<usize>) -> Self {
+        self.traces = Some(traces);
+        self
+    }
+
+    /// Set the traces of the frame.
+    pub fn data(mut self, data: Vec<Box<dyn Trace>>) -> Self {
+        let mut traces = Traces::new();
+        for trace in data {
+            traces.push(trace);
+        }
+        self.data = Some(traces);
+        self
+    }
+
+    /// Set the layout of the frame.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+        self
+    }
+}
+
 /// Plot is a container for structs that implement the `Trace` trait. Optionally a `Layout` can
 /// also be specified. Its function is to serialize `Trace`s and the `Layout` in html format and
 /// display and/or persist the resulting plot.
@@ -164,6 +309,7 @@ impl Traces {
 ///     let layout = Layout::new().title("<b>Line and Scatter Plot</b>".into());
 ///     plot.set_layout(layout);
 ///
+/// #   #[cfg(not(feature = "wasm"))]
 ///     plot.show();
 /// }
 ///
@@ -172,15 +318,24 @@ impl Traces {
 ///     Ok(())
 /// }
 /// ```
-#[derive(Default, Serialize, Clone)]
+#[derive(Default, Serialize, Deserialize, Clone)]
 pub struct Plot {
-    #[serde(rename = "data")]
+    #[serde(rename = "data", default)]
     traces: Traces,
+    #[serde(default)]
     layout: Layout,
-    #[serde(rename = "config")]
+    #[serde(rename = "config", default)]
     configuration: Configuration,
-    #[serde(skip)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    frames: Vec<Frame>,
+    #[serde(skip, default = "default_remote_plotly_js")]
     remote_plotly_js: bool,
+    #[serde(skip)]
+    topojson: BTreeMap<String, serde_json::Value>,
+}
+
+fn default_remote_plotly_js() -> bool {
+    true
 }
 
 impl Plot {
@@ -202,6 +357,34 @@ impl Plot {
         self.remote_plotly_js = false;
     }
 
+    /// Embed a topojson file in the HTML output and in static images, so that geographic plots
+    /// using it can be rendered without fetching it from the network.
+    ///
+    /// The `name` is the one Plotly.js derives from the `scope` and `resolution` of a `LayoutGeo`,
+    /// for example "world_110m", "usa_50m" or "south-america_110m". With the `topojson` feature,
+    /// the files Plotly.js ships in its `dist/topojson/` directory are bundled, and those the geo
+    /// subplots of the plot use are embedded without calling this; a file added here takes
+    /// precedence over the bundled one of the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use plotly::Plot;
+    ///
+    /// let topojson = serde_json::json!({
+    ///     "type": "Topology",
+    ///     "objects": {},
+    ///     "arcs": []
+    /// });
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.add_topojson("world_110m", topojson);
+    /// assert!(plot.to_html().contains("world_110m"));
+    /// ```
+    pub fn add_topojson(&mut self, name: &str, topojson: serde_json::Value) {
+        self.topojson.insert(name.to_string(), topojson);
+    }
+
     /// Add a `Trace` to the `Plot`.
     pub fn add_trace(&mut self, trace: Box<dyn Trace>) {
         self.traces.push(trace);
@@ -214,6 +397,20 @@ impl Plot {
         }
     }
 
+    /// Add a `Frame` to the `Plot`. The frames are passed to `Plotly.newPlot` along with the
+    /// data and layout in the HTML output, from where `Plotly.animate` can play them, for example
+    /// from a `layout::Button` using `layout::ButtonMethod::Animate`.
+    pub fn add_frame(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    /// Add multiple `Frame`s to the `Plot`.
+    pub fn add_frames(&mut self, frames: Vec<Frame>) {
+        for frame in frames {
+            self.add_frame(frame);
+        }
+    }
+
     /// Set the `Layout` to be used by `Plot`.
     pub fn set_layout(&mut self, layout: Layout) {
         self.layout = layout;
@@ -239,39 +436,58 @@ impl Plot {
         &self.configuration
     }
 
+    /// Get the animation frames of the plot.
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
     /// Display the fully rendered HTML `Plot` in the default system browser.
     ///
     /// The HTML file is saved in a temp file, from which it is read and displayed by the browser.
+    ///
+    /// Panics if the file cannot be written or the browser cannot be started; use
+    /// `Plot::try_show` to handle these errors instead.
     #[cfg(not(feature = "wasm"))]
     pub fn show(&self) {
-        use std::env;
-
-        let rendered = self.render();
-
-        // Set up the temp file with a unique filename.
-        let mut temp = env::temp_dir();
-        let mut plot_name = Alphanumeric.sample_string(&mut thread_rng(), 22);
-        plot_name.push_str(".html");
-        plot_name = format!("plotly_{}", plot_name);
-        temp.push(plot_name);
-
-        // Save the rendered plot to the temp file.
-        let temp_path = temp.to_str().unwrap();
-        let mut file = File::create(temp_path).unwrap();
-        file.write_all(rendered.as_bytes())
-            .expect("failed to write html output");
-        file.flush().unwrap();
+        self.try_show()
+            .unwrap_or_else(|e| panic!("failed to show plot: {}", e));
+    }
 
-        // Hand off the job of opening the browser to an OS-specific implementation.
-        Plot::show_with_default_app(temp_path);
+    /// Display the fully rendered HTML `Plot` in the default system browser, returning an error
+    /// if the temp file cannot be written or the browser cannot be started.
+    #[cfg(not(feature = "wasm"))]
+    pub fn try_show(&self) -> Result<(), Error> {
+        let rendered = self.render()?;
+        Plot::show_rendered(&rendered)
     }
 
     /// Display the fully rendered `Plot` as a static image of the given format in the default system browser.
+    ///
+    /// Panics if the file cannot be written or the browser cannot be started; use
+    /// `Plot::try_show_image` to handle these errors instead.
     #[cfg(not(feature = "wasm"))]
     pub fn show_image(&self, format: ImageFormat, width: usize, height: usize) {
-        use std::env;
+        self.try_show_image(format, width, height)
+            .unwrap_or_else(|e| panic!("failed to show plot: {}", e));
+    }
 
-        let rendered = self.render_static(format, width, height);
+    /// Display the fully rendered `Plot` as a static image of the given format in the default
+    /// system browser, returning an error if the temp file cannot be written or the browser cannot
+    /// be started.
+    #[cfg(not(feature = "wasm"))]
+    pub fn try_show_image(
+        &self,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        let rendered = self.render_static(format, width, height)?;
+        Plot::show_rendered(&rendered)
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    fn show_rendered(rendered: &str) -> Result<(), Error> {
+        use std::env;
 
         // Set up the temp file with a unique filename.
         let mut temp = env::temp_dir();
@@ -281,35 +497,56 @@ impl Plot {
         temp.push(plot_name);
 
         // Save the rendered plot to the temp file.
-        let temp_path = temp.to_str().unwrap();
-        let mut file = File::create(temp_path).unwrap();
-        file.write_all(rendered.as_bytes())
-            .expect("failed to write html output");
-        file.flush().unwrap();
+        let mut file = File::create(&temp)?;
+        file.write_all(rendered.as_bytes())?;
+        file.flush()?;
 
         // Hand off the job of opening the browser to an OS-specific implementation.
-        Plot::show_with_default_app(temp_path);
+        Plot::show_with_default_app(&temp).map_err(|e| {
+            io::Error::new(e.kind(), format!("{}\n{}", e, DEFAULT_HTML_APP_NOT_FOUND)).into()
+        })
     }
 
     /// Save the rendered `Plot` to a file at the given location.
     ///
     /// This method will render the plot to a full, standalone HTML document, before saving it to
     /// the given location.
+    ///
+    /// Panics if the file cannot be written; use `Plot::try_write_html` to handle this error
+    /// instead.
     #[cfg(not(feature = "wasm"))]
     pub fn write_html<P: AsRef<Path>>(&self, filename: P) {
-        let rendered = self.to_html();
+        self.try_write_html(filename)
+            .unwrap_or_else(|e| panic!("failed to write html output: {}", e));
+    }
+
+    /// Save the rendered `Plot` to a file at the given location, returning an error if it cannot
+    /// be written.
+    #[cfg(not(feature = "wasm"))]
+    pub fn try_write_html<P: AsRef<Path>>(&self, filename: P) -> Result<(), Error> {
+        let rendered = self.render()?;
 
-        let mut file = File::create(filename).unwrap();
-        file.write_all(rendered.as_bytes())
-            .expect("failed to write html output");
-        file.flush().unwrap();
+        let mut file = File::create(filename)?;
+        file.write_all(rendered.as_bytes())?;
+        file.flush()?;
+        Ok(())
     }
 
     /// Convert a `Plot` to an HTML string representation.
     ///
     /// This method will generate a full, standalone HTML document. To generate a minimal HTML string
     /// which can be embedded within an existing HTML page, use `Plot::to_inline_html()`.
+    ///
+    /// Panics if the plot cannot be rendered; use `Plot::try_to_html` to handle this error
+    /// instead.
     pub fn to_html(&self) -> String {
+        self.try_to_html()
+            .unwrap_or_else(|e| panic!("failed to render html output: {}", e))
+    }
+
+    /// Convert a `Plot` to an HTML string representation, returning an error if it cannot be
+    /// rendered.
+    pub fn try_to_html(&self) -> Result<String, Error> {
         self.render()
     }
 
@@ -331,7 +568,7 @@ impl Plot {
             Some(id) => id.to_string(),
             None => Alphanumeric.sample_string(&mut thread_rng(), 20),
         };
-        self.render_inline(&plot_div_id)
+        self.render_inline(&plot_div_id).unwrap()
     }
 
     fn to_jupyter_notebook_html(&self) -> String {
@@ -339,6 +576,7 @@ impl Plot {
 
         let tmpl = JupyterNotebookPlotTemplate {
             plot: self,
+            topojson: self.topojson_assets(),
             plot_div_id: plot_div_id.as_str(),
         };
         tmpl.render().unwrap()
@@ -369,6 +607,9 @@ impl Plot {
     }
 
     /// Convert the `Plot` to a static image of the given image format and save at the given location.
+    ///
+    /// Panics if Kaleido cannot be found or fails to export the plot; use `Plot::try_write_image`
+    /// to handle these errors instead.
     #[cfg(feature = "kaleido")]
     pub fn write_image<P: AsRef<Path>>(
         &self,
@@ -378,50 +619,360 @@ impl Plot {
         height: usize,
         scale: f64,
     ) {
-        let kaleido = plotly_kaleido::Kaleido::new();
-        kaleido
-            .save(
-                filename.as_ref(),
-                &serde_json::to_value(self).unwrap(),
-                &format.to_string(),
-                width,
-                height,
-                scale,
-            )
-            .unwrap_or_else(|_| panic!("failed to export plot to {:?}", filename.as_ref()));
-    }
-
-    fn render(&self) -> String {
+        self.try_write_image(filename.as_ref(), format, width, height, scale)
+            .unwrap_or_else(|e| panic!("failed to export plot to {:?}: {}", filename.as_ref(), e));
+    }
+
+    /// Convert the `Plot` to a static image of the given image format and save at the given
+    /// location, returning an error if Kaleido cannot be found or fails to export the plot.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use plotly::{ImageFormat, Plot, Scatter};
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+    ///
+    /// if let Err(e) = plot.try_write_image("report/figure", ImageFormat::PNG, 800, 600, 1.0) {
+    ///     eprintln!("skipping figure: {}", e);
+    /// }
+    /// ```
+    #[cfg(feature = "kaleido")]
+    pub fn try_write_image<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<(), Error> {
+        let topojson = TopojsonDir::new(&self.topojson_assets())?;
+        let kaleido = Plot::kaleido(topojson.as_ref())?;
+        kaleido.save(
+            filename.as_ref(),
+            &serde_json::to_value(self)?,
+            &format.to_string(),
+            width,
+            height,
+            scale,
+        )?;
+        Ok(())
+    }
+
+    /// Convert each of the given plots to a static image of the given image format, and save it at
+    /// the location it is paired with.
+    ///
+    /// A single Kaleido process exports all of the plots, which is much faster than calling
+    /// `Plot::write_image` on each of them. The result of each export is returned in order, so that
+    /// one plot failing to export does not prevent the others from being saved. The outer `Result`
+    /// is an error if Kaleido cannot be found at all; an error shutting Kaleido down once every plot
+    /// has been exported is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use plotly::{ImageFormat, Plot, Scatter};
+    ///
+    /// let plots: Vec<(Plot, String)> = (0..100)
+    ///     .map(|i| {
+    ///         let mut plot = Plot::new();
+    ///         plot.add_trace(Scatter::new(vec![0, 1, 2], vec![i, 2 * i, 3 * i]));
+    ///         (plot, format!("report/figure_{}", i))
+    ///     })
+    ///     .collect();
+    ///
+    /// let results = Plot::write_images(
+    ///     plots.iter().map(|(plot, path)| (plot, path)),
+    ///     ImageFormat::PNG,
+    ///     800,
+    ///     600,
+    ///     1.0,
+    /// )
+    /// .unwrap();
+    /// for ((_, path), result) in plots.iter().zip(results) {
+    ///     if let Err(e) = result {
+    ///         eprintln!("could not export {}: {}", path, e);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "kaleido")]
+    pub fn write_images<'a, I, P>(
+        plots: I,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<Vec<Result<(), Error>>, Error>
+    where
+        I: IntoIterator<Item = (&'a Plot, P)>,
+        P: AsRef<Path>,
+    {
+        let plots: Vec<_> = plots.into_iter().collect();
+        let topojson = plots
+            .iter()
+            .flat_map(|(plot, _)| plot.topojson_assets())
+            .collect();
+        let topojson = TopojsonDir::new(&topojson)?;
+        let kaleido = Plot::kaleido(topojson.as_ref())?;
+        let mut session = kaleido.session();
+        let format = format.to_string();
+        let results = plots
+            .into_iter()
+            .map(|(plot, filename)| {
+                session.save(
+                    filename.as_ref(),
+                    &serde_json::to_value(plot)?,
+                    &format,
+                    width,
+                    height,
+                    scale,
+                )?;
+                Ok(())
+            })
+            .collect();
+        // Every image is written by the time `save` returns, so failing to shut Kaleido down
+        // afterwards must not throw away the results of the exports.
+        let _ = session.close();
+        Ok(results)
+    }
+
+    /// Convert the `Plot` to a static image of the given image format, and return its contents
+    /// without writing it to disk.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use plotly::{ImageFormat, Plot, Scatter};
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+    ///
+    /// let png = plot.to_image_bytes(ImageFormat::PNG, 800, 600, 1.0).unwrap();
+    /// assert!(png.starts_with(b"\x89PNG"));
+    /// ```
+    #[cfg(feature = "kaleido")]
+    pub fn to_image_bytes(
+        &self,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<Vec<u8>, Error> {
+        let topojson = TopojsonDir::new(&self.topojson_assets())?;
+        let kaleido = Plot::kaleido(topojson.as_ref())?;
+        Ok(kaleido.to_bytes(
+            &serde_json::to_value(self)?,
+            &format.to_string(),
+            width,
+            height,
+            scale,
+        )?)
+    }
+
+    /// Convert the `Plot` to a static image of the given image format, and return its contents
+    /// encoded as base64.
+    #[cfg(feature = "kaleido")]
+    pub fn to_base64(
+        &self,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<String, Error> {
+        let topojson = TopojsonDir::new(&self.topojson_assets())?;
+        let kaleido = Plot::kaleido(topojson.as_ref())?;
+        Ok(kaleido.to_base64(
+            &serde_json::to_value(self)?,
+            &format.to_string(),
+            width,
+            height,
+            scale,
+        )?)
+    }
+
+    /// Convert the `Plot` to a static image of the given image format, and return it as a data
+    /// URI, which can be used as the `src` of an HTML `img` tag, e.g. in an email.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use plotly::{ImageFormat, Plot, Scatter};
+    ///
+    /// let mut plot = Plot::new();
+    /// plot.add_trace(Scatter::new(vec![0, 1, 2], vec![2, 1, 0]));
+    ///
+    /// let uri = plot.to_data_uri(ImageFormat::SVG, 800, 600, 1.0).unwrap();
+    /// let html = format!(r#"<img src="{}">"#, uri);
+    /// assert!(html.contains("data:image/svg+xml;base64,"));
+    /// ```
+    #[cfg(feature = "kaleido")]
+    pub fn to_data_uri(
+        &self,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<String, Error> {
+        let mime_type = format.mime_type();
+        let data = self.to_base64(format, width, height, scale)?;
+        Ok(format!("data:{};base64,{}", mime_type, data))
+    }
+
+    /// The names of the topojson files Plotly.js loads for the geo subplots of the plot, derived
+    /// from the `scope` and `resolution` of each as Plotly.js does.
+    fn topojson_names(&self) -> BTreeSet<String> {
+        self.traces
+            .iter()
+            .filter_map(|trace| trace.geo_subplot())
+            .map(|geo| match self.layout.geo_subplot(geo) {
+                Some(geo) => geo.topojson_name(),
+                None => LayoutGeo::new().topojson_name(),
+            })
+            .collect()
+    }
+
+    /// The topojson to embed in the plot: the bundled files its geo subplots use, if any, and the
+    /// files added with `Plot::add_topojson`.
+    fn topojson_assets(&self) -> BTreeMap<String, serde_json::Value> {
+        let mut topojson = BTreeMap::new();
+        if !BUNDLED_TOPOJSON.is_empty() {
+            for name in self.topojson_names() {
+                if let Some((_, file)) = BUNDLED_TOPOJSON
+                    .iter()
+                    .find(|(bundled, _)| *bundled == name)
+                {
+                    let file = serde_json::from_str(file).expect("the bundled topojson is valid");
+                    topojson.insert(name, file);
+                }
+            }
+        }
+        topojson.extend(self.topojson.clone());
+        topojson
+    }
+
+    /// Locate Kaleido, pointing it at `topojson` for the topojson files of geographic plots.
+    #[cfg(feature = "kaleido")]
+    fn kaleido(topojson: Option<&TopojsonDir>) -> Result<plotly_kaleido::Kaleido, Error> {
+        let kaleido = plotly_kaleido::Kaleido::try_new()?;
+        Ok(match topojson {
+            Some(topojson) => kaleido.topojson(&topojson.url()),
+            None => kaleido,
+        })
+    }
+
+    fn render(&self) -> Result<String, Error> {
         let tmpl = PlotTemplate {
             plot: self,
+            topojson: self.topojson_assets(),
             remote_plotly_js: self.remote_plotly_js,
         };
-        tmpl.render().unwrap()
+        Ok(tmpl.render()?)
     }
 
-    fn render_static(&self, format: ImageFormat, width: usize, height: usize) -> String {
+    #[cfg(not(feature = "wasm"))]
+    fn render_static(
+        &self,
+        format: ImageFormat,
+        width: usize,
+        height: usize,
+    ) -> Result<String, Error> {
         let tmpl = StaticPlotTemplate {
             plot: self,
+            topojson: self.topojson_assets(),
             format,
             remote_plotly_js: self.remote_plotly_js,
             width,
             height,
         };
-        tmpl.render().unwrap()
+        Ok(tmpl.render()?)
     }
 
-    fn render_inline(&self, plot_div_id: &str) -> String {
+    fn render_inline(&self, plot_div_id: &str) -> Result<String, Error> {
         let tmpl = InlinePlotTemplate {
             plot: self,
+            topojson: self.topojson_assets(),
             plot_div_id,
         };
-        tmpl.render().unwrap()
+        Ok(tmpl.render()?)
     }
 
     pub fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap()
     }
 
+    /// Read a `Plot` back from JSON, as written by `Plot::to_json` or by plotly.py's
+    /// `Figure.to_json`.
+    ///
+    /// Each trace is read into the trace struct given by its "type", with its data kept as
+    /// `serde_json::Value`s. Attributes which this crate does not know about, as well as traces of
+    /// an unknown type, are kept as they are and written back out when the plot is serialized. The
+    /// same goes for layout attributes holding a value this crate cannot read, such as a `tickmode`
+    /// added in a newer Plotly, so that reading a plot only fails on JSON that is not a plot at all.
+    /// `Plot::read_errors` tells what was kept that way and why.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use plotly::{Layout, Plot};
+    ///
+    /// let json = r#"{
+    ///     "data": [{"type": "bar", "x": ["a", "b"], "y": [1, 2], "xhoverformat": "%b"}],
+    ///     "layout": {"title": {"text": "Loaded"}}
+    /// }"#;
+    ///
+    /// let mut plot = Plot::from_json(json).unwrap();
+    /// plot.set_layout(plot.layout().clone().height(300));
+    ///
+    /// let expected = serde_json::json!({
+    ///     "data": [{"type": "bar", "x": ["a", "b"], "y": [1, 2], "xhoverformat": "%b"}],
+    ///     "layout": {"title": {"text": "Loaded"}, "height": 300},
+    ///     "config": {}
+    /// });
+    ///
+    /// assert_eq!(serde_json::to_value(&plot).unwrap(), expected);
+    /// ```
+    pub fn from_json(json: &str) -> Result<Plot, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// The errors met by `Plot::from_json` on what it kept as raw JSON, each prefixed with where it
+    /// was met, e.g. `data[1]: unknown variant "lnes", ...` for a trace whose mode is misspelled or
+    /// `layout.height: invalid type: ...` for a layout attribute.
+    /// Empty if the whole plot was read into the structs of this crate.
+    pub fn read_errors(&self) -> Vec<String> {
+        let mut errors: Vec<String> = self
+            .traces
+            .read_errors
+            .iter()
+            .map(|(index, e)| format!("data[{}]: {}", index, e))
+            .collect();
+        errors.extend(
+            self.layout
+                .read_errors()
+                .into_iter()
+                .map(|e| format!("layout.{}", e)),
+        );
+        for (frame_index, frame) in self.frames.iter().enumerate() {
+            if let Some(traces) = &frame.data {
+                errors.extend(
+                    traces.read_errors.iter().map(|(index, e)| {
+                        format!("frames[{}].data[{}]: {}", frame_index, index, e)
+                    }),
+                );
+            }
+            if let Some(layout) = &frame.layout {
+                errors.extend(
+                    layout
+                        .read_errors()
+                        .into_iter()
+                        .map(|e| format!("frames[{}].layout.{}", frame_index, e)),
+                );
+            }
+        }
+        errors
+    }
+
     #[cfg(feature = "wasm")]
     /// Convert a `Plot` to a native Javasript `js_sys::Object`.
     pub fn to_js_object(&self) -> js_sys::Object {
@@ -435,31 +986,27 @@ impl Plot {
     }
 
     #[cfg(all(target_os = "linux", not(feature = "wasm")))]
-    fn show_with_default_app(temp_path: &str) {
+    fn show_with_default_app(temp_path: &Path) -> io::Result<()> {
         use std::process::Command;
-        Command::new("xdg-open")
-            .args(&[temp_path])
-            .output()
-            .expect(DEFAULT_HTML_APP_NOT_FOUND);
+        Command::new("xdg-open").arg(temp_path).output()?;
+        Ok(())
     }
 
     #[cfg(all(target_os = "macos", not(feature = "wasm")))]
-    fn show_with_default_app(temp_path: &str) {
+    fn show_with_default_app(temp_path: &Path) -> io::Result<()> {
         use std::process::Command;
-        Command::new("open")
-            .args(&[temp_path])
-            .output()
-            .expect(DEFAULT_HTML_APP_NOT_FOUND);
+        Command::new("open").arg(temp_path).output()?;
+        Ok(())
     }
 
     #[cfg(all(target_os = "windows", not(feature = "wasm")))]
-    fn show_with_default_app(temp_path: &str) {
+    fn show_with_default_app(temp_path: &Path) -> io::Result<()> {
         use std::process::Command;
         Command::new("cmd")
             .arg("/C")
-            .arg(format!(r#"start {}"#, temp_path))
-            .output()
-            .expect(DEFAULT_HTML_APP_NOT_FOUND);
+            .arg(format!(r#"start {}"#, temp_path.display()))
+            .output()?;
+        Ok(())
     }
 }
 
@@ -469,6 +1016,40 @@ impl PartialEq for Plot {
     }
 }
 
+/// A temporary directory holding topojson files for Kaleido to load, removed when dropped.
+#[cfg(feature = "kaleido")]
+struct TopojsonDir(std::path::PathBuf);
+
+#[cfg(feature = "kaleido")]
+impl TopojsonDir {
+    /// Write `topojson` to a new temporary directory, if there is any.
+    fn new(topojson: &BTreeMap<String, serde_json::Value>) -> Result<Option<TopojsonDir>, Error> {
+        if topojson.is_empty() {
+            return Ok(None);
+        }
+        let name = Alphanumeric.sample_string(&mut thread_rng(), 20);
+        let dir = TopojsonDir(std::env::temp_dir().join(format!("plotly_topojson_{}", name)));
+        std::fs::create_dir_all(&dir.0)?;
+        for (name, topojson) in topojson {
+            std::fs::write(dir.0.join(format!("{}.json", name)), topojson.to_string())?;
+        }
+        Ok(Some(dir))
+    }
+
+    /// The `file://` URL of the directory, as Kaleido expects it.
+    fn url(&self) -> String {
+        let path = self.0.to_string_lossy().replace('\\', "/");
+        format!("file:///{}/", path.trim_start_matches('/'))
+    }
+}
+
+#[cfg(feature = "kaleido")]
+impl Drop for TopojsonDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -476,7 +1057,7 @@ mod tests {
     use serde_json::{json, to_value};
 
     use super::*;
-    use crate::Scatter;
+    use crate::{layout::LayoutPolar, BarPolar, Scatter, ScatterPolar};
 
     fn create_test_plot() -> Plot {
         let trace1 = Scatter::new(vec![0, 1, 2], vec![6, 10, 2]).name("trace1");
@@ -595,6 +1176,200 @@ mod tests {
         assert_eq!(to_value(plot.layout()).unwrap(), expected);
     }
 
+    #[test]
+    fn test_plot_serialize_with_frames() {
+        let mut plot = create_test_plot();
+        plot.add_frame(
+            Frame::new()
+                .name("frame1")
+                .group("group")
+                .base_frame("frame0")
+                .traces(vec![0])
+                .data(vec![Scatter::new(vec![0, 1, 2], vec![2, 6, 10])])
+                .layout(Layout::new().title("Title".into())),
+        );
+        plot.add_frames(vec![Frame::new().name("frame2")]);
+
+        let expected = json!({
+            "data": [
+                {
+                    "type": "scatter",
+                    "name": "trace1",
+                    "x": [0, 1, 2],
+                    "y": [6, 10, 2]
+                }
+            ],
+            "layout": {},
+            "config": {},
+            "frames": [
+                {
+                    "name": "frame1",
+                    "group": "group",
+                    "baseframe": "frame0",
+                    "traces": [0],
+                    "data": [{"type": "scatter", "x": [0, 1, 2], "y": [2, 6, 10]}],
+                    "layout": {"title": {"text": "Title"}}
+                },
+                {"name": "frame2"}
+            ]
+        });
+
+        assert_eq!(to_value(plot).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_frames_in_html() {
+        let mut plot = create_test_plot();
+        plot.add_frame(Frame::new().name("frame_name"));
+
+        assert!(plot.to_html().contains("frame_name"));
+        assert!(plot.to_inline_html(None).contains("frame_name"));
+        assert!(plot.to_jupyter_notebook_html().contains("frame_name"));
+    }
+
+    #[test]
+    fn test_plot_from_json() {
+        let mut plot = create_test_plot();
+        plot.set_layout(Layout::new().title("Title".into()).height(300));
+        plot.set_configuration(Configuration::new().responsive(true));
+        plot.add_frame(
+            Frame::new()
+                .name("frame1")
+                .data(vec![Scatter::new(vec![0, 1, 2], vec![2, 6, 10])]),
+        );
+
+        let read = Plot::from_json(&plot.to_json()).unwrap();
+
+        assert_eq!(to_value(read).unwrap(), to_value(plot).unwrap());
+    }
+
+    #[test]
+    fn test_plot_many_polar_subplots() {
+        let mut plot = Plot::new();
+        plot.add_trace(BarPolar::new(vec![0.0], vec![1.0]).subplot("polar12"));
+        plot.add_trace(ScatterPolar::new(vec![0.0], vec![1.0]).subplot("polar12"));
+        plot.set_layout(Layout::new().polar_n(12, LayoutPolar::new().hole(0.2)));
+
+        let json = to_value(&plot).unwrap();
+        assert_eq!(json["data"][0]["subplot"], json!("polar12"));
+        assert_eq!(json["data"][1]["subplot"], json!("polar12"));
+        assert_eq!(json["layout"]["polar12"], json!({"hole": 0.2}));
+
+        let read = Plot::from_json(&plot.to_json()).unwrap();
+        assert_eq!(to_value(read).unwrap(), json);
+    }
+
+    #[test]
+    fn test_plot_from_json_keeps_unknown_attributes() {
+        let json = json!({
+            "data": [
+                {
+                    "type": "bar",
+                    "x": ["a", "b"],
+                    "y": [1, 2],
+                    "xhoverformat": ".2f",
+                    "marker": {"color": "red", "cornerradius": 5}
+                }
+            ],
+            "layout": {
+                "template": {"data": {"bar": [{"type": "bar"}]}, "layout": {"font": {"size": 12.5}}},
+                "xaxis": {"title": {"text": "x"}, "autotickangles": [0, 90]},
+                "newshape": {"line": {"color": "red"}}
+            }
+        });
+
+        let plot = Plot::from_json(&json.to_string()).unwrap();
+        let expected = json!({
+            "data": json["data"],
+            "layout": json["layout"],
+            "config": {}
+        });
+
+        assert_eq!(to_value(plot).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_plot_from_json_keeps_invalid_layout_attributes() {
+        let json = json!({
+            "data": [],
+            "layout": {"title": "Title", "height": "tall", "width": 300},
+            "config": {}
+        });
+        let plot = Plot::from_json(&json.to_string()).unwrap();
+        let expected = json!({
+            "data": [],
+            "layout": {"title": {"text": "Title"}, "height": "tall", "width": 300},
+            "config": {}
+        });
+
+        let errors = plot.read_errors();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("layout.height: "));
+        assert_eq!(to_value(plot).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_plot_from_json_unknown_trace_type() {
+        let trace = json!({
+            "type": "parcoords",
+            "dimensions": [{"label": "A", "values": [1, 2]}]
+        });
+        let plot = Plot::from_json(&json!({ "data": [trace] }).to_string()).unwrap();
+
+        assert_eq!(to_value(plot.data()).unwrap(), json!([trace]));
+        assert_eq!(plot.read_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_plot_from_json_malformed_trace() {
+        let json = json!({
+            "data": [
+                {"type": "scatter", "x": [0, 1], "y": [1, 0]},
+                {"type": "scatter", "x": [0, 1], "y": [1, 0], "mode": "lnes"}
+            ],
+            "frames": [{"data": [{"type": "bar", "orientation": "up"}]}]
+        });
+        let plot = Plot::from_json(&json.to_string()).unwrap();
+
+        assert_eq!(to_value(plot.data()).unwrap(), json["data"]);
+        let errors = plot.read_errors();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("data[1]: unknown variant `lnes`"));
+        assert!(errors[1].starts_with("frames[0].data[0]: unknown variant `up`"));
+
+        let plot = Plot::from_json(&json!({"data": [json["data"][0]]}).to_string()).unwrap();
+        assert!(plot.read_errors().is_empty());
+    }
+
+    #[test]
+    fn test_plot_from_json_trace_without_type() {
+        let json = json!({"data": [{"x": [0, 1], "y": [1, 0]}]});
+        let plot = Plot::from_json(&json.to_string()).unwrap();
+        let expected = json!([{"type": "scatter", "x": [0, 1], "y": [1, 0]}]);
+
+        assert_eq!(to_value(plot.data()).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_plot_from_json_invalid() {
+        assert!(Plot::from_json("{\"data\": {}}").is_err());
+        assert!(Plot::from_json("{\"layout\": \"tall\"}").is_err());
+        assert!(matches!(
+            Plot::from_json("not json"),
+            Err(Error::Serialization(_))
+        ));
+    }
+
+    #[test]
+    fn test_image_format_mime_type() {
+        assert_eq!(ImageFormat::PNG.mime_type(), "image/png");
+        assert_eq!(ImageFormat::JPEG.mime_type(), "image/jpeg");
+        assert_eq!(ImageFormat::WEBP.mime_type(), "image/webp");
+        assert_eq!(ImageFormat::SVG.mime_type(), "image/svg+xml");
+        assert_eq!(ImageFormat::PDF.mime_type(), "application/pdf");
+        assert_eq!(ImageFormat::EPS.mime_type(), "application/postscript");
+    }
+
     #[test]
     fn test_plot_eq() {
         let plot1 = create_test_plot();
@@ -622,12 +1397,133 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "wasm"))]
     fn test_show_image() {
         let plot = create_test_plot();
         plot.show_image(ImageFormat::PNG, 1024, 680);
     }
 
     #[test]
+    fn test_embed_topojson() {
+        let mut plot = create_test_plot();
+        plot.add_topojson("world_110m", json!({"type": "Topology", "objects": {}}));
+
+        #[allow(unused_mut)]
+        let mut htmls = vec![
+            plot.to_html(),
+            plot.to_inline_html(None),
+            plot.to_jupyter_notebook_html(),
+        ];
+        #[cfg(not(feature = "wasm"))]
+        htmls.push(plot.render_static(ImageFormat::PNG, 1024, 680).unwrap());
+
+        for html in htmls {
+            assert!(html.contains("PlotlyGeoAssets"));
+            assert!(html.contains("world_110m"));
+            assert!(html.contains("Topology"));
+        }
+        assert!(to_value(plot).unwrap().get("topojson").is_none());
+    }
+
+    #[test]
+    fn test_no_topojson_by_default() {
+        let plot = create_test_plot();
+
+        assert!(!plot.to_html().contains("PlotlyGeoAssets"));
+        assert!(!plot.to_inline_html(None).contains("PlotlyGeoAssets"));
+    }
+
+    #[test]
+    fn test_topojson_names() {
+        use crate::layout::{GeoResolution, GeoScope, LayoutGeo};
+        use crate::{Choropleth, ScatterGeo};
+
+        let mut plot = create_test_plot();
+        assert!(plot.topojson_names().is_empty());
+
+        plot.add_trace(ScatterGeo::new(vec![0.0], vec![0.0]));
+        assert_eq!(
+            plot.topojson_names(),
+            BTreeSet::from(["world_110m".to_string()])
+        );
+
+        plot.set_layout(
+            Layout::new().geo(
+                LayoutGeo::new()
+                    .scope(GeoScope::NorthAmerica)
+                    .resolution(GeoResolution::High),
+            ),
+        );
+        assert_eq!(
+            plot.topojson_names(),
+            BTreeSet::from(["north-america_50m".to_string()])
+        );
+
+        plot.add_trace(ScatterGeo::new(vec![0.0], vec![0.0]).geo("geo12"));
+        plot.set_layout(
+            plot.layout()
+                .clone()
+                .geo_n(12, LayoutGeo::new().scope(GeoScope::Europe)),
+        );
+        let expected = BTreeSet::from(["europe_110m".to_string(), "north-america_50m".to_string()]);
+        assert_eq!(plot.topojson_names(), expected);
+
+        let json = json!({
+            "data": [
+                {"type": "choropleth", "locations": ["CA"], "z": [1], "geo": "geo2"},
+                {"type": "scattergeo", "lat": [0], "lon": [0]}
+            ],
+            "layout": {"geo2": {"scope": "usa"}}
+        });
+        let plot = Plot::from_json(&json.to_string()).unwrap();
+        let expected = BTreeSet::from(["usa_110m".to_string(), "world_110m".to_string()]);
+        assert_eq!(plot.topojson_names(), expected);
+
+        let mut plot = Plot::new();
+        plot.add_trace(Choropleth::new(vec!["CA"], vec![1]));
+        plot.add_topojson("world_110m", json!({"type": "Topology"}));
+        assert_eq!(
+            plot.topojson_assets()["world_110m"],
+            json!({"type": "Topology"})
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "topojson")]
+    fn test_embed_bundled_topojson() {
+        let mut plot = create_test_plot();
+        plot.add_trace(crate::ScatterGeo::new(vec![0.0], vec![0.0]));
+        let topojson = plot.topojson_assets();
+
+        let (_, file) = BUNDLED_TOPOJSON
+            .iter()
+            .find(|(name, _)| *name == "world_110m")
+            .unwrap();
+        let file: serde_json::Value = serde_json::from_str(file).unwrap();
+        assert_eq!(topojson.len(), 1);
+        assert_eq!(topojson["world_110m"], file);
+    }
+
+    #[test]
+    #[cfg(feature = "kaleido")]
+    fn test_topojson_dir() {
+        assert!(TopojsonDir::new(&BTreeMap::new()).unwrap().is_none());
+
+        let mut topojson = BTreeMap::new();
+        topojson.insert("world_110m".to_string(), json!({"type": "Topology"}));
+        let dir = TopojsonDir::new(&topojson).unwrap().unwrap();
+        let path = dir.0.clone();
+
+        assert!(dir.url().starts_with("file:///"));
+        assert!(dir.url().ends_with('/'));
+        let file = std::fs::read_to_string(path.join("world_110m.json")).unwrap();
+        assert_eq!(file, r#"{"type":"Topology"}"#);
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    #[cfg(not(feature = "wasm"))]
     fn test_save_html() {
         let plot = create_test_plot();
         let dst = PathBuf::from("example.html");
@@ -637,6 +1533,65 @@ mod tests {
         assert!(!dst.exists());
     }
 
+    #[test]
+    #[cfg(not(feature = "wasm"))]
+    fn test_try_write_html_to_missing_directory() {
+        let plot = create_test_plot();
+        let dst = PathBuf::from("missing_directory").join("example.html");
+
+        assert!(matches!(plot.try_write_html(&dst), Err(Error::Io(_))));
+        assert!(!dst.exists());
+    }
+
+    #[test]
+    fn test_try_to_html() {
+        let plot = create_test_plot();
+        let html = plot.try_to_html().unwrap();
+
+        assert!(html.starts_with("<!doctype html>"));
+        assert_eq!(html, plot.to_html());
+    }
+
+    #[test]
+    #[cfg(feature = "kaleido")]
+    fn test_to_image_bytes() {
+        let plot = create_test_plot();
+        let bytes = plot
+            .to_image_bytes(ImageFormat::PNG, 1024, 680, 1.0)
+            .unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    #[cfg(feature = "kaleido")]
+    fn test_to_data_uri() {
+        let plot = create_test_plot();
+        let uri = plot.to_data_uri(ImageFormat::JPEG, 1024, 680, 1.0).unwrap();
+        assert!(uri.starts_with("data:image/jpeg;base64,"));
+    }
+
+    #[test]
+    #[cfg(feature = "kaleido")]
+    fn test_write_images() {
+        let plot = create_test_plot();
+        let dst: Vec<PathBuf> = (0..3)
+            .map(|i| PathBuf::from(format!("example_batch_{}.png", i)))
+            .collect();
+        let results = Plot::write_images(
+            dst.iter().map(|p| (&plot, p)),
+            ImageFormat::PNG,
+            1024,
+            680,
+            1.0,
+        )
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        for (dst, result) in dst.iter().zip(results) {
+            assert!(result.is_ok());
+            assert!(std::fs::remove_file(dst).is_ok());
+        }
+    }
+
     #[test]
     #[cfg(feature = "kaleido")]
     fn test_save_to_png() {
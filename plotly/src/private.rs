@@ -1,6 +1,9 @@
 #[cfg(feature = "plotly_ndarray")]
 use ndarray::{Array, Ix2};
-use serde::Serialize;
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
 #[cfg(feature = "plotly_ndarray")]
 use crate::ndarray::ArrayTraces;
@@ -11,6 +14,42 @@ pub fn owned_string_vector<S: AsRef<str>>(s: Vec<S>) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
+/// The id of the `n`th axis or subplot named `prefix`, e.g. "x" for the first x axis and "x2" for
+/// the second.
+pub(crate) fn axis_id(prefix: &str, n: usize) -> String {
+    if n == 1 {
+        prefix.to_string()
+    } else {
+        format!("{}{}", prefix, n)
+    }
+}
+
+/// The number of the axis or subplot with the given id, the inverse of `axis_id`. Like Plotly.js, accepts
+/// neither a number 1 nor leading zeros.
+pub(crate) fn parse_axis_id(prefix: &str, id: &str) -> Option<usize> {
+    let number = id.strip_prefix(prefix)?;
+    if number.is_empty() {
+        return Some(1);
+    }
+    if number.starts_with('0') {
+        return None;
+    }
+    number.parse().ok().filter(|n| *n > 1)
+}
+
+/// Attributes read by `Deserialize` that have no field of their own in the struct holding them.
+/// They are kept so that serializing the struct again writes them back out unchanged.
+pub type Extra = serde_json::Map<String, serde_json::Value>;
+
+/// The value of an attribute that is either a boolean or one of a few strings, read when
+/// deserializing the enums mirroring such attributes.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum BoolOrString {
+    Bool(bool),
+    String(String),
+}
+
 #[derive(Serialize, Clone, Debug, PartialEq)]
 #[serde(untagged)]
 pub enum NumOrString {
@@ -20,6 +59,42 @@ pub enum NumOrString {
     U(u64),
 }
 
+// Not derived, as an untagged enum would read every integer as an `F`.
+impl<'de> Deserialize<'de> for NumOrString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct NumOrStringVisitor;
+
+        impl<'de> Visitor<'de> for NumOrStringVisitor {
+            type Value = NumOrString;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a number or a string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(NumOrString::S(v.to_string()))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(NumOrString::F(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(NumOrString::I(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(NumOrString::U(v))
+            }
+        }
+
+        deserializer.deserialize_any(NumOrStringVisitor)
+    }
+}
+
 impl From<String> for NumOrString {
     fn from(item: String) -> Self {
         NumOrString::S(item)
@@ -86,7 +161,7 @@ impl From<i32> for NumOrString {
     }
 }
 
-#[derive(Serialize, Clone, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct NumOrStringCollection(Vec<NumOrString>);
 
 impl<T> From<Vec<T>> for NumOrStringCollection
@@ -131,7 +206,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{json, to_value};
+    use serde_json::{from_value, json, to_value};
 
     use super::*;
 
@@ -207,4 +282,27 @@ mod tests {
         assert_eq!(to_value(NumOrStringCollection(vec![NumOrString::I(-50)])).unwrap(), json!([-50]));
         assert_eq!(to_value(NumOrStringCollection(vec![NumOrString::U(50)])).unwrap(), json!([50]));
     }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_deserialize_num_or_string() {
+        assert_eq!(from_value::<NumOrString>(json!("&str")).unwrap(), NumOrString::S("&str".to_string()));
+        assert_eq!(from_value::<NumOrString>(json!(100.0)).unwrap(), NumOrString::F(100.));
+        assert_eq!(from_value::<NumOrString>(json!(-50)).unwrap(), NumOrString::I(-50));
+        assert_eq!(from_value::<NumOrString>(json!(50)).unwrap(), NumOrString::U(50));
+        assert!(from_value::<NumOrString>(json!(true)).is_err());
+    }
+
+    #[test]
+    fn test_axis_id() {
+        assert_eq!(axis_id("x", 1), "x");
+        assert_eq!(axis_id("yaxis", 10), "yaxis10");
+
+        assert_eq!(parse_axis_id("x", "x"), Some(1));
+        assert_eq!(parse_axis_id("xaxis", "xaxis10"), Some(10));
+        assert_eq!(parse_axis_id("x", "x1"), None);
+        assert_eq!(parse_axis_id("x", "x02"), None);
+        assert_eq!(parse_axis_id("x", "xy"), None);
+        assert_eq!(parse_axis_id("x", "y2"), None);
+    }
 }
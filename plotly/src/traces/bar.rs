@@ -1,13 +1,14 @@
 //! Bar trace
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     common::{
         Calendar, ConstrainText, Dim, ErrorData, Font, HoverInfo, Label, Marker, Orientation,
-        PlotType, TextAnchor, TextPosition, Visible,
+        PlotType, TextAnchor, TextPosition, Visible, XAxisRef, YAxisRef,
     },
-    private, Trace,
+    private::{self, Extra},
+    Trace,
 };
 
 /// Construct a bar trace.
@@ -33,7 +34,7 @@ use crate::{
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Bar<X, Y>
 where
     X: Serialize + Clone,
@@ -64,9 +65,9 @@ where
     #[serde(rename = "hovertemplate")]
     hover_template: Option<Dim<String>>,
     #[serde(rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisRef>,
     #[serde(rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisRef>,
     orientation: Option<Orientation>,
     #[serde(rename = "alignmentgroup")]
     alignment_group: Option<String>,
@@ -95,6 +96,8 @@ where
     x_calendar: Option<Calendar>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<X, Y> Default for Bar<X, Y>
@@ -139,6 +142,7 @@ where
             outside_text_font: None,
             x_calendar: None,
             y_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -330,8 +334,8 @@ where
         Box::new(self)
     }
 
-    pub fn x_axis(mut self, axis: &str) -> Box<Self> {
-        self.x_axis = Some(axis.to_owned());
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
         Box::new(self)
     }
 
@@ -339,8 +343,8 @@ where
         self.x_calendar = Some(x_calendar);
         Box::new(self)
     }
-    pub fn y_axis(mut self, axis: &str) -> Box<Self> {
-        self.y_axis = Some(axis.to_owned());
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
         Box::new(self)
     }
 
@@ -412,9 +416,9 @@ mod tests {
             .text_template_array(vec!["text_template"])
             .visible(Visible::LegendOnly)
             .width(999)
-            .x_axis("xaxis")
+            .x_axis(XAxisRef::new(2))
             .x_calendar(Calendar::Nanakshahi)
-            .y_axis("yaxis")
+            .y_axis(YAxisRef::new(2))
             .y_calendar(Calendar::Ummalqura);
 
         let expected = json!({
@@ -435,8 +439,8 @@ mod tests {
             "textposition": ["none"],
             "texttemplate": ["text_template"],
             "hovertext": ["hover_text"],
-            "xaxis": "xaxis",
-            "yaxis": "yaxis",
+            "xaxis": "x2",
+            "yaxis": "y2",
             "orientation": "v",
             "alignmentgroup": "alignment_group",
             "offsetgroup": "offset_group",
@@ -0,0 +1,380 @@
+//! Polar bar trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{Dim, HoverInfo, Label, Marker, PlotType, Visible},
+    private::{self, Extra, NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Construct a polar bar trace, drawing each bar as a sector of a `LayoutPolar` subplot. Stacking
+/// several of them gives a wind rose chart.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::BarPolar;
+///
+/// let trace = BarPolar::new(vec!["N", "E", "S", "W"], vec![2.5, 1.0, 3.0, 0.5]).name("< 5 m/s");
+///
+/// let expected = serde_json::json!({
+///     "type": "barpolar",
+///     "theta": ["N", "E", "S", "W"],
+///     "r": [2.5, 1.0, 3.0, 0.5],
+///     "name": "< 5 m/s"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BarPolar<Theta, R>
+where
+    Theta: Serialize + Clone,
+    R: Serialize + Clone,
+{
+    r#type: PlotType,
+    theta: Option<Vec<Theta>>,
+    r: Option<Vec<R>>,
+    name: Option<String>,
+    visible: Option<Visible>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    opacity: Option<f64>,
+    ids: Option<Vec<String>>,
+    theta0: Option<NumOrString>,
+    dtheta: Option<f64>,
+    r0: Option<NumOrString>,
+    dr: Option<f64>,
+    base: Option<Dim<f64>>,
+    offset: Option<Dim<f64>>,
+    width: Option<Dim<f64>>,
+    text: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    meta: Option<NumOrString>,
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+    subplot: Option<String>,
+    marker: Option<Marker>,
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<u32>>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<Theta, R> Default for BarPolar<Theta, R>
+where
+    Theta: Serialize + Clone,
+    R: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::BarPolar,
+            theta: None,
+            r: None,
+            name: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            theta0: None,
+            dtheta: None,
+            r0: None,
+            dr: None,
+            base: None,
+            offset: None,
+            width: None,
+            text: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            meta: None,
+            custom_data: None,
+            subplot: None,
+            marker: None,
+            selected_points: None,
+            hover_label: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<Theta, R> BarPolar<Theta, R>
+where
+    Theta: Serialize + Clone,
+    R: Serialize + Clone,
+{
+    pub fn new(theta: Vec<Theta>, r: Vec<R>) -> Box<Self> {
+        Box::new(Self {
+            theta: Some(theta),
+            r: Some(r),
+            ..Default::default()
+        })
+    }
+
+    /// Sets where the bar base is drawn (in radial axis units).
+    pub fn base(mut self, base: f64) -> Box<Self> {
+        self.base = Some(Dim::Scalar(base));
+        Box::new(self)
+    }
+
+    /// Sets where the bar base is drawn (in radial axis units), for each bar.
+    pub fn base_array(mut self, base: Vec<f64>) -> Box<Self> {
+        self.base = Some(Dim::Vector(base));
+        Box::new(self)
+    }
+
+    pub fn custom_data<V: Into<NumOrString> + Clone>(mut self, custom_data: Vec<V>) -> Box<Self> {
+        self.custom_data = Some(custom_data.into());
+        Box::new(self)
+    }
+
+    /// Sets the r coordinate step.
+    pub fn dr(mut self, dr: f64) -> Box<Self> {
+        self.dr = Some(dr);
+        Box::new(self)
+    }
+
+    /// Sets the theta coordinate step. By default, the `dtheta` step equals the subplot's period
+    /// divided by the length of the `r` coordinates.
+    pub fn dtheta(mut self, dtheta: f64) -> Box<Self> {
+        self.dtheta = Some(dtheta);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Self> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<Self> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn meta<V: Into<NumOrString>>(mut self, meta: V) -> Box<Self> {
+        self.meta = Some(meta.into());
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    /// Shifts the angular position where the bar is drawn (in `theta` units).
+    pub fn offset(mut self, offset: f64) -> Box<Self> {
+        self.offset = Some(Dim::Scalar(offset));
+        Box::new(self)
+    }
+
+    /// Shifts the angular position where each bar is drawn (in `theta` units).
+    pub fn offset_array(mut self, offset: Vec<f64>) -> Box<Self> {
+        self.offset = Some(Dim::Vector(offset));
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    /// Alternate to `r`. Builds a linear space of r coordinates. Use with `dr` where `r0` is the
+    /// starting coordinate and `dr` the step.
+    pub fn r0<V: Into<NumOrString>>(mut self, r0: V) -> Box<Self> {
+        self.r0 = Some(r0.into());
+        Box::new(self)
+    }
+
+    pub fn selected_points(mut self, selected_points: Vec<u32>) -> Box<Self> {
+        self.selected_points = Some(selected_points);
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's data coordinates and a polar subplot. If "polar"
+    /// (the default value), the data refer to `layout.polar`. If "polar2", the data refer to
+    /// `layout.polar2`, and so on.
+    pub fn subplot(mut self, subplot: &str) -> Box<Self> {
+        self.subplot = Some(subplot.to_owned());
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    /// Alternate to `theta`. Builds a linear space of theta coordinates. Use with `dtheta` where
+    /// `theta0` is the starting coordinate and `dtheta` the step.
+    pub fn theta0<V: Into<NumOrString>>(mut self, theta0: V) -> Box<Self> {
+        self.theta0 = Some(theta0.into());
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+
+    /// Sets the bar angular width (in `theta` units).
+    pub fn width(mut self, width: f64) -> Box<Self> {
+        self.width = Some(Dim::Scalar(width));
+        Box::new(self)
+    }
+
+    /// Sets the angular width (in `theta` units) of each bar.
+    pub fn width_array(mut self, width: Vec<f64>) -> Box<Self> {
+        self.width = Some(Dim::Vector(width));
+        Box::new(self)
+    }
+}
+
+impl<Theta, R> Trace for BarPolar<Theta, R>
+where
+    Theta: Serialize + Clone,
+    R: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_default_bar_polar() {
+        let trace: BarPolar<f64, f64> = BarPolar::default();
+        let expected = json!({"type": "barpolar"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_bar_polar() {
+        let trace = BarPolar::new(vec![0.0, 90.0], vec![1.0, 2.0])
+            .base(0.5)
+            .base_array(vec![0.5, 1.0])
+            .custom_data(vec!["custom_data"])
+            .dr(1.0)
+            .dtheta(45.0)
+            .hover_info(HoverInfo::All)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .ids(vec!["1"])
+            .legend_group("legend_group")
+            .marker(Marker::new())
+            .meta("meta")
+            .name("bar_polar_trace")
+            .offset(5.0)
+            .offset_array(vec![5.0, 10.0])
+            .opacity(0.5)
+            .r0(0)
+            .selected_points(vec![0])
+            .show_legend(false)
+            .subplot("polar2")
+            .text("text")
+            .text_array(vec!["text"])
+            .theta0(0)
+            .visible(Visible::LegendOnly)
+            .width(20.0)
+            .width_array(vec![20.0, 30.0]);
+
+        let expected = json!({
+            "type": "barpolar",
+            "theta": [0.0, 90.0],
+            "r": [1.0, 2.0],
+            "base": [0.5, 1.0],
+            "customdata": ["custom_data"],
+            "dr": 1.0,
+            "dtheta": 45.0,
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "ids": ["1"],
+            "legendgroup": "legend_group",
+            "marker": {},
+            "meta": "meta",
+            "name": "bar_polar_trace",
+            "offset": [5.0, 10.0],
+            "opacity": 0.5,
+            "r0": 0,
+            "selectedpoints": [0],
+            "showlegend": false,
+            "subplot": "polar2",
+            "text": ["text"],
+            "theta0": 0,
+            "visible": "legendonly",
+            "width": [20.0, 30.0]
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
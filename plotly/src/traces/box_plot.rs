@@ -1,11 +1,15 @@
 //! Box trace
 
-use serde::{Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
     color::Color,
-    common::{Calendar, Dim, HoverInfo, Label, Line, Marker, Orientation, PlotType, Visible},
-    private, Trace,
+    common::{
+        Calendar, Dim, HoverInfo, Label, Line, Marker, Orientation, PlotType, Visible, XAxisRef,
+        YAxisRef,
+    },
+    private::{self, BoolOrString, Extra},
+    Trace,
 };
 
 #[derive(Debug, Clone)]
@@ -28,6 +32,22 @@ impl Serialize for BoxMean {
     }
 }
 
+impl<'de> Deserialize<'de> for BoxMean {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(true) => Ok(Self::True),
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::String(s) => match s.as_str() {
+                "sd" => Ok(Self::StandardDeviation),
+                _ => Err(de::Error::unknown_variant(&s, &["sd"])),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum BoxPoints {
     All,
@@ -50,7 +70,31 @@ impl Serialize for BoxPoints {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+impl<'de> Deserialize<'de> for BoxPoints {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "all" => Ok(Self::All),
+                "outliers" => Ok(Self::Outliers),
+                "suspectedoutliers" => Ok(Self::SuspectedOutliers),
+                _ => Err(de::Error::unknown_variant(
+                    &s,
+                    &["all", "outliers", "suspectedoutliers"],
+                )),
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum QuartileMethod {
     Linear,
@@ -58,7 +102,7 @@ pub enum QuartileMethod {
     Inclusive,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum HoverOn {
     Boxes,
@@ -90,7 +134,7 @@ pub enum HoverOn {
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct BoxPlot<X, Y>
 where
     X: Serialize + Clone,
@@ -116,9 +160,9 @@ where
     #[serde(rename = "hovertemplate")]
     hover_template: Option<Dim<String>>,
     #[serde(rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisRef>,
     #[serde(rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisRef>,
     orientation: Option<Orientation>,
     #[serde(rename = "alignmentgroup")]
     alignment_group: Option<String>,
@@ -162,6 +206,8 @@ where
     x_calendar: Option<Calendar>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<X, Y> Default for BoxPlot<X, Y>
@@ -213,6 +259,7 @@ where
             jitter: None,
             x_calendar: None,
             y_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -435,8 +482,8 @@ where
         Box::new(self)
     }
 
-    pub fn x_axis(mut self, axis: &str) -> Box<Self> {
-        self.x_axis = Some(axis.to_string());
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
         Box::new(self)
     }
 
@@ -445,8 +492,8 @@ where
         Box::new(self)
     }
 
-    pub fn y_axis(mut self, axis: &str) -> Box<Self> {
-        self.y_axis = Some(axis.to_string());
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
         Box::new(self)
     }
 
@@ -468,7 +515,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{json, to_value};
+    use serde_json::{from_value, json, to_value};
 
     use super::*;
 
@@ -479,6 +526,17 @@ mod tests {
         assert_eq!(to_value(BoxMean::StandardDeviation).unwrap(), json!("sd"));
     }
 
+    #[test]
+    fn test_deserialize_box_mean() {
+        assert!(matches!(from_value(json!(true)).unwrap(), BoxMean::True));
+        assert!(matches!(from_value(json!(false)).unwrap(), BoxMean::False));
+        assert!(matches!(
+            from_value(json!("sd")).unwrap(),
+            BoxMean::StandardDeviation
+        ));
+        assert!(from_value::<BoxMean>(json!("mean")).is_err());
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_serialize_box_points() {
@@ -488,6 +546,24 @@ mod tests {
         assert_eq!(to_value(BoxPoints::False).unwrap(), json!(false));
     }
 
+    #[test]
+    fn test_deserialize_box_points() {
+        assert!(matches!(from_value(json!("all")).unwrap(), BoxPoints::All));
+        assert!(matches!(
+            from_value(json!("outliers")).unwrap(),
+            BoxPoints::Outliers
+        ));
+        assert!(matches!(
+            from_value(json!("suspectedoutliers")).unwrap(),
+            BoxPoints::SuspectedOutliers
+        ));
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            BoxPoints::False
+        ));
+        assert!(from_value::<BoxPoints>(json!(true)).is_err());
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_serialize_quartile_method() {
@@ -564,9 +640,9 @@ mod tests {
             .visible(Visible::LegendOnly)
             .whisker_width(0.2)
             .width(50)
-            .x_axis("xaxis")
+            .x_axis(XAxisRef::new(2))
             .x_calendar(Calendar::Chinese)
-            .y_axis("yaxis")
+            .y_axis(YAxisRef::new(2))
             .y_calendar(Calendar::Coptic);
 
         let expected = json!({
@@ -607,10 +683,10 @@ mod tests {
             "whiskerwidth": 0.2,
             "width": 50,
             "x": [1, 2, 3],
-            "xaxis": "xaxis",
+            "xaxis": "x2",
             "xcalendar": "chinese",
             "y": [4, 5, 6],
-            "yaxis": "yaxis",
+            "yaxis": "y2",
             "ycalendar": "coptic"
         });
 
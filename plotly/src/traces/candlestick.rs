@@ -1,11 +1,14 @@
 //! Candlestick trace
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     color::NamedColor,
-    common::{Calendar, Dim, Direction, HoverInfo, Label, Line, PlotType, Visible},
-    private, Trace,
+    common::{
+        Calendar, Dim, Direction, HoverInfo, Label, Line, PlotType, Visible, XAxisRef, YAxisRef,
+    },
+    private::{self, Extra},
+    Trace,
 };
 
 /// Construct a candlestick trace.
@@ -41,7 +44,7 @@ use crate::{
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Candlestick<T, O>
 where
     T: Serialize + Clone,
@@ -66,9 +69,9 @@ where
     #[serde(rename = "hoverinfo")]
     hover_info: Option<HoverInfo>,
     #[serde(rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisRef>,
     #[serde(rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisRef>,
     line: Option<Line>,
     #[serde(rename = "whiskerwidth")]
     whisker_width: Option<f64>,
@@ -78,6 +81,8 @@ where
     hover_label: Option<Label>,
     #[serde(rename = "xcalendar")]
     x_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<T, O> Default for Candlestick<T, O>
@@ -109,6 +114,7 @@ where
             decreasing: None,
             hover_label: None,
             x_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -209,8 +215,8 @@ where
         self.whisker_width = Some(whisker_width);
         Box::new(self)
     }
-    pub fn x_axis(mut self, axis: &str) -> Box<Self> {
-        self.x_axis = Some(axis.to_string());
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
         Box::new(self)
     }
 
@@ -219,8 +225,8 @@ where
         Box::new(self)
     }
 
-    pub fn y_axis(mut self, axis: &str) -> Box<Self> {
-        self.y_axis = Some(axis.to_string());
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
         Box::new(self)
     }
 }
@@ -268,8 +274,8 @@ mod tests {
         .hover_text_array(vec!["hover", "text"])
         .hover_text("hover text")
         .hover_info(HoverInfo::Skip)
-        .x_axis("x1")
-        .y_axis("y1")
+        .x_axis(XAxisRef::new(1))
+        .y_axis(YAxisRef::new(1))
         .line(Line::new())
         .whisker_width(0.4)
         .increasing(Direction::Increasing { line: Line::new() })
@@ -292,8 +298,8 @@ mod tests {
             "text": "text here",
             "hovertext": "hover text",
             "hoverinfo": "skip",
-            "xaxis": "x1",
-            "yaxis": "y1",
+            "xaxis": "x",
+            "yaxis": "y",
             "line": {},
             "whiskerwidth": 0.4,
             "increasing": {"line": {}},
@@ -0,0 +1,369 @@
+//! Choropleth trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{
+        ColorBar, ColorScale, Dim, HoverInfo, Label, LocationMode, Marker, PlotType, Visible,
+    },
+    private::{self, Extra},
+    Trace,
+};
+
+/// Construct a choropleth trace, coloring the regions given by `locations` on a `LayoutGeo` map
+/// according to the values in `z`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{common::LocationMode, Choropleth};
+///
+/// let trace = Choropleth::new(vec!["FRA", "DEU", "ITA"], vec![1.0, 2.0, 3.0])
+///     .location_mode(LocationMode::Iso3);
+///
+/// let expected = serde_json::json!({
+///     "type": "choropleth",
+///     "locations": ["FRA", "DEU", "ITA"],
+///     "z": [1.0, 2.0, 3.0],
+///     "locationmode": "ISO-3"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Choropleth<Z>
+where
+    Z: Serialize + Clone,
+{
+    r#type: PlotType,
+    locations: Option<Vec<String>>,
+    z: Option<Vec<Z>>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "featureidkey")]
+    feature_id_key: Option<String>,
+    geo: Option<String>,
+    #[serde(rename = "geojson")]
+    geo_json: Option<serde_json::Value>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(rename = "locationmode")]
+    location_mode: Option<LocationMode>,
+    marker: Option<Marker>,
+    name: Option<String>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    text: Option<Dim<String>>,
+    visible: Option<Visible>,
+    zauto: Option<bool>,
+    zmax: Option<Z>,
+    zmid: Option<Z>,
+    zmin: Option<Z>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<Z> Default for Choropleth<Z>
+where
+    Z: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Choropleth,
+            locations: None,
+            z: None,
+            auto_color_scale: None,
+            color_bar: None,
+            color_scale: None,
+            feature_id_key: None,
+            geo: None,
+            geo_json: None,
+            hover_info: None,
+            hover_label: None,
+            hover_template: None,
+            hover_text: None,
+            legend_group: None,
+            location_mode: None,
+            marker: None,
+            name: None,
+            reverse_scale: None,
+            show_legend: None,
+            show_scale: None,
+            text: None,
+            visible: None,
+            zauto: None,
+            zmax: None,
+            zmid: None,
+            zmin: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<Z> Choropleth<Z>
+where
+    Z: Serialize + Clone,
+{
+    pub fn new<S: AsRef<str>>(locations: Vec<S>, z: Vec<Z>) -> Box<Self> {
+        Box::new(Self {
+            locations: Some(private::owned_string_vector(locations)),
+            z: Some(z),
+            ..Default::default()
+        })
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Self> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Self> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Self> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    /// Sets the key in GeoJSON features which is used as id to match the items included in the
+    /// `locations` array. Support nested property, for example "properties.name".
+    pub fn feature_id_key(mut self, feature_id_key: &str) -> Box<Self> {
+        self.feature_id_key = Some(feature_id_key.to_owned());
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's data and a geographic map. If "geo" (the default
+    /// value), the data refer to `layout.geo`. If "geo2", the data refer to `layout.geo2`, and so
+    /// on.
+    pub fn geo(mut self, geo: &str) -> Box<Self> {
+        self.geo = Some(geo.to_owned());
+        Box::new(self)
+    }
+
+    /// Sets optional GeoJSON data associated with this trace. Can be given either as a URL to a
+    /// GeoJSON file, or as a GeoJSON object of type "FeatureCollection" or "Feature" with
+    /// geometries of type "Polygon" or "MultiPolygon". Only has an effect when `location_mode` is
+    /// `LocationMode::GeoJsonId`.
+    pub fn geo_json<G: Into<serde_json::Value>>(mut self, geo_json: G) -> Box<Self> {
+        self.geo_json = Some(geo_json.into());
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    /// Determines the set of locations used to match entries in `locations` to regions on the map.
+    /// Values `LocationMode::Iso3`, `LocationMode::UsaStates` and `LocationMode::CountryNames`
+    /// correspond to features on the base map, while `LocationMode::GeoJsonId` matches the
+    /// features of the trace's `geo_json`.
+    pub fn location_mode(mut self, location_mode: LocationMode) -> Box<Self> {
+        self.location_mode = Some(location_mode);
+        Box::new(self)
+    }
+
+    /// Sets the line and opacity of the region outlines.
+    pub fn marker(mut self, marker: Marker) -> Box<Self> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Self> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Self> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+
+    pub fn zauto(mut self, zauto: bool) -> Box<Self> {
+        self.zauto = Some(zauto);
+        Box::new(self)
+    }
+
+    pub fn zmax(mut self, zmax: Z) -> Box<Self> {
+        self.zmax = Some(zmax);
+        Box::new(self)
+    }
+
+    pub fn zmid(mut self, zmid: Z) -> Box<Self> {
+        self.zmid = Some(zmid);
+        Box::new(self)
+    }
+
+    pub fn zmin(mut self, zmin: Z) -> Box<Self> {
+        self.zmin = Some(zmin);
+        Box::new(self)
+    }
+}
+
+impl<Z> Trace for Choropleth<Z>
+where
+    Z: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn geo_subplot(&self) -> Option<&str> {
+        Some(self.geo.as_deref().unwrap_or("geo"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_default_choropleth() {
+        let trace: Choropleth<f64> = Choropleth::default();
+        let expected = json!({"type": "choropleth"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_choropleth() {
+        let trace = Choropleth::new(vec!["a", "b"], vec![1.0, 2.0])
+            .auto_color_scale(false)
+            .color_bar(ColorBar::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+            .feature_id_key("properties.name")
+            .geo("geo2")
+            .geo_json(json!({"type": "FeatureCollection", "features": []}))
+            .hover_info(HoverInfo::Text)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .legend_group("legend_group")
+            .location_mode(LocationMode::GeoJsonId)
+            .marker(Marker::new().opacity(0.5))
+            .name("choropleth_trace")
+            .reverse_scale(true)
+            .show_legend(true)
+            .show_scale(false)
+            .text("text")
+            .text_array(vec!["text"])
+            .visible(Visible::LegendOnly)
+            .zauto(false)
+            .zmax(2.0)
+            .zmid(1.5)
+            .zmin(1.0);
+
+        let expected = json!({
+            "type": "choropleth",
+            "locations": ["a", "b"],
+            "z": [1.0, 2.0],
+            "autocolorscale": false,
+            "colorbar": {},
+            "colorscale": "Viridis",
+            "featureidkey": "properties.name",
+            "geo": "geo2",
+            "geojson": {"type": "FeatureCollection", "features": []},
+            "hoverinfo": "text",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "legendgroup": "legend_group",
+            "locationmode": "geojson-id",
+            "marker": {"opacity": 0.5},
+            "name": "choropleth_trace",
+            "reversescale": true,
+            "showlegend": true,
+            "showscale": false,
+            "text": ["text"],
+            "visible": "legendonly",
+            "zauto": false,
+            "zmax": 2.0,
+            "zmid": 1.5,
+            "zmin": 1.0
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -1,23 +1,25 @@
 //! Contour trace
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     color::Color,
     common::{
         Calendar, ColorBar, ColorScale, Dim, Font, HoverInfo, Label, Line, PlotType, Visible,
+        XAxisRef, YAxisRef,
     },
-    private, Trace,
+    private::{self, Extra},
+    Trace,
 };
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum ContoursType {
     Levels,
     Constraint,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum Coloring {
     Fill,
@@ -26,7 +28,7 @@ pub enum Coloring {
     None,
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Operation {
     #[serde(rename = "=")]
     Equals,
@@ -45,7 +47,7 @@ pub enum Operation {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct Contours {
     r#type: Option<ContoursType>,
     start: Option<f64>,
@@ -62,6 +64,8 @@ pub struct Contours {
     label_format: Option<String>,
     operation: Option<Operation>,
     value: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Contours {
@@ -152,7 +156,7 @@ impl Contours {
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Contour<Z, X = f64, Y = f64>
 where
     X: Serialize + Clone,
@@ -182,9 +186,9 @@ where
     #[serde(rename = "hovertemplate")]
     hover_template: Option<Dim<String>>,
     #[serde(rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisRef>,
     #[serde(rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisRef>,
     line: Option<Line>,
     #[serde(rename = "colorbar")]
     color_bar: Option<ColorBar>,
@@ -220,6 +224,8 @@ where
     x_calendar: Option<Calendar>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<Z, X, Y> Default for Contour<Z, X, Y>
@@ -270,6 +276,7 @@ where
             transpose: None,
             x_calendar: None,
             y_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -439,8 +446,8 @@ where
         Box::new(self)
     }
 
-    pub fn x_axis(mut self, axis: &str) -> Box<Self> {
-        self.x_axis = Some(axis.to_string());
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
         Box::new(self)
     }
 
@@ -454,8 +461,8 @@ where
         Box::new(self)
     }
 
-    pub fn y_axis(mut self, axis: &str) -> Box<Self> {
-        self.y_axis = Some(axis.to_string());
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
         Box::new(self)
     }
 
@@ -633,11 +640,11 @@ mod tests {
             .transpose(true)
             .visible(Visible::True)
             .x(vec![0.0, 1.0])
-            .x_axis("x0")
+            .x_axis(XAxisRef::new(2))
             .x_calendar(Calendar::Ethiopian)
             .x0(0.)
             .y(vec![2.0, 3.0])
-            .y_axis("y0")
+            .y_axis(YAxisRef::new(2))
             .y_calendar(Calendar::Gregorian)
             .y0(0.)
             .zauto(false)
@@ -664,8 +671,8 @@ mod tests {
             "hovertext": ["p3", "p4"],
             "hoverinfo": "x+y+z",
             "hovertemplate": ["ok {1}", "ok {2}"],
-            "xaxis": "x0",
-            "yaxis": "y0",
+            "xaxis": "x2",
+            "yaxis": "y2",
             "line": {},
             "colorbar": {},
             "autocolorscale": true,
@@ -0,0 +1,364 @@
+//! Mapbox density trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible},
+    private::{self, Extra},
+    Trace,
+};
+
+/// Construct a density trace drawn on a `LayoutMapbox` tile map, which draws a heatmap of the
+/// points at `lat` and `lon`, weighted by the values in `z`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::DensityMapbox;
+///
+/// let trace = DensityMapbox::new(vec![45.5, 46.0], vec![-73.6, -74.0], vec![1.0, 3.0]).radius(20);
+///
+/// let expected = serde_json::json!({
+///     "type": "densitymapbox",
+///     "lat": [45.5, 46.0],
+///     "lon": [-73.6, -74.0],
+///     "z": [1.0, 3.0],
+///     "radius": 20
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DensityMapbox<Lat, Lon, Z>
+where
+    Lat: Serialize + Clone,
+    Lon: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    r#type: PlotType,
+    lat: Option<Vec<Lat>>,
+    lon: Option<Vec<Lon>>,
+    z: Option<Vec<Z>>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    below: Option<String>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    name: Option<String>,
+    opacity: Option<f64>,
+    radius: Option<Dim<usize>>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    subplot: Option<String>,
+    text: Option<Dim<String>>,
+    visible: Option<Visible>,
+    zauto: Option<bool>,
+    zmax: Option<Z>,
+    zmid: Option<Z>,
+    zmin: Option<Z>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<Lat, Lon, Z> Default for DensityMapbox<Lat, Lon, Z>
+where
+    Lat: Serialize + Clone,
+    Lon: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::DensityMapbox,
+            lat: None,
+            lon: None,
+            z: None,
+            auto_color_scale: None,
+            below: None,
+            color_bar: None,
+            color_scale: None,
+            hover_info: None,
+            hover_label: None,
+            hover_template: None,
+            hover_text: None,
+            legend_group: None,
+            name: None,
+            opacity: None,
+            radius: None,
+            reverse_scale: None,
+            show_legend: None,
+            show_scale: None,
+            subplot: None,
+            text: None,
+            visible: None,
+            zauto: None,
+            zmax: None,
+            zmid: None,
+            zmin: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<Lat, Lon, Z> DensityMapbox<Lat, Lon, Z>
+where
+    Lat: Serialize + Clone,
+    Lon: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    pub fn new(lat: Vec<Lat>, lon: Vec<Lon>, z: Vec<Z>) -> Box<Self> {
+        Box::new(Self {
+            lat: Some(lat),
+            lon: Some(lon),
+            z: Some(z),
+            ..Default::default()
+        })
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Self> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    /// Determines if the density heatmap will be inserted before the layer with the specified id.
+    /// By default, density mapbox traces are placed below the first layer of type symbol. If set
+    /// to "", the layer will be inserted above every existing layer.
+    pub fn below(mut self, below: &str) -> Box<Self> {
+        self.below = Some(below.to_owned());
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Self> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Self> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    /// Sets the radius of influence of one `lat` / `lon` point in pixels. Increasing the value
+    /// makes the density heatmap trace smoother, but less detailed.
+    pub fn radius(mut self, radius: usize) -> Box<Self> {
+        self.radius = Some(Dim::Scalar(radius));
+        Box::new(self)
+    }
+
+    /// Sets the radius of influence of each `lat` / `lon` point in pixels.
+    pub fn radius_array(mut self, radius: Vec<usize>) -> Box<Self> {
+        self.radius = Some(Dim::Vector(radius));
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Self> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Self> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's data coordinates and a mapbox subplot. If "mapbox"
+    /// (the default value), the data refer to `layout.mapbox`. If "mapbox2", the data refer to
+    /// `layout.mapbox2`, and so on.
+    pub fn subplot(mut self, subplot: &str) -> Box<Self> {
+        self.subplot = Some(subplot.to_owned());
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+
+    pub fn zauto(mut self, zauto: bool) -> Box<Self> {
+        self.zauto = Some(zauto);
+        Box::new(self)
+    }
+
+    pub fn zmax(mut self, zmax: Z) -> Box<Self> {
+        self.zmax = Some(zmax);
+        Box::new(self)
+    }
+
+    pub fn zmid(mut self, zmid: Z) -> Box<Self> {
+        self.zmid = Some(zmid);
+        Box::new(self)
+    }
+
+    pub fn zmin(mut self, zmin: Z) -> Box<Self> {
+        self.zmin = Some(zmin);
+        Box::new(self)
+    }
+}
+
+impl<Lat, Lon, Z> Trace for DensityMapbox<Lat, Lon, Z>
+where
+    Lat: Serialize + Clone,
+    Lon: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_default_density_mapbox() {
+        let trace: DensityMapbox<f64, f64, f64> = DensityMapbox::default();
+        let expected = json!({"type": "densitymapbox"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_density_mapbox() {
+        let trace = DensityMapbox::new(vec![0.0, 1.0], vec![2.0, 3.0], vec![1.0, 2.0])
+            .auto_color_scale(false)
+            .below("water")
+            .color_bar(ColorBar::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+            .hover_info(HoverInfo::Text)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .legend_group("legend_group")
+            .name("density_mapbox_trace")
+            .opacity(0.5)
+            .radius(5)
+            .radius_array(vec![5, 10])
+            .reverse_scale(true)
+            .show_legend(true)
+            .show_scale(false)
+            .subplot("mapbox2")
+            .text("text")
+            .text_array(vec!["text"])
+            .visible(Visible::LegendOnly)
+            .zauto(false)
+            .zmax(2.0)
+            .zmid(1.5)
+            .zmin(1.0);
+
+        let expected = json!({
+            "type": "densitymapbox",
+            "lat": [0.0, 1.0],
+            "lon": [2.0, 3.0],
+            "z": [1.0, 2.0],
+            "autocolorscale": false,
+            "below": "water",
+            "colorbar": {},
+            "colorscale": "Viridis",
+            "hoverinfo": "text",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "legendgroup": "legend_group",
+            "name": "density_mapbox_trace",
+            "opacity": 0.5,
+            "radius": [5, 10],
+            "reversescale": true,
+            "showlegend": true,
+            "showscale": false,
+            "subplot": "mapbox2",
+            "text": ["text"],
+            "visible": "legendonly",
+            "zauto": false,
+            "zmax": 2.0,
+            "zmid": 1.5,
+            "zmin": 1.0
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
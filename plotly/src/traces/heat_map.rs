@@ -1,10 +1,14 @@
 //! Heat map trace
 
-use serde::Serialize;
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 use crate::{
-    common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible},
-    private, Trace,
+    common::{
+        Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible, XAxisRef,
+        YAxisRef,
+    },
+    private::{self, BoolOrString, Extra},
+    Trace,
 };
 
 #[derive(Debug, Clone)]
@@ -27,6 +31,26 @@ impl Serialize for Smoothing {
     }
 }
 
+impl<'de> Deserialize<'de> for Smoothing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match BoolOrString::deserialize(deserializer)? {
+            BoolOrString::Bool(false) => Ok(Self::False),
+            BoolOrString::Bool(b) => Err(de::Error::invalid_value(
+                de::Unexpected::Bool(b),
+                &"false or a string",
+            )),
+            BoolOrString::String(s) => match s.as_str() {
+                "fast" => Ok(Self::Fast),
+                "best" => Ok(Self::Best),
+                _ => Err(de::Error::unknown_variant(&s, &["fast", "best"])),
+            },
+        }
+    }
+}
+
 /// Construct a heat map trace.
 ///
 /// # Examples
@@ -50,7 +74,7 @@ impl Serialize for Smoothing {
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct HeatMap<X, Y, Z>
 where
     X: Serialize + Clone,
@@ -91,12 +115,12 @@ where
     visible: Option<Visible>,
     x: Option<Vec<X>>,
     #[serde(rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisRef>,
     #[serde(rename = "xcalendar")]
     x_calendar: Option<Calendar>,
     y: Option<Vec<Y>>,
     #[serde(rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisRef>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
     z: Option<Vec<Z>>,
@@ -107,6 +131,8 @@ where
     zmid: Option<Z>,
     zmin: Option<Z>,
     zsmooth: Option<Smoothing>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<X, Y, Z> Default for HeatMap<X, Y, Z>
@@ -149,6 +175,7 @@ where
             zmid: None,
             zmin: None,
             zsmooth: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -277,8 +304,8 @@ where
         Box::new(self)
     }
 
-    pub fn x_axis(mut self, axis: &str) -> Box<Self> {
-        self.x_axis = Some(axis.to_string());
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
         Box::new(self)
     }
 
@@ -287,8 +314,8 @@ where
         Box::new(self)
     }
 
-    pub fn y_axis(mut self, axis: &str) -> Box<Self> {
-        self.y_axis = Some(axis.to_string());
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
         Box::new(self)
     }
 
@@ -340,7 +367,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{json, to_value};
+    use serde_json::{from_value, json, to_value};
 
     use super::*;
     use crate::common::ColorScalePalette;
@@ -352,6 +379,23 @@ mod tests {
         assert_eq!(to_value(Smoothing::False).unwrap(), json!(false));
     }
 
+    #[test]
+    fn test_deserialize_smoothing() {
+        assert!(matches!(
+            from_value(json!("fast")).unwrap(),
+            Smoothing::Fast
+        ));
+        assert!(matches!(
+            from_value(json!("best")).unwrap(),
+            Smoothing::Best
+        ));
+        assert!(matches!(
+            from_value(json!(false)).unwrap(),
+            Smoothing::False
+        ));
+        assert!(from_value::<Smoothing>(json!(true)).is_err());
+    }
+
     #[test]
     fn test_serialize_default_heat_map() {
         let trace = HeatMap::<f64, f64, f64>::default();
@@ -393,9 +437,9 @@ mod tests {
             .text(vec!["te", "xt"])
             .transpose(true)
             .visible(Visible::LegendOnly)
-            .x_axis("x")
+            .x_axis(XAxisRef::new(1))
             .x_calendar(Calendar::Hebrew)
-            .y_axis("y")
+            .y_axis(YAxisRef::new(1))
             .y_calendar(Calendar::Islamic)
             .zauto(true)
             .zhover_format("fmt")
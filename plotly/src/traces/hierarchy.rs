@@ -0,0 +1,614 @@
+//! Types shared by the hierarchical traces: `Sunburst`, `Treemap` and `Icicle`
+
+use std::collections::{HashMap, HashSet};
+use std::ops::AddAssign;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    color::Color,
+    common::{Font, Orientation},
+    private::Extra,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum BranchValues {
+    Remainder,
+    Total,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum Count {
+    Branches,
+    Leaves,
+    #[serde(rename = "branches+leaves")]
+    BranchesAndLeaves,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum TextInfo {
+    Label,
+    Text,
+    Value,
+    #[serde(rename = "current path")]
+    CurrentPath,
+    #[serde(rename = "percent root")]
+    PercentRoot,
+    #[serde(rename = "percent entry")]
+    PercentEntry,
+    #[serde(rename = "percent parent")]
+    PercentParent,
+    #[serde(rename = "label+text")]
+    LabelAndText,
+    #[serde(rename = "label+value")]
+    LabelAndValue,
+    #[serde(rename = "label+percent root")]
+    LabelAndPercentRoot,
+    #[serde(rename = "label+percent entry")]
+    LabelAndPercentEntry,
+    #[serde(rename = "label+percent parent")]
+    LabelAndPercentParent,
+    #[serde(rename = "label+value+percent parent")]
+    LabelValueAndPercentParent,
+    None,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum Packing {
+    Squarify,
+    Binary,
+    Dice,
+    Slice,
+    SliceDice,
+    DiceSlice,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum Flip {
+    X,
+    Y,
+    #[serde(rename = "x+y")]
+    XAndY,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum PathBarSide {
+    Top,
+    Bottom,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum EdgeShape {
+    #[serde(rename = ">")]
+    Greater,
+    #[serde(rename = "<")]
+    Less,
+    #[serde(rename = "|")]
+    Pipe,
+    #[serde(rename = "/")]
+    Slash,
+    #[serde(rename = "\\")]
+    Backslash,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Leaf {
+    opacity: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Leaf {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Root {
+    color: Option<Box<dyn Color>>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Root {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
+        self
+    }
+}
+
+/// The bar showing the path to the currently viewed sector, used by `Treemap` and `Icicle`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct PathBar {
+    visible: Option<bool>,
+    side: Option<PathBarSide>,
+    #[serde(rename = "edgeshape")]
+    edge_shape: Option<EdgeShape>,
+    thickness: Option<f64>,
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl PathBar {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn side(mut self, side: PathBarSide) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    pub fn edge_shape(mut self, edge_shape: EdgeShape) -> Self {
+        self.edge_shape = Some(edge_shape);
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f64) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Self {
+        self.text_font = Some(text_font);
+        self
+    }
+}
+
+/// Controls how the sectors of a `Treemap` or `Icicle` are laid out.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Tiling {
+    packing: Option<Packing>,
+    #[serde(rename = "squarifyratio")]
+    squarify_ratio: Option<f64>,
+    orientation: Option<Orientation>,
+    flip: Option<Flip>,
+    pad: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Tiling {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines the algorithm used to pack the sectors. Only used by `Treemap`.
+    pub fn packing(mut self, packing: Packing) -> Self {
+        self.packing = Some(packing);
+        self
+    }
+
+    /// When using the `Squarify` packing, sets the preferred ratio between the height and width
+    /// of individual sectors. Only used by `Treemap`.
+    pub fn squarify_ratio(mut self, squarify_ratio: f64) -> Self {
+        self.squarify_ratio = Some(squarify_ratio);
+        self
+    }
+
+    /// Sets the direction in which the levels are laid out. Only used by `Icicle`.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    pub fn flip(mut self, flip: Flip) -> Self {
+        self.flip = Some(flip);
+        self
+    }
+
+    /// Sets the inner padding (in px).
+    pub fn pad(mut self, pad: f64) -> Self {
+        self.pad = Some(pad);
+        self
+    }
+}
+
+/// A node of a tree, used to build a `Hierarchy`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::hierarchy::TreeNode;
+///
+/// let tree = TreeNode::new("/", 10)
+///     .child(TreeNode::new("home", 6))
+///     .child(TreeNode::new("usr", 4).child(TreeNode::new("lib", 3)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TreeNode<V> {
+    label: String,
+    value: V,
+    children: Vec<TreeNode<V>>,
+}
+
+impl<V> TreeNode<V> {
+    pub fn new(label: &str, value: V) -> Self {
+        Self {
+            label: label.to_owned(),
+            value,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn child(mut self, child: TreeNode<V>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children(mut self, children: Vec<TreeNode<V>>) -> Self {
+        self.children.extend(children);
+        self
+    }
+}
+
+/// The `ids`, `labels`, `parents` and `values` of a hierarchical trace, flattened from a Rust
+/// data structure.
+///
+/// The id of each node is the path of labels leading to it, joined with `/`, so that nodes
+/// sharing a label in different branches remain distinct. A `/` or `\` within a label is escaped
+/// with a `\`, so that e.g. the path `["a/b"]` does not get the same id as `["a", "b"]`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{hierarchy::Hierarchy, Sunburst};
+///
+/// let hierarchy = Hierarchy::from_paths(vec![
+///     (vec!["usr", "lib"], 3),
+///     (vec!["usr", "bin"], 1),
+///     (vec!["home"], 6),
+/// ]);
+/// let trace = Sunburst::from_hierarchy(hierarchy);
+///
+/// let expected = serde_json::json!({
+///     "type": "sunburst",
+///     "ids": ["usr", "usr/lib", "usr/bin", "home"],
+///     "labels": ["usr", "lib", "bin", "home"],
+///     "parents": ["", "usr", "usr", ""],
+///     "values": [4, 3, 1, 6],
+///     "branchvalues": "total"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Hierarchy<V> {
+    pub(crate) ids: Vec<String>,
+    pub(crate) labels: Vec<String>,
+    pub(crate) parents: Vec<String>,
+    pub(crate) values: Vec<V>,
+    pub(crate) branch_values: Option<BranchValues>,
+}
+
+impl<V> Default for Hierarchy<V> {
+    fn default() -> Self {
+        Self {
+            ids: Vec::new(),
+            labels: Vec::new(),
+            parents: Vec::new(),
+            values: Vec::new(),
+            branch_values: None,
+        }
+    }
+}
+
+impl<V> Hierarchy<V> {
+    /// Flattens a forest of trees, visiting each node before its children. The value of each
+    /// node is used as given, so set `branch_values` on the trace to match its meaning.
+    ///
+    /// Sibling nodes sharing a label are kept apart by suffixing the id of every one but the
+    /// first with its position among them, as in `a`, `a#2`, `a#3`.
+    pub fn from_tree(roots: Vec<TreeNode<V>>) -> Self {
+        let mut hierarchy = Self::default();
+        let mut ids = HashSet::new();
+        for root in roots {
+            hierarchy.push_node(root, "", &mut ids);
+        }
+        hierarchy
+    }
+
+    fn push_node(&mut self, node: TreeNode<V>, parent: &str, ids: &mut HashSet<String>) {
+        let base = Self::child_id(parent, &node.label);
+        let mut id = base.clone();
+        let mut n = 1;
+        while ids.contains(&id) {
+            n += 1;
+            id = format!("{}#{}", base, n);
+        }
+        ids.insert(id.clone());
+
+        self.ids.push(id.clone());
+        self.labels.push(node.label);
+        self.parents.push(parent.to_owned());
+        self.values.push(node.value);
+        for child in node.children {
+            self.push_node(child, &id, ids);
+        }
+    }
+
+    fn child_id(parent: &str, label: &str) -> String {
+        let label = label.replace('\\', "\\\\").replace('/', "\\/");
+        if parent.is_empty() {
+            label
+        } else {
+            format!("{}/{}", parent, label)
+        }
+    }
+}
+
+impl<V> Hierarchy<V>
+where
+    V: Clone + Default + AddAssign,
+{
+    /// Builds the hierarchy from a list of paths, each one ending in a leaf with the given
+    /// value. The value of each branch is the total of the values beneath it, and the trace
+    /// built from it uses `BranchValues::Total` accordingly.
+    pub fn from_paths<S: AsRef<str>>(paths: Vec<(Vec<S>, V)>) -> Self {
+        let mut hierarchy = Self {
+            branch_values: Some(BranchValues::Total),
+            ..Default::default()
+        };
+        let mut index: HashMap<String, usize> = HashMap::new();
+
+        for (path, value) in paths {
+            let mut parent = String::new();
+            for label in path.iter().map(|label| label.as_ref()) {
+                let id = Self::child_id(&parent, label);
+                let i = match index.get(&id) {
+                    Some(&i) => i,
+                    None => {
+                        hierarchy.ids.push(id.clone());
+                        hierarchy.labels.push(label.to_owned());
+                        hierarchy.parents.push(parent);
+                        hierarchy.values.push(V::default());
+                        index.insert(id.clone(), hierarchy.ids.len() - 1);
+                        hierarchy.ids.len() - 1
+                    }
+                };
+                hierarchy.values[i] += value.clone();
+                parent = id;
+            }
+        }
+
+        hierarchy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_branch_values() {
+        assert_eq!(
+            to_value(BranchValues::Remainder).unwrap(),
+            json!("remainder")
+        );
+        assert_eq!(to_value(BranchValues::Total).unwrap(), json!("total"));
+    }
+
+    #[test]
+    fn test_serialize_count() {
+        assert_eq!(to_value(Count::Branches).unwrap(), json!("branches"));
+        assert_eq!(to_value(Count::Leaves).unwrap(), json!("leaves"));
+        assert_eq!(
+            to_value(Count::BranchesAndLeaves).unwrap(),
+            json!("branches+leaves")
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_text_info() {
+        assert_eq!(to_value(TextInfo::Label).unwrap(), json!("label"));
+        assert_eq!(to_value(TextInfo::Text).unwrap(), json!("text"));
+        assert_eq!(to_value(TextInfo::Value).unwrap(), json!("value"));
+        assert_eq!(to_value(TextInfo::CurrentPath).unwrap(), json!("current path"));
+        assert_eq!(to_value(TextInfo::PercentRoot).unwrap(), json!("percent root"));
+        assert_eq!(to_value(TextInfo::PercentEntry).unwrap(), json!("percent entry"));
+        assert_eq!(to_value(TextInfo::PercentParent).unwrap(), json!("percent parent"));
+        assert_eq!(to_value(TextInfo::LabelAndText).unwrap(), json!("label+text"));
+        assert_eq!(to_value(TextInfo::LabelAndValue).unwrap(), json!("label+value"));
+        assert_eq!(to_value(TextInfo::LabelAndPercentRoot).unwrap(), json!("label+percent root"));
+        assert_eq!(to_value(TextInfo::LabelAndPercentEntry).unwrap(), json!("label+percent entry"));
+        assert_eq!(to_value(TextInfo::LabelAndPercentParent).unwrap(), json!("label+percent parent"));
+        assert_eq!(to_value(TextInfo::LabelValueAndPercentParent).unwrap(), json!("label+value+percent parent"));
+        assert_eq!(to_value(TextInfo::None).unwrap(), json!("none"));
+    }
+
+    #[test]
+    fn test_serialize_packing() {
+        assert_eq!(to_value(Packing::Squarify).unwrap(), json!("squarify"));
+        assert_eq!(to_value(Packing::Binary).unwrap(), json!("binary"));
+        assert_eq!(to_value(Packing::Dice).unwrap(), json!("dice"));
+        assert_eq!(to_value(Packing::Slice).unwrap(), json!("slice"));
+        assert_eq!(to_value(Packing::SliceDice).unwrap(), json!("slice-dice"));
+        assert_eq!(to_value(Packing::DiceSlice).unwrap(), json!("dice-slice"));
+    }
+
+    #[test]
+    fn test_serialize_flip() {
+        assert_eq!(to_value(Flip::X).unwrap(), json!("x"));
+        assert_eq!(to_value(Flip::Y).unwrap(), json!("y"));
+        assert_eq!(to_value(Flip::XAndY).unwrap(), json!("x+y"));
+    }
+
+    #[test]
+    fn test_serialize_path_bar_side() {
+        assert_eq!(to_value(PathBarSide::Top).unwrap(), json!("top"));
+        assert_eq!(to_value(PathBarSide::Bottom).unwrap(), json!("bottom"));
+    }
+
+    #[test]
+    fn test_serialize_edge_shape() {
+        assert_eq!(to_value(EdgeShape::Greater).unwrap(), json!(">"));
+        assert_eq!(to_value(EdgeShape::Less).unwrap(), json!("<"));
+        assert_eq!(to_value(EdgeShape::Pipe).unwrap(), json!("|"));
+        assert_eq!(to_value(EdgeShape::Slash).unwrap(), json!("/"));
+        assert_eq!(to_value(EdgeShape::Backslash).unwrap(), json!("\\"));
+    }
+
+    #[test]
+    fn test_serialize_leaf() {
+        let leaf = Leaf::new().opacity(0.5);
+        let expected = json!({"opacity": 0.5});
+
+        assert_eq!(to_value(leaf).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_root() {
+        let root = Root::new().color("#FFFFFF");
+        let expected = json!({"color": "#FFFFFF"});
+
+        assert_eq!(to_value(root).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_path_bar() {
+        let path_bar = PathBar::new()
+            .visible(true)
+            .side(PathBarSide::Bottom)
+            .edge_shape(EdgeShape::Slash)
+            .thickness(20.0)
+            .text_font(Font::new());
+        let expected = json!({
+            "visible": true,
+            "side": "bottom",
+            "edgeshape": "/",
+            "thickness": 20.0,
+            "textfont": {}
+        });
+
+        assert_eq!(to_value(path_bar).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_tiling() {
+        let tiling = Tiling::new()
+            .packing(Packing::SliceDice)
+            .squarify_ratio(1.5)
+            .orientation(Orientation::Horizontal)
+            .flip(Flip::XAndY)
+            .pad(2.0);
+        let expected = json!({
+            "packing": "slice-dice",
+            "squarifyratio": 1.5,
+            "orientation": "h",
+            "flip": "x+y",
+            "pad": 2.0
+        });
+
+        assert_eq!(to_value(tiling).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_hierarchy_from_tree() {
+        let tree = TreeNode::new("root", 10)
+            .child(TreeNode::new("a", 6).child(TreeNode::new("c", 6)))
+            .child(TreeNode::new("b", 4).child(TreeNode::new("c", 1)));
+        let hierarchy = Hierarchy::from_tree(vec![tree]);
+
+        assert_eq!(
+            hierarchy.ids,
+            vec!["root", "root/a", "root/a/c", "root/b", "root/b/c"]
+        );
+        assert_eq!(hierarchy.labels, vec!["root", "a", "c", "b", "c"]);
+        assert_eq!(
+            hierarchy.parents,
+            vec!["", "root", "root/a", "root", "root/b"]
+        );
+        assert_eq!(hierarchy.values, vec![10, 6, 6, 4, 1]);
+        assert!(hierarchy.branch_values.is_none());
+    }
+
+    #[test]
+    fn test_hierarchy_from_tree_with_same_sibling_labels() {
+        let tree = TreeNode::new("root", 7)
+            .child(TreeNode::new("a", 4).child(TreeNode::new("c", 4)))
+            .child(TreeNode::new("a", 2).child(TreeNode::new("c", 2)))
+            .child(TreeNode::new("a", 1));
+        let hierarchy = Hierarchy::from_tree(vec![tree, TreeNode::new("root", 3)]);
+
+        assert_eq!(
+            hierarchy.ids,
+            vec![
+                "root",
+                "root/a",
+                "root/a/c",
+                "root/a#2",
+                "root/a#2/c",
+                "root/a#3",
+                "root#2"
+            ]
+        );
+        assert_eq!(
+            hierarchy.labels,
+            vec!["root", "a", "c", "a", "c", "a", "root"]
+        );
+        assert_eq!(
+            hierarchy.parents,
+            vec!["", "root", "root/a", "root", "root/a#2", "root", ""]
+        );
+    }
+
+    #[test]
+    fn test_hierarchy_from_paths() {
+        let hierarchy = Hierarchy::from_paths(vec![
+            (vec!["a", "x"], 1.5),
+            (vec!["b"], 2.0),
+            (vec!["a", "y"], 0.5),
+            (vec!["a", "x"], 1.0),
+        ]);
+
+        assert_eq!(hierarchy.ids, vec!["a", "a/x", "b", "a/y"]);
+        assert_eq!(hierarchy.labels, vec!["a", "x", "b", "y"]);
+        assert_eq!(hierarchy.parents, vec!["", "a", "", "a"]);
+        assert_eq!(hierarchy.values, vec![3.0, 2.5, 2.0, 0.5]);
+        assert!(matches!(hierarchy.branch_values, Some(BranchValues::Total)));
+    }
+
+    #[test]
+    fn test_hierarchy_from_paths_with_separator_in_label() {
+        let hierarchy = Hierarchy::from_paths(vec![
+            (vec!["a/b"], 1),
+            (vec!["a", "b"], 2),
+            (vec!["a\\", "b"], 4),
+        ]);
+
+        assert_eq!(hierarchy.ids, vec!["a\\/b", "a", "a/b", "a\\\\", "a\\\\/b"]);
+        assert_eq!(hierarchy.labels, vec!["a/b", "a", "b", "a\\", "b"]);
+        assert_eq!(hierarchy.parents, vec!["", "", "a", "", "a\\\\"]);
+        assert_eq!(hierarchy.values, vec![1, 2, 2, 4, 4]);
+    }
+}
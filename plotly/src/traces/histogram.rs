@@ -2,35 +2,48 @@
 
 #[cfg(feature = "plotly_ndarray")]
 use ndarray::{Array, Ix1, Ix2};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "plotly_ndarray")]
 use crate::ndarray::ArrayTraces;
 use crate::{
-    common::{Calendar, Dim, ErrorData, HoverInfo, Label, Marker, Orientation, PlotType, Visible},
-    private, Trace,
+    common::{
+        Calendar, Dim, ErrorData, HoverInfo, Label, Marker, Orientation, PlotType, Visible,
+        XAxisRef, YAxisRef,
+    },
+    private::{self, Extra},
+    Trace,
 };
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Bins {
     start: f64,
     end: f64,
     size: f64,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Bins {
     pub fn new(start: f64, end: f64, size: f64) -> Self {
-        Self { start, end, size }
+        Self {
+            start,
+            end,
+            size,
+            extra: Extra::new(),
+        }
     }
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Cumulative {
     enabled: Option<bool>,
     direction: Option<HistDirection>,
     #[serde(rename = "currentbin")]
     current_bin: Option<CurrentBin>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Cumulative {
@@ -54,7 +67,7 @@ impl Cumulative {
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum CurrentBin {
     Include,
@@ -62,14 +75,14 @@ pub enum CurrentBin {
     Half,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum HistDirection {
     Increasing,
     Decreasing,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum HistFunc {
     Count,
@@ -82,7 +95,7 @@ pub enum HistFunc {
     Maximum,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum HistNorm {
     #[serde(rename = "")]
@@ -115,7 +128,7 @@ pub enum HistNorm {
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Histogram<H>
 where
     H: Serialize + Clone,
@@ -162,18 +175,20 @@ where
     visible: Option<Visible>,
     x: Option<Vec<H>>,
     #[serde(rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisRef>,
     #[serde(rename = "xbins")]
     x_bins: Option<Bins>,
     #[serde(rename = "xcalendar")]
     x_calendar: Option<Calendar>,
     y: Option<Vec<H>>,
     #[serde(rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisRef>,
     #[serde(rename = "ybins")]
     y_bins: Option<Bins>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<H> Default for Histogram<H>
@@ -215,6 +230,7 @@ where
             y_axis: None,
             y_bins: None,
             y_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -251,9 +267,9 @@ where
     /// # Arguments
     /// * `x`             - One dimensional array (or view) that represents the `x` axis coordinates.
     /// * `traces_matrix` - Two dimensional array (or view) containing the `y` axis coordinates of
-    /// the traces.
+    ///   the traces.
     /// * `array_traces`  - Determines whether the traces are arranged in the matrix over the
-    /// columns (`ArrayTraces::OverColumns`) or over the rows (`ArrayTraces::OverRows`).
+    ///   columns (`ArrayTraces::OverColumns`) or over the rows (`ArrayTraces::OverRows`).
     ///
     /// # Examples
     ///
@@ -289,6 +305,7 @@ where
     ///     let mut plot = Plot::new();
     ///     plot.set_layout(layout);
     ///     plot.add_traces(traces);
+    /// #   #[cfg(not(feature = "wasm"))]
     ///     plot.show();
     /// }
     /// fn main() -> std::io::Result<()> {
@@ -463,8 +480,8 @@ where
         Box::new(self)
     }
 
-    pub fn x_axis(mut self, axis: &str) -> Box<Self> {
-        self.x_axis = Some(axis.to_string());
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
         Box::new(self)
     }
 
@@ -478,8 +495,8 @@ where
         Box::new(self)
     }
 
-    pub fn y_axis(mut self, axis: &str) -> Box<Self> {
-        self.y_axis = Some(axis.to_string());
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
         Box::new(self)
     }
 
@@ -630,10 +647,10 @@ mod tests {
             .text("text")
             .text_array(vec!["text_1", "text_2"])
             .visible(Visible::True)
-            .x_axis("xaxis")
+            .x_axis(XAxisRef::new(2))
             .x_bins(Bins::new(1.0, 2.0, 1.0))
             .x_calendar(Calendar::Julian)
-            .y_axis("yaxis")
+            .y_axis(YAxisRef::new(2))
             .y_bins(Bins::new(2.0, 3.0, 4.0))
             .y_calendar(Calendar::Mayan);
 
@@ -663,10 +680,10 @@ mod tests {
             "text": ["text_1", "text_2"],
             "visible": true,
             "x": [0, 1, 2],
-            "xaxis": "xaxis",
+            "xaxis": "x2",
             "xbins": {"start": 1.0, "end": 2.0, "size": 1.0},
             "xcalendar": "julian",
-            "yaxis": "yaxis",
+            "yaxis": "y2",
             "ybins": {"start": 2.0, "end": 3.0, "size": 4.0},
             "ycalendar": "mayan"
         });
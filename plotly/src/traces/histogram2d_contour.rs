@@ -0,0 +1,502 @@
+//! Two dimensional histogram contour trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{
+        Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, Line, PlotType, Visible, XAxisRef,
+        YAxisRef,
+    },
+    contour::Contours,
+    histogram::{Bins, HistFunc, HistNorm},
+    private::{self, Extra},
+    Trace,
+};
+
+/// Construct a two dimensional histogram contour trace, in which the counts of the `x` and `y`
+/// samples falling in each bin are shown as contour lines.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{contour::{Coloring, Contours}, Histogram2dContour};
+///
+/// let trace = Histogram2dContour::new(vec![0.1, 0.5, 1.2], vec![1.0, 1.4, 2.3])
+///     .contours(Contours::new().coloring(Coloring::Lines));
+///
+/// let expected = serde_json::json!({
+///     "type": "histogram2dcontour",
+///     "x": [0.1, 0.5, 1.2],
+///     "y": [1.0, 1.4, 2.3],
+///     "contours": {"coloring": "lines"}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Histogram2dContour<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    r#type: PlotType,
+    #[serde(rename = "autobinx")]
+    auto_bin_x: Option<bool>,
+    #[serde(rename = "autobiny")]
+    auto_bin_y: Option<bool>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(rename = "autocontour")]
+    auto_contour: Option<bool>,
+    #[serde(rename = "bingroup")]
+    bin_group: Option<String>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    contours: Option<Contours>,
+    #[serde(rename = "histfunc")]
+    hist_func: Option<HistFunc>,
+    #[serde(rename = "histnorm")]
+    hist_norm: Option<HistNorm>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    line: Option<Line>,
+    #[serde(rename = "nbinsx")]
+    n_bins_x: Option<usize>,
+    #[serde(rename = "nbinsy")]
+    n_bins_y: Option<usize>,
+    #[serde(rename = "ncontours")]
+    n_contours: Option<usize>,
+    name: Option<String>,
+    opacity: Option<f64>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    visible: Option<Visible>,
+    x: Option<Vec<X>>,
+    #[serde(rename = "xaxis")]
+    x_axis: Option<XAxisRef>,
+    #[serde(rename = "xbingroup")]
+    x_bin_group: Option<String>,
+    #[serde(rename = "xbins")]
+    x_bins: Option<Bins>,
+    #[serde(rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    y: Option<Vec<Y>>,
+    #[serde(rename = "yaxis")]
+    y_axis: Option<YAxisRef>,
+    #[serde(rename = "ybingroup")]
+    y_bin_group: Option<String>,
+    #[serde(rename = "ybins")]
+    y_bins: Option<Bins>,
+    #[serde(rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    z: Option<Vec<f64>>,
+    zauto: Option<bool>,
+    #[serde(rename = "zhoverformat")]
+    zhover_format: Option<String>,
+    zmax: Option<f64>,
+    zmid: Option<f64>,
+    zmin: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<X, Y> Default for Histogram2dContour<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Histogram2dContour,
+            auto_bin_x: None,
+            auto_bin_y: None,
+            auto_color_scale: None,
+            auto_contour: None,
+            bin_group: None,
+            color_bar: None,
+            color_scale: None,
+            contours: None,
+            hist_func: None,
+            hist_norm: None,
+            hover_info: None,
+            hover_label: None,
+            hover_template: None,
+            legend_group: None,
+            line: None,
+            n_bins_x: None,
+            n_bins_y: None,
+            n_contours: None,
+            name: None,
+            opacity: None,
+            reverse_scale: None,
+            show_legend: None,
+            show_scale: None,
+            visible: None,
+            x: None,
+            x_axis: None,
+            x_bin_group: None,
+            x_bins: None,
+            x_calendar: None,
+            y: None,
+            y_axis: None,
+            y_bin_group: None,
+            y_bins: None,
+            y_calendar: None,
+            z: None,
+            zauto: None,
+            zhover_format: None,
+            zmax: None,
+            zmid: None,
+            zmin: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<X, Y> Histogram2dContour<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+
+    pub fn auto_bin_x(mut self, auto_bin_x: bool) -> Box<Self> {
+        self.auto_bin_x = Some(auto_bin_x);
+        Box::new(self)
+    }
+
+    pub fn auto_bin_y(mut self, auto_bin_y: bool) -> Box<Self> {
+        self.auto_bin_y = Some(auto_bin_y);
+        Box::new(self)
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Self> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    /// Determines whether or not the contour level attributes are picked by an algorithm. If
+    /// `false`, set the levels with `Contours`.
+    pub fn auto_contour(mut self, auto_contour: bool) -> Box<Self> {
+        self.auto_contour = Some(auto_contour);
+        Box::new(self)
+    }
+
+    pub fn bin_group(mut self, bin_group: &str) -> Box<Self> {
+        self.bin_group = Some(bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Self> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Self> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn contours(mut self, contours: Contours) -> Box<Self> {
+        self.contours = Some(contours);
+        Box::new(self)
+    }
+
+    /// Specifies the binning function used for this histogram trace. With `Count`, the
+    /// samples in each bin are counted, otherwise the function is applied to the `z` values of
+    /// the samples in each bin.
+    pub fn hist_func(mut self, hist_func: HistFunc) -> Box<Self> {
+        self.hist_func = Some(hist_func);
+        Box::new(self)
+    }
+
+    pub fn hist_norm(mut self, hist_norm: HistNorm) -> Box<Self> {
+        self.hist_norm = Some(hist_norm);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn line(mut self, line: Line) -> Box<Self> {
+        self.line = Some(line);
+        Box::new(self)
+    }
+
+    pub fn n_bins_x(mut self, n_bins_x: usize) -> Box<Self> {
+        self.n_bins_x = Some(n_bins_x);
+        Box::new(self)
+    }
+
+    pub fn n_bins_y(mut self, n_bins_y: usize) -> Box<Self> {
+        self.n_bins_y = Some(n_bins_y);
+        Box::new(self)
+    }
+
+    /// Sets the maximum number of contour levels. The actual number of contours will be chosen
+    /// automatically to be less than or equal to `n_contours`. Has an effect only if
+    /// `auto_contour` is `true`.
+    pub fn n_contours(mut self, n_contours: usize) -> Box<Self> {
+        self.n_contours = Some(n_contours);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Self> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Self> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
+        Box::new(self)
+    }
+
+    pub fn x_bin_group(mut self, x_bin_group: &str) -> Box<Self> {
+        self.x_bin_group = Some(x_bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn x_bins(mut self, x_bins: Bins) -> Box<Self> {
+        self.x_bins = Some(x_bins);
+        Box::new(self)
+    }
+
+    pub fn x_calendar(mut self, x_calendar: Calendar) -> Box<Self> {
+        self.x_calendar = Some(x_calendar);
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
+        Box::new(self)
+    }
+
+    pub fn y_bin_group(mut self, y_bin_group: &str) -> Box<Self> {
+        self.y_bin_group = Some(y_bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_bins(mut self, y_bins: Bins) -> Box<Self> {
+        self.y_bins = Some(y_bins);
+        Box::new(self)
+    }
+
+    pub fn y_calendar(mut self, y_calendar: Calendar) -> Box<Self> {
+        self.y_calendar = Some(y_calendar);
+        Box::new(self)
+    }
+
+    /// Sets the aggregation data, to which `hist_func` is applied.
+    pub fn z(mut self, z: Vec<f64>) -> Box<Self> {
+        self.z = Some(z);
+        Box::new(self)
+    }
+
+    pub fn zauto(mut self, zauto: bool) -> Box<Self> {
+        self.zauto = Some(zauto);
+        Box::new(self)
+    }
+
+    pub fn zhover_format(mut self, zhover_format: &str) -> Box<Self> {
+        self.zhover_format = Some(zhover_format.to_owned());
+        Box::new(self)
+    }
+
+    pub fn zmax(mut self, zmax: f64) -> Box<Self> {
+        self.zmax = Some(zmax);
+        Box::new(self)
+    }
+
+    pub fn zmid(mut self, zmid: f64) -> Box<Self> {
+        self.zmid = Some(zmid);
+        Box::new(self)
+    }
+
+    pub fn zmin(mut self, zmin: f64) -> Box<Self> {
+        self.zmin = Some(zmin);
+        Box::new(self)
+    }
+}
+
+impl<X, Y> Trace for Histogram2dContour<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_default_histogram2dcontour() {
+        let trace: Histogram2dContour<f64, f64> = Histogram2dContour::default();
+        let expected = json!({"type": "histogram2dcontour"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_histogram2dcontour() {
+        let trace = Histogram2dContour::new(vec![0.0, 1.0], vec![2.0, 3.0])
+            .auto_bin_x(false)
+            .auto_bin_y(true)
+            .auto_color_scale(true)
+            .auto_contour(false)
+            .bin_group("bin_group")
+            .color_bar(ColorBar::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Viridis))
+            .contours(Contours::new())
+            .hist_func(HistFunc::Average)
+            .hist_norm(HistNorm::Probability)
+            .hover_info(HoverInfo::XAndYAndZ)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .legend_group("legend_group")
+            .line(Line::new())
+            .n_bins_x(10)
+            .n_bins_y(20)
+            .n_contours(5)
+            .name("histogram2dcontour")
+            .opacity(0.5)
+            .reverse_scale(true)
+            .show_legend(false)
+            .show_scale(true)
+            .visible(Visible::True)
+            .x_axis(XAxisRef::new(2))
+            .x_bin_group("x_bin_group")
+            .x_bins(Bins::new(0.0, 1.0, 0.5))
+            .x_calendar(Calendar::Coptic)
+            .y_axis(YAxisRef::new(2))
+            .y_bin_group("y_bin_group")
+            .y_bins(Bins::new(2.0, 3.0, 0.5))
+            .y_calendar(Calendar::Jalali)
+            .z(vec![4.0, 5.0])
+            .zauto(false)
+            .zhover_format("fmt")
+            .zmax(10.0)
+            .zmid(5.0)
+            .zmin(0.0);
+
+        let expected = json!({
+            "type": "histogram2dcontour",
+            "autobinx": false,
+            "autobiny": true,
+            "autocolorscale": true,
+            "autocontour": false,
+            "bingroup": "bin_group",
+            "colorbar": {},
+            "colorscale": "Viridis",
+            "contours": {},
+            "histfunc": "avg",
+            "histnorm": "probability",
+            "hoverinfo": "x+y+z",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "legendgroup": "legend_group",
+            "line": {},
+            "nbinsx": 10,
+            "nbinsy": 20,
+            "ncontours": 5,
+            "name": "histogram2dcontour",
+            "opacity": 0.5,
+            "reversescale": true,
+            "showlegend": false,
+            "showscale": true,
+            "visible": true,
+            "x": [0.0, 1.0],
+            "xaxis": "x2",
+            "xbingroup": "x_bin_group",
+            "xbins": {"start": 0.0, "end": 1.0, "size": 0.5},
+            "xcalendar": "coptic",
+            "y": [2.0, 3.0],
+            "yaxis": "y2",
+            "ybingroup": "y_bin_group",
+            "ybins": {"start": 2.0, "end": 3.0, "size": 0.5},
+            "ycalendar": "jalali",
+            "z": [4.0, 5.0],
+            "zauto": false,
+            "zhoverformat": "fmt",
+            "zmax": 10.0,
+            "zmid": 5.0,
+            "zmin": 0.0
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,443 @@
+//! Isosurface trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible},
+    private::{self, Extra},
+    surface::{Lighting, Position},
+    volume::{Caps, Slices, SpaceFrame, VolumeSurface},
+    Trace,
+};
+
+/// Construct an isosurface trace, drawing the surfaces on which the values sampled on a 3D grid
+/// given by `x`, `y` and `z` are constant.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{volume::{Cap, Caps}, Isosurface};
+///
+/// let trace = Isosurface::new(
+///     vec![0, 0, 1, 1],
+///     vec![0, 1, 0, 1],
+///     vec![0, 0, 0, 0],
+///     vec![0.1, 0.4, 0.6, 0.9],
+/// )
+/// .iso_min(0.2)
+/// .iso_max(0.8)
+/// .caps(Caps::new().x(Cap::new().show(false)));
+///
+/// let expected = serde_json::json!({
+///     "type": "isosurface",
+///     "x": [0, 0, 1, 1],
+///     "y": [0, 1, 0, 1],
+///     "z": [0, 0, 0, 0],
+///     "value": [0.1, 0.4, 0.6, 0.9],
+///     "isomin": 0.2,
+///     "isomax": 0.8,
+///     "caps": {"x": {"show": false}}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Isosurface<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    r#type: PlotType,
+    x: Option<Vec<X>>,
+    y: Option<Vec<Y>>,
+    z: Option<Vec<Z>>,
+    value: Option<Vec<V>>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    caps: Option<Caps>,
+    cauto: Option<bool>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    cmin: Option<f64>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "flatshading")]
+    flat_shading: Option<bool>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "isomax")]
+    iso_max: Option<f64>,
+    #[serde(rename = "isomin")]
+    iso_min: Option<f64>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<Position>,
+    lighting: Option<Lighting>,
+    name: Option<String>,
+    opacity: Option<f64>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    scene: Option<String>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    slices: Option<Slices>,
+    #[serde(rename = "spaceframe")]
+    space_frame: Option<SpaceFrame>,
+    surface: Option<VolumeSurface>,
+    text: Option<Dim<String>>,
+    visible: Option<Visible>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<X, Y, Z, V> Default for Isosurface<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Isosurface,
+            x: None,
+            y: None,
+            z: None,
+            value: None,
+            auto_color_scale: None,
+            caps: None,
+            cauto: None,
+            cmax: None,
+            cmid: None,
+            cmin: None,
+            color_bar: None,
+            color_scale: None,
+            flat_shading: None,
+            hover_info: None,
+            hover_label: None,
+            hover_template: None,
+            hover_text: None,
+            iso_max: None,
+            iso_min: None,
+            legend_group: None,
+            light_position: None,
+            lighting: None,
+            name: None,
+            opacity: None,
+            reverse_scale: None,
+            scene: None,
+            show_legend: None,
+            show_scale: None,
+            slices: None,
+            space_frame: None,
+            surface: None,
+            text: None,
+            visible: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<X, Y, Z, V> Isosurface<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>, value: Vec<V>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Self> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    pub fn caps(mut self, caps: Caps) -> Box<Self> {
+        self.caps = Some(caps);
+        Box::new(self)
+    }
+
+    pub fn cauto(mut self, cauto: bool) -> Box<Self> {
+        self.cauto = Some(cauto);
+        Box::new(self)
+    }
+
+    pub fn cmax(mut self, cmax: f64) -> Box<Self> {
+        self.cmax = Some(cmax);
+        Box::new(self)
+    }
+
+    pub fn cmid(mut self, cmid: f64) -> Box<Self> {
+        self.cmid = Some(cmid);
+        Box::new(self)
+    }
+
+    pub fn cmin(mut self, cmin: f64) -> Box<Self> {
+        self.cmin = Some(cmin);
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Self> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Self> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn flat_shading(mut self, flat_shading: bool) -> Box<Self> {
+        self.flat_shading = Some(flat_shading);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    /// Sets the maximum boundary for iso-surface plot.
+    pub fn iso_max(mut self, iso_max: f64) -> Box<Self> {
+        self.iso_max = Some(iso_max);
+        Box::new(self)
+    }
+
+    /// Sets the minimum boundary for iso-surface plot.
+    pub fn iso_min(mut self, iso_min: f64) -> Box<Self> {
+        self.iso_min = Some(iso_min);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn lighting(mut self, lighting: Lighting) -> Box<Self> {
+        self.lighting = Some(lighting);
+        Box::new(self)
+    }
+
+    pub fn light_position(mut self, light_position: Position) -> Box<Self> {
+        self.light_position = Some(light_position);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Self> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's 3D coordinate system and a 3D scene. If "scene" (the
+    /// default value), the (x,y,z) coordinates refer to `layout.scene`. If "scene2", the (x, y, z)
+    /// coordinates refer to `layout.scene2`, and so on.
+    pub fn scene(mut self, scene: &str) -> Box<Self> {
+        self.scene = Some(scene.to_owned());
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Self> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    pub fn slices(mut self, slices: Slices) -> Box<Self> {
+        self.slices = Some(slices);
+        Box::new(self)
+    }
+
+    pub fn space_frame(mut self, space_frame: SpaceFrame) -> Box<Self> {
+        self.space_frame = Some(space_frame);
+        Box::new(self)
+    }
+
+    pub fn surface(mut self, surface: VolumeSurface) -> Box<Self> {
+        self.surface = Some(surface);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+}
+
+impl<X, Y, Z, V> Trace for Isosurface<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_default_isosurface() {
+        let trace: Isosurface<f64, f64, f64, f64> = Isosurface::default();
+        let expected = json!({"type": "isosurface"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_isosurface() {
+        let trace = Isosurface::new(vec![0], vec![1], vec![2], vec![3.0])
+            .auto_color_scale(false)
+            .caps(Caps::new())
+            .cauto(true)
+            .cmax(5.0)
+            .cmid(2.5)
+            .cmin(0.0)
+            .color_bar(ColorBar::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Jet))
+            .flat_shading(true)
+            .hover_info(HoverInfo::All)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .iso_max(10.0)
+            .iso_min(1.0)
+            .legend_group("legend_group")
+            .lighting(Lighting::new())
+            .light_position(Position::new(1, 2, 3))
+            .name("isosurface")
+            .opacity(0.1)
+            .reverse_scale(true)
+            .scene("scene2")
+            .show_legend(true)
+            .show_scale(false)
+            .slices(Slices::new())
+            .space_frame(SpaceFrame::new())
+            .surface(VolumeSurface::new())
+            .text("text")
+            .text_array(vec!["text"])
+            .visible(Visible::True);
+
+        let expected = json!({
+            "type": "isosurface",
+            "x": [0],
+            "y": [1],
+            "z": [2],
+            "value": [3.0],
+            "autocolorscale": false,
+            "caps": {},
+            "cauto": true,
+            "cmax": 5.0,
+            "cmid": 2.5,
+            "cmin": 0.0,
+            "colorbar": {},
+            "colorscale": "Jet",
+            "flatshading": true,
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "isomax": 10.0,
+            "isomin": 1.0,
+            "legendgroup": "legend_group",
+            "lighting": {},
+            "lightposition": {"x": 1, "y": 2, "z": 3},
+            "name": "isosurface",
+            "opacity": 0.1,
+            "reversescale": true,
+            "scene": "scene2",
+            "showlegend": true,
+            "showscale": false,
+            "slices": {},
+            "spaceframe": {},
+            "surface": {},
+            "text": ["text"],
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,585 @@
+//! Mesh3D trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    color::{Color, ColorArray},
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible},
+    private::{self, Extra},
+    surface::{Lighting, Position},
+    Trace,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum IntensityMode {
+    Vertex,
+    Cell,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum DelaunayAxis {
+    X,
+    Y,
+    Z,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Contour {
+    show: Option<bool>,
+    color: Option<Box<dyn Color>>,
+    width: Option<usize>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Contour {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+/// Construct a mesh3d trace, drawing a set of triangles with vertices given by `x`, `y` and `z`.
+///
+/// The triangles are specified by indexing into the vertices with `i`, `j` and `k`. If they are
+/// not given, the triangles are computed from the vertices, using either `alpha_hull` or
+/// `delaunay_axis`.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::Mesh3D;
+///
+/// let trace = Mesh3D::new(
+///     vec![0.0, 1.0, 2.0, 0.0],
+///     vec![0.0, 0.0, 1.0, 2.0],
+///     vec![0.0, 2.0, 0.0, 1.0],
+///     vec![0, 0, 0, 1],
+///     vec![1, 2, 3, 2],
+///     vec![2, 3, 1, 3],
+/// );
+///
+/// let expected = serde_json::json!({
+///     "type": "mesh3d",
+///     "x": [0.0, 1.0, 2.0, 0.0],
+///     "y": [0.0, 0.0, 1.0, 2.0],
+///     "z": [0.0, 2.0, 0.0, 1.0],
+///     "i": [0, 0, 0, 1],
+///     "j": [1, 2, 3, 2],
+///     "k": [2, 3, 1, 3],
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Mesh3D<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    r#type: PlotType,
+    x: Option<Vec<X>>,
+    y: Option<Vec<Y>>,
+    z: Option<Vec<Z>>,
+    i: Option<Vec<usize>>,
+    j: Option<Vec<usize>>,
+    k: Option<Vec<usize>>,
+    #[serde(rename = "alphahull")]
+    alpha_hull: Option<f64>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    cauto: Option<bool>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    cmin: Option<f64>,
+    color: Option<Box<dyn Color>>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    contour: Option<Contour>,
+    #[serde(rename = "delaunayaxis")]
+    delaunay_axis: Option<DelaunayAxis>,
+    #[serde(rename = "facecolor")]
+    face_color: Option<Vec<Box<dyn Color>>>,
+    #[serde(rename = "flatshading")]
+    flat_shading: Option<bool>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    intensity: Option<Vec<f64>>,
+    #[serde(rename = "intensitymode")]
+    intensity_mode: Option<IntensityMode>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<Position>,
+    lighting: Option<Lighting>,
+    name: Option<String>,
+    opacity: Option<f64>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    scene: Option<String>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    text: Option<Dim<String>>,
+    #[serde(rename = "vertexcolor")]
+    vertex_color: Option<Vec<Box<dyn Color>>>,
+    visible: Option<Visible>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<X, Y, Z> Default for Mesh3D<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Mesh3D,
+            x: None,
+            y: None,
+            z: None,
+            i: None,
+            j: None,
+            k: None,
+            alpha_hull: None,
+            auto_color_scale: None,
+            cauto: None,
+            cmax: None,
+            cmid: None,
+            cmin: None,
+            color: None,
+            color_bar: None,
+            color_scale: None,
+            contour: None,
+            delaunay_axis: None,
+            face_color: None,
+            flat_shading: None,
+            hover_info: None,
+            hover_label: None,
+            hover_template: None,
+            hover_text: None,
+            intensity: None,
+            intensity_mode: None,
+            legend_group: None,
+            light_position: None,
+            lighting: None,
+            name: None,
+            opacity: None,
+            reverse_scale: None,
+            scene: None,
+            show_legend: None,
+            show_scale: None,
+            text: None,
+            vertex_color: None,
+            visible: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<X, Y, Z> Mesh3D<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    pub fn new(
+        x: Vec<X>,
+        y: Vec<Y>,
+        z: Vec<Z>,
+        i: Vec<usize>,
+        j: Vec<usize>,
+        k: Vec<usize>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            i: Some(i),
+            j: Some(j),
+            k: Some(k),
+            ..Default::default()
+        })
+    }
+
+    /// Construct a mesh from its vertices only, leaving the triangulation to be computed
+    /// according to `alpha_hull` and `delaunay_axis`.
+    pub fn new_vertices(x: Vec<X>, y: Vec<Y>, z: Vec<Z>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            ..Default::default()
+        })
+    }
+
+    /// Determines how the mesh surface triangles are derived from the set of vertices when `i`,
+    /// `j` and `k` are not given. If negative, the Delaunay triangulation is used. If zero, the
+    /// convex hull is used. If positive, the alpha-shape algorithm is used with this value as
+    /// the parameter.
+    pub fn alpha_hull(mut self, alpha_hull: f64) -> Box<Self> {
+        self.alpha_hull = Some(alpha_hull);
+        Box::new(self)
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Self> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    pub fn cauto(mut self, cauto: bool) -> Box<Self> {
+        self.cauto = Some(cauto);
+        Box::new(self)
+    }
+
+    pub fn cmax(mut self, cmax: f64) -> Box<Self> {
+        self.cmax = Some(cmax);
+        Box::new(self)
+    }
+
+    pub fn cmid(mut self, cmid: f64) -> Box<Self> {
+        self.cmid = Some(cmid);
+        Box::new(self)
+    }
+
+    pub fn cmin(mut self, cmin: f64) -> Box<Self> {
+        self.cmin = Some(cmin);
+        Box::new(self)
+    }
+
+    /// Sets the color of the whole mesh.
+    pub fn color<C: Color>(mut self, color: C) -> Box<Self> {
+        self.color = Some(Box::new(color));
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Self> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Self> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn contour(mut self, contour: Contour) -> Box<Self> {
+        self.contour = Some(contour);
+        Box::new(self)
+    }
+
+    /// Sets the Delaunay axis, which is the axis that is perpendicular to the surface of the
+    /// Delaunay triangulation. Only has an effect when `alpha_hull` is negative.
+    pub fn delaunay_axis(mut self, delaunay_axis: DelaunayAxis) -> Box<Self> {
+        self.delaunay_axis = Some(delaunay_axis);
+        Box::new(self)
+    }
+
+    /// Sets the color of each face. Overrides `color` and `vertex_color`.
+    pub fn face_color<C: Color>(mut self, face_color: Vec<C>) -> Box<Self> {
+        self.face_color = Some(ColorArray(face_color).into());
+        Box::new(self)
+    }
+
+    /// Determines whether or not normal smoothing is applied to the mesh, giving it a faceted
+    /// appearance.
+    pub fn flat_shading(mut self, flat_shading: bool) -> Box<Self> {
+        self.flat_shading = Some(flat_shading);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    /// Sets the intensity values, used to color the mesh with the color scale.
+    pub fn intensity(mut self, intensity: Vec<f64>) -> Box<Self> {
+        self.intensity = Some(intensity);
+        Box::new(self)
+    }
+
+    /// Determines whether the intensity values are given per vertex or per cell (face).
+    pub fn intensity_mode(mut self, intensity_mode: IntensityMode) -> Box<Self> {
+        self.intensity_mode = Some(intensity_mode);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn lighting(mut self, lighting: Lighting) -> Box<Self> {
+        self.lighting = Some(lighting);
+        Box::new(self)
+    }
+
+    pub fn light_position(mut self, light_position: Position) -> Box<Self> {
+        self.light_position = Some(light_position);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Self> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's 3D coordinate system and a 3D scene. If "scene" (the
+    /// default value), the (x,y,z) coordinates refer to `layout.scene`. If "scene2", the (x, y, z)
+    /// coordinates refer to `layout.scene2`, and so on.
+    pub fn scene(mut self, scene: &str) -> Box<Self> {
+        self.scene = Some(scene.to_owned());
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Self> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    /// Sets the color of each vertex. Overrides `color`.
+    pub fn vertex_color<C: Color>(mut self, vertex_color: Vec<C>) -> Box<Self> {
+        self.vertex_color = Some(ColorArray(vertex_color).into());
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+}
+
+impl<X, Y, Z> Trace for Mesh3D<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_intensity_mode() {
+        assert_eq!(to_value(IntensityMode::Vertex).unwrap(), json!("vertex"));
+        assert_eq!(to_value(IntensityMode::Cell).unwrap(), json!("cell"));
+    }
+
+    #[test]
+    fn test_serialize_delaunay_axis() {
+        assert_eq!(to_value(DelaunayAxis::X).unwrap(), json!("x"));
+        assert_eq!(to_value(DelaunayAxis::Y).unwrap(), json!("y"));
+        assert_eq!(to_value(DelaunayAxis::Z).unwrap(), json!("z"));
+    }
+
+    #[test]
+    fn test_serialize_contour() {
+        let contour = Contour::new().show(true).color("#123456").width(2);
+        let expected = json!({"show": true, "color": "#123456", "width": 2});
+
+        assert_eq!(to_value(contour).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_default_mesh3d() {
+        let trace: Mesh3D<f64, f64, f64> = Mesh3D::default();
+        let expected = json!({"type": "mesh3d"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_new_vertices_mesh3d() {
+        let trace = Mesh3D::new_vertices(vec![0, 1], vec![2, 3], vec![4, 5]).alpha_hull(0.0);
+        let expected = json!({
+            "type": "mesh3d",
+            "x": [0, 1],
+            "y": [2, 3],
+            "z": [4, 5],
+            "alphahull": 0.0
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_mesh3d() {
+        let trace = Mesh3D::new(
+            vec![0.0, 1.0, 2.0],
+            vec![3.0, 4.0, 5.0],
+            vec![6.0, 7.0, 8.0],
+            vec![0],
+            vec![1],
+            vec![2],
+        )
+        .alpha_hull(7.5)
+        .auto_color_scale(false)
+        .cauto(true)
+        .cmax(5.0)
+        .cmid(2.5)
+        .cmin(0.0)
+        .color("#ff0000")
+        .color_bar(ColorBar::new())
+        .color_scale(ColorScale::Palette(ColorScalePalette::Greens))
+        .contour(Contour::new())
+        .delaunay_axis(DelaunayAxis::Y)
+        .face_color(vec!["#00ff00"])
+        .flat_shading(true)
+        .hover_info(HoverInfo::XAndYAndZ)
+        .hover_label(Label::new())
+        .hover_template("hover_template")
+        .hover_template_array(vec!["hover_template"])
+        .hover_text("hover_text")
+        .hover_text_array(vec!["hover_text"])
+        .intensity(vec![1.0, 2.0, 3.0])
+        .intensity_mode(IntensityMode::Vertex)
+        .legend_group("legend_group")
+        .lighting(Lighting::new())
+        .light_position(Position::new(1, 2, 3))
+        .name("mesh")
+        .opacity(0.5)
+        .reverse_scale(true)
+        .scene("scene2")
+        .show_legend(true)
+        .show_scale(false)
+        .text("text")
+        .text_array(vec!["text"])
+        .vertex_color(vec!["#0000ff", "#0000ff", "#0000ff"])
+        .visible(Visible::True);
+
+        let expected = json!({
+            "type": "mesh3d",
+            "x": [0.0, 1.0, 2.0],
+            "y": [3.0, 4.0, 5.0],
+            "z": [6.0, 7.0, 8.0],
+            "i": [0],
+            "j": [1],
+            "k": [2],
+            "alphahull": 7.5,
+            "autocolorscale": false,
+            "cauto": true,
+            "cmax": 5.0,
+            "cmid": 2.5,
+            "cmin": 0.0,
+            "color": "#ff0000",
+            "colorbar": {},
+            "colorscale": "Greens",
+            "contour": {},
+            "delaunayaxis": "y",
+            "facecolor": ["#00ff00"],
+            "flatshading": true,
+            "hoverinfo": "x+y+z",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "intensity": [1.0, 2.0, 3.0],
+            "intensitymode": "vertex",
+            "legendgroup": "legend_group",
+            "lighting": {},
+            "lightposition": {"x": 1, "y": 2, "z": 3},
+            "name": "mesh",
+            "opacity": 0.5,
+            "reversescale": true,
+            "scene": "scene2",
+            "showlegend": true,
+            "showscale": false,
+            "text": ["text"],
+            "vertexcolor": ["#0000ff", "#0000ff", "#0000ff"],
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -1,27 +1,162 @@
 //! The various supported traces
 
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{common::PlotType, Trace};
+
 mod bar;
+mod bar_polar;
 pub mod box_plot;
 mod candlestick;
+mod choropleth;
+mod choropleth_mapbox;
+pub mod cone;
 pub mod contour;
+mod density_mapbox;
 mod heat_map;
+pub mod hierarchy;
 pub mod histogram;
+mod histogram2d;
+mod histogram2d_contour;
+mod icicle;
+mod isosurface;
+pub mod mesh3d;
 mod ohlc;
+pub mod pie;
 pub mod sankey;
 mod scatter;
 mod scatter3d;
+mod scatter_geo;
+mod scatter_mapbox;
 mod scatter_polar;
+mod scatter_ternary;
+pub mod streamtube;
+mod sunburst;
 pub mod surface;
+mod treemap;
+pub mod violin;
+pub mod volume;
+pub mod waterfall;
 
 pub use bar::Bar;
+pub use bar_polar::BarPolar;
 pub use box_plot::BoxPlot;
 pub use candlestick::Candlestick;
+pub use choropleth::Choropleth;
+pub use choropleth_mapbox::ChoroplethMapbox;
+pub use cone::Cone;
 pub use contour::Contour;
+pub use density_mapbox::DensityMapbox;
 pub use heat_map::HeatMap;
 pub use histogram::Histogram;
+pub use histogram2d::Histogram2d;
+pub use histogram2d_contour::Histogram2dContour;
+pub use icicle::Icicle;
+pub use isosurface::Isosurface;
+pub use mesh3d::Mesh3D;
 pub use ohlc::Ohlc;
+pub use pie::Pie;
 pub use sankey::Sankey;
 pub use scatter::Scatter;
 pub use scatter3d::Scatter3D;
+pub use scatter_geo::ScatterGeo;
+pub use scatter_mapbox::ScatterMapbox;
 pub use scatter_polar::ScatterPolar;
+pub use scatter_ternary::ScatterTernary;
+pub use streamtube::Streamtube;
+pub use sunburst::Sunburst;
 pub use surface::Surface;
+pub use treemap::Treemap;
+pub use violin::Violin;
+pub use volume::Volume;
+pub use waterfall::Waterfall;
+
+/// A trace kept as it was read, as its type has no struct of its own in this crate or as it does not
+/// fit in that struct.
+#[derive(Serialize, Clone)]
+#[serde(transparent)]
+struct UnknownTrace(Value);
+
+impl Trace for UnknownTrace {
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn geo_subplot(&self) -> Option<&str> {
+        match self.0["type"].as_str() {
+            Some("scattergeo") | Some("choropleth") => {
+                Some(self.0["geo"].as_str().unwrap_or("geo"))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn typed<T>(trace: Value) -> Result<Box<dyn Trace>, (Box<dyn Trace>, serde_json::Error)>
+where
+    T: Trace + DeserializeOwned + 'static,
+{
+    match T::deserialize(&trace) {
+        Ok(typed) => Ok(Box::new(typed)),
+        Err(e) => Err((Box::new(UnknownTrace(trace)), e)),
+    }
+}
+
+/// Read a trace from its JSON form, picking the struct to read it into from its "type". The data
+/// of the trace is kept as `serde_json::Value`s, so that any kind of data can be read back.
+///
+/// A trace of an unknown type, or one that does not fit the struct of its type (e.g. as it holds a
+/// misspelled enum value), is kept as it was read. It is returned as an error, along with what kept
+/// it from being read.
+pub(crate) fn from_value(
+    mut trace: Value,
+) -> Result<Box<dyn Trace>, (Box<dyn Trace>, serde_json::Error)> {
+    if let Value::Object(attributes) = &mut trace {
+        // Plotly.js draws traces without a type as scatter traces.
+        attributes
+            .entry("type")
+            .or_insert_with(|| Value::from("scatter"));
+    }
+    let plot_type = match PlotType::deserialize(&trace["type"]) {
+        Ok(plot_type) => plot_type,
+        Err(e) => return Err((Box::new(UnknownTrace(trace)), e)),
+    };
+
+    match plot_type {
+        PlotType::Scatter | PlotType::ScatterGL => typed::<Scatter<Value, Value>>(trace),
+        PlotType::Scatter3D => typed::<Scatter3D<Value, Value, Value>>(trace),
+        PlotType::ScatterGeo => typed::<ScatterGeo<Value, Value>>(trace),
+        PlotType::ScatterMapbox => typed::<ScatterMapbox<Value, Value>>(trace),
+        PlotType::ScatterPolar | PlotType::ScatterPolarGL => {
+            typed::<ScatterPolar<Value, Value>>(trace)
+        }
+        PlotType::ScatterTernary => typed::<ScatterTernary<Value, Value, Value>>(trace),
+        PlotType::Bar => typed::<Bar<Value, Value>>(trace),
+        PlotType::BarPolar => typed::<BarPolar<Value, Value>>(trace),
+        PlotType::Box => typed::<BoxPlot<Value, Value>>(trace),
+        PlotType::Candlestick => typed::<Candlestick<Value, Value>>(trace),
+        PlotType::Choropleth => typed::<Choropleth<Value>>(trace),
+        PlotType::ChoroplethMapbox => typed::<ChoroplethMapbox<Value>>(trace),
+        PlotType::Cone => typed::<Cone<Value, Value, Value>>(trace),
+        PlotType::Contour => typed::<Contour<Value, Value, Value>>(trace),
+        PlotType::DensityMapbox => typed::<DensityMapbox<Value, Value, Value>>(trace),
+        PlotType::HeatMap => typed::<HeatMap<Value, Value, Value>>(trace),
+        PlotType::Histogram => typed::<Histogram<Value>>(trace),
+        PlotType::Histogram2d => typed::<Histogram2d<Value, Value>>(trace),
+        PlotType::Histogram2dContour => typed::<Histogram2dContour<Value, Value>>(trace),
+        PlotType::Icicle => typed::<Icicle<Value>>(trace),
+        PlotType::Isosurface => typed::<Isosurface<Value, Value, Value, Value>>(trace),
+        PlotType::Mesh3D => typed::<Mesh3D<Value, Value, Value>>(trace),
+        PlotType::Ohlc => typed::<Ohlc<Value, Value>>(trace),
+        PlotType::Pie => typed::<Pie<Value>>(trace),
+        PlotType::Sankey => typed::<Sankey<Value>>(trace),
+        PlotType::Streamtube => typed::<Streamtube<Value, Value, Value>>(trace),
+        PlotType::Sunburst => typed::<Sunburst<Value>>(trace),
+        PlotType::Surface => typed::<Surface<Value, Value, Value>>(trace),
+        PlotType::Treemap => typed::<Treemap<Value>>(trace),
+        PlotType::Violin => typed::<Violin<Value, Value>>(trace),
+        PlotType::Volume => typed::<Volume<Value, Value, Value, Value>>(trace),
+        PlotType::Waterfall => typed::<Waterfall<Value, Value>>(trace),
+    }
+}
@@ -1,10 +1,11 @@
 //! Open-high-low-close (OHLC) trace
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     common::{Calendar, Dim, Direction, HoverInfo, Label, Line, PlotType, Visible},
-    private, Trace,
+    private::{self, Extra},
+    Trace,
 };
 
 /// Construct an OHLC trace.
@@ -34,7 +35,7 @@ use crate::{
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Ohlc<X, O>
 where
     X: Serialize + Clone,
@@ -67,6 +68,8 @@ where
     visible: Option<Visible>,
     #[serde(rename = "xcalendar")]
     x_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<X, O> Default for Ohlc<X, O>
@@ -96,6 +99,7 @@ where
             tick_width: None,
             visible: None,
             x_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
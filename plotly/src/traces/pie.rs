@@ -0,0 +1,521 @@
+//! Pie trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{Dim, Domain, Font, HoverInfo, Label, Marker, PlotType, TextPosition, Title, Visible},
+    private::{self, Extra},
+    Trace,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum TextInfo {
+    Label,
+    Text,
+    Value,
+    Percent,
+    #[serde(rename = "label+percent")]
+    LabelAndPercent,
+    #[serde(rename = "label+value")]
+    LabelAndValue,
+    #[serde(rename = "value+percent")]
+    ValueAndPercent,
+    #[serde(rename = "label+value+percent")]
+    LabelValueAndPercent,
+    None,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum InsideTextOrientation {
+    Horizontal,
+    Radial,
+    Tangential,
+    Auto,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Construct a pie trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::Pie;
+///
+/// let trace = Pie::new(vec![19, 26, 55]).labels(vec!["Residential", "Non-Residential", "Utility"]);
+///
+/// let expected = serde_json::json!({
+///     "type": "pie",
+///     "values": [19, 26, 55],
+///     "labels": ["Residential", "Non-Residential", "Utility"]
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Pie<V>
+where
+    V: Serialize + Clone,
+{
+    r#type: PlotType,
+    values: Option<Vec<V>>,
+    labels: Option<Vec<String>>,
+    label0: Option<f64>,
+    dlabel: Option<f64>,
+    name: Option<String>,
+    visible: Option<Visible>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    opacity: Option<f64>,
+    ids: Option<Vec<String>>,
+    title: Option<Title>,
+    domain: Option<Domain>,
+    marker: Option<Marker>,
+    hole: Option<f64>,
+    pull: Option<Dim<f64>>,
+    sort: Option<bool>,
+    direction: Option<Direction>,
+    rotation: Option<f64>,
+    #[serde(rename = "scalegroup")]
+    scale_group: Option<String>,
+    text: Option<Dim<String>>,
+    #[serde(rename = "textposition")]
+    text_position: Option<Dim<TextPosition>>,
+    #[serde(rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(rename = "insidetextorientation")]
+    inside_text_orientation: Option<InsideTextOrientation>,
+    #[serde(rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    automargin: Option<bool>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<V> Default for Pie<V>
+where
+    V: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Pie,
+            values: None,
+            labels: None,
+            label0: None,
+            dlabel: None,
+            name: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            title: None,
+            domain: None,
+            marker: None,
+            hole: None,
+            pull: None,
+            sort: None,
+            direction: None,
+            rotation: None,
+            scale_group: None,
+            text: None,
+            text_position: None,
+            text_info: None,
+            text_template: None,
+            text_font: None,
+            inside_text_orientation: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            automargin: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            hover_label: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<V> Pie<V>
+where
+    V: Serialize + Clone,
+{
+    pub fn new(values: Vec<V>) -> Box<Self> {
+        Box::new(Pie {
+            values: Some(values),
+            ..Default::default()
+        })
+    }
+
+    /// Determines whether outside text labels can push the margins.
+    pub fn automargin(mut self, automargin: bool) -> Box<Self> {
+        self.automargin = Some(automargin);
+        Box::new(self)
+    }
+
+    /// Specifies the direction at which succeeding sectors follow one another.
+    pub fn direction(mut self, direction: Direction) -> Box<Self> {
+        self.direction = Some(direction);
+        Box::new(self)
+    }
+
+    /// Sets the label step when `labels` are not given. See `label0` for more info.
+    pub fn dlabel(mut self, dlabel: f64) -> Box<Self> {
+        self.dlabel = Some(dlabel);
+        Box::new(self)
+    }
+
+    /// Sets the area of the plot, or the cell of a grid layout, the pie is drawn in.
+    pub fn domain(mut self, domain: Domain) -> Box<Self> {
+        self.domain = Some(domain);
+        Box::new(self)
+    }
+
+    /// Sets the fraction of the radius to cut out of the pie. Use this to make a donut chart.
+    pub fn hole(mut self, hole: f64) -> Box<Self> {
+        self.hole = Some(hole);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Self> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Self> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    /// Controls the orientation of the text inside chart sectors.
+    pub fn inside_text_orientation(
+        mut self,
+        inside_text_orientation: InsideTextOrientation,
+    ) -> Box<Self> {
+        self.inside_text_orientation = Some(inside_text_orientation);
+        Box::new(self)
+    }
+
+    /// Alternate to `labels`. Builds a numeric set of labels. Use with `dlabel` where `label0`
+    /// is the starting label and `dlabel` the step.
+    pub fn label0(mut self, label0: f64) -> Box<Self> {
+        self.label0 = Some(label0);
+        Box::new(self)
+    }
+
+    /// Sets the sector labels. If `labels` entries are duplicated, the associated `values` are
+    /// summed.
+    pub fn labels<S: AsRef<str>>(mut self, labels: Vec<S>) -> Box<Self> {
+        let labels = private::owned_string_vector(labels);
+        self.labels = Some(labels);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    /// Sets the styling of the sectors. Use `Marker::colors` to set the color of each sector.
+    pub fn marker(mut self, marker: Marker) -> Box<Self> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Self> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    /// Sets the fraction of the radius to pull all sectors out from the center.
+    pub fn pull(mut self, pull: f64) -> Box<Self> {
+        self.pull = Some(Dim::Scalar(pull));
+        Box::new(self)
+    }
+
+    /// Sets the fraction of the radius to pull each sector out from the center.
+    pub fn pull_array(mut self, pull: Vec<f64>) -> Box<Self> {
+        self.pull = Some(Dim::Vector(pull));
+        Box::new(self)
+    }
+
+    /// Instead of the first sector starting at 12 o'clock, rotate to some other angle (in
+    /// degrees).
+    pub fn rotation(mut self, rotation: f64) -> Box<Self> {
+        self.rotation = Some(rotation);
+        Box::new(self)
+    }
+
+    /// Pie traces with the same scale group will have their radii scaled by their total value.
+    pub fn scale_group(mut self, scale_group: &str) -> Box<Self> {
+        self.scale_group = Some(scale_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    /// Determines whether or not the sectors are reordered from largest to smallest.
+    pub fn sort(mut self, sort: bool) -> Box<Self> {
+        self.sort = Some(sort);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Self> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    /// Determines which trace information appear on the graph.
+    pub fn text_info(mut self, text_info: TextInfo) -> Box<Self> {
+        self.text_info = Some(text_info);
+        Box::new(self)
+    }
+
+    pub fn text_position(mut self, text_position: TextPosition) -> Box<Self> {
+        self.text_position = Some(Dim::Scalar(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_position_array(mut self, text_position: Vec<TextPosition>) -> Box<Self> {
+        self.text_position = Some(Dim::Vector(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Self> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Self> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn title(mut self, title: Title) -> Box<Self> {
+        self.title = Some(title);
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+}
+
+impl<V> Trace for Pie<V>
+where
+    V: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_text_info() {
+        assert_eq!(to_value(TextInfo::Label).unwrap(), json!("label"));
+        assert_eq!(to_value(TextInfo::Text).unwrap(), json!("text"));
+        assert_eq!(to_value(TextInfo::Value).unwrap(), json!("value"));
+        assert_eq!(to_value(TextInfo::Percent).unwrap(), json!("percent"));
+        assert_eq!(to_value(TextInfo::LabelAndPercent).unwrap(), json!("label+percent"));
+        assert_eq!(to_value(TextInfo::LabelAndValue).unwrap(), json!("label+value"));
+        assert_eq!(to_value(TextInfo::ValueAndPercent).unwrap(), json!("value+percent"));
+        assert_eq!(to_value(TextInfo::LabelValueAndPercent).unwrap(), json!("label+value+percent"));
+        assert_eq!(to_value(TextInfo::None).unwrap(), json!("none"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_inside_text_orientation() {
+        assert_eq!(to_value(InsideTextOrientation::Horizontal).unwrap(), json!("horizontal"));
+        assert_eq!(to_value(InsideTextOrientation::Radial).unwrap(), json!("radial"));
+        assert_eq!(to_value(InsideTextOrientation::Tangential).unwrap(), json!("tangential"));
+        assert_eq!(to_value(InsideTextOrientation::Auto).unwrap(), json!("auto"));
+    }
+
+    #[test]
+    fn test_serialize_direction() {
+        assert_eq!(to_value(Direction::Clockwise).unwrap(), json!("clockwise"));
+        assert_eq!(
+            to_value(Direction::CounterClockwise).unwrap(),
+            json!("counterclockwise")
+        );
+    }
+
+    #[test]
+    fn test_default_pie() {
+        let trace: Pie<i32> = Pie::default();
+        let expected = json!({"type": "pie"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_pie() {
+        let trace = Pie::new(vec![1, 2, 3])
+            .automargin(true)
+            .direction(Direction::CounterClockwise)
+            .dlabel(1.0)
+            .domain(Domain::new().row(0).column(1))
+            .hole(0.4)
+            .hover_info(HoverInfo::All)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .ids(vec!["1", "2", "3"])
+            .inside_text_font(Font::new())
+            .inside_text_orientation(InsideTextOrientation::Radial)
+            .label0(0.0)
+            .labels(vec!["a", "b", "c"])
+            .legend_group("legend_group")
+            .marker(Marker::new().colors(vec!["#FF0000", "#00FF00", "#0000FF"]))
+            .name("pie")
+            .opacity(0.5)
+            .outside_text_font(Font::new())
+            .pull(0.1)
+            .pull_array(vec![0.0, 0.2, 0.0])
+            .rotation(90.0)
+            .scale_group("scale_group")
+            .show_legend(true)
+            .sort(false)
+            .text("text")
+            .text_array(vec!["a", "b", "c"])
+            .text_font(Font::new())
+            .text_info(TextInfo::LabelAndPercent)
+            .text_position(TextPosition::Inside)
+            .text_position_array(vec![TextPosition::Outside])
+            .text_template("text_template")
+            .text_template_array(vec!["text_template"])
+            .title(Title::new("title"))
+            .visible(Visible::True);
+
+        let expected = json!({
+            "type": "pie",
+            "values": [1, 2, 3],
+            "automargin": true,
+            "direction": "counterclockwise",
+            "dlabel": 1.0,
+            "domain": {"row": 0, "column": 1},
+            "hole": 0.4,
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "ids": ["1", "2", "3"],
+            "insidetextfont": {},
+            "insidetextorientation": "radial",
+            "label0": 0.0,
+            "labels": ["a", "b", "c"],
+            "legendgroup": "legend_group",
+            "marker": {"colors": ["#FF0000", "#00FF00", "#0000FF"]},
+            "name": "pie",
+            "opacity": 0.5,
+            "outsidetextfont": {},
+            "pull": [0.0, 0.2, 0.0],
+            "rotation": 90.0,
+            "scalegroup": "scale_group",
+            "showlegend": true,
+            "sort": false,
+            "text": ["a", "b", "c"],
+            "textfont": {},
+            "textinfo": "label+percent",
+            "textposition": ["outside"],
+            "texttemplate": ["text_template"],
+            "title": {"text": "title"},
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -1,14 +1,15 @@
 //! Sankey trace
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     color::{Color, ColorArray},
     common::{Dim, Domain, Font, HoverInfo, Label, LegendGroupTitle, Orientation, PlotType},
+    private::Extra,
     Trace,
 };
 
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum Arrangement {
     Snap,
@@ -18,10 +19,12 @@ pub enum Arrangement {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct Line {
     color: Option<Dim<Box<dyn Color>>>,
     width: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Line {
@@ -46,7 +49,7 @@ impl Line {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 pub struct Node {
     // Missing: customdata, groups
     color: Option<Dim<Box<dyn Color>>>,
@@ -62,6 +65,8 @@ pub struct Node {
     thickness: Option<usize>,
     x: Option<f64>,
     y: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Node {
@@ -126,7 +131,7 @@ impl Node {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Link<V>
 where
     V: Serialize + Clone,
@@ -143,6 +148,8 @@ where
     source: Option<Vec<usize>>,
     target: Option<Vec<usize>>,
     value: Option<Vec<V>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<V> Default for Link<V>
@@ -159,6 +166,7 @@ where
             source: None,
             target: None,
             value: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -270,7 +278,7 @@ where
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Sankey<V>
 where
     V: Serialize + Clone,
@@ -301,6 +309,8 @@ where
     #[serde(rename = "valuesuffix")]
     value_suffix: Option<String>,
     visible: Option<bool>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<V> Default for Sankey<V>
@@ -326,6 +336,7 @@ where
             value_format: None,
             value_suffix: None,
             visible: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -336,7 +347,7 @@ where
 {
     /// Creates a new empty Sankey diagram.
     pub fn new() -> Box<Self> {
-        Box::new(Default::default())
+        Box::default()
     }
 
     /// Sets the trace name. The trace name appears as the legend item and on hover.
@@ -2,7 +2,7 @@
 
 #[cfg(feature = "plotly_ndarray")]
 use ndarray::{Array, Ix1, Ix2};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "plotly_ndarray")]
 use crate::ndarray::ArrayTraces;
@@ -10,12 +10,13 @@ use crate::{
     color::Color,
     common::{
         Calendar, Dim, ErrorData, Fill, Font, HoverInfo, HoverOn, Label, Line, Marker, Mode,
-        Orientation, PlotType, Position, Visible,
+        Orientation, PlotType, Position, Visible, XAxisRef, YAxisRef,
     },
-    private, Trace,
+    private::{self, Extra},
+    Trace,
 };
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum GroupNorm {
     #[serde(rename = "")]
@@ -24,7 +25,7 @@ pub enum GroupNorm {
     Percent,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum StackGaps {
     #[serde(rename = "infer zero")]
@@ -50,7 +51,7 @@ pub enum StackGaps {
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Scatter<X, Y>
 where
     X: Serialize + Clone + 'static,
@@ -93,9 +94,9 @@ where
     custom_data: Option<private::NumOrStringCollection>,
 
     #[serde(rename = "xaxis")]
-    x_axis: Option<String>,
+    x_axis: Option<XAxisRef>,
     #[serde(rename = "yaxis")]
-    y_axis: Option<String>,
+    y_axis: Option<YAxisRef>,
     orientation: Option<Orientation>,
     #[serde(rename = "groupnorm")]
     group_norm: Option<GroupNorm>,
@@ -124,6 +125,8 @@ where
     x_calendar: Option<Calendar>,
     #[serde(rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<X, Y> Default for Scatter<X, Y>
@@ -174,6 +177,7 @@ where
             stack_gaps: None,
             x_calendar: None,
             y_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -206,9 +210,9 @@ where
     /// # Arguments
     /// * `x`             - One dimensional array (or view) that represents the `x` axis coordinates.
     /// * `traces_matrix` - Two dimensional array (or view) containing the `y` axis coordinates of
-    /// the traces.
+    ///   the traces.
     /// * `array_traces`  - Determines whether the traces are arranged in the matrix over the
-    /// columns (`ArrayTraces::OverColumns`) or over the rows (`ArrayTraces::OverRows`).
+    ///   columns (`ArrayTraces::OverColumns`) or over the rows (`ArrayTraces::OverRows`).
     ///
     /// # Examples
     ///
@@ -235,6 +239,7 @@ where
     ///
     ///     let mut plot = Plot::new();
     ///     plot.add_traces(traces);
+    /// #   #[cfg(not(feature = "wasm"))]
     ///     plot.show();
     /// }
     /// fn main() -> std::io::Result<()> {
@@ -499,19 +504,19 @@ where
         Box::new(self)
     }
 
-    /// Sets a reference between this trace's x coordinates and a 2D cartesian x axis. If "x" (
-    /// the default value), the x coordinates refer to `Layout::x_axis`. If "x2", the x coordinates
-    /// refer to `Layout::x_axis2`, and so on.
-    pub fn x_axis(mut self, axis: &str) -> Box<Self> {
-        self.x_axis = Some(axis.to_string());
+    /// Sets a reference between this trace's x coordinates and a 2D cartesian x axis. By default,
+    /// the x coordinates refer to `Layout::x_axis`. With `XAxisRef::new(2)`, they refer to the
+    /// axis set with `Layout::x_axis_n(2, ..)`, and so on.
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
         Box::new(self)
     }
 
-    /// Sets a reference between this trace's y coordinates and a 2D cartesian y axis. If "y"
-    /// (the default value), the y coordinates refer to `Layout::y_axis`. If "y2", the y coordinates
-    /// refer to `Layout::y_axis2`, and so on.
-    pub fn y_axis(mut self, axis: &str) -> Box<Self> {
-        self.y_axis = Some(axis.to_string());
+    /// Sets a reference between this trace's y coordinates and a 2D cartesian y axis. By default,
+    /// the y coordinates refer to `Layout::y_axis`. With `YAxisRef::new(2)`, they refer to the
+    /// axis set with `Layout::y_axis_n(2, ..)`, and so on.
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
         Box::new(self)
     }
 
@@ -737,10 +742,10 @@ mod tests {
             .text_template("text_template")
             .text_template_array(vec!["text_template"])
             .visible(Visible::True)
-            .x_axis("x_axis")
+            .x_axis(XAxisRef::new(2))
             .x_calendar(Calendar::Chinese)
             .x0(0)
-            .y_axis("y_axis")
+            .y_axis(YAxisRef::new(2))
             .y_calendar(Calendar::Coptic)
             .y0(2)
             .web_gl_mode(true);
@@ -781,10 +786,10 @@ mod tests {
             "textposition": ["middle left"],
             "texttemplate": ["text_template"],
             "visible": true,
-            "xaxis": "x_axis",
+            "xaxis": "x2",
             "xcalendar": "chinese",
             "x0": 0,
-            "yaxis": "y_axis",
+            "yaxis": "y2",
             "ycalendar": "coptic",
             "y0": 2
         });
@@ -2,7 +2,7 @@
 
 #[cfg(feature = "plotly_ndarray")]
 use ndarray::{Array, Ix1};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     color::Color,
@@ -10,15 +10,18 @@ use crate::{
         Calendar, Dim, ErrorData, HoverInfo, Label, LegendGroupTitle, Line, Marker, Mode, PlotType,
         Position, Visible,
     },
-    private, Trace,
+    private::{self, Extra},
+    Trace,
 };
 
 #[serde_with::skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ProjectionCoord {
     opacity: Option<f64>,
     scale: Option<f64>,
     show: Option<bool>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ProjectionCoord {
@@ -46,11 +49,13 @@ impl ProjectionCoord {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Debug, Default, Clone, Serialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Projection {
     x: Option<ProjectionCoord>,
     y: Option<ProjectionCoord>,
     z: Option<ProjectionCoord>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Projection {
@@ -74,7 +79,7 @@ impl Projection {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SurfaceAxis {
     #[serde(rename = "-1")]
     MinusOne,
@@ -110,7 +115,7 @@ pub enum SurfaceAxis {
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Scatter3D<X, Y, Z>
 where
     X: Serialize + Clone,
@@ -178,6 +183,8 @@ where
     y_calendar: Option<Calendar>,
     #[serde(rename = "zcalendar")]
     z_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<X, Y, Z> Default for Scatter3D<X, Y, Z>
@@ -226,6 +233,7 @@ where
             x_calendar: None,
             y_calendar: None,
             z_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -0,0 +1,533 @@
+//! Geographic scatter trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    color::Color,
+    common::{
+        Dim, Fill, Font, HoverInfo, Label, Line, LocationMode, Marker, Mode, PlotType, Position,
+        Visible,
+    },
+    private::{self, Extra, NumOrString, NumOrStringCollection},
+    Trace,
+};
+
+/// Construct a geographic scatter trace, drawn on a `LayoutGeo` map.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{common::Mode, ScatterGeo};
+///
+/// let trace = ScatterGeo::new(vec![51.5, 48.9], vec![-0.1, 2.4]).mode(Mode::Markers);
+///
+/// let expected = serde_json::json!({
+///     "type": "scattergeo",
+///     "lat": [51.5, 48.9],
+///     "lon": [-0.1, 2.4],
+///     "mode": "markers"
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScatterGeo<Lat, Lon>
+where
+    Lat: Serialize + Clone + 'static,
+    Lon: Serialize + Clone + 'static,
+{
+    r#type: PlotType,
+    name: Option<String>,
+    visible: Option<Visible>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    opacity: Option<f64>,
+    mode: Option<Mode>,
+    ids: Option<Vec<String>>,
+
+    lat: Option<Vec<Lat>>,
+    lon: Option<Vec<Lon>>,
+    locations: Option<Vec<String>>,
+    #[serde(rename = "locationmode")]
+    location_mode: Option<LocationMode>,
+    #[serde(rename = "geojson")]
+    geo_json: Option<serde_json::Value>,
+    #[serde(rename = "featureidkey")]
+    feature_id_key: Option<String>,
+
+    geo: Option<String>,
+
+    text: Option<Dim<String>>,
+    #[serde(rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    #[serde(rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+
+    meta: Option<NumOrString>,
+    #[serde(rename = "customdata")]
+    custom_data: Option<NumOrStringCollection>,
+
+    #[serde(rename = "selectedpoints")]
+    selected_points: Option<Vec<u32>>,
+    marker: Option<Marker>,
+    line: Option<Line>,
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+    fill: Option<Fill>,
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<Lat, Lon> Default for ScatterGeo<Lat, Lon>
+where
+    Lat: Serialize + Clone + 'static,
+    Lon: Serialize + Clone + 'static,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::ScatterGeo,
+            name: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            mode: None,
+            ids: None,
+            lat: None,
+            lon: None,
+            locations: None,
+            location_mode: None,
+            geo_json: None,
+            feature_id_key: None,
+            geo: None,
+            text: None,
+            text_position: None,
+            text_template: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            meta: None,
+            custom_data: None,
+            selected_points: None,
+            marker: None,
+            line: None,
+            text_font: None,
+            connect_gaps: None,
+            fill: None,
+            fill_color: None,
+            hover_label: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl ScatterGeo<f64, f64> {
+    /// Construct a geographic scatter trace positioned by `locations` rather than by latitude and
+    /// longitude. The meaning of the locations is set with `location_mode`.
+    pub fn from_locations<S: AsRef<str>>(locations: Vec<S>) -> Box<Self> {
+        Box::new(Self {
+            locations: Some(private::owned_string_vector(locations)),
+            ..Default::default()
+        })
+    }
+}
+
+impl<Lat, Lon> ScatterGeo<Lat, Lon>
+where
+    Lat: Serialize + Clone + 'static,
+    Lon: Serialize + Clone + 'static,
+{
+    pub fn new(lat: Vec<Lat>, lon: Vec<Lon>) -> Box<Self> {
+        Box::new(Self {
+            lat: Some(lat),
+            lon: Some(lon),
+            ..Default::default()
+        })
+    }
+
+    /// Sets the trace name. The trace name appear as the legend item and on hover.
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_string());
+        Box::new(self)
+    }
+
+    /// Determines whether or not this trace is visible. If `Visible::LegendOnly`, the trace is not
+    /// drawn, but can appear as a legend item (provided that the legend itself is visible).
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+
+    /// Determines whether or not an item corresponding to this trace is shown in the legend.
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    /// Sets the legend group for this trace. Traces part of the same legend group hide/show at the
+    /// same time when toggling legend items.
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_string());
+        Box::new(self)
+    }
+
+    /// Sets the opacity of the trace.
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    /// Determines the drawing mode for this scatter trace. If the provided `Mode` includes
+    /// "Text" then the `text` elements appear at the coordinates. Otherwise, the `text` elements
+    /// appear on hover.
+    pub fn mode(mut self, mode: Mode) -> Box<Self> {
+        self.mode = Some(mode);
+        Box::new(self)
+    }
+
+    /// Assigns id labels to each datum. These ids for object constancy of data points during
+    /// animation. Should be an array of strings, not numbers or any other type.
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Self> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    /// Sets the coordinates via location IDs or names. Coordinates correspond to the centroid of
+    /// each location given. See `location_mode` for more info.
+    pub fn locations<S: AsRef<str>>(mut self, locations: Vec<S>) -> Box<Self> {
+        let locations = private::owned_string_vector(locations);
+        self.locations = Some(locations);
+        Box::new(self)
+    }
+
+    /// Determines the set of locations used to match entries in `locations` to regions on the map.
+    /// Values `LocationMode::Iso3`, `LocationMode::UsaStates` and `LocationMode::CountryNames`
+    /// correspond to features on the base map, while `LocationMode::GeoJsonId` matches the
+    /// features of the trace's `geo_json`.
+    pub fn location_mode(mut self, location_mode: LocationMode) -> Box<Self> {
+        self.location_mode = Some(location_mode);
+        Box::new(self)
+    }
+
+    /// Sets optional GeoJSON data associated with this trace. Can be given either as a URL to a
+    /// GeoJSON file, or as a GeoJSON object of type "FeatureCollection" or "Feature" with
+    /// geometries of type "Polygon" or "MultiPolygon". Only has an effect when `location_mode` is
+    /// `LocationMode::GeoJsonId`.
+    pub fn geo_json<G: Into<serde_json::Value>>(mut self, geo_json: G) -> Box<Self> {
+        self.geo_json = Some(geo_json.into());
+        Box::new(self)
+    }
+
+    /// Sets the key in GeoJSON features which is used as id to match the items included in the
+    /// `locations` array. Support nested property, for example "properties.name".
+    pub fn feature_id_key(mut self, feature_id_key: &str) -> Box<Self> {
+        self.feature_id_key = Some(feature_id_key.to_string());
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's geospatial coordinates and a geographic map. If "geo"
+    /// (the default value), the geospatial coordinates refer to `layout.geo`. If "geo2", the
+    /// geospatial coordinates refer to `layout.geo2`, and so on.
+    pub fn geo(mut self, geo: &str) -> Box<Self> {
+        self.geo = Some(geo.to_string());
+        Box::new(self)
+    }
+
+    /// Sets text elements associated with each (lat,lon) pair or item in `locations`. If a single
+    /// string, the same string appears over all the data points. If an array of string, the items
+    /// are mapped in order to the this trace's coordinates. If the trace `HoverInfo` contains a
+    /// "text" flag and `hover_text` is not set, these elements will be seen in the hover labels.
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_string()));
+        Box::new(self)
+    }
+
+    /// Sets text elements associated with each (lat,lon) pair or item in `locations`. If a single
+    /// string, the same string appears over all the data points. If an array of string, the items
+    /// are mapped in order to the this trace's coordinates. If the trace `HoverInfo` contains a
+    /// "text" flag and `hover_text` is not set, these elements will be seen in the hover labels.
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    /// Sets the positions of the `text` elements with respects to the (lat,lon) coordinates.
+    pub fn text_position(mut self, text_position: Position) -> Box<Self> {
+        self.text_position = Some(Dim::Scalar(text_position));
+        Box::new(self)
+    }
+
+    /// Sets the positions of the `text` elements with respects to the (lat,lon) coordinates.
+    pub fn text_position_array(mut self, text_position: Vec<Position>) -> Box<Self> {
+        self.text_position = Some(Dim::Vector(text_position));
+        Box::new(self)
+    }
+
+    /// Template string used for rendering the information text that appear on points. Variables
+    /// are inserted using %{variable}, for example "lat: %{lat}". Every attributes that can be
+    /// specified per-point (the ones that are `arrayOk: true`) are available.
+    pub fn text_template(mut self, text_template: &str) -> Box<Self> {
+        self.text_template = Some(Dim::Scalar(text_template.to_string()));
+        Box::new(self)
+    }
+
+    /// Template string used for rendering the information text that appear on points. Variables
+    /// are inserted using %{variable}, for example "lat: %{lat}". Every attributes that can be
+    /// specified per-point (the ones that are `arrayOk: true`) are available.
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Self> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    /// Sets hover text elements associated with each (lat,lon) pair or item in `locations`. If a
+    /// single string, the same string appears over all the data points. If an array of string,
+    /// the items are mapped in order to the this trace's coordinates. To be seen, trace
+    /// `HoverInfo` must contain a "Text" flag.
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_string()));
+        Box::new(self)
+    }
+
+    /// Sets hover text elements associated with each (lat,lon) pair or item in `locations`. If a
+    /// single string, the same string appears over all the data points. If an array of string,
+    /// the items are mapped in order to the this trace's coordinates. To be seen, trace
+    /// `HoverInfo` must contain a "Text" flag.
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    /// Determines which trace information appear on hover. If `HoverInfo::None` or `HoverInfo::Skip`
+    /// are set, no information is displayed upon hovering. But, if `HoverInfo::None` is set, click
+    /// and hover events are still fired.
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    /// Template string used for rendering the information that appear on hover box. Note that this
+    /// will override `HoverInfo`. Variables are inserted using %{variable}, for example
+    /// "lat: %{lat}". Anything contained in tag `<extra>` is displayed in the secondary box, for
+    /// example "<extra>{fullData.name}</extra>". To hide the secondary box completely, use an
+    /// empty tag `<extra></extra>`.
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_string()));
+        Box::new(self)
+    }
+
+    /// Template string used for rendering the information that appear on hover box. Note that this
+    /// will override `HoverInfo`. Variables are inserted using %{variable}, for example
+    /// "lat: %{lat}". Anything contained in tag `<extra>` is displayed in the secondary box, for
+    /// example "<extra>{fullData.name}</extra>". To hide the secondary box completely, use an
+    /// empty tag `<extra></extra>`.
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    /// Assigns extra meta information associated with this trace that can be used in various text
+    /// attributes. To access the trace `meta` values in an attribute in the same trace, simply use
+    /// `%{meta[i]}` where `i` is the index or key of the `meta` item in question.
+    pub fn meta<V: Into<NumOrString>>(mut self, meta: V) -> Box<Self> {
+        self.meta = Some(meta.into());
+        Box::new(self)
+    }
+
+    /// Assigns extra data each datum. This may be useful when listening to hover, click and
+    /// selection events.
+    pub fn custom_data<V: Into<NumOrString> + Clone>(mut self, custom_data: Vec<V>) -> Box<Self> {
+        self.custom_data = Some(custom_data.into());
+        Box::new(self)
+    }
+
+    /// Array containing integer indices of selected points. Has an effect only for traces that
+    /// support selections. Note that an empty array means an empty selection where the
+    /// `unselected` are turned on for all points, whereas, any other non-array values means no
+    /// selection all where the `selected` and `unselected` styles have no effect.
+    pub fn selected_points(mut self, selected_points: Vec<u32>) -> Box<Self> {
+        self.selected_points = Some(selected_points);
+        Box::new(self)
+    }
+
+    /// Determines how points are displayed and joined.
+    pub fn marker(mut self, marker: Marker) -> Box<Self> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    /// Line display properties.
+    pub fn line(mut self, line: Line) -> Box<Self> {
+        self.line = Some(line);
+        Box::new(self)
+    }
+
+    /// Sets the text font.
+    pub fn text_font(mut self, text_font: Font) -> Box<Self> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    /// Determines whether or not gaps (i.e. {nan} or missing values) in the provided data arrays
+    /// are connected.
+    pub fn connect_gaps(mut self, connect_gaps: bool) -> Box<Self> {
+        self.connect_gaps = Some(connect_gaps);
+        Box::new(self)
+    }
+
+    /// Sets the area to fill with a solid color. Only `Fill::None` and `Fill::ToSelf` are
+    /// supported: "toself" connects the endpoints of the trace (or each segment of the trace if it
+    /// has gaps) into a closed shape.
+    pub fn fill(mut self, fill: Fill) -> Box<Self> {
+        self.fill = Some(fill);
+        Box::new(self)
+    }
+
+    /// Sets the fill color. Defaults to a half-transparent variant of the line color, marker color,
+    /// or marker line color, whichever is available.
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Box<Self> {
+        self.fill_color = Some(Box::new(fill_color));
+        Box::new(self)
+    }
+
+    /// Properties of label displayed on mouse hover.
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+}
+
+impl<Lat, Lon> Trace for ScatterGeo<Lat, Lon>
+where
+    Lat: Serialize + Clone + 'static,
+    Lon: Serialize + Clone + 'static,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    fn geo_subplot(&self) -> Option<&str> {
+        Some(self.geo.as_deref().unwrap_or("geo"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_default_scatter_geo() {
+        let trace = ScatterGeo::<f64, f64>::default();
+        let expected = json!({"type": "scattergeo"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_scatter_geo_from_locations() {
+        let trace =
+            ScatterGeo::from_locations(vec!["FRA", "DEU"]).location_mode(LocationMode::Iso3);
+        let expected = json!({
+            "type": "scattergeo",
+            "locations": ["FRA", "DEU"],
+            "locationmode": "ISO-3"
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_scatter_geo() {
+        let trace = ScatterGeo::new(vec![0.0, 1.0], vec![2.0, 3.0])
+            .connect_gaps(false)
+            .custom_data(vec!["custom_data"])
+            .feature_id_key("properties.name")
+            .fill(Fill::ToSelf)
+            .fill_color("#789456")
+            .geo("geo2")
+            .geo_json("https://example.com/regions.json")
+            .hover_info(HoverInfo::Name)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .ids(vec!["1"])
+            .legend_group("legend_group")
+            .line(Line::new())
+            .location_mode(LocationMode::GeoJsonId)
+            .locations(vec!["a", "b"])
+            .marker(Marker::new())
+            .meta("meta")
+            .mode(Mode::LinesMarkers)
+            .name("scatter_geo_trace")
+            .opacity(0.6)
+            .selected_points(vec![0])
+            .show_legend(false)
+            .text("text")
+            .text_array(vec!["text"])
+            .text_font(Font::new())
+            .text_position(Position::MiddleCenter)
+            .text_position_array(vec![Position::MiddleLeft])
+            .text_template("text_template")
+            .text_template_array(vec!["text_template"])
+            .visible(Visible::True);
+
+        let expected = json!({
+            "type": "scattergeo",
+            "lat": [0.0, 1.0],
+            "lon": [2.0, 3.0],
+            "connectgaps": false,
+            "customdata": ["custom_data"],
+            "featureidkey": "properties.name",
+            "fill": "toself",
+            "fillcolor": "#789456",
+            "geo": "geo2",
+            "geojson": "https://example.com/regions.json",
+            "hoverinfo": "name",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "ids": ["1"],
+            "legendgroup": "legend_group",
+            "line": {},
+            "locationmode": "geojson-id",
+            "locations": ["a", "b"],
+            "marker": {},
+            "meta": "meta",
+            "mode": "lines+markers",
+            "name": "scatter_geo_trace",
+            "opacity": 0.6,
+            "selectedpoints": [0],
+            "showlegend": false,
+            "text": ["text"],
+            "textfont": {},
+            "textposition": ["middle left"],
+            "texttemplate": ["text_template"],
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
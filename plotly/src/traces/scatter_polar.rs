@@ -2,7 +2,7 @@
 
 #[cfg(feature = "plotly_ndarray")]
 use ndarray::{Array, Ix1, Ix2};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "plotly_ndarray")]
 use crate::ndarray::ArrayTraces;
@@ -11,7 +11,7 @@ use crate::{
     common::{
         Dim, Fill, Font, HoverInfo, HoverOn, Label, Line, Marker, Mode, PlotType, Position, Visible,
     },
-    private::{self, NumOrString, NumOrStringCollection},
+    private::{self, Extra, NumOrString, NumOrStringCollection},
     Trace,
 };
 
@@ -33,7 +33,7 @@ use crate::{
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ScatterPolar<Theta, R>
 where
     Theta: Serialize + Clone + 'static,
@@ -92,6 +92,8 @@ where
     hover_label: Option<Label>,
     #[serde(rename = "hoveron")]
     hover_on: Option<HoverOn>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<Theta, R> Default for ScatterPolar<Theta, R>
@@ -134,6 +136,7 @@ where
             fill_color: None,
             hover_label: None,
             hover_on: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -166,9 +169,9 @@ where
     /// # Arguments
     /// * `x`             - One dimensional array (or view) that represents the `x` axis coordinates.
     /// * `traces_matrix` - Two dimensional array (or view) containing the `y` axis coordinates of
-    /// the traces.
+    ///   the traces.
     /// * `array_traces`  - Determines whether the traces are arranged in the matrix over the
-    /// columns (`ArrayTraces::OverColumns`) or over the rows (`ArrayTraces::OverRows`).
+    ///   columns (`ArrayTraces::OverColumns`) or over the rows (`ArrayTraces::OverRows`).
     ///
     /// # Examples
     ///
@@ -195,6 +198,7 @@ where
     ///
     ///     let mut plot = Plot::new();
     ///     plot.add_traces(traces);
+    /// #   #[cfg(not(feature = "wasm"))]
     ///     plot.show();
     /// }
     /// fn main() -> std::io::Result<()> {
@@ -0,0 +1,455 @@
+//! Streamtube trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible},
+    private::{self, Extra},
+    surface::{Lighting, Position},
+    Trace,
+};
+
+/// The positions from which the streamtubes start.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Starts {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    z: Vec<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Starts {
+    pub fn new(x: Vec<f64>, y: Vec<f64>, z: Vec<f64>) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            extra: Extra::new(),
+        }
+    }
+}
+
+/// Construct a streamtube trace, drawing tubes along the flow of the vector field given by `u`, `v`
+/// and `w` at the positions `x`, `y` and `z`. The tubes start from the points given by `starts`,
+/// and their diameter shows the local divergence of the field.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{streamtube::Starts, Streamtube};
+///
+/// let trace = Streamtube::new(
+///     vec![1, 2],
+///     vec![1, 2],
+///     vec![1, 2],
+///     vec![1.0, 0.0],
+///     vec![0.0, 1.0],
+///     vec![0.0, 0.0],
+/// )
+/// .starts(Starts::new(vec![1.0], vec![1.0], vec![1.0]));
+///
+/// let expected = serde_json::json!({
+///     "type": "streamtube",
+///     "x": [1, 2],
+///     "y": [1, 2],
+///     "z": [1, 2],
+///     "u": [1.0, 0.0],
+///     "v": [0.0, 1.0],
+///     "w": [0.0, 0.0],
+///     "starts": {"x": [1.0], "y": [1.0], "z": [1.0]}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Streamtube<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    r#type: PlotType,
+    x: Option<Vec<X>>,
+    y: Option<Vec<Y>>,
+    z: Option<Vec<Z>>,
+    u: Option<Vec<f64>>,
+    v: Option<Vec<f64>>,
+    w: Option<Vec<f64>>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    cauto: Option<bool>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    cmin: Option<f64>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<Position>,
+    lighting: Option<Lighting>,
+    #[serde(rename = "maxdisplayed")]
+    max_displayed: Option<usize>,
+    name: Option<String>,
+    opacity: Option<f64>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    scene: Option<String>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(rename = "sizeref")]
+    size_ref: Option<f64>,
+    starts: Option<Starts>,
+    text: Option<Dim<String>>,
+    visible: Option<Visible>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<X, Y, Z> Default for Streamtube<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Streamtube,
+            x: None,
+            y: None,
+            z: None,
+            u: None,
+            v: None,
+            w: None,
+            auto_color_scale: None,
+            cauto: None,
+            cmax: None,
+            cmid: None,
+            cmin: None,
+            color_bar: None,
+            color_scale: None,
+            hover_info: None,
+            hover_label: None,
+            hover_template: None,
+            hover_text: None,
+            legend_group: None,
+            light_position: None,
+            lighting: None,
+            max_displayed: None,
+            name: None,
+            opacity: None,
+            reverse_scale: None,
+            scene: None,
+            show_legend: None,
+            show_scale: None,
+            size_ref: None,
+            starts: None,
+            text: None,
+            visible: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<X, Y, Z> Streamtube<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    pub fn new(
+        x: Vec<X>,
+        y: Vec<Y>,
+        z: Vec<Z>,
+        u: Vec<f64>,
+        v: Vec<f64>,
+        w: Vec<f64>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            u: Some(u),
+            v: Some(v),
+            w: Some(w),
+            ..Default::default()
+        })
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Self> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    pub fn cauto(mut self, cauto: bool) -> Box<Self> {
+        self.cauto = Some(cauto);
+        Box::new(self)
+    }
+
+    pub fn cmax(mut self, cmax: f64) -> Box<Self> {
+        self.cmax = Some(cmax);
+        Box::new(self)
+    }
+
+    pub fn cmid(mut self, cmid: f64) -> Box<Self> {
+        self.cmid = Some(cmid);
+        Box::new(self)
+    }
+
+    pub fn cmin(mut self, cmin: f64) -> Box<Self> {
+        self.cmin = Some(cmin);
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Self> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    /// Sets the color scale used to color the tubes by the norm of the vector field.
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Self> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn lighting(mut self, lighting: Lighting) -> Box<Self> {
+        self.lighting = Some(lighting);
+        Box::new(self)
+    }
+
+    pub fn light_position(mut self, light_position: Position) -> Box<Self> {
+        self.light_position = Some(light_position);
+        Box::new(self)
+    }
+
+    /// Sets the maximum number of displayed segments in a streamtube.
+    pub fn max_displayed(mut self, max_displayed: usize) -> Box<Self> {
+        self.max_displayed = Some(max_displayed);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Self> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's 3D coordinate system and a 3D scene. If "scene" (the
+    /// default value), the (x,y,z) coordinates refer to `layout.scene`. If "scene2", the (x, y, z)
+    /// coordinates refer to `layout.scene2`, and so on.
+    pub fn scene(mut self, scene: &str) -> Box<Self> {
+        self.scene = Some(scene.to_owned());
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Self> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    /// Scales the diameter of the tubes.
+    pub fn size_ref(mut self, size_ref: f64) -> Box<Self> {
+        self.size_ref = Some(size_ref);
+        Box::new(self)
+    }
+
+    /// Sets the starting positions of the tubes. By default, they start from the points on the
+    /// faces of the domain at which the flow enters it.
+    pub fn starts(mut self, starts: Starts) -> Box<Self> {
+        self.starts = Some(starts);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+}
+
+impl<X, Y, Z> Trace for Streamtube<X, Y, Z>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_starts() {
+        let starts = Starts::new(vec![0.0, 1.0], vec![2.0, 3.0], vec![4.0, 5.0]);
+        let expected = json!({"x": [0.0, 1.0], "y": [2.0, 3.0], "z": [4.0, 5.0]});
+
+        assert_eq!(to_value(starts).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_default_streamtube() {
+        let trace: Streamtube<f64, f64, f64> = Streamtube::default();
+        let expected = json!({"type": "streamtube"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_streamtube() {
+        let trace = Streamtube::new(
+            vec![0.0],
+            vec![1.0],
+            vec![2.0],
+            vec![3.0],
+            vec![4.0],
+            vec![5.0],
+        )
+        .auto_color_scale(false)
+        .cauto(true)
+        .cmax(5.0)
+        .cmid(2.5)
+        .cmin(0.0)
+        .color_bar(ColorBar::new())
+        .color_scale(ColorScale::Palette(ColorScalePalette::Blues))
+        .hover_info(HoverInfo::All)
+        .hover_label(Label::new())
+        .hover_template("hover_template")
+        .hover_template_array(vec!["hover_template"])
+        .hover_text("hover_text")
+        .hover_text_array(vec!["hover_text"])
+        .legend_group("legend_group")
+        .lighting(Lighting::new())
+        .light_position(Position::new(1, 2, 3))
+        .max_displayed(1000)
+        .name("streamtube")
+        .opacity(0.5)
+        .reverse_scale(true)
+        .scene("scene2")
+        .show_legend(true)
+        .show_scale(false)
+        .size_ref(2.0)
+        .starts(Starts::new(vec![0.0], vec![1.0], vec![2.0]))
+        .text("text")
+        .text_array(vec!["text"])
+        .visible(Visible::True);
+
+        let expected = json!({
+            "type": "streamtube",
+            "x": [0.0],
+            "y": [1.0],
+            "z": [2.0],
+            "u": [3.0],
+            "v": [4.0],
+            "w": [5.0],
+            "autocolorscale": false,
+            "cauto": true,
+            "cmax": 5.0,
+            "cmid": 2.5,
+            "cmin": 0.0,
+            "colorbar": {},
+            "colorscale": "Blues",
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "legendgroup": "legend_group",
+            "lighting": {},
+            "lightposition": {"x": 1, "y": 2, "z": 3},
+            "maxdisplayed": 1000,
+            "name": "streamtube",
+            "opacity": 0.5,
+            "reversescale": true,
+            "scene": "scene2",
+            "showlegend": true,
+            "showscale": false,
+            "sizeref": 2.0,
+            "starts": {"x": [0.0], "y": [1.0], "z": [2.0]},
+            "text": ["text"],
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
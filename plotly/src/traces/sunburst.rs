@@ -0,0 +1,420 @@
+//! Sunburst trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{Dim, Domain, Font, HoverInfo, Label, Marker, PlotType, Visible},
+    hierarchy::{BranchValues, Count, Hierarchy, Leaf, Root, TextInfo},
+    pie::InsideTextOrientation,
+    private::{self, Extra},
+    Trace,
+};
+
+/// Construct a sunburst trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::Sunburst;
+///
+/// let trace = Sunburst::new(vec!["Eve", "Cain", "Seth"], vec!["", "Eve", "Eve"], vec![10, 14, 12]);
+///
+/// let expected = serde_json::json!({
+///     "type": "sunburst",
+///     "labels": ["Eve", "Cain", "Seth"],
+///     "parents": ["", "Eve", "Eve"],
+///     "values": [10, 14, 12]
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Sunburst<V>
+where
+    V: Serialize + Clone,
+{
+    r#type: PlotType,
+    ids: Option<Vec<String>>,
+    labels: Option<Vec<String>>,
+    parents: Option<Vec<String>>,
+    values: Option<Vec<V>>,
+    name: Option<String>,
+    visible: Option<Visible>,
+    opacity: Option<f64>,
+    #[serde(rename = "branchvalues")]
+    branch_values: Option<BranchValues>,
+    count: Option<Count>,
+    level: Option<String>,
+    #[serde(rename = "maxdepth")]
+    max_depth: Option<i32>,
+    domain: Option<Domain>,
+    marker: Option<Marker>,
+    leaf: Option<Leaf>,
+    root: Option<Root>,
+    rotation: Option<f64>,
+    sort: Option<bool>,
+    text: Option<Dim<String>>,
+    #[serde(rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(rename = "insidetextorientation")]
+    inside_text_orientation: Option<InsideTextOrientation>,
+    #[serde(rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<V> Default for Sunburst<V>
+where
+    V: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Sunburst,
+            ids: None,
+            labels: None,
+            parents: None,
+            values: None,
+            name: None,
+            visible: None,
+            opacity: None,
+            branch_values: None,
+            count: None,
+            level: None,
+            max_depth: None,
+            domain: None,
+            marker: None,
+            leaf: None,
+            root: None,
+            rotation: None,
+            sort: None,
+            text: None,
+            text_info: None,
+            text_template: None,
+            text_font: None,
+            inside_text_orientation: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            hover_label: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<V> Sunburst<V>
+where
+    V: Serialize + Clone,
+{
+    /// Each sector is given by its label, the label (or id) of its parent and its value. Root
+    /// sectors have an empty parent.
+    pub fn new<S: AsRef<str>>(labels: Vec<S>, parents: Vec<S>, values: Vec<V>) -> Box<Self> {
+        Box::new(Sunburst {
+            labels: Some(private::owned_string_vector(labels)),
+            parents: Some(private::owned_string_vector(parents)),
+            values: Some(values),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_hierarchy(hierarchy: Hierarchy<V>) -> Box<Self> {
+        Box::new(Sunburst {
+            ids: Some(hierarchy.ids),
+            labels: Some(hierarchy.labels),
+            parents: Some(hierarchy.parents),
+            values: Some(hierarchy.values),
+            branch_values: hierarchy.branch_values,
+            ..Default::default()
+        })
+    }
+
+    /// Determines how the items in `values` are summed. With `Total`, the value of each branch
+    /// is the sum of its children, plus its own, whereas with `Remainder` it is taken to be the
+    /// amount in addition to the sum of its children.
+    pub fn branch_values(mut self, branch_values: BranchValues) -> Box<Self> {
+        self.branch_values = Some(branch_values);
+        Box::new(self)
+    }
+
+    /// Determines which items are counted when `values` is not given.
+    pub fn count(mut self, count: Count) -> Box<Self> {
+        self.count = Some(count);
+        Box::new(self)
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Box<Self> {
+        self.domain = Some(domain);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    /// Assigns a unique id to each sector. Needed when labels are not unique, in which case
+    /// `parents` must refer to these ids.
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Self> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Self> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_orientation(
+        mut self,
+        inside_text_orientation: InsideTextOrientation,
+    ) -> Box<Self> {
+        self.inside_text_orientation = Some(inside_text_orientation);
+        Box::new(self)
+    }
+
+    pub fn leaf(mut self, leaf: Leaf) -> Box<Self> {
+        self.leaf = Some(leaf);
+        Box::new(self)
+    }
+
+    /// Sets the level from which this trace hierarchy is rendered, given as the id of a sector.
+    pub fn level(mut self, level: &str) -> Box<Self> {
+        self.level = Some(level.to_owned());
+        Box::new(self)
+    }
+
+    /// Sets the styling of the sectors. Use `Marker::colors` to set the color of each sector.
+    pub fn marker(mut self, marker: Marker) -> Box<Self> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    /// Sets the number of rendered sectors from any given `level`. Set to `-1` to render all
+    /// the levels in the hierarchy.
+    pub fn max_depth(mut self, max_depth: i32) -> Box<Self> {
+        self.max_depth = Some(max_depth);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Self> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    pub fn root(mut self, root: Root) -> Box<Self> {
+        self.root = Some(root);
+        Box::new(self)
+    }
+
+    /// Rotates the whole diagram counterclockwise by some angle (in degrees).
+    pub fn rotation(mut self, rotation: f64) -> Box<Self> {
+        self.rotation = Some(rotation);
+        Box::new(self)
+    }
+
+    /// Determines whether or not the sectors are reordered from largest to smallest.
+    pub fn sort(mut self, sort: bool) -> Box<Self> {
+        self.sort = Some(sort);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Self> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn text_info(mut self, text_info: TextInfo) -> Box<Self> {
+        self.text_info = Some(text_info);
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Self> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Self> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+}
+
+impl<V> Trace for Sunburst<V>
+where
+    V: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::hierarchy::TreeNode;
+
+    #[test]
+    fn test_default_sunburst() {
+        let trace: Sunburst<i32> = Sunburst::default();
+        let expected = json!({"type": "sunburst"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_sunburst_from_hierarchy() {
+        let tree = TreeNode::new("a", 3).child(TreeNode::new("b", 2));
+        let trace = Sunburst::from_hierarchy(Hierarchy::from_tree(vec![tree]));
+        let expected = json!({
+            "type": "sunburst",
+            "ids": ["a", "a/b"],
+            "labels": ["a", "b"],
+            "parents": ["", "a"],
+            "values": [3, 2]
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_sunburst() {
+        let trace = Sunburst::new(vec!["a", "b", "c"], vec!["", "a", "a"], vec![5, 3, 2])
+            .branch_values(BranchValues::Total)
+            .count(Count::Leaves)
+            .domain(Domain::new())
+            .hover_info(HoverInfo::All)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .ids(vec!["1", "2", "3"])
+            .inside_text_font(Font::new())
+            .inside_text_orientation(InsideTextOrientation::Tangential)
+            .leaf(Leaf::new())
+            .level("1")
+            .marker(Marker::new())
+            .max_depth(2)
+            .name("sunburst")
+            .opacity(0.5)
+            .outside_text_font(Font::new())
+            .root(Root::new())
+            .rotation(45.0)
+            .sort(false)
+            .text("text")
+            .text_array(vec!["a", "b", "c"])
+            .text_font(Font::new())
+            .text_info(TextInfo::LabelAndPercentParent)
+            .text_template("text_template")
+            .text_template_array(vec!["text_template"])
+            .visible(Visible::True);
+
+        let expected = json!({
+            "type": "sunburst",
+            "labels": ["a", "b", "c"],
+            "parents": ["", "a", "a"],
+            "values": [5, 3, 2],
+            "branchvalues": "total",
+            "count": "leaves",
+            "domain": {},
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "ids": ["1", "2", "3"],
+            "insidetextfont": {},
+            "insidetextorientation": "tangential",
+            "leaf": {},
+            "level": "1",
+            "marker": {},
+            "maxdepth": 2,
+            "name": "sunburst",
+            "opacity": 0.5,
+            "outsidetextfont": {},
+            "root": {},
+            "rotation": 45.0,
+            "sort": false,
+            "text": ["a", "b", "c"],
+            "textfont": {},
+            "textinfo": "label+percent parent",
+            "texttemplate": ["text_template"],
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -1,21 +1,24 @@
 //! Surface trace
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     color::{Color, ColorArray},
     common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible},
-    private, Trace,
+    private::{self, Extra},
+    Trace,
 };
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct Lighting {
     ambient: Option<f64>,
     diffuse: Option<f64>,
     fresnel: Option<f64>,
     roughness: Option<f64>,
     specular: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Lighting {
@@ -49,25 +52,34 @@ impl Lighting {
     }
 }
 
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Position {
     x: i32,
     y: i32,
     z: i32,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Position {
     pub fn new(x: i32, y: i32, z: i32) -> Self {
-        Self { x, y, z }
+        Self {
+            x,
+            y,
+            z,
+            extra: Extra::new(),
+        }
     }
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct PlaneProject {
     x: Option<bool>,
     y: Option<bool>,
     z: Option<bool>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl PlaneProject {
@@ -92,7 +104,7 @@ impl PlaneProject {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct PlaneContours {
     color: Option<Box<dyn Color>>,
     end: Option<f64>,
@@ -108,6 +120,8 @@ pub struct PlaneContours {
     #[serde(rename = "usecolormap")]
     use_colormap: Option<bool>,
     width: Option<usize>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl PlaneContours {
@@ -172,11 +186,13 @@ impl PlaneContours {
 }
 
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
 pub struct SurfaceContours {
     x: Option<PlaneContours>,
     y: Option<PlaneContours>,
     z: Option<PlaneContours>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl SurfaceContours {
@@ -218,7 +234,7 @@ impl SurfaceContours {
 /// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
 /// ```
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Surface<X, Y, Z>
 where
     X: Serialize + Clone,
@@ -261,6 +277,7 @@ where
     opacity: Option<f64>,
     #[serde(rename = "reversescale")]
     reverse_scale: Option<bool>,
+    scene: Option<String>,
     #[serde(rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(rename = "showscale")]
@@ -275,6 +292,8 @@ where
     y_calendar: Option<Calendar>,
     #[serde(rename = "zcalendar")]
     z_calendar: Option<Calendar>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl<X, Y, Z> Default for Surface<X, Y, Z>
@@ -309,6 +328,7 @@ where
             name: None,
             opacity: None,
             reverse_scale: None,
+            scene: None,
             show_legend: None,
             show_scale: None,
             surface_color: None,
@@ -317,6 +337,7 @@ where
             x_calendar: None,
             y_calendar: None,
             z_calendar: None,
+            extra: Extra::new(),
         }
     }
 }
@@ -437,6 +458,14 @@ where
         Box::new(self)
     }
 
+    /// Sets a reference between this trace's 3D coordinate system and a 3D scene. If "scene" (the
+    /// default value), the (x,y,z) coordinates refer to `layout.scene`. If "scene2", the (x, y, z)
+    /// coordinates refer to `layout.scene2`, and so on.
+    pub fn scene(mut self, scene: &str) -> Box<Self> {
+        self.scene = Some(scene.to_owned());
+        Box::new(self)
+    }
+
     pub fn cauto(mut self, cauto: bool) -> Box<Self> {
         self.cauto = Some(cauto);
         Box::new(self)
@@ -648,6 +677,7 @@ mod tests {
             .name("surface_trace")
             .opacity(0.5)
             .reverse_scale(true)
+            .scene("scene2")
             .surface_color(vec!["#123456"])
             .show_legend(true)
             .show_scale(false)
@@ -683,6 +713,7 @@ mod tests {
             "name": "surface_trace",
             "opacity": 0.5,
             "reversescale": true,
+            "scene": "scene2",
             "surfacecolor": ["#123456"],
             "showlegend": true,
             "showscale": false,
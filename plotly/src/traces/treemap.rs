@@ -0,0 +1,430 @@
+//! Treemap trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{Dim, Domain, Font, HoverInfo, Label, Marker, PlotType, Position, Visible},
+    hierarchy::{BranchValues, Count, Hierarchy, Leaf, PathBar, Root, TextInfo, Tiling},
+    private::{self, Extra},
+    Trace,
+};
+
+/// Construct a treemap trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{hierarchy::{Packing, Tiling}, Treemap};
+///
+/// let trace = Treemap::new(vec!["Costs", "Rent", "Salaries"], vec!["", "Costs", "Costs"], vec![0, 3, 7])
+///     .tiling(Tiling::new().packing(Packing::Binary));
+///
+/// let expected = serde_json::json!({
+///     "type": "treemap",
+///     "labels": ["Costs", "Rent", "Salaries"],
+///     "parents": ["", "Costs", "Costs"],
+///     "values": [0, 3, 7],
+///     "tiling": {"packing": "binary"}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Treemap<V>
+where
+    V: Serialize + Clone,
+{
+    r#type: PlotType,
+    ids: Option<Vec<String>>,
+    labels: Option<Vec<String>>,
+    parents: Option<Vec<String>>,
+    values: Option<Vec<V>>,
+    name: Option<String>,
+    visible: Option<Visible>,
+    opacity: Option<f64>,
+    #[serde(rename = "branchvalues")]
+    branch_values: Option<BranchValues>,
+    count: Option<Count>,
+    level: Option<String>,
+    #[serde(rename = "maxdepth")]
+    max_depth: Option<i32>,
+    domain: Option<Domain>,
+    marker: Option<Marker>,
+    leaf: Option<Leaf>,
+    root: Option<Root>,
+    tiling: Option<Tiling>,
+    #[serde(rename = "pathbar")]
+    path_bar: Option<PathBar>,
+    sort: Option<bool>,
+    text: Option<Dim<String>>,
+    #[serde(rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(rename = "textposition")]
+    text_position: Option<Position>,
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<V> Default for Treemap<V>
+where
+    V: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Treemap,
+            ids: None,
+            labels: None,
+            parents: None,
+            values: None,
+            name: None,
+            visible: None,
+            opacity: None,
+            branch_values: None,
+            count: None,
+            level: None,
+            max_depth: None,
+            domain: None,
+            marker: None,
+            leaf: None,
+            root: None,
+            tiling: None,
+            path_bar: None,
+            sort: None,
+            text: None,
+            text_info: None,
+            text_template: None,
+            text_position: None,
+            text_font: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            hover_label: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<V> Treemap<V>
+where
+    V: Serialize + Clone,
+{
+    /// Each sector is given by its label, the label (or id) of its parent and its value. Root
+    /// sectors have an empty parent.
+    pub fn new<S: AsRef<str>>(labels: Vec<S>, parents: Vec<S>, values: Vec<V>) -> Box<Self> {
+        Box::new(Treemap {
+            labels: Some(private::owned_string_vector(labels)),
+            parents: Some(private::owned_string_vector(parents)),
+            values: Some(values),
+            ..Default::default()
+        })
+    }
+
+    pub fn from_hierarchy(hierarchy: Hierarchy<V>) -> Box<Self> {
+        Box::new(Treemap {
+            ids: Some(hierarchy.ids),
+            labels: Some(hierarchy.labels),
+            parents: Some(hierarchy.parents),
+            values: Some(hierarchy.values),
+            branch_values: hierarchy.branch_values,
+            ..Default::default()
+        })
+    }
+
+    /// Determines how the items in `values` are summed. With `Total`, the value of each branch
+    /// is the sum of its children, plus its own, whereas with `Remainder` it is taken to be the
+    /// amount in addition to the sum of its children.
+    pub fn branch_values(mut self, branch_values: BranchValues) -> Box<Self> {
+        self.branch_values = Some(branch_values);
+        Box::new(self)
+    }
+
+    /// Determines which items are counted when `values` is not given.
+    pub fn count(mut self, count: Count) -> Box<Self> {
+        self.count = Some(count);
+        Box::new(self)
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Box<Self> {
+        self.domain = Some(domain);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    /// Assigns a unique id to each sector. Needed when labels are not unique, in which case
+    /// `parents` must refer to these ids.
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Self> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Self> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    pub fn leaf(mut self, leaf: Leaf) -> Box<Self> {
+        self.leaf = Some(leaf);
+        Box::new(self)
+    }
+
+    /// Sets the level from which this trace hierarchy is rendered, given as the id of a sector.
+    pub fn level(mut self, level: &str) -> Box<Self> {
+        self.level = Some(level.to_owned());
+        Box::new(self)
+    }
+
+    /// Sets the styling of the sectors. Use `Marker::colors` to set the color of each sector.
+    pub fn marker(mut self, marker: Marker) -> Box<Self> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    /// Sets the number of rendered sectors from any given `level`. Set to `-1` to render all
+    /// the levels in the hierarchy.
+    pub fn max_depth(mut self, max_depth: i32) -> Box<Self> {
+        self.max_depth = Some(max_depth);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Self> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    /// Sets the styling of the bar showing the path to the currently viewed sector.
+    pub fn path_bar(mut self, path_bar: PathBar) -> Box<Self> {
+        self.path_bar = Some(path_bar);
+        Box::new(self)
+    }
+
+    pub fn root(mut self, root: Root) -> Box<Self> {
+        self.root = Some(root);
+        Box::new(self)
+    }
+
+    /// Determines whether or not the sectors are reordered from largest to smallest.
+    pub fn sort(mut self, sort: bool) -> Box<Self> {
+        self.sort = Some(sort);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Self> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn text_info(mut self, text_info: TextInfo) -> Box<Self> {
+        self.text_info = Some(text_info);
+        Box::new(self)
+    }
+
+    /// Sets the position of the text within each sector.
+    pub fn text_position(mut self, text_position: Position) -> Box<Self> {
+        self.text_position = Some(text_position);
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Self> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Self> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    /// Controls how the sectors are laid out.
+    pub fn tiling(mut self, tiling: Tiling) -> Box<Self> {
+        self.tiling = Some(tiling);
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+}
+
+impl<V> Trace for Treemap<V>
+where
+    V: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use crate::hierarchy::TreeNode;
+
+    #[test]
+    fn test_default_treemap() {
+        let trace: Treemap<i32> = Treemap::default();
+        let expected = json!({"type": "treemap"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_treemap_from_hierarchy() {
+        let tree = TreeNode::new("a", 3).child(TreeNode::new("b", 2));
+        let trace = Treemap::from_hierarchy(Hierarchy::from_tree(vec![tree]));
+        let expected = json!({
+            "type": "treemap",
+            "ids": ["a", "a/b"],
+            "labels": ["a", "b"],
+            "parents": ["", "a"],
+            "values": [3, 2]
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_treemap() {
+        let trace = Treemap::new(vec!["a", "b", "c"], vec!["", "a", "a"], vec![5, 3, 2])
+            .branch_values(BranchValues::Total)
+            .count(Count::Leaves)
+            .domain(Domain::new())
+            .hover_info(HoverInfo::All)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .ids(vec!["1", "2", "3"])
+            .inside_text_font(Font::new())
+            .leaf(Leaf::new())
+            .level("1")
+            .marker(Marker::new())
+            .max_depth(2)
+            .name("treemap")
+            .opacity(0.5)
+            .outside_text_font(Font::new())
+            .path_bar(PathBar::new())
+            .root(Root::new())
+            .sort(false)
+            .text("text")
+            .text_array(vec!["a", "b", "c"])
+            .text_font(Font::new())
+            .text_info(TextInfo::LabelAndPercentParent)
+            .text_position(Position::TopLeft)
+            .text_template("text_template")
+            .text_template_array(vec!["text_template"])
+            .tiling(Tiling::new())
+            .visible(Visible::True);
+
+        let expected = json!({
+            "type": "treemap",
+            "labels": ["a", "b", "c"],
+            "parents": ["", "a", "a"],
+            "values": [5, 3, 2],
+            "branchvalues": "total",
+            "count": "leaves",
+            "domain": {},
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "ids": ["1", "2", "3"],
+            "insidetextfont": {},
+            "leaf": {},
+            "level": "1",
+            "marker": {},
+            "maxdepth": 2,
+            "name": "treemap",
+            "opacity": 0.5,
+            "outsidetextfont": {},
+            "pathbar": {},
+            "root": {},
+            "sort": false,
+            "text": ["a", "b", "c"],
+            "textfont": {},
+            "textinfo": "label+percent parent",
+            "textposition": "top left",
+            "texttemplate": ["text_template"],
+            "tiling": {},
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,684 @@
+//! Violin trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    box_plot::{BoxPoints, QuartileMethod},
+    color::Color,
+    common::{
+        Dim, HoverInfo, Label, Line, Marker, Orientation, PlotType, Visible, XAxisRef, YAxisRef,
+    },
+    private::{self, Extra},
+    Trace,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ScaleMode {
+    Width,
+    Count,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum SpanMode {
+    Soft,
+    Hard,
+    Manual,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Both,
+    Positive,
+    Negative,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum HoverOn {
+    Violins,
+    Points,
+    Kde,
+    #[serde(rename = "violins+points")]
+    ViolinsAndPoints,
+    #[serde(rename = "violins+kde")]
+    ViolinsAndKde,
+    #[serde(rename = "points+kde")]
+    PointsAndKde,
+    All,
+}
+
+/// Styling of the box plot drawn inside the violins.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ViolinBox {
+    visible: Option<bool>,
+    width: Option<f64>,
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    line: Option<Line>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl ViolinBox {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines if a mini box plot is drawn inside the violins.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Sets the width of the inner box plots relative to the violins' width.
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Self {
+        self.fill_color = Some(Box::new(fill_color));
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+/// Styling of the line marking the sample mean.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct MeanLine {
+    visible: Option<bool>,
+    color: Option<Box<dyn Color>>,
+    width: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl MeanLine {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Determines if a line corresponding to the sample's mean is shown inside the violins. If the
+    /// inner box plot is visible, the mean line is drawn inside the box as well.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Self {
+        self.color = Some(Box::new(color));
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+/// Construct a violin trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{Violin, violin::{MeanLine, ViolinBox}};
+///
+/// let trace = Violin::new(vec![0, 1, 2, 3, 4, 5])
+///     .box_(ViolinBox::new().visible(true))
+///     .mean_line(MeanLine::new().visible(true));
+///
+/// let expected = serde_json::json!({
+///     "type": "violin",
+///     "y": [0, 1, 2, 3, 4, 5],
+///     "box": {"visible": true},
+///     "meanline": {"visible": true}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Violin<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    r#type: PlotType,
+    x: Option<Vec<X>>,
+    y: Option<Vec<Y>>,
+    x0: Option<X>,
+    y0: Option<Y>,
+    name: Option<String>,
+    visible: Option<Visible>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    opacity: Option<f64>,
+    ids: Option<Vec<String>>,
+    width: Option<f64>,
+    text: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "xaxis")]
+    x_axis: Option<XAxisRef>,
+    #[serde(rename = "yaxis")]
+    y_axis: Option<YAxisRef>,
+    orientation: Option<Orientation>,
+    #[serde(rename = "alignmentgroup")]
+    alignment_group: Option<String>,
+    #[serde(rename = "offsetgroup")]
+    offset_group: Option<String>,
+    marker: Option<Marker>,
+    line: Option<Line>,
+    #[serde(rename = "box")]
+    box_: Option<ViolinBox>,
+    #[serde(rename = "meanline")]
+    mean_line: Option<MeanLine>,
+    points: Option<BoxPoints>,
+    bandwidth: Option<f64>,
+    #[serde(rename = "scalegroup")]
+    scale_group: Option<String>,
+    #[serde(rename = "scalemode")]
+    scale_mode: Option<ScaleMode>,
+    #[serde(rename = "spanmode")]
+    span_mode: Option<SpanMode>,
+    span: Option<Vec<f64>>,
+    side: Option<Side>,
+    #[serde(rename = "quartilemethod")]
+    quartile_method: Option<QuartileMethod>,
+    #[serde(rename = "fillcolor")]
+    fill_color: Option<Box<dyn Color>>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hoveron")]
+    hover_on: Option<HoverOn>,
+    #[serde(rename = "pointpos")]
+    point_pos: Option<f64>,
+    jitter: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<X, Y> Default for Violin<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Violin,
+            x: None,
+            y: None,
+            x0: None,
+            y0: None,
+            name: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            width: None,
+            text: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            x_axis: None,
+            y_axis: None,
+            orientation: None,
+            alignment_group: None,
+            offset_group: None,
+            marker: None,
+            line: None,
+            box_: None,
+            mean_line: None,
+            points: None,
+            bandwidth: None,
+            scale_group: None,
+            scale_mode: None,
+            span_mode: None,
+            span: None,
+            side: None,
+            quartile_method: None,
+            fill_color: None,
+            hover_label: None,
+            hover_on: None,
+            point_pos: None,
+            jitter: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<Y> Violin<f64, Y>
+where
+    Y: Serialize + Clone,
+{
+    pub fn new(y: Vec<Y>) -> Box<Violin<f64, Y>> {
+        Box::new(Violin {
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+}
+
+impl<X, Y> Violin<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    pub fn new_xy(x: Vec<X>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Violin {
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+
+    pub fn alignment_group(mut self, alignment_group: &str) -> Box<Self> {
+        self.alignment_group = Some(alignment_group.to_string());
+        Box::new(self)
+    }
+
+    /// Sets the bandwidth used to compute the kernel density estimate. By default, the bandwidth
+    /// is determined by Silverman's rule of thumb.
+    pub fn bandwidth(mut self, bandwidth: f64) -> Box<Self> {
+        self.bandwidth = Some(bandwidth);
+        Box::new(self)
+    }
+
+    /// Sets the styling of the inner box plot.
+    pub fn box_(mut self, box_: ViolinBox) -> Box<Self> {
+        self.box_ = Some(box_);
+        Box::new(self)
+    }
+
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Box<Self> {
+        self.fill_color = Some(Box::new(fill_color));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_on(mut self, hover_on: HoverOn) -> Box<Self> {
+        self.hover_on = Some(hover_on);
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_string()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_string()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Self> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn jitter(mut self, jitter: f64) -> Box<Self> {
+        self.jitter = Some(jitter);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_string());
+        Box::new(self)
+    }
+
+    pub fn line(mut self, line: Line) -> Box<Self> {
+        self.line = Some(line);
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<Self> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    /// Sets the styling of the line marking the sample mean.
+    pub fn mean_line(mut self, mean_line: MeanLine) -> Box<Self> {
+        self.mean_line = Some(mean_line);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_string());
+        Box::new(self)
+    }
+
+    pub fn offset_group(mut self, offset_group: &str) -> Box<Self> {
+        self.offset_group = Some(offset_group.to_string());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Box<Self> {
+        self.orientation = Some(orientation);
+        Box::new(self)
+    }
+
+    pub fn point_pos(mut self, point_pos: f64) -> Box<Self> {
+        self.point_pos = Some(point_pos);
+        Box::new(self)
+    }
+
+    /// Determines which sample points are displayed next to the violins. `BoxPoints::False` hides
+    /// all sample points.
+    pub fn points(mut self, points: BoxPoints) -> Box<Self> {
+        self.points = Some(points);
+        Box::new(self)
+    }
+
+    pub fn quartile_method(mut self, quartile_method: QuartileMethod) -> Box<Self> {
+        self.quartile_method = Some(quartile_method);
+        Box::new(self)
+    }
+
+    /// Violins with the same `scale_group` share the same scaling of their width, see
+    /// `scale_mode`.
+    pub fn scale_group(mut self, scale_group: &str) -> Box<Self> {
+        self.scale_group = Some(scale_group.to_string());
+        Box::new(self)
+    }
+
+    /// Sets the metric by which the width of each violin is determined. `ScaleMode::Width` gives
+    /// every violin the same maximum width, `ScaleMode::Count` scales it by the number of sample
+    /// points.
+    pub fn scale_mode(mut self, scale_mode: ScaleMode) -> Box<Self> {
+        self.scale_mode = Some(scale_mode);
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    /// Determines on which side of the position value the density function is drawn. Use
+    /// `Side::Positive` and `Side::Negative` on two traces sharing a position to draw split
+    /// violins.
+    pub fn side(mut self, side: Side) -> Box<Self> {
+        self.side = Some(side);
+        Box::new(self)
+    }
+
+    /// Sets the span in data space for which the density function is computed. Only has an effect
+    /// when `span_mode` is `SpanMode::Manual`.
+    pub fn span(mut self, span: Vec<f64>) -> Box<Self> {
+        self.span = Some(span);
+        Box::new(self)
+    }
+
+    /// Sets the method by which the span in data space where the density function is computed.
+    pub fn span_mode(mut self, span_mode: SpanMode) -> Box<Self> {
+        self.span_mode = Some(span_mode);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_string()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+
+    pub fn width(mut self, width: f64) -> Box<Self> {
+        self.width = Some(width);
+        Box::new(self)
+    }
+
+    pub fn x0(mut self, x0: X) -> Box<Self> {
+        self.x0 = Some(x0);
+        Box::new(self)
+    }
+
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
+        Box::new(self)
+    }
+
+    pub fn y0(mut self, y0: Y) -> Box<Self> {
+        self.y0 = Some(y0);
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
+        Box::new(self)
+    }
+}
+
+impl<X, Y> Trace for Violin<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_scale_mode() {
+        assert_eq!(to_value(ScaleMode::Width).unwrap(), json!("width"));
+        assert_eq!(to_value(ScaleMode::Count).unwrap(), json!("count"));
+    }
+
+    #[test]
+    fn test_serialize_span_mode() {
+        assert_eq!(to_value(SpanMode::Soft).unwrap(), json!("soft"));
+        assert_eq!(to_value(SpanMode::Hard).unwrap(), json!("hard"));
+        assert_eq!(to_value(SpanMode::Manual).unwrap(), json!("manual"));
+    }
+
+    #[test]
+    fn test_serialize_side() {
+        assert_eq!(to_value(Side::Both).unwrap(), json!("both"));
+        assert_eq!(to_value(Side::Positive).unwrap(), json!("positive"));
+        assert_eq!(to_value(Side::Negative).unwrap(), json!("negative"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_hover_on() {
+        assert_eq!(to_value(HoverOn::Violins).unwrap(), json!("violins"));
+        assert_eq!(to_value(HoverOn::Points).unwrap(), json!("points"));
+        assert_eq!(to_value(HoverOn::Kde).unwrap(), json!("kde"));
+        assert_eq!(to_value(HoverOn::ViolinsAndPoints).unwrap(), json!("violins+points"));
+        assert_eq!(to_value(HoverOn::ViolinsAndKde).unwrap(), json!("violins+kde"));
+        assert_eq!(to_value(HoverOn::PointsAndKde).unwrap(), json!("points+kde"));
+        assert_eq!(to_value(HoverOn::All).unwrap(), json!("all"));
+    }
+
+    #[test]
+    fn test_serialize_violin_box() {
+        let violin_box = ViolinBox::new()
+            .visible(true)
+            .width(0.2)
+            .fill_color("#FFFFFF")
+            .line(Line::new());
+        let expected = json!({
+            "visible": true,
+            "width": 0.2,
+            "fillcolor": "#FFFFFF",
+            "line": {}
+        });
+
+        assert_eq!(to_value(violin_box).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_mean_line() {
+        let mean_line = MeanLine::new().visible(true).color("#000000").width(2.0);
+        let expected = json!({
+            "visible": true,
+            "color": "#000000",
+            "width": 2.0
+        });
+
+        assert_eq!(to_value(mean_line).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_default_violin() {
+        let trace: Violin<i32, i32> = Violin::default();
+        let expected = json!({"type": "violin"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_violin_new() {
+        let trace = Violin::new(vec![0.0, 0.1]);
+        let expected = json!({
+            "type": "violin",
+            "y": [0.0, 0.1]
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_violin() {
+        let trace = Violin::new_xy(vec![1, 2, 3], vec![4, 5, 6])
+            .alignment_group("alignment_group")
+            .bandwidth(0.5)
+            .box_(ViolinBox::new())
+            .fill_color("#522622")
+            .hover_info(HoverInfo::Name)
+            .hover_label(Label::new())
+            .hover_on(HoverOn::ViolinsAndPoints)
+            .hover_template("templ2")
+            .hover_template_array(vec!["templ1", "templ2"])
+            .hover_text("ok")
+            .hover_text_array(vec!["okey", "dokey"])
+            .ids(vec!["1", "2"])
+            .jitter(0.5)
+            .legend_group("one")
+            .line(Line::new())
+            .marker(Marker::new())
+            .mean_line(MeanLine::new())
+            .name("violin")
+            .offset_group("offset_group")
+            .opacity(0.6)
+            .orientation(Orientation::Horizontal)
+            .point_pos(-1.)
+            .points(BoxPoints::Outliers)
+            .quartile_method(QuartileMethod::Linear)
+            .scale_group("scale_group")
+            .scale_mode(ScaleMode::Count)
+            .show_legend(false)
+            .side(Side::Negative)
+            .span(vec![0.0, 10.0])
+            .span_mode(SpanMode::Manual)
+            .text("hi")
+            .text_array(vec!["hi", "there"])
+            .visible(Visible::LegendOnly)
+            .width(0.8)
+            .x0(1)
+            .x_axis(XAxisRef::new(2))
+            .y0(2)
+            .y_axis(YAxisRef::new(2));
+
+        let expected = json!({
+            "type": "violin",
+            "alignmentgroup": "alignment_group",
+            "bandwidth": 0.5,
+            "box": {},
+            "fillcolor": "#522622",
+            "hoverinfo": "name",
+            "hoverlabel": {},
+            "hoveron": "violins+points",
+            "hovertemplate": ["templ1", "templ2"],
+            "hovertext": ["okey", "dokey"],
+            "ids": ["1", "2"],
+            "jitter": 0.5,
+            "legendgroup": "one",
+            "line": {},
+            "marker": {},
+            "meanline": {},
+            "name": "violin",
+            "offsetgroup": "offset_group",
+            "opacity": 0.6,
+            "orientation": "h",
+            "pointpos": -1.0,
+            "points": "outliers",
+            "quartilemethod": "linear",
+            "scalegroup": "scale_group",
+            "scalemode": "count",
+            "showlegend": false,
+            "side": "negative",
+            "span": [0.0, 10.0],
+            "spanmode": "manual",
+            "text": ["hi", "there"],
+            "visible": "legendonly",
+            "width": 0.8,
+            "x": [1, 2, 3],
+            "x0": 1,
+            "xaxis": "x2",
+            "y": [4, 5, 6],
+            "y0": 2,
+            "yaxis": "y2"
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,794 @@
+//! Volume trace
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    common::{ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible},
+    private::{self, Extra},
+    surface::{Lighting, Position},
+    Trace,
+};
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Cap {
+    show: Option<bool>,
+    fill: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Cap {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
+    /// Sets the fill ratio of the cap. The default fill value of the caps is 1 meaning that they
+    /// are entirely shaded. Setting it to a value less than 1 creates openings, making it
+    /// possible to see through the volume.
+    pub fn fill(mut self, fill: f64) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+}
+
+/// The caps drawn where the volume meets the boundaries of the domain along each axis.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Caps {
+    x: Option<Cap>,
+    y: Option<Cap>,
+    z: Option<Cap>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Caps {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn x(mut self, x: Cap) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: Cap) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn z(mut self, z: Cap) -> Self {
+        self.z = Some(z);
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Slice {
+    show: Option<bool>,
+    fill: Option<f64>,
+    locations: Option<Vec<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Slice {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
+    pub fn fill(mut self, fill: f64) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Specifies the location(s) of the slices on the axis. When not given, slices are created
+    /// for all points of the axis.
+    pub fn locations(mut self, locations: Vec<f64>) -> Self {
+        self.locations = Some(locations);
+        self
+    }
+}
+
+/// The slices cut through the volume perpendicular to each axis.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Slices {
+    x: Option<Slice>,
+    y: Option<Slice>,
+    z: Option<Slice>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Slices {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn x(mut self, x: Slice) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: Slice) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn z(mut self, z: Slice) -> Self {
+        self.z = Some(z);
+        self
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct SpaceFrame {
+    show: Option<bool>,
+    fill: Option<f64>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl SpaceFrame {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
+    pub fn fill(mut self, fill: f64) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum SurfacePattern {
+    All,
+    Odd,
+    Even,
+}
+
+/// The iso-surfaces drawn between `iso_min` and `iso_max`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct VolumeSurface {
+    show: Option<bool>,
+    count: Option<usize>,
+    fill: Option<f64>,
+    pattern: Option<SurfacePattern>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl VolumeSurface {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
+    /// Sets the number of iso-surfaces between minimum and maximum iso-values.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn fill(mut self, fill: f64) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    pub fn pattern(mut self, pattern: SurfacePattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum OpacityScale {
+    Uniform,
+    Min,
+    Max,
+    Extremes,
+    /// Pairs of normalized values and the opacity to use at each one, e.g.
+    /// `vec![(0.0, 1.0), (0.5, 0.2), (1.0, 1.0)]`.
+    Custom(Vec<(f64, f64)>),
+}
+
+impl Serialize for OpacityScale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Uniform => serializer.serialize_str("uniform"),
+            Self::Min => serializer.serialize_str("min"),
+            Self::Max => serializer.serialize_str("max"),
+            Self::Extremes => serializer.serialize_str("extremes"),
+            Self::Custom(scale) => scale.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OpacityScale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum NamedOrCustom {
+            Named(String),
+            Custom(Vec<(f64, f64)>),
+        }
+
+        match NamedOrCustom::deserialize(deserializer)? {
+            NamedOrCustom::Named(s) => match s.as_str() {
+                "uniform" => Ok(Self::Uniform),
+                "min" => Ok(Self::Min),
+                "max" => Ok(Self::Max),
+                "extremes" => Ok(Self::Extremes),
+                _ => Err(de::Error::unknown_variant(
+                    &s,
+                    &["uniform", "min", "max", "extremes"],
+                )),
+            },
+            NamedOrCustom::Custom(scale) => Ok(Self::Custom(scale)),
+        }
+    }
+}
+
+/// Construct a volume trace, drawing the values sampled on a 3D grid given by `x`, `y` and `z`
+/// as a number of semi-transparent iso-surfaces.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{volume::VolumeSurface, Volume};
+///
+/// let trace = Volume::new(
+///     vec![0, 0, 1, 1],
+///     vec![0, 1, 0, 1],
+///     vec![0, 0, 0, 0],
+///     vec![0.1, 0.4, 0.6, 0.9],
+/// )
+/// .surface(VolumeSurface::new().count(5));
+///
+/// let expected = serde_json::json!({
+///     "type": "volume",
+///     "x": [0, 0, 1, 1],
+///     "y": [0, 1, 0, 1],
+///     "z": [0, 0, 0, 0],
+///     "value": [0.1, 0.4, 0.6, 0.9],
+///     "surface": {"count": 5}
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Volume<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    r#type: PlotType,
+    x: Option<Vec<X>>,
+    y: Option<Vec<Y>>,
+    z: Option<Vec<Z>>,
+    value: Option<Vec<V>>,
+    #[serde(rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    caps: Option<Caps>,
+    cauto: Option<bool>,
+    cmax: Option<f64>,
+    cmid: Option<f64>,
+    cmin: Option<f64>,
+    #[serde(rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(rename = "flatshading")]
+    flat_shading: Option<bool>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "isomax")]
+    iso_max: Option<f64>,
+    #[serde(rename = "isomin")]
+    iso_min: Option<f64>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(rename = "lightposition")]
+    light_position: Option<Position>,
+    lighting: Option<Lighting>,
+    name: Option<String>,
+    opacity: Option<f64>,
+    #[serde(rename = "opacityscale")]
+    opacity_scale: Option<OpacityScale>,
+    #[serde(rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    scene: Option<String>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "showscale")]
+    show_scale: Option<bool>,
+    slices: Option<Slices>,
+    #[serde(rename = "spaceframe")]
+    space_frame: Option<SpaceFrame>,
+    surface: Option<VolumeSurface>,
+    text: Option<Dim<String>>,
+    visible: Option<Visible>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<X, Y, Z, V> Default for Volume<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Volume,
+            x: None,
+            y: None,
+            z: None,
+            value: None,
+            auto_color_scale: None,
+            caps: None,
+            cauto: None,
+            cmax: None,
+            cmid: None,
+            cmin: None,
+            color_bar: None,
+            color_scale: None,
+            flat_shading: None,
+            hover_info: None,
+            hover_label: None,
+            hover_template: None,
+            hover_text: None,
+            iso_max: None,
+            iso_min: None,
+            legend_group: None,
+            light_position: None,
+            lighting: None,
+            name: None,
+            opacity: None,
+            opacity_scale: None,
+            reverse_scale: None,
+            scene: None,
+            show_legend: None,
+            show_scale: None,
+            slices: None,
+            space_frame: None,
+            surface: None,
+            text: None,
+            visible: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<X, Y, Z, V> Volume<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>, value: Vec<V>) -> Box<Self> {
+        Box::new(Self {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Self> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    pub fn caps(mut self, caps: Caps) -> Box<Self> {
+        self.caps = Some(caps);
+        Box::new(self)
+    }
+
+    pub fn cauto(mut self, cauto: bool) -> Box<Self> {
+        self.cauto = Some(cauto);
+        Box::new(self)
+    }
+
+    pub fn cmax(mut self, cmax: f64) -> Box<Self> {
+        self.cmax = Some(cmax);
+        Box::new(self)
+    }
+
+    pub fn cmid(mut self, cmid: f64) -> Box<Self> {
+        self.cmid = Some(cmid);
+        Box::new(self)
+    }
+
+    pub fn cmin(mut self, cmin: f64) -> Box<Self> {
+        self.cmin = Some(cmin);
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Self> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Self> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn flat_shading(mut self, flat_shading: bool) -> Box<Self> {
+        self.flat_shading = Some(flat_shading);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    /// Sets the maximum boundary for iso-surface plot.
+    pub fn iso_max(mut self, iso_max: f64) -> Box<Self> {
+        self.iso_max = Some(iso_max);
+        Box::new(self)
+    }
+
+    /// Sets the minimum boundary for iso-surface plot.
+    pub fn iso_min(mut self, iso_min: f64) -> Box<Self> {
+        self.iso_min = Some(iso_min);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn lighting(mut self, lighting: Lighting) -> Box<Self> {
+        self.lighting = Some(lighting);
+        Box::new(self)
+    }
+
+    pub fn light_position(mut self, light_position: Position) -> Box<Self> {
+        self.light_position = Some(light_position);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    /// Sets the opacity of the surfaces. Values below 1 are needed to see inside the volume.
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    /// Sets the opacity scale, mapping the values to an opacity in the same way the color scale
+    /// maps them to a color.
+    pub fn opacity_scale(mut self, opacity_scale: OpacityScale) -> Box<Self> {
+        self.opacity_scale = Some(opacity_scale);
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Self> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    /// Sets a reference between this trace's 3D coordinate system and a 3D scene. If "scene" (the
+    /// default value), the (x,y,z) coordinates refer to `layout.scene`. If "scene2", the (x, y, z)
+    /// coordinates refer to `layout.scene2`, and so on.
+    pub fn scene(mut self, scene: &str) -> Box<Self> {
+        self.scene = Some(scene.to_owned());
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Self> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    pub fn slices(mut self, slices: Slices) -> Box<Self> {
+        self.slices = Some(slices);
+        Box::new(self)
+    }
+
+    pub fn space_frame(mut self, space_frame: SpaceFrame) -> Box<Self> {
+        self.space_frame = Some(space_frame);
+        Box::new(self)
+    }
+
+    pub fn surface(mut self, surface: VolumeSurface) -> Box<Self> {
+        self.surface = Some(surface);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+}
+
+impl<X, Y, Z, V> Trace for Volume<X, Y, Z, V>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+    Z: Serialize + Clone,
+    V: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, to_value};
+
+    use super::*;
+    use crate::common::ColorScalePalette;
+
+    #[test]
+    fn test_serialize_caps() {
+        let caps = Caps::new()
+            .x(Cap::new().show(true).fill(0.5))
+            .y(Cap::new())
+            .z(Cap::new().show(false));
+        let expected = json!({
+            "x": {"show": true, "fill": 0.5},
+            "y": {},
+            "z": {"show": false}
+        });
+
+        assert_eq!(to_value(caps).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_slices() {
+        let slices = Slices::new()
+            .x(Slice::new().show(true).fill(0.5).locations(vec![1.0, 2.0]))
+            .y(Slice::new())
+            .z(Slice::new().show(false));
+        let expected = json!({
+            "x": {"show": true, "fill": 0.5, "locations": [1.0, 2.0]},
+            "y": {},
+            "z": {"show": false}
+        });
+
+        assert_eq!(to_value(slices).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_space_frame() {
+        let space_frame = SpaceFrame::new().show(true).fill(0.2);
+        let expected = json!({"show": true, "fill": 0.2});
+
+        assert_eq!(to_value(space_frame).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_surface_pattern() {
+        assert_eq!(to_value(SurfacePattern::All).unwrap(), json!("all"));
+        assert_eq!(to_value(SurfacePattern::Odd).unwrap(), json!("odd"));
+        assert_eq!(to_value(SurfacePattern::Even).unwrap(), json!("even"));
+    }
+
+    #[test]
+    fn test_serialize_surface() {
+        let surface = VolumeSurface::new()
+            .show(true)
+            .count(3)
+            .fill(0.8)
+            .pattern(SurfacePattern::Odd);
+        let expected = json!({"show": true, "count": 3, "fill": 0.8, "pattern": "odd"});
+
+        assert_eq!(to_value(surface).unwrap(), expected);
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_opacity_scale() {
+        assert_eq!(to_value(OpacityScale::Uniform).unwrap(), json!("uniform"));
+        assert_eq!(to_value(OpacityScale::Min).unwrap(), json!("min"));
+        assert_eq!(to_value(OpacityScale::Max).unwrap(), json!("max"));
+        assert_eq!(to_value(OpacityScale::Extremes).unwrap(), json!("extremes"));
+        assert_eq!(to_value(OpacityScale::Custom(vec![(0.0, 1.0), (1.0, 0.2)])).unwrap(), json!([[0.0, 1.0], [1.0, 0.2]]));
+    }
+
+    #[test]
+    fn test_deserialize_opacity_scale() {
+        assert!(matches!(
+            from_value(json!("uniform")).unwrap(),
+            OpacityScale::Uniform
+        ));
+        assert!(matches!(
+            from_value(json!("min")).unwrap(),
+            OpacityScale::Min
+        ));
+        assert!(matches!(
+            from_value(json!("max")).unwrap(),
+            OpacityScale::Max
+        ));
+        assert!(matches!(
+            from_value(json!("extremes")).unwrap(),
+            OpacityScale::Extremes
+        ));
+        assert!(matches!(
+            from_value(json!([[0.0, 1.0], [1.0, 0.2]])).unwrap(),
+            OpacityScale::Custom(scale) if scale == vec![(0.0, 1.0), (1.0, 0.2)]
+        ));
+        assert!(from_value::<OpacityScale>(json!("none")).is_err());
+    }
+
+    #[test]
+    fn test_default_volume() {
+        let trace: Volume<f64, f64, f64, f64> = Volume::default();
+        let expected = json!({"type": "volume"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_volume() {
+        let trace = Volume::new(vec![0], vec![1], vec![2], vec![3.0])
+            .auto_color_scale(false)
+            .caps(Caps::new())
+            .cauto(true)
+            .cmax(5.0)
+            .cmid(2.5)
+            .cmin(0.0)
+            .color_bar(ColorBar::new())
+            .color_scale(ColorScale::Palette(ColorScalePalette::Jet))
+            .flat_shading(true)
+            .hover_info(HoverInfo::All)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .iso_max(10.0)
+            .iso_min(1.0)
+            .legend_group("legend_group")
+            .lighting(Lighting::new())
+            .light_position(Position::new(1, 2, 3))
+            .name("volume")
+            .opacity(0.1)
+            .opacity_scale(OpacityScale::Extremes)
+            .reverse_scale(true)
+            .scene("scene2")
+            .show_legend(true)
+            .show_scale(false)
+            .slices(Slices::new())
+            .space_frame(SpaceFrame::new())
+            .surface(VolumeSurface::new())
+            .text("text")
+            .text_array(vec!["text"])
+            .visible(Visible::True);
+
+        let expected = json!({
+            "type": "volume",
+            "x": [0],
+            "y": [1],
+            "z": [2],
+            "value": [3.0],
+            "autocolorscale": false,
+            "caps": {},
+            "cauto": true,
+            "cmax": 5.0,
+            "cmid": 2.5,
+            "cmin": 0.0,
+            "colorbar": {},
+            "colorscale": "Jet",
+            "flatshading": true,
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "isomax": 10.0,
+            "isomin": 1.0,
+            "legendgroup": "legend_group",
+            "lighting": {},
+            "lightposition": {"x": 1, "y": 2, "z": 3},
+            "name": "volume",
+            "opacity": 0.1,
+            "opacityscale": "extremes",
+            "reversescale": true,
+            "scene": "scene2",
+            "showlegend": true,
+            "showscale": false,
+            "slices": {},
+            "spaceframe": {},
+            "surface": {},
+            "text": ["text"],
+            "visible": true
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,624 @@
+//! Waterfall trace
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    common::{
+        ConstrainText, Dim, Font, HoverInfo, Label, Line, Marker, Orientation, PlotType,
+        TextAnchor, TextPosition, Visible, XAxisRef, YAxisRef,
+    },
+    private::{self, Extra},
+    Trace,
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum Measure {
+    Relative,
+    Total,
+    Absolute,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum TextInfo {
+    Label,
+    Text,
+    Initial,
+    Delta,
+    Final,
+    #[serde(rename = "label+delta")]
+    LabelAndDelta,
+    #[serde(rename = "label+final")]
+    LabelAndFinal,
+    #[serde(rename = "delta+final")]
+    DeltaAndFinal,
+    #[serde(rename = "initial+delta+final")]
+    InitialDeltaAndFinal,
+    None,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectorMode {
+    Spanning,
+    Between,
+}
+
+/// Styling of the bars of one kind, i.e. increasing, decreasing or totals.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct BarStyle {
+    marker: Option<Marker>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl BarStyle {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+}
+
+/// Styling of the lines connecting consecutive bars.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct Connector {
+    line: Option<Line>,
+    mode: Option<ConnectorMode>,
+    visible: Option<bool>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl Connector {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn line(mut self, line: Line) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn mode(mut self, mode: ConnectorMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+}
+
+/// Construct a waterfall trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{Waterfall, waterfall::Measure};
+///
+/// let trace = Waterfall::new(vec!["Sales", "Costs", "Profit"], vec![60, -20, 0])
+///     .measure(vec![Measure::Relative, Measure::Relative, Measure::Total]);
+///
+/// let expected = serde_json::json!({
+///     "type": "waterfall",
+///     "x": ["Sales", "Costs", "Profit"],
+///     "y": [60, -20, 0],
+///     "measure": ["relative", "relative", "total"]
+/// });
+///
+/// assert_eq!(serde_json::to_value(trace).unwrap(), expected);
+/// ```
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Waterfall<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    r#type: PlotType,
+    x: Option<Vec<X>>,
+    y: Option<Vec<Y>>,
+    name: Option<String>,
+    visible: Option<Visible>,
+    #[serde(rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(rename = "legendgroup")]
+    legend_group: Option<String>,
+    opacity: Option<f64>,
+    ids: Option<Vec<String>>,
+    measure: Option<Vec<Measure>>,
+    base: Option<f64>,
+    width: Option<f64>,
+    offset: Option<f64>,
+    text: Option<Dim<String>>,
+    #[serde(rename = "textposition")]
+    text_position: Option<Dim<TextPosition>>,
+    #[serde(rename = "textinfo")]
+    text_info: Option<TextInfo>,
+    #[serde(rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(rename = "xaxis")]
+    x_axis: Option<XAxisRef>,
+    #[serde(rename = "yaxis")]
+    y_axis: Option<YAxisRef>,
+    orientation: Option<Orientation>,
+    #[serde(rename = "alignmentgroup")]
+    alignment_group: Option<String>,
+    #[serde(rename = "offsetgroup")]
+    offset_group: Option<String>,
+    increasing: Option<BarStyle>,
+    decreasing: Option<BarStyle>,
+    totals: Option<BarStyle>,
+    connector: Option<Connector>,
+    #[serde(rename = "textangle")]
+    text_angle: Option<f64>,
+    #[serde(rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(rename = "cliponaxis")]
+    clip_on_axis: Option<bool>,
+    #[serde(rename = "constraintext")]
+    constrain_text: Option<ConstrainText>,
+    #[serde(rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(rename = "insidetextanchor")]
+    inside_text_anchor: Option<TextAnchor>,
+    #[serde(rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+impl<X, Y> Default for Waterfall<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn default() -> Self {
+        Self {
+            r#type: PlotType::Waterfall,
+            x: None,
+            y: None,
+            name: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            measure: None,
+            base: None,
+            width: None,
+            offset: None,
+            text: None,
+            text_position: None,
+            text_info: None,
+            text_template: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            x_axis: None,
+            y_axis: None,
+            orientation: None,
+            alignment_group: None,
+            offset_group: None,
+            increasing: None,
+            decreasing: None,
+            totals: None,
+            connector: None,
+            text_angle: None,
+            text_font: None,
+            clip_on_axis: None,
+            constrain_text: None,
+            hover_label: None,
+            inside_text_anchor: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            extra: Extra::new(),
+        }
+    }
+}
+
+impl<X, Y> Waterfall<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Self> {
+        Box::new(Waterfall {
+            x: Some(x),
+            y: Some(y),
+            ..Default::default()
+        })
+    }
+
+    pub fn alignment_group(mut self, alignment_group: &str) -> Box<Self> {
+        self.alignment_group = Some(alignment_group.to_owned());
+        Box::new(self)
+    }
+
+    /// Sets where the bar base is drawn (in position axis units).
+    pub fn base(mut self, base: f64) -> Box<Self> {
+        self.base = Some(base);
+        Box::new(self)
+    }
+
+    pub fn clip_on_axis(mut self, clip_on_axis: bool) -> Box<Self> {
+        self.clip_on_axis = Some(clip_on_axis);
+        Box::new(self)
+    }
+
+    /// Sets the styling of the lines connecting the bars.
+    pub fn connector(mut self, connector: Connector) -> Box<Self> {
+        self.connector = Some(connector);
+        Box::new(self)
+    }
+
+    pub fn constrain_text(mut self, constrain_text: ConstrainText) -> Box<Self> {
+        self.constrain_text = Some(constrain_text);
+        Box::new(self)
+    }
+
+    /// Sets the styling of the bars with a negative relative value.
+    pub fn decreasing(mut self, decreasing: BarStyle) -> Box<Self> {
+        self.decreasing = Some(decreasing);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Self> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Self> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Self> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Self> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Self> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Self> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Self> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    /// Sets the styling of the bars with a positive relative value.
+    pub fn increasing(mut self, increasing: BarStyle) -> Box<Self> {
+        self.increasing = Some(increasing);
+        Box::new(self)
+    }
+
+    pub fn inside_text_anchor(mut self, inside_text_anchor: TextAnchor) -> Box<Self> {
+        self.inside_text_anchor = Some(inside_text_anchor);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Self> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Self> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    /// An array containing types of values. By default the values are considered as `relative`.
+    /// A `total` computes the sum of all the previous values and an `absolute` resets the
+    /// computed total or declares an initial value where needed.
+    pub fn measure(mut self, measure: Vec<Measure>) -> Box<Self> {
+        self.measure = Some(measure);
+        Box::new(self)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Self> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    /// Shifts the position where the bar is drawn (in position axis units).
+    pub fn offset(mut self, offset: f64) -> Box<Self> {
+        self.offset = Some(offset);
+        Box::new(self)
+    }
+
+    pub fn offset_group(mut self, offset_group: &str) -> Box<Self> {
+        self.offset_group = Some(offset_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Self> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Box<Self> {
+        self.orientation = Some(orientation);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Self> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Self> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Self> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Self> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_angle(mut self, text_angle: f64) -> Box<Self> {
+        self.text_angle = Some(text_angle);
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Self> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    /// Determines which trace information appear on the graph. In the case of having multiple
+    /// waterfalls, totals are computed separately (per trace).
+    pub fn text_info(mut self, text_info: TextInfo) -> Box<Self> {
+        self.text_info = Some(text_info);
+        Box::new(self)
+    }
+
+    pub fn text_position(mut self, text_position: TextPosition) -> Box<Self> {
+        self.text_position = Some(Dim::Scalar(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_position_array(mut self, text_position: Vec<TextPosition>) -> Box<Self> {
+        self.text_position = Some(Dim::Vector(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Self> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Self> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    /// Sets the styling of the bars computed as totals.
+    pub fn totals(mut self, totals: BarStyle) -> Box<Self> {
+        self.totals = Some(totals);
+        Box::new(self)
+    }
+
+    pub fn visible(mut self, visible: Visible) -> Box<Self> {
+        self.visible = Some(visible);
+        Box::new(self)
+    }
+
+    pub fn width(mut self, width: f64) -> Box<Self> {
+        self.width = Some(width);
+        Box::new(self)
+    }
+
+    pub fn x_axis(mut self, axis: XAxisRef) -> Box<Self> {
+        self.x_axis = Some(axis);
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, axis: YAxisRef) -> Box<Self> {
+        self.y_axis = Some(axis);
+        Box::new(self)
+    }
+}
+
+impl<X, Y> Trace for Waterfall<X, Y>
+where
+    X: Serialize + Clone,
+    Y: Serialize + Clone,
+{
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn test_serialize_measure() {
+        assert_eq!(to_value(Measure::Relative).unwrap(), json!("relative"));
+        assert_eq!(to_value(Measure::Total).unwrap(), json!("total"));
+        assert_eq!(to_value(Measure::Absolute).unwrap(), json!("absolute"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_serialize_text_info() {
+        assert_eq!(to_value(TextInfo::Label).unwrap(), json!("label"));
+        assert_eq!(to_value(TextInfo::Text).unwrap(), json!("text"));
+        assert_eq!(to_value(TextInfo::Initial).unwrap(), json!("initial"));
+        assert_eq!(to_value(TextInfo::Delta).unwrap(), json!("delta"));
+        assert_eq!(to_value(TextInfo::Final).unwrap(), json!("final"));
+        assert_eq!(to_value(TextInfo::LabelAndDelta).unwrap(), json!("label+delta"));
+        assert_eq!(to_value(TextInfo::LabelAndFinal).unwrap(), json!("label+final"));
+        assert_eq!(to_value(TextInfo::DeltaAndFinal).unwrap(), json!("delta+final"));
+        assert_eq!(to_value(TextInfo::InitialDeltaAndFinal).unwrap(), json!("initial+delta+final"));
+        assert_eq!(to_value(TextInfo::None).unwrap(), json!("none"));
+    }
+
+    #[test]
+    fn test_serialize_connector_mode() {
+        assert_eq!(
+            to_value(ConnectorMode::Spanning).unwrap(),
+            json!("spanning")
+        );
+        assert_eq!(to_value(ConnectorMode::Between).unwrap(), json!("between"));
+    }
+
+    #[test]
+    fn test_serialize_bar_style() {
+        let bar_style = BarStyle::new().marker(Marker::new().color("#3D9970"));
+        let expected = json!({"marker": {"color": "#3D9970"}});
+
+        assert_eq!(to_value(bar_style).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_connector() {
+        let connector = Connector::new()
+            .line(Line::new().width(2.0))
+            .mode(ConnectorMode::Between)
+            .visible(true);
+        let expected = json!({
+            "line": {"width": 2.0},
+            "mode": "between",
+            "visible": true
+        });
+
+        assert_eq!(to_value(connector).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_default_waterfall() {
+        let trace: Waterfall<i32, i32> = Waterfall::default();
+        let expected = json!({"type": "waterfall"}).to_string();
+
+        assert_eq!(trace.to_json(), expected);
+    }
+
+    #[test]
+    fn test_serialize_waterfall() {
+        let trace = Waterfall::new(vec!["a", "b", "c"], vec![10, -5, 0])
+            .alignment_group("alignment_group")
+            .base(100.0)
+            .clip_on_axis(false)
+            .connector(Connector::new())
+            .constrain_text(ConstrainText::Both)
+            .decreasing(BarStyle::new())
+            .hover_info(HoverInfo::All)
+            .hover_label(Label::new())
+            .hover_template("hover_template")
+            .hover_template_array(vec!["hover_template"])
+            .hover_text("hover_text")
+            .hover_text_array(vec!["hover_text"])
+            .ids(vec!["1", "2", "3"])
+            .increasing(BarStyle::new())
+            .inside_text_anchor(TextAnchor::Middle)
+            .inside_text_font(Font::new())
+            .legend_group("legend_group")
+            .measure(vec![Measure::Absolute, Measure::Relative, Measure::Total])
+            .name("waterfall")
+            .offset(0.1)
+            .offset_group("offset_group")
+            .opacity(0.5)
+            .orientation(Orientation::Vertical)
+            .outside_text_font(Font::new())
+            .show_legend(true)
+            .text("text")
+            .text_array(vec!["a", "b", "c"])
+            .text_angle(45.0)
+            .text_font(Font::new())
+            .text_info(TextInfo::DeltaAndFinal)
+            .text_position(TextPosition::Inside)
+            .text_position_array(vec![TextPosition::Outside])
+            .text_template("text_template")
+            .text_template_array(vec!["text_template"])
+            .totals(BarStyle::new())
+            .visible(Visible::True)
+            .width(0.5)
+            .x_axis(XAxisRef::new(2))
+            .y_axis(YAxisRef::new(2));
+
+        let expected = json!({
+            "type": "waterfall",
+            "x": ["a", "b", "c"],
+            "y": [10, -5, 0],
+            "alignmentgroup": "alignment_group",
+            "base": 100.0,
+            "cliponaxis": false,
+            "connector": {},
+            "constraintext": "both",
+            "decreasing": {},
+            "hoverinfo": "all",
+            "hoverlabel": {},
+            "hovertemplate": ["hover_template"],
+            "hovertext": ["hover_text"],
+            "ids": ["1", "2", "3"],
+            "increasing": {},
+            "insidetextanchor": "middle",
+            "insidetextfont": {},
+            "legendgroup": "legend_group",
+            "measure": ["absolute", "relative", "total"],
+            "name": "waterfall",
+            "offset": 0.1,
+            "offsetgroup": "offset_group",
+            "opacity": 0.5,
+            "orientation": "v",
+            "outsidetextfont": {},
+            "showlegend": true,
+            "text": ["a", "b", "c"],
+            "textangle": 45.0,
+            "textfont": {},
+            "textinfo": "delta+final",
+            "textposition": ["outside"],
+            "texttemplate": ["text_template"],
+            "totals": {},
+            "visible": true,
+            "width": 0.5,
+            "xaxis": "x2",
+            "yaxis": "y2"
+        });
+
+        assert_eq!(to_value(trace).unwrap(), expected);
+    }
+}
@@ -35,9 +35,15 @@ const KALEIDO_BIN: &str = "kaleido.exe";
 #[cfg(target_os = "macos")]
 const KALEIDO_BIN: &str = "kaleido";
 
+// Points at an existing Kaleido install, which is then used instead of installing one.
+const KALEIDO_PATH_ENV: &str = "PLOTLY_KALEIDO_PATH";
+
+// Points at a Kaleido release archive, which is then unpacked instead of downloading it.
+const KALEIDO_ARCHIVE_ENV: &str = "PLOTLY_KALEIDO_ARCHIVE";
+
 fn extract_zip(p: &Path, zip_file: &Path) -> Result<()> {
-    let file = fs::File::open(&zip_file).unwrap();
-    let mut archive = zip::ZipArchive::new(file).unwrap();
+    let file = fs::File::open(zip_file)?;
+    let mut archive = zip::ZipArchive::new(file)?;
 
     for i in 0..archive.len() {
         let mut file = archive.by_index(i).unwrap();
@@ -86,11 +92,36 @@ fn extract_zip(p: &Path, zip_file: &Path) -> Result<()> {
         }
     }
 
-    fs::remove_file(zip_file)?;
+    Ok(())
+}
+
+fn download_zip(zip_file: &Path) -> std::result::Result<(), String> {
+    let installed = matches!(
+        Command::new("cargo").args(["install", "ruget"]).status(),
+        Ok(status) if status.success()
+    );
+    if !installed {
+        return Err("could not install ruget".to_string());
+    }
+
+    let downloaded = matches!(
+        Command::new("ruget").arg(KALEIDO_URL).arg("-o").arg(zip_file).status(),
+        Ok(status) if status.success()
+    );
+    if !downloaded || !zip_file.exists() {
+        return Err(format!("could not download {}", KALEIDO_URL));
+    }
     Ok(())
 }
 
 fn main() -> Result<()> {
+    println!("cargo:rerun-if-env-changed={}", KALEIDO_PATH_ENV);
+    println!("cargo:rerun-if-env-changed={}", KALEIDO_ARCHIVE_ENV);
+
+    if env::var_os(KALEIDO_PATH_ENV).is_some() {
+        return Ok(());
+    }
+
     let project_dirs = ProjectDirs::from("org", "plotly", "kaleido")
         .expect("Could not create plotly_kaleido config directory.");
     let dst: PathBuf = project_dirs.config_dir().into();
@@ -100,26 +131,38 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if let Some(archive) = env::var_os(KALEIDO_ARCHIVE_ENV) {
+        let archive = PathBuf::from(archive);
+        println!("cargo:rerun-if-changed={}", archive.display());
+        return extract_zip(&dst, &archive).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!(
+                    "could not unpack the Kaleido archive {} given by {}: {}",
+                    archive.display(),
+                    KALEIDO_ARCHIVE_ENV,
+                    e
+                ),
+            )
+        });
+    }
+
     let p = PathBuf::from(env::var("OUT_DIR").unwrap());
     let kaleido_zip_file = p.join("kaleido.zip");
 
-    let mut cmd = Command::new("cargo")
-        .args(&["install", "ruget"])
-        .spawn()
-        .unwrap();
-    cmd.wait()?;
-
-    let mut cmd = Command::new("ruget")
-        .args(&[
-            KALEIDO_URL,
-            "-o",
-            kaleido_zip_file.as_path().to_str().unwrap(),
-        ])
-        .spawn()
-        .unwrap();
-    cmd.wait()?;
+    // Without network access, the build still succeeds: exporting images then fails with an error
+    // explaining how to provide Kaleido.
+    if let Err(e) = download_zip(&kaleido_zip_file) {
+        println!(
+            "cargo:warning=Kaleido could not be downloaded ({}). Set {} to an existing Kaleido \
+            install, or {} to a Kaleido release archive from {}, to export static images.",
+            e, KALEIDO_PATH_ENV, KALEIDO_ARCHIVE_ENV, KALEIDO_URL
+        );
+        return Ok(());
+    }
 
     extract_zip(&dst, &kaleido_zip_file)?;
+    fs::remove_file(&kaleido_zip_file)?;
     println!("cargo:rerun-if-changed=src/lib.rs");
     Ok(())
 }
@@ -12,11 +12,72 @@
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::env;
+use std::fmt;
 use std::fs::File;
 use std::io::prelude::*;
-use std::io::BufReader;
+use std::io::{self, BufReader, Lines};
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+/// The ways in which exporting a plot with Kaleido can fail.
+#[derive(Debug)]
+pub enum Error {
+    /// The Kaleido executable could not be found or started, or the image could not be written.
+    Io(io::Error),
+    /// Kaleido ran, but failed to export the plot or returned output which could not be read.
+    Kaleido { code: i32, message: String },
+    /// The plot could not be passed to Kaleido, or Kaleido's response could not be parsed.
+    Serialization(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Kaleido { code, message } => {
+                write!(f, "Kaleido failed with code {}: {}", code, message)
+            }
+            Error::Serialization(e) => write!(f, "invalid Kaleido data: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Kaleido { .. } => None,
+            Error::Serialization(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Serialization(e)
+    }
+}
+
+/// Points at an existing Kaleido install, which is then used instead of the one installed when
+/// building this crate.
+const KALEIDO_PATH_ENV: &str = "PLOTLY_KALEIDO_PATH";
+
+/// Points at a Kaleido release archive, which is unpacked when building this crate instead of
+/// downloading it.
+const KALEIDO_ARCHIVE_ENV: &str = "PLOTLY_KALEIDO_ARCHIVE";
+
+#[cfg(not(target_os = "windows"))]
+const KALEIDO_EXECUTABLE: &str = "kaleido";
+
+#[cfg(target_os = "windows")]
+const KALEIDO_EXECUTABLE: &str = "kaleido.cmd";
 
 #[allow(dead_code)]
 #[derive(Deserialize, Debug)]
@@ -34,8 +95,8 @@ struct KaleidoResult {
 }
 
 impl KaleidoResult {
-    fn from(result: &str) -> KaleidoResult {
-        serde_json::from_str(result).unwrap()
+    fn from(result: &str) -> Result<KaleidoResult, Error> {
+        Ok(serde_json::from_str(result)?)
     }
 }
 
@@ -63,62 +124,144 @@ impl<'a> PlotData<'a> {
         }
     }
 
-    fn to_json(&self) -> String {
-        serde_json::to_string(self).unwrap()
+    fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string(self)?)
     }
 }
 
 #[derive(Default)]
 pub struct Kaleido {
     cmd_path: PathBuf,
+    topojson: Option<String>,
 }
 
 impl Kaleido {
+    /// Locate the Kaleido executable.
+    ///
+    /// Panics if it cannot be found; use `Kaleido::try_new` to handle that case instead.
     pub fn new() -> Kaleido {
-        let path = match Kaleido::binary_path() {
-            Ok(path) => path,
-            Err(msg) => panic!("{}", msg),
-        };
+        match Kaleido::try_new() {
+            Ok(kaleido) => kaleido,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Locate the Kaleido executable, returning an `Error::Io` if it cannot be found.
+    ///
+    /// If the `PLOTLY_KALEIDO_PATH` environment variable is set, the Kaleido install it points at
+    /// is used, as with `Kaleido::with_path`. Otherwise, this is the install which was downloaded,
+    /// or unpacked from the archive given by `PLOTLY_KALEIDO_ARCHIVE`, when building this crate.
+    pub fn try_new() -> Result<Kaleido, Error> {
+        if let Some(path) = env::var_os(KALEIDO_PATH_ENV) {
+            return Kaleido::with_path(path).map_err(|e| match e {
+                Error::Io(e) => {
+                    io::Error::new(e.kind(), format!("{} (from {})", e, KALEIDO_PATH_ENV)).into()
+                }
+                e => e,
+            });
+        }
+        let path = Kaleido::binary_path()?;
 
-        Kaleido { cmd_path: path }
+        Ok(Kaleido {
+            cmd_path: path,
+            topojson: None,
+        })
     }
 
-    fn root_dir() -> Result<PathBuf, &'static str> {
-        let project_dirs = ProjectDirs::from("org", "plotly", "kaleido")
-            .expect("Could not create plotly_kaleido config directory.");
+    /// Use an existing Kaleido install: either the directory of an unpacked Kaleido release, or
+    /// the `kaleido` executable in it (`kaleido.cmd` on Windows).
+    pub fn with_path<P: AsRef<Path>>(path: P) -> Result<Kaleido, Error> {
+        let mut p = path.as_ref().to_path_buf();
+        if p.is_dir() {
+            p = p.join(KALEIDO_EXECUTABLE);
+        }
+        if !p.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("could not find the Kaleido executable at {}", p.display()),
+            )
+            .into());
+        }
+
+        Ok(Kaleido {
+            cmd_path: dunce::canonicalize(p)?,
+            topojson: None,
+        })
+    }
+
+    /// Sets the URL Kaleido loads the topojson files of geographic plots from, in place of
+    /// cdn.plot.ly. It is the URL of the directory holding the files, e.g.
+    /// "file:///path/to/plotly.js/dist/topojson/".
+    pub fn topojson(mut self, url: &str) -> Self {
+        self.topojson = Some(url.to_string());
+        self
+    }
+
+    fn root_dir() -> Result<PathBuf, Error> {
+        let project_dirs = ProjectDirs::from("org", "plotly", "kaleido").ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "could not find the plotly_kaleido config directory",
+            )
+        })?;
         Ok(project_dirs.config_dir().into())
     }
 
+    fn not_found(root_dir: &Path) -> Error {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "could not find the Kaleido executable in {}. Set {} to an existing Kaleido \
+                install, or rebuild plotly_kaleido with network access or with {} set to a \
+                Kaleido release archive",
+                root_dir.display(),
+                KALEIDO_PATH_ENV,
+                KALEIDO_ARCHIVE_ENV
+            ),
+        )
+        .into()
+    }
+
     #[cfg(target_os = "linux")]
-    fn binary_path() -> Result<PathBuf, &'static str> {
-        let mut p = Kaleido::root_dir()?;
-        p = p.join("kaleido").canonicalize().unwrap();
+    fn binary_path() -> Result<PathBuf, Error> {
+        let root_dir = Kaleido::root_dir()?;
+        let p = root_dir
+            .join(KALEIDO_EXECUTABLE)
+            .canonicalize()
+            .map_err(|_| Kaleido::not_found(&root_dir))?;
         if !p.exists() {
-            return Err("could not find kaleido executable in path");
+            return Err(Kaleido::not_found(&root_dir));
         }
         Ok(p)
     }
 
     #[cfg(target_os = "macos")]
-    fn binary_path() -> Result<PathBuf, &'static str> {
-        let mut p = Kaleido::root_dir()?;
-        p = p.join("kaleido").canonicalize().unwrap();
+    fn binary_path() -> Result<PathBuf, Error> {
+        let root_dir = Kaleido::root_dir()?;
+        let p = root_dir
+            .join(KALEIDO_EXECUTABLE)
+            .canonicalize()
+            .map_err(|_| Kaleido::not_found(&root_dir))?;
         if !p.exists() {
-            return Err("could not find kaleido executable in path");
+            return Err(Kaleido::not_found(&root_dir));
         }
         Ok(p)
     }
 
     #[cfg(target_os = "windows")]
-    fn binary_path() -> Result<PathBuf, &'static str> {
-        let mut p = Kaleido::root_dir()?;
-        p = p.join("kaleido.cmd");
+    fn binary_path() -> Result<PathBuf, Error> {
+        let root_dir = Kaleido::root_dir()?;
+        let p = root_dir.join(KALEIDO_EXECUTABLE);
         if !p.exists() {
-            return Err("could not find kaleido executable in path");
+            return Err(Kaleido::not_found(&root_dir));
         }
         Ok(p)
     }
 
+    /// Export `plotly_data` to an image of the given format, and write it to `dst` with the
+    /// format's extension.
+    ///
+    /// Returns an `Error::Kaleido` with Kaleido's code and message if it could not export the plot.
     pub fn save(
         &self,
         dst: &Path,
@@ -127,57 +270,389 @@ impl Kaleido {
         width: usize,
         height: usize,
         scale: f64,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<(), Error> {
         let mut dst = PathBuf::from(dst);
         dst.set_extension(format);
 
-        let p = self.cmd_path.as_path();
-        let p = p.to_str().unwrap();
-        let p = String::from(p);
+        let data = self.to_bytes(plotly_data, format, width, height, scale)?;
+        let mut file = File::create(dst.as_path())?;
+        file.write_all(&data)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Export `plotly_data` to an image of the given format, and return its contents without
+    /// writing it to disk.
+    pub fn to_bytes(
+        &self,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<Vec<u8>, Error> {
+        let image_data = self.export(plotly_data, format, width, height, scale)?;
+        decode_image(format, image_data)
+    }
+
+    /// Export `plotly_data` to an image of the given format, and return its contents encoded as
+    /// base64, e.g. to embed it in a data URI.
+    pub fn to_base64(
+        &self,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<String, Error> {
+        let image_data = self.export(plotly_data, format, width, height, scale)?;
+        Ok(encode_image(format, image_data))
+    }
+
+    /// Start a `KaleidoSession`, which keeps a single Kaleido process running to export many
+    /// plots in a row.
+    pub fn session(&self) -> KaleidoSession {
+        KaleidoSession {
+            cmd_path: self.cmd_path.clone(),
+            topojson: self.topojson.clone(),
+            process: None,
+            started: false,
+            restarts: 0,
+            max_restarts: 3,
+        }
+    }
 
-        let process = Command::new(p.as_str())
-            .current_dir(self.cmd_path.parent().unwrap())
-            .args(&[
+    fn command(cmd_path: &Path, topojson: Option<&str>) -> Command {
+        let mut command = Command::new(cmd_path);
+        command
+            .current_dir(cmd_path.parent().unwrap_or_else(|| Path::new(".")))
+            .args([
                 "plotly",
                 "--disable-gpu",
                 "--allow-file-access-from-files",
                 "--disable-breakpad",
                 "--disable-dev-shm-usage",
                 "--single-process",
-            ])
+            ]);
+        if let Some(topojson) = topojson {
+            command.arg(format!("--topojson={}", topojson));
+        }
+        command
+    }
+
+    /// Send `plot_data` to a Kaleido process and read back the image it returns, if any.
+    fn communicate(process: &mut Child, plot_data: &str) -> Result<Option<String>, Error> {
+        {
+            let mut process_stdin = process.stdin.take().expect("stdin is piped");
+            process_stdin.write_all(plot_data.as_bytes())?;
+            process_stdin.flush()?;
+        }
+
+        let mut image_data = None;
+        let stdout = process.stdout.take().expect("stdout is piped");
+        for line in BufReader::new(stdout).lines() {
+            let res = KaleidoResult::from(line?.as_str())?;
+            if res.code != 0 {
+                return Err(Error::Kaleido {
+                    code: res.code,
+                    message: res.message.unwrap_or_default(),
+                });
+            }
+            if res.result.is_some() {
+                image_data = res.result;
+            }
+        }
+        Ok(image_data)
+    }
+
+    /// Run Kaleido on `plotly_data`, returning the image exactly as Kaleido does: as text for svg
+    /// and eps, and base64-encoded for the other formats.
+    fn export(
+        &self,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<String, Error> {
+        let plot_data = PlotData::new(plotly_data, format, width, height, scale).to_json()?;
+        let mut process = Kaleido::command(&self.cmd_path, self.topojson.as_deref())
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .spawn()
-            .expect("failed to spawn Kaleido binary");
+            .spawn()?;
 
-        {
-            let plot_data = PlotData::new(plotly_data, format, width, height, scale).to_json();
-            let mut process_stdin = process.stdin.unwrap();
-            process_stdin
-                .write_all(plot_data.as_bytes())
-                .expect("couldn't write to Kaleido stdin");
-            process_stdin.flush()?;
+        let image_data = match Kaleido::communicate(&mut process, &plot_data) {
+            Ok(image_data) => image_data,
+            Err(e) => {
+                // Kaleido may still be running, e.g. after reporting an error: stop it and reap it,
+                // so that it is not left behind as a zombie process.
+                let _ = process.kill();
+                let _ = process.wait();
+                return Err(e);
+            }
+        };
+
+        let status = process.wait()?;
+        if let Some(image_data) = image_data {
+            return Ok(image_data);
+        }
+
+        // Kaleido exited without returning an image, e.g. because it crashed on startup.
+        let mut message = String::new();
+        if let Some(mut stderr) = process.stderr.take() {
+            stderr.read_to_string(&mut message)?;
+        }
+        Err(Error::Kaleido {
+            code: status.code().unwrap_or(-1),
+            message: message.trim().to_string(),
+        })
+    }
+}
+
+/// A long-lived Kaleido process, which exports plots one after the other without paying for
+/// Kaleido's startup on each of them.
+///
+/// The process is started on the first export. If it crashes, the export it was working on fails,
+/// and a new process is started for the next export, up to `max_restarts` times over the life of
+/// the session. After that, exports return an `Error::Kaleido` with code -1. It is shut down when the session is
+/// closed or dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use plotly_kaleido::Kaleido;
+/// use serde_json::json;
+/// use std::path::Path;
+///
+/// let kaleido = Kaleido::new();
+/// let mut session = kaleido.session();
+/// for i in 0..100 {
+///     let plot = json!({"data": [{"type": "bar", "y": [i, 2 * i]}], "layout": {}});
+///     let dst = format!("bar_{}", i);
+///     session.save(Path::new(&dst), &plot, "png", 800, 600, 1.0).unwrap();
+/// }
+/// session.close().unwrap();
+/// ```
+pub struct KaleidoSession {
+    cmd_path: PathBuf,
+    topojson: Option<String>,
+    process: Option<KaleidoProcess>,
+    started: bool,
+    restarts: usize,
+    max_restarts: usize,
+}
+
+impl KaleidoSession {
+    /// Sets how many times the Kaleido process is restarted after crashing, over the life of the
+    /// session. Defaults to 3.
+    pub fn max_restarts(mut self, max_restarts: usize) -> Self {
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    /// Export `plotly_data` to an image of the given format, and write it to `dst` with the
+    /// format's extension.
+    pub fn save(
+        &mut self,
+        dst: &Path,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<(), Error> {
+        let mut dst = PathBuf::from(dst);
+        dst.set_extension(format);
+
+        let data = self.to_bytes(plotly_data, format, width, height, scale)?;
+        let mut file = File::create(dst.as_path())?;
+        file.write_all(&data)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Export `plotly_data` to an image of the given format, and return its contents without
+    /// writing it to disk.
+    pub fn to_bytes(
+        &mut self,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<Vec<u8>, Error> {
+        let image_data = self.export(plotly_data, format, width, height, scale)?;
+        decode_image(format, image_data)
+    }
+
+    /// Export `plotly_data` to an image of the given format, and return its contents encoded as
+    /// base64.
+    pub fn to_base64(
+        &mut self,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<String, Error> {
+        let image_data = self.export(plotly_data, format, width, height, scale)?;
+        Ok(encode_image(format, image_data))
+    }
+
+    /// Shut down the Kaleido process, waiting for it to exit.
+    pub fn close(mut self) -> Result<(), Error> {
+        match self.process.take() {
+            Some(process) => process.close(),
+            None => Ok(()),
         }
+    }
 
-        let output_lines = BufReader::new(process.stdout.unwrap()).lines();
-        for line in output_lines.flatten() {
-            let res = KaleidoResult::from(line.as_str());
-            if let Some(image_data) = res.result {
-                let data: Vec<u8> = match format {
-                    "svg" | "eps" => image_data.as_bytes().to_vec(),
-                    _ => base64::decode(image_data).unwrap(),
-                };
-                let mut file = File::create(dst.as_path())?;
-                file.write_all(&data)?;
-                file.flush()?;
+    fn export(
+        &mut self,
+        plotly_data: &Value,
+        format: &str,
+        width: usize,
+        height: usize,
+        scale: f64,
+    ) -> Result<String, Error> {
+        let request = PlotData::new(plotly_data, format, width, height, scale).to_json()?;
+        loop {
+            let mut process = match self.process.take() {
+                Some(process) => process,
+                None => self.start()?,
+            };
+            if process.send(&request).is_err() {
+                // The process had exited before receiving the request: retry with a new one.
+                continue;
             }
+            // If the process crashes on this request, it is dropped here and restarted on the next
+            // export, rather than being fed the same request again.
+            let res = process.receive()?;
+            self.process = Some(process);
+            return res.into_image_data();
         }
+    }
 
+    fn start(&mut self) -> Result<KaleidoProcess, Error> {
+        if self.started {
+            if self.restarts >= self.max_restarts {
+                return Err(Error::Kaleido {
+                    code: -1,
+                    message: format!(
+                        "Kaleido was not restarted, as the maximum of {} restarts was reached",
+                        self.max_restarts
+                    ),
+                });
+            }
+            self.restarts += 1;
+        }
+        self.started = true;
+        KaleidoProcess::spawn(&self.cmd_path, self.topojson.as_deref())
+    }
+}
+
+/// A running Kaleido process, shut down by closing its stdin when dropped.
+struct KaleidoProcess {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    stdout: Lines<BufReader<ChildStdout>>,
+}
+
+impl KaleidoProcess {
+    fn spawn(cmd_path: &Path, topojson: Option<&str>) -> Result<KaleidoProcess, Error> {
+        let mut child = Kaleido::command(cmd_path, topojson)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            // Not piped, as nothing would read it and Kaleido would block once the pipe is full.
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        Ok(KaleidoProcess {
+            child,
+            stdin: Some(stdin),
+            stdout: BufReader::new(stdout).lines(),
+        })
+    }
+
+    /// Send one request to Kaleido, which reads one request per line.
+    fn send(&mut self, request: &str) -> io::Result<()> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .expect("stdin is open until the process is closed");
+        stdin.write_all(request.as_bytes())?;
+        stdin.write_all(b"\n")?;
+        stdin.flush()
+    }
+
+    /// Read Kaleido's response to the last request, which it writes as one line.
+    fn receive(&mut self) -> Result<KaleidoResult, Error> {
+        loop {
+            let line = self.stdout.next().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Kaleido exited before returning an image",
+                )
+            })??;
+            let res = KaleidoResult::from(line.as_str())?;
+            // Skip the message which Kaleido writes once it has started.
+            if res.code != 0 || res.result.is_some() {
+                return Ok(res);
+            }
+        }
+    }
+
+    fn close(mut self) -> Result<(), Error> {
+        self.stdin.take();
+        self.child.wait()?;
         Ok(())
     }
 }
 
+impl Drop for KaleidoProcess {
+    fn drop(&mut self) {
+        // Kaleido exits once its stdin is closed.
+        if self.stdin.take().is_some() {
+            let _ = self.child.wait();
+        }
+    }
+}
+
+impl KaleidoResult {
+    fn into_image_data(self) -> Result<String, Error> {
+        match (self.code, self.result) {
+            (0, Some(result)) => Ok(result),
+            (code, _) => Err(Error::Kaleido {
+                code,
+                message: self.message.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+/// Decode an image as returned by Kaleido: as text for svg and eps, and base64-encoded for the
+/// other formats.
+fn decode_image(format: &str, image_data: String) -> Result<Vec<u8>, Error> {
+    match format {
+        "svg" | "eps" => Ok(image_data.into_bytes()),
+        _ => base64::decode(image_data).map_err(|e| Error::Kaleido {
+            code: 0,
+            message: format!("invalid image data: {}", e),
+        }),
+    }
+}
+
+/// Encode an image as returned by Kaleido to base64.
+fn encode_image(format: &str, image_data: String) -> String {
+    match format {
+        "svg" | "eps" => base64::encode(image_data),
+        _ => image_data,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde_json::{json, to_value};
@@ -214,6 +689,44 @@ mod tests {
         .unwrap()
     }
 
+    #[test]
+    fn test_with_path() {
+        let dir = env::temp_dir().join("plotly_kaleido_test_with_path");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(KALEIDO_EXECUTABLE), "").unwrap();
+
+        let from_dir = Kaleido::with_path(&dir).unwrap();
+        let from_file = Kaleido::with_path(dir.join(KALEIDO_EXECUTABLE)).unwrap();
+        assert!(from_dir.cmd_path.ends_with(KALEIDO_EXECUTABLE));
+        assert_eq!(from_dir.cmd_path, from_file.cmd_path);
+
+        assert!(std::fs::remove_dir_all(&dir).is_ok());
+    }
+
+    #[test]
+    fn test_with_missing_path() {
+        let dir = PathBuf::from("missing_directory");
+        let err = Kaleido::with_path(&dir).err().unwrap();
+        assert!(matches!(&err, Error::Io(e) if e.kind() == io::ErrorKind::NotFound));
+        assert!(err.to_string().contains("missing_directory"));
+    }
+
+    #[test]
+    fn test_command_topojson() {
+        let args = |command: Command| {
+            command
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+        let cmd_path = Path::new("kaleido");
+
+        let without = args(Kaleido::command(cmd_path, None));
+        assert!(!without.iter().any(|arg| arg.starts_with("--topojson")));
+        let with = args(Kaleido::command(cmd_path, Some("file:///tmp/topojson/")));
+        assert_eq!(with.last().unwrap(), "--topojson=file:///tmp/topojson/");
+    }
+
     #[test]
     fn test_can_find_kaleido_executable() {
         let _k = Kaleido::new();
@@ -234,6 +747,30 @@ mod tests {
         assert_eq!(to_value(kaleido_data).unwrap(), expected);
     }
 
+    #[test]
+    fn test_kaleido_result_from() {
+        let res = KaleidoResult::from(r#"{"code": 525, "message": "Mapbox error."}"#).unwrap();
+        assert_eq!(res.code, 525);
+        assert_eq!(res.message.as_deref(), Some("Mapbox error."));
+
+        assert!(matches!(
+            KaleidoResult::from("Segmentation fault"),
+            Err(Error::Serialization(_))
+        ));
+    }
+
+    #[test]
+    fn test_error_display() {
+        let err = Error::Kaleido {
+            code: 525,
+            message: "Mapbox error.".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Kaleido failed with code 525: Mapbox error."
+        );
+    }
+
     #[test]
     fn test_save_png() {
         let test_plot = create_test_plot();
@@ -284,6 +821,92 @@ mod tests {
         assert!(std::fs::remove_file(dst.as_path()).is_ok());
     }
 
+    #[test]
+    fn test_to_bytes_png() {
+        let test_plot = create_test_plot();
+        let k = Kaleido::new();
+        let bytes = k.to_bytes(&test_plot, "png", 1200, 900, 4.5).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_to_base64_svg() {
+        let test_plot = create_test_plot();
+        let k = Kaleido::new();
+        let encoded = k.to_base64(&test_plot, "svg", 1200, 900, 4.5).unwrap();
+        let svg = String::from_utf8(base64::decode(encoded).unwrap()).unwrap();
+        assert!(svg.starts_with("<svg"));
+    }
+
+    #[test]
+    fn test_session_save_many() {
+        let test_plot = create_test_plot();
+        let mut session = Kaleido::new().session();
+        for i in 0..3 {
+            let dst = PathBuf::from(format!("example_session_{}.png", i));
+            let r = session.save(dst.as_path(), &test_plot, "png", 1200, 900, 4.5);
+            assert!(r.is_ok());
+            assert!(std::fs::remove_file(dst.as_path()).is_ok());
+        }
+        assert!(session.close().is_ok());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_export_stops_kaleido_on_error() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Reports an error, then keeps running as Kaleido may do.
+        let dir = env::temp_dir().join("plotly_kaleido_test_export_error");
+        std::fs::create_dir_all(&dir).unwrap();
+        let cmd_path = dir.join(KALEIDO_EXECUTABLE);
+        let script = "#!/bin/sh\n\
+            echo '{\"code\": 525, \"message\": \"Mapbox error.\", \"result\": null}'\n\
+            exec sleep 60\n";
+        std::fs::write(&cmd_path, script).unwrap();
+        std::fs::set_permissions(&cmd_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let kaleido = Kaleido {
+            cmd_path,
+            topojson: None,
+        };
+        let r = kaleido.to_base64(&create_test_plot(), "png", 1200, 900, 4.5);
+        assert!(matches!(r, Err(Error::Kaleido { code: 525, .. })));
+
+        assert!(std::fs::remove_dir_all(&dir).is_ok());
+    }
+
+    #[test]
+    fn test_session_gives_up_after_max_restarts() {
+        let test_plot = create_test_plot();
+        let kaleido = Kaleido {
+            cmd_path: PathBuf::from("missing_directory").join("kaleido"),
+            topojson: None,
+        };
+        let mut session = kaleido.session().max_restarts(2);
+
+        for _ in 0..3 {
+            let r = session.to_bytes(&test_plot, "png", 1200, 900, 4.5);
+            assert!(matches!(r, Err(Error::Io(_))));
+        }
+        let r = session.to_bytes(&test_plot, "png", 1200, 900, 4.5);
+        assert!(matches!(r, Err(Error::Kaleido { code: -1, .. })));
+        assert_eq!(session.restarts, 2);
+        assert!(session.close().is_ok());
+    }
+
+    #[test]
+    fn test_kaleido_result_into_image_data() {
+        let res = KaleidoResult::from(r#"{"code": 0, "result": "abc"}"#).unwrap();
+        assert_eq!(res.into_image_data().unwrap(), "abc");
+
+        let res = KaleidoResult::from(r#"{"code": 525, "message": "Mapbox error."}"#).unwrap();
+        assert!(matches!(
+            res.into_image_data(),
+            Err(Error::Kaleido { code: 525, .. })
+        ));
+    }
+
     #[test]
     #[ignore]
     fn test_save_eps() {